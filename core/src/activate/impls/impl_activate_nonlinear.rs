@@ -3,8 +3,13 @@
     Created At: 2025.12.14:11:13:15
     Contrib: @FL03
 */
-use crate::activate::{ReLUActivation, SigmoidActivation, SoftmaxActivation, TanhActivation};
-use ndarray::{Array, ArrayBase, Data, Dimension, ScalarOperand};
+use crate::activate::utils as funcs;
+use crate::activate::{
+    EluActivation, GeluActivation, GeluTanhActivation, LeakyReLUActivation, MishActivation,
+    ReLUActivation, SeluActivation, SigmoidActivation, SiluActivation, SoftmaxActivation,
+    SoftmaxAxis, SoftmaxGradient, SoftplusActivation, TanhActivation,
+};
+use ndarray::{Array, ArrayBase, Data, Dimension, RemoveAxis, ScalarOperand};
 use num_traits::{Float, One, Zero};
 
 impl<A, S, D> ReLUActivation for ArrayBase<S, D, A>
@@ -33,17 +38,13 @@ where
     type Output = Array<A, D>;
 
     fn sigmoid(self) -> Self::Output {
-        let dim = self.dim();
-        let ones = Array::<A, D>::ones(dim);
-
-        (ones + self.signum().exp()).recip()
+        // the logistic function `1 / (1 + e^{-x})`; previously computed (incorrectly) as
+        // `1 / (1 + e^{signum(x)})`
+        self.mapv(funcs::sigmoid)
     }
 
     fn sigmoid_derivative(self) -> Self::Output {
-        self.mapv(|i| {
-            let s = (A::one() + i.neg().exp()).recip();
-            s * (A::one() - s)
-        })
+        self.mapv(funcs::sigmoid_derivative)
     }
 }
 
@@ -56,8 +57,7 @@ where
     type Output = Array<A, D>;
 
     fn softmax(&self) -> Self::Output {
-        let exp = self.exp();
-        &exp / exp.sum()
+        funcs::softmax(self)
     }
 
     fn softmax_derivative(&self) -> Self::Output {
@@ -68,6 +68,33 @@ where
     }
 }
 
+impl<A, S, D> SoftmaxAxis for ArrayBase<S, D, A>
+where
+    A: Float + ScalarOperand,
+    S: Data<Elem = A>,
+    D: RemoveAxis,
+{
+    fn softmax_axis(self, axis: usize) -> Self::Output {
+        funcs::softmax_axis(&self, axis)
+    }
+}
+
+impl<A, S, T, D> SoftmaxGradient<ArrayBase<T, D, A>> for ArrayBase<S, D, A>
+where
+    A: Float + ScalarOperand,
+    S: Data<Elem = A>,
+    T: Data<Elem = A>,
+    D: RemoveAxis,
+{
+    fn softmax_vjp(&self, grad: &ArrayBase<T, D, A>) -> Self::Output {
+        funcs::softmax_vjp(self, grad)
+    }
+
+    fn softmax_axis_vjp(&self, axis: usize, grad: &ArrayBase<T, D, A>) -> Self::Output {
+        funcs::softmax_axis_vjp(self, axis, grad)
+    }
+}
+
 impl<A, S, D> TanhActivation for ArrayBase<S, D, A>
 where
     A: 'static + Float,
@@ -84,3 +111,71 @@ where
         self.mapv(|i| A::one() - i.tanh().powi(2))
     }
 }
+
+macro_rules! impl_elementwise {
+    ($($trait:ident::$call:ident),* $(,)?) => {
+        $(
+            paste::paste! {
+                impl<A, S, D> $trait for ArrayBase<S, D, A>
+                where
+                    A: Float,
+                    S: Data<Elem = A>,
+                    D: Dimension,
+                {
+                    type Output = Array<A, D>;
+
+                    fn $call(&self) -> Self::Output {
+                        self.mapv(funcs::$call)
+                    }
+
+                    fn [<$call _derivative>](&self) -> Self::Output {
+                        self.mapv(funcs::[<$call _derivative>])
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_elementwise! {
+    GeluActivation::gelu,
+    GeluTanhActivation::gelu_tanh,
+    SiluActivation::silu,
+    SeluActivation::selu,
+    SoftplusActivation::softplus,
+    MishActivation::mish,
+}
+
+impl<A, S, D> LeakyReLUActivation<A> for ArrayBase<S, D, A>
+where
+    A: Float,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    type Output = Array<A, D>;
+
+    fn leaky_relu(&self, slope: A) -> Self::Output {
+        self.mapv(|x| funcs::leaky_relu(x, slope))
+    }
+
+    fn leaky_relu_derivative(&self, slope: A) -> Self::Output {
+        self.mapv(|x| funcs::leaky_relu_derivative(x, slope))
+    }
+}
+
+impl<A, S, D> EluActivation<A> for ArrayBase<S, D, A>
+where
+    A: Float,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    type Output = Array<A, D>;
+
+    fn elu(&self, alpha: A) -> Self::Output {
+        self.mapv(|x| funcs::elu(x, alpha))
+    }
+
+    fn elu_derivative(&self, alpha: A) -> Self::Output {
+        self.mapv(|x| funcs::elu_derivative(x, alpha))
+    }
+}
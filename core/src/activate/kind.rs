@@ -0,0 +1,186 @@
+/*
+    Appellation: kind <module>
+    Created At: 2026.10.18:13:12:40
+    Contrib: @FL03
+*/
+use crate::activate::utils::heavyside;
+use crate::activate::{
+    Activator, ActivatorVjp, Elu, Gelu, GeluTanh, HeavySide, HyperbolicTangent, LeakyReLU, Linear,
+    Mish, ReLU, Selu, Sigmoid, Silu, SoftmaxLastAxis, Softplus,
+};
+use ndarray::{Array, ArrayBase, Data, RemoveAxis, ScalarOperand};
+use num_traits::Float;
+
+/// The [`ActivationKind`] enumerates each of the activation functions provided by the crate,
+/// enabling the activation of a layer to be selected at runtime (e.g. from a configuration
+/// string) or serialized alongside its parameters. The enum implements both the
+/// [`Activator`] and [`ActivatorVjp`] traits by dispatching to the corresponding activator;
+/// as with the [`SoftmaxLastAxis`] activator, the softmax is computed along the last axis.
+///
+/// Parameterized activations store their parameters as `f64`, casting them onto the element
+/// type of the input whenever the activation is applied. When parsed from a string, they
+/// assume their default parameters.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    PartialEq,
+    PartialOrd,
+    strum::AsRefStr,
+    strum::Display,
+    strum::EnumCount,
+    strum::EnumIs,
+    strum::EnumIter,
+    strum::EnumString,
+    strum::VariantNames,
+)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+#[strum(serialize_all = "snake_case")]
+#[non_exhaustive]
+pub enum ActivationKind {
+    #[default]
+    #[strum(to_string = "linear", serialize = "identity")]
+    Linear,
+    Relu,
+    Sigmoid,
+    Tanh,
+    #[strum(to_string = "heavyside", serialize = "heaviside")]
+    Heavyside,
+    Softmax,
+    Gelu,
+    GeluTanh,
+    #[strum(to_string = "silu", serialize = "swish")]
+    Silu,
+    LeakyRelu(LeakyReLU),
+    Elu(Elu),
+    Selu,
+    Softplus,
+    Mish,
+}
+
+impl ActivationKind {
+    /// returns a list of variants as strings
+    pub const fn variants() -> &'static [&'static str] {
+        use strum::VariantNames;
+        ActivationKind::VARIANTS
+    }
+    /// returns a new leaky relu activation with the given slope
+    pub const fn leaky_relu(slope: f64) -> Self {
+        Self::LeakyRelu(LeakyReLU::new(slope))
+    }
+    /// returns a new elu activation with the given alpha
+    pub const fn elu(alpha: f64) -> Self {
+        Self::Elu(Elu::new(alpha))
+    }
+}
+
+/// cast the parameter of an activation onto the element type of its input
+fn cast<A: Float>(value: f64) -> A {
+    A::from(value).expect("the parameter cannot be represented by the element type")
+}
+
+/*
+ ************* Implementations *************
+*/
+
+macro_rules! impl_from_activator {
+    ($($variant:ident($T:ty)),* $(,)?) => {
+        $(
+            impl From<$T> for ActivationKind {
+                fn from(_: $T) -> Self {
+                    Self::$variant
+                }
+            }
+        )*
+    };
+}
+
+impl_from_activator! {
+    Linear(Linear),
+    Relu(ReLU),
+    Sigmoid(Sigmoid),
+    Tanh(HyperbolicTangent),
+    Heavyside(HeavySide),
+    Softmax(SoftmaxLastAxis),
+    Gelu(Gelu),
+    GeluTanh(GeluTanh),
+    Silu(Silu),
+    Selu(Selu),
+    Softplus(Softplus),
+    Mish(Mish),
+}
+
+impl From<LeakyReLU> for ActivationKind {
+    fn from(rho: LeakyReLU) -> Self {
+        Self::LeakyRelu(rho)
+    }
+}
+
+impl From<Elu> for ActivationKind {
+    fn from(rho: Elu) -> Self {
+        Self::Elu(rho)
+    }
+}
+
+impl<A, S, D> Activator<ArrayBase<S, D, A>> for ActivationKind
+where
+    A: Float + ScalarOperand,
+    S: Data<Elem = A>,
+    D: RemoveAxis,
+{
+    type Output = Array<A, D>;
+
+    fn activate(&self, x: ArrayBase<S, D, A>) -> Self::Output {
+        match *self {
+            Self::Linear => x.to_owned(),
+            Self::Relu => ReLU.activate(x),
+            Self::Sigmoid => Sigmoid.activate(x),
+            Self::Tanh => HyperbolicTangent.activate(x),
+            Self::Heavyside => x.mapv(heavyside),
+            Self::Softmax => SoftmaxLastAxis.activate(x),
+            Self::Gelu => Gelu.activate(x),
+            Self::GeluTanh => GeluTanh.activate(x),
+            Self::Silu => Silu.activate(x),
+            Self::LeakyRelu(rho) => LeakyReLU::new(cast::<A>(rho.slope())).activate(x),
+            Self::Elu(rho) => Elu::new(cast::<A>(rho.alpha())).activate(x),
+            Self::Selu => Selu.activate(x),
+            Self::Softplus => Softplus.activate(x),
+            Self::Mish => Mish.activate(x),
+        }
+    }
+}
+
+impl<A, S, T, D> ActivatorVjp<ArrayBase<S, D, A>, ArrayBase<T, D, A>> for ActivationKind
+where
+    A: Float + ScalarOperand,
+    D: RemoveAxis,
+    S: Data<Elem = A>,
+    T: Data<Elem = A>,
+{
+    type Output = Array<A, D>;
+
+    fn activate_vjp(&self, x: ArrayBase<S, D, A>, grad: &ArrayBase<T, D, A>) -> Self::Output {
+        match *self {
+            Self::Linear => grad.to_owned(),
+            Self::Relu => ReLU.activate_vjp(x, grad),
+            Self::Sigmoid => Sigmoid.activate_vjp(x, grad),
+            Self::Tanh => HyperbolicTangent.activate_vjp(x, grad),
+            // consistent with the `HeavySide` activator
+            Self::Heavyside => x.mapv(heavyside) * grad,
+            Self::Softmax => SoftmaxLastAxis.activate_vjp(x, grad),
+            Self::Gelu => Gelu.activate_vjp(x, grad),
+            Self::GeluTanh => GeluTanh.activate_vjp(x, grad),
+            Self::Silu => Silu.activate_vjp(x, grad),
+            Self::LeakyRelu(rho) => LeakyReLU::new(cast::<A>(rho.slope())).activate_vjp(x, grad),
+            Self::Elu(rho) => Elu::new(cast::<A>(rho.alpha())).activate_vjp(x, grad),
+            Self::Selu => Selu.activate_vjp(x, grad),
+            Self::Softplus => Softplus.activate_vjp(x, grad),
+            Self::Mish => Mish.activate_vjp(x, grad),
+        }
+    }
+}
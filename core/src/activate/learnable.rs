@@ -0,0 +1,400 @@
+/*
+    Appellation: learnable <module>
+    Created At: 2026.10.18:13:48:21
+    Contrib: @FL03
+*/
+//! this module implements _parametric_ activation functions whose parameters are learned
+//! alongside the parameters of the layer they belong to.
+use crate::activate::{Activator, ActivatorGradient, sigmoid};
+use crate::optim::Optimizer;
+use concision_params::ParamsBase;
+use ndarray::{
+    Array, Array0, Array1, ArrayBase, ArrayView1, ArrayViewMut1, Axis, Data, Dimension, Ix1,
+    ScalarOperand, Zip,
+};
+use num_traits::{Float, FromPrimitive};
+
+/// The [`LearnableParameters`] trait exposes the trainable parameters of an activation as a
+/// flat, one-dimensional view so that they may be updated by any [`Optimizer`] (see
+/// [`step`](LearnableParameters::step)), maintaining its own state for the activation just as
+/// it would for the parameters of a layer.
+pub trait LearnableParameters {
+    type Elem;
+
+    /// returns a view of the trainable parameters of the activation
+    fn parameters(&self) -> ArrayView1<'_, Self::Elem>;
+    /// returns a mutable view of the trainable parameters of the activation
+    fn parameters_mut(&mut self) -> ArrayViewMut1<'_, Self::Elem>;
+    /// update the parameters of the activation using the given gradient, following the
+    /// convention of the [`Backward`](concision_traits::Backward) trait, i.e. the parameters
+    /// are incremented by `gamma * grad`
+    fn apply_parameter_gradient(&mut self, grad: &Array1<Self::Elem>, gamma: Self::Elem)
+    where
+        Self::Elem: Float,
+    {
+        self.parameters_mut()
+            .zip_mut_with(grad, |p, &g| *p = *p + gamma * g);
+    }
+    /// initialize the state of the optimizer for the parameters of the activation
+    fn init_state<O>(&self, optimizer: &O) -> O::State
+    where
+        Self::Elem: Float,
+        O: Optimizer<Self::Elem, Ix1>,
+    {
+        let bias = Array0::zeros(());
+        optimizer.init_state(&ParamsBase::new(bias.view(), self.parameters()))
+    }
+    /// update the parameters of the activation using the optimizer, given the gradient of the
+    /// loss w.r.t. the parameters (see [`Optimizer::step`])
+    fn step<O>(
+        &mut self,
+        optimizer: &O,
+        grad: &Array1<Self::Elem>,
+        state: &mut O::State,
+    ) -> crate::Result<()>
+    where
+        Self::Elem: Float,
+        O: Optimizer<Self::Elem, Ix1>,
+    {
+        // the activation has no bias; a detached scalar stands in for it
+        let (mut bias, dbias) = (Array0::zeros(()), Array0::zeros(()));
+        let mut params = ParamsBase::new(bias.view_mut(), self.parameters_mut());
+        optimizer.step(
+            &mut params,
+            &ParamsBase::new(dbias.view(), grad.view()),
+            state,
+        )
+    }
+}
+
+/// The [`LearnableActivator`] trait extends the [`ActivatorGradient`] trait for activations
+/// with trainable parameters ([`LearnableParameters`]), exposing the gradient of some
+/// objective w.r.t. both the input and the parameters of the activation. The `grad` supplied
+/// to each method is the gradient w.r.t. the _output_ of the activation.
+pub trait LearnableActivator<T>: ActivatorGradient<T> + LearnableParameters {
+    /// compute the gradient w.r.t. the input of the activation
+    fn input_gradient(&self, input: &T, grad: &T) -> Self::Delta;
+    /// compute the gradient w.r.t. the parameters of the activation, shaped like the
+    /// [`parameters`](LearnableParameters::parameters)
+    fn parameter_gradient(&self, input: &T, grad: &T) -> Array1<Self::Elem>;
+}
+
+/// The parametric rectified linear unit, or [`PReLU`], scales the negative inputs of each
+/// channel (i.e. the last axis of the input) by a learnable slope:
+///
+/// ```math
+/// f(x_c)=\begin{cases}x_c &x_c\gt{0} \\ a_c x_c &x_c\leq{0} \end{cases}
+/// ```
+///
+/// A single slope may be used to share the parameter across every channel.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub struct PReLU<A = f64> {
+    pub(crate) slopes: Array1<A>,
+}
+
+/// The [`Swish`] activation generalizes the [`Silu`](crate::activate::Silu) by introducing a
+/// learnable parameter `beta`:
+///
+/// ```math
+/// f(x) = x\sigma(\beta x)
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub struct Swish<A = f64> {
+    pub(crate) beta: A,
+}
+
+impl<A> PReLU<A> {
+    /// create a new instance with `channels` slopes, each initialized to `0.25`
+    pub fn new(channels: usize) -> Self
+    where
+        A: Clone + FromPrimitive,
+    {
+        Self::from_elem(channels, A::from_f64(0.25).unwrap())
+    }
+    /// create a new instance with `channels` slopes, each set to the given value
+    pub fn from_elem(channels: usize, slope: A) -> Self
+    where
+        A: Clone,
+    {
+        Self::from_slopes(Array1::from_elem(channels, slope))
+    }
+    /// create a new instance from the given slopes
+    pub const fn from_slopes(slopes: Array1<A>) -> Self {
+        Self { slopes }
+    }
+    /// returns the number of channels, or slopes, of the activation
+    pub fn channels(&self) -> usize {
+        self.slopes.len()
+    }
+    /// returns an immutable reference to the slopes
+    pub const fn slopes(&self) -> &Array1<A> {
+        &self.slopes
+    }
+    /// returns a mutable reference to the slopes
+    pub const fn slopes_mut(&mut self) -> &mut Array1<A> {
+        &mut self.slopes
+    }
+    /// returns the slopes broadcast to the given shape
+    fn broadcast_slopes<D>(&self, dim: D) -> ndarray::ArrayView<'_, A, D>
+    where
+        D: Dimension,
+    {
+        self.slopes
+            .broadcast(dim)
+            .expect("the slopes must either be shared or match the last axis of the input")
+    }
+    /// compute the gradient w.r.t. the input of the activation
+    pub fn input_gradient<S, T, D>(
+        &self,
+        input: &ArrayBase<S, D, A>,
+        grad: &ArrayBase<T, D, A>,
+    ) -> Array<A, D>
+    where
+        A: Float,
+        D: Dimension,
+        S: Data<Elem = A>,
+        T: Data<Elem = A>,
+    {
+        let slopes = self.broadcast_slopes(input.raw_dim());
+        Zip::from(input)
+            .and(grad)
+            .and(&slopes)
+            .map_collect(|&x, &g, &a| if x > A::zero() { g } else { a * g })
+    }
+    /// compute the gradient w.r.t. the slopes of the activation, accumulating the gradient of
+    /// each channel over every other axis of the input
+    pub fn slope_gradient<S, T, D>(
+        &self,
+        input: &ArrayBase<S, D, A>,
+        grad: &ArrayBase<T, D, A>,
+    ) -> Array1<A>
+    where
+        A: Float,
+        D: Dimension,
+        S: Data<Elem = A>,
+        T: Data<Elem = A>,
+    {
+        let local = Zip::from(input)
+            .and(grad)
+            .map_collect(|&x, &g| if x > A::zero() { A::zero() } else { x * g });
+        let channels = self.channels();
+        if channels == 1 {
+            return Array1::from_elem(1, local.sum());
+        }
+        let rows = local.len() / channels;
+        local
+            .into_shape_with_order((rows, channels))
+            .expect("the last axis of the input must match the number of channels")
+            .sum_axis(Axis(0))
+    }
+}
+
+impl<A> Swish<A> {
+    /// create a new instance with the given `beta`
+    pub const fn new(beta: A) -> Self {
+        Self { beta }
+    }
+    /// returns a copy of `beta`
+    pub const fn beta(&self) -> A
+    where
+        A: Copy,
+    {
+        self.beta
+    }
+    /// returns a mutable reference to `beta`
+    pub const fn beta_mut(&mut self) -> &mut A {
+        &mut self.beta
+    }
+    /// set the value of `beta`
+    pub fn set_beta(&mut self, beta: A) -> &mut Self {
+        self.beta = beta;
+        self
+    }
+    /// consumes the current instance to create another with the given `beta`
+    pub fn with_beta(self, beta: A) -> Self {
+        Self { beta }
+    }
+    /// compute the gradient w.r.t. the input of the activation, i.e.
+    /// `grad * (σ(βx) + βxσ(βx)(1 - σ(βx)))`
+    pub fn input_gradient<S, T, D>(
+        &self,
+        input: &ArrayBase<S, D, A>,
+        grad: &ArrayBase<T, D, A>,
+    ) -> Array<A, D>
+    where
+        A: Float,
+        D: Dimension,
+        S: Data<Elem = A>,
+        T: Data<Elem = A>,
+    {
+        Zip::from(input)
+            .and(grad)
+            .map_collect(|&x, &g| g * self.derivative(x))
+    }
+    /// compute the gradient w.r.t. `beta`, i.e. `sum(grad * x^2 σ(βx)(1 - σ(βx)))`
+    pub fn beta_gradient<S, T, D>(&self, input: &ArrayBase<S, D, A>, grad: &ArrayBase<T, D, A>) -> A
+    where
+        A: Float,
+        D: Dimension,
+        S: Data<Elem = A>,
+        T: Data<Elem = A>,
+    {
+        Zip::from(input).and(grad).fold(A::zero(), |acc, &x, &g| {
+            let s = sigmoid(self.beta * x);
+            acc + g * x * x * s * (A::one() - s)
+        })
+    }
+    /// the derivative of the activation w.r.t. its input
+    fn derivative(&self, x: A) -> A
+    where
+        A: Float,
+    {
+        let s = sigmoid(self.beta * x);
+        s + self.beta * x * s * (A::one() - s)
+    }
+}
+
+/*
+ ************* Implementations *************
+*/
+
+impl<A> Default for Swish<A>
+where
+    A: num_traits::One,
+{
+    fn default() -> Self {
+        Self::new(A::one())
+    }
+}
+
+impl<A, S, D> Activator<ArrayBase<S, D, A>> for PReLU<A>
+where
+    A: Float,
+    D: Dimension,
+    S: Data<Elem = A>,
+{
+    type Output = Array<A, D>;
+
+    fn activate(&self, input: ArrayBase<S, D, A>) -> Self::Output {
+        let slopes = self.broadcast_slopes(input.raw_dim());
+        Zip::from(&input)
+            .and(&slopes)
+            .map_collect(|&x, &a| if x > A::zero() { x } else { a * x })
+    }
+}
+
+impl<A, S, D> ActivatorGradient<ArrayBase<S, D, A>> for PReLU<A>
+where
+    A: Float,
+    D: Dimension,
+    S: Data<Elem = A>,
+{
+    type Rel = Self;
+    type Delta = Array<A, D>;
+
+    fn activate_gradient(&self, input: ArrayBase<S, D, A>) -> Self::Delta {
+        let slopes = self.broadcast_slopes(input.raw_dim());
+        Zip::from(&input)
+            .and(&slopes)
+            .map_collect(|&x, &a| if x > A::zero() { A::one() } else { a })
+    }
+}
+
+impl<A> LearnableParameters for PReLU<A> {
+    type Elem = A;
+
+    fn parameters(&self) -> ArrayView1<'_, A> {
+        self.slopes.view()
+    }
+
+    fn parameters_mut(&mut self) -> ArrayViewMut1<'_, A> {
+        self.slopes.view_mut()
+    }
+}
+
+impl<A, S, D> LearnableActivator<ArrayBase<S, D, A>> for PReLU<A>
+where
+    A: Float + ScalarOperand,
+    D: Dimension,
+    S: Data<Elem = A>,
+{
+    fn input_gradient(&self, input: &ArrayBase<S, D, A>, grad: &ArrayBase<S, D, A>) -> Self::Delta {
+        PReLU::input_gradient(self, input, grad)
+    }
+
+    fn parameter_gradient(
+        &self,
+        input: &ArrayBase<S, D, A>,
+        grad: &ArrayBase<S, D, A>,
+    ) -> Array1<A> {
+        self.slope_gradient(input, grad)
+    }
+}
+
+impl<A, S, D> Activator<ArrayBase<S, D, A>> for Swish<A>
+where
+    A: Float,
+    D: Dimension,
+    S: Data<Elem = A>,
+{
+    type Output = Array<A, D>;
+
+    fn activate(&self, input: ArrayBase<S, D, A>) -> Self::Output {
+        input.mapv(|x| x * sigmoid(self.beta * x))
+    }
+}
+
+impl<A, S, D> ActivatorGradient<ArrayBase<S, D, A>> for Swish<A>
+where
+    A: Float,
+    D: Dimension,
+    S: Data<Elem = A>,
+{
+    type Rel = Self;
+    type Delta = Array<A, D>;
+
+    fn activate_gradient(&self, input: ArrayBase<S, D, A>) -> Self::Delta {
+        input.mapv(|x| self.derivative(x))
+    }
+}
+
+impl<A> LearnableParameters for Swish<A> {
+    type Elem = A;
+
+    fn parameters(&self) -> ArrayView1<'_, A> {
+        ArrayView1::from(core::slice::from_ref(&self.beta))
+    }
+
+    fn parameters_mut(&mut self) -> ArrayViewMut1<'_, A> {
+        ArrayViewMut1::from(core::slice::from_mut(&mut self.beta))
+    }
+}
+
+impl<A, S, D> LearnableActivator<ArrayBase<S, D, A>> for Swish<A>
+where
+    A: Float,
+    D: Dimension,
+    S: Data<Elem = A>,
+{
+    fn input_gradient(&self, input: &ArrayBase<S, D, A>, grad: &ArrayBase<S, D, A>) -> Self::Delta {
+        Swish::input_gradient(self, input, grad)
+    }
+
+    fn parameter_gradient(
+        &self,
+        input: &ArrayBase<S, D, A>,
+        grad: &ArrayBase<S, D, A>,
+    ) -> Array1<A> {
+        Array1::from_elem(1, self.beta_gradient(input, grad))
+    }
+}
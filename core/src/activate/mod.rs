@@ -6,8 +6,10 @@
 //! this module provides the [`Activate`] trait alongside additional primitives and utilities
 //! for activating neurons within a neural network.
 #[doc(inline)]
-pub use self::{rho::*, traits::*, utils::*};
+pub use self::{kind::ActivationKind, learnable::*, rho::*, traits::*, utils::*};
 
+pub mod kind;
+pub mod learnable;
 pub mod rho;
 
 mod impls {
@@ -34,6 +36,8 @@ pub mod utils {
 #[doc(hidden)]
 #[allow(unused_imports)]
 pub(crate) mod prelude {
+    pub use super::kind::ActivationKind;
+    pub use super::learnable::{LearnableActivator, LearnableParameters};
     pub use super::traits::*;
     pub use super::utils::*;
 }
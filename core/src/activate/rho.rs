@@ -4,6 +4,9 @@
     Contrib: @FL03
 */
 //! this module defines _structural_ implementations of various activation functions
+use crate::activate::utils::{softmax_axis, softmax_axis_vjp, softmax_vjp};
+use ndarray::{Array, ArrayBase, Data, Dimension, RemoveAxis, ScalarOperand};
+use num_traits::Float;
 
 /// An [`Activator`] defines an interface for _structural_ activation functions that can be
 /// applied onto various types.
@@ -22,6 +25,16 @@ pub trait ActivatorGradient<T> {
     /// compute the gradient of some input
     fn activate_gradient(&self, input: T) -> Self::Delta;
 }
+/// The [`ActivatorVjp`] trait computes the vector-Jacobian product of an activation, i.e. the
+/// gradient w.r.t. its `input` given the gradient `grad` w.r.t. its output. Unlike the
+/// [`ActivatorGradient`], this accounts for activations whose outputs are coupled, such as
+/// the [`Softmax`].
+pub trait ActivatorVjp<T, G = T> {
+    type Output;
+
+    /// compute the gradient w.r.t. the input of the activation
+    fn activate_vjp(&self, input: T, grad: &G) -> Self::Output;
+}
 
 macro_rules! activator {
     ($($vis:vis struct $name:ident::<$T:ident>::$method:ident $({where $($where:tt)*})?),* $(,)?) => {
@@ -65,5 +78,206 @@ activator! {
     pub struct Sigmoid::<T>::sigmoid { where T: crate::activate::SigmoidActivation },
     pub struct HyperbolicTangent::<T>::tanh { where T: crate::activate::TanhActivation },
     pub struct HeavySide::<T>::heavyside { where T: crate::activate::HeavysideActivation },
-    pub struct Softmax::<T>::softmax { where T: crate::activate::SoftmaxActivation },
+    pub struct Gelu::<T>::gelu { where T: crate::activate::GeluActivation },
+    pub struct GeluTanh::<T>::gelu_tanh { where T: crate::activate::GeluTanhActivation },
+    pub struct Silu::<T>::silu { where T: crate::activate::SiluActivation },
+    pub struct Selu::<T>::selu { where T: crate::activate::SeluActivation },
+    pub struct Softplus::<T>::softplus { where T: crate::activate::SoftplusActivation },
+    pub struct Mish::<T>::mish { where T: crate::activate::MishActivation },
+}
+
+/// the [`ActivatorVjp`] of an elementwise activation is simply the product of its derivative
+/// and the gradient w.r.t. its output
+macro_rules! elementwise_vjp {
+    ($($name:ident$(<$T:ident>)?),* $(,)?) => {
+        $(
+            impl<A, S, T, D $(, $T)?> ActivatorVjp<ArrayBase<S, D, A>, ArrayBase<T, D, A>> for $name$(<$T>)?
+            where
+                A: Clone + core::ops::Mul<Output = A>,
+                D: Dimension,
+                S: Data<Elem = A>,
+                T: Data<Elem = A>,
+                Self: ActivatorGradient<ArrayBase<S, D, A>, Delta = Array<A, D>>,
+            {
+                type Output = Array<A, D>;
+
+                fn activate_vjp(&self, input: ArrayBase<S, D, A>, grad: &ArrayBase<T, D, A>) -> Self::Output {
+                    self.activate_gradient(input) * grad
+                }
+            }
+        )*
+    };
+}
+
+elementwise_vjp! {
+    Linear, ReLU, Sigmoid, HyperbolicTangent, HeavySide, Gelu, GeluTanh, Silu, Selu, Softplus,
+    Mish, LeakyReLU<B>, Elu<B>,
+}
+
+/// The [`Softmax`] activation normalizes every element of its input, treating the entire
+/// array as a single distribution.
+///
+/// **Note:** the outputs of the softmax are coupled, so the [`ActivatorGradient`] only yields
+/// the diagonal of its Jacobian; use the [`ActivatorVjp`] to compute its gradient. See the
+/// [`SoftmaxLastAxis`] for the softmax of each lane along the last axis.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Softmax;
+
+impl<T> Activator<T> for Softmax
+where
+    T: crate::activate::SoftmaxActivation,
+{
+    type Output = <T>::Output;
+
+    fn activate(&self, x: T) -> Self::Output {
+        x.softmax()
+    }
+}
+
+impl<T> ActivatorGradient<T> for Softmax
+where
+    T: crate::activate::SoftmaxActivation,
+{
+    type Rel = Self;
+    type Delta = <T>::Output;
+
+    #[allow(deprecated)]
+    fn activate_gradient(&self, inputs: T) -> Self::Delta {
+        inputs.softmax_derivative()
+    }
+}
+
+impl<A, S, T, D> ActivatorVjp<ArrayBase<S, D, A>, ArrayBase<T, D, A>> for Softmax
+where
+    A: Float + ScalarOperand,
+    D: Dimension,
+    S: Data<Elem = A>,
+    T: Data<Elem = A>,
+{
+    type Output = Array<A, D>;
+
+    fn activate_vjp(&self, input: ArrayBase<S, D, A>, grad: &ArrayBase<T, D, A>) -> Self::Output {
+        softmax_vjp(&input, grad)
+    }
+}
+
+/// The [`SoftmaxLastAxis`] activation normalizes its input along the last axis, treating each
+/// lane as an independent distribution. Since its outputs are coupled, the gradient of the
+/// activation is only available as a vector-Jacobian product ([`ActivatorVjp`]) rather than
+/// the elementwise [`ActivatorGradient`].
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SoftmaxLastAxis;
+
+impl<A, S, D> Activator<ArrayBase<S, D, A>> for SoftmaxLastAxis
+where
+    A: Float + ScalarOperand,
+    D: RemoveAxis,
+    S: Data<Elem = A>,
+{
+    type Output = Array<A, D>;
+
+    fn activate(&self, x: ArrayBase<S, D, A>) -> Self::Output {
+        softmax_axis(&x, x.ndim().saturating_sub(1))
+    }
+}
+
+impl<A, S, T, D> ActivatorVjp<ArrayBase<S, D, A>, ArrayBase<T, D, A>> for SoftmaxLastAxis
+where
+    A: Float + ScalarOperand,
+    D: RemoveAxis,
+    S: Data<Elem = A>,
+    T: Data<Elem = A>,
+{
+    type Output = Array<A, D>;
+
+    fn activate_vjp(&self, input: ArrayBase<S, D, A>, grad: &ArrayBase<T, D, A>) -> Self::Output {
+        softmax_axis_vjp(&input, input.ndim().saturating_sub(1), grad)
+    }
+}
+
+macro_rules! parameterized_activator {
+    ($(
+        $(#[doc = $doc:literal])*
+        $vis:vis struct $name:ident<$A:ident>($field:ident = $default:literal)::$method:ident
+            where $trait:ident
+    ),* $(,)?) => {
+        $(
+            $(#[doc = $doc])*
+            #[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+            #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+            $vis struct $name<$A = f64> {
+                pub(crate) $field: $A,
+            }
+
+            paste::paste! {
+                impl<$A> $name<$A> {
+                    #[doc = "create a new instance using the given `" $field "`"]
+                    pub const fn new($field: $A) -> Self {
+                        Self { $field }
+                    }
+                    #[doc = "returns a copy of the `" $field "` of the activation"]
+                    pub const fn $field(&self) -> $A
+                    where
+                        $A: Copy,
+                    {
+                        self.$field
+                    }
+                    #[doc = "update the `" $field "` of the activation"]
+                    pub fn [<set_ $field>](&mut self, $field: $A) -> &mut Self {
+                        self.$field = $field;
+                        self
+                    }
+                    #[doc = "consumes the current instance to create another with the given `" $field "`"]
+                    pub fn [<with_ $field>](self, $field: $A) -> Self {
+                        Self { $field }
+                    }
+                }
+
+                impl<$A> Default for $name<$A>
+                where
+                    $A: num_traits::Float,
+                {
+                    fn default() -> Self {
+                        Self::new($A::from($default).unwrap())
+                    }
+                }
+
+                impl<T, $A> Activator<T> for $name<$A>
+                where
+                    $A: Copy,
+                    T: crate::activate::$trait<$A>,
+                {
+                    type Output = T::Output;
+
+                    fn activate(&self, x: T) -> Self::Output {
+                        x.$method(self.$field)
+                    }
+                }
+
+                impl<T, $A> ActivatorGradient<T> for $name<$A>
+                where
+                    $A: Copy,
+                    T: crate::activate::$trait<$A>,
+                {
+                    type Rel = Self;
+                    type Delta = T::Output;
+
+                    fn activate_gradient(&self, inputs: T) -> Self::Delta {
+                        inputs.[<$method _derivative>](self.$field)
+                    }
+                }
+            }
+        )*
+    };
+}
+
+parameterized_activator! {
+    /// The [`LeakyReLU`] activation scales negative inputs by some `slope`; defaults to a
+    /// slope of `0.01`.
+    pub struct LeakyReLU<A>(slope = 0.01)::leaky_relu where LeakyReLUActivation,
+    /// The [`Elu`] activation, or exponential linear unit, saturates to `-alpha` for
+    /// negative inputs; defaults to an alpha of `1`.
+    pub struct Elu<A>(alpha = 1.0)::elu where EluActivation,
 }
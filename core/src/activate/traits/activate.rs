@@ -3,6 +3,7 @@
     Created At: 2026.01.12:09:50:26
     Contrib: @FL03
 */
+use crate::activate::utils as funcs;
 use concision_traits::Tanh;
 use num_traits::{Float, One, Zero};
 
@@ -41,6 +42,55 @@ pub trait Activate<T> {
     {
         self.rho(|i| i.tanh())
     }
+    /// apply the exact GELU function onto each element
+    fn gelu(&self) -> Self::Cont<T>
+    where
+        T: Float,
+    {
+        self.rho(funcs::gelu)
+    }
+    /// apply the SiLU (swish) function onto each element
+    fn silu(&self) -> Self::Cont<T>
+    where
+        T: Float,
+    {
+        self.rho(funcs::silu)
+    }
+    /// apply the leaky ReLU function, with the given slope, onto each element
+    fn leaky_relu(&self, slope: T) -> Self::Cont<T>
+    where
+        T: Float,
+    {
+        self.rho(|i| funcs::leaky_relu(i, slope))
+    }
+    /// apply the ELU function, with the given alpha, onto each element
+    fn elu(&self, alpha: T) -> Self::Cont<T>
+    where
+        T: Float,
+    {
+        self.rho(|i| funcs::elu(i, alpha))
+    }
+    /// apply the SELU function onto each element
+    fn selu(&self) -> Self::Cont<T>
+    where
+        T: Float,
+    {
+        self.rho(funcs::selu)
+    }
+    /// apply the softplus function onto each element
+    fn softplus(&self) -> Self::Cont<T>
+    where
+        T: Float,
+    {
+        self.rho(funcs::softplus)
+    }
+    /// apply the mish function onto each element
+    fn mish(&self) -> Self::Cont<T>
+    where
+        T: Float,
+    {
+        self.rho(funcs::mish)
+    }
 }
 /*
  ************* Implementations *************
@@ -8,6 +8,17 @@ pub trait SoftmaxAxis: SoftmaxActivation {
     fn softmax_axis(self, axis: usize) -> Self::Output;
 }
 
+/// The [`SoftmaxGradient`] trait computes the vector-Jacobian product of the softmax, i.e.
+/// the gradient w.r.t. its inputs given the gradient `grad` w.r.t. its outputs. Unlike
+/// `softmax_derivative`, which only yields the diagonal of the Jacobian, this accounts for
+/// the coupling between the outputs.
+pub trait SoftmaxGradient<G>: SoftmaxActivation {
+    /// the vector-Jacobian product of the softmax computed over every element
+    fn softmax_vjp(&self, grad: &G) -> Self::Output;
+    /// the vector-Jacobian product of the softmax computed along the given axis
+    fn softmax_axis_vjp(&self, axis: usize, grad: &G) -> Self::Output;
+}
+
 macro_rules! unary {
     (@impl $name:ident::$call:ident($($rest:tt)*)) => {
         paste::paste! {
@@ -31,7 +42,45 @@ unary! {
     HeavysideActivation::heavyside(self),
     LinearActivation::linear(self),
     SigmoidActivation::sigmoid(self),
-    SoftmaxActivation::softmax(&self),
     ReLUActivation::relu(&self),
     TanhActivation::tanh(&self),
+    GeluActivation::gelu(&self),
+    GeluTanhActivation::gelu_tanh(&self),
+    SiluActivation::silu(&self),
+    SeluActivation::selu(&self),
+    SoftplusActivation::softplus(&self),
+    MishActivation::mish(&self),
+}
+
+/// The [`SoftmaxActivation`] trait defines the softmax function, normalizing every element of
+/// the input.
+pub trait SoftmaxActivation {
+    type Output;
+
+    fn softmax(&self) -> Self::Output;
+
+    #[deprecated(
+        note = "the outputs of the softmax are coupled, so its elementwise derivative is not its gradient; use `SoftmaxGradient::softmax_vjp` instead"
+    )]
+    fn softmax_derivative(&self) -> Self::Output;
+}
+
+/// The [`LeakyReLUActivation`] trait defines the leaky relu function, whose negative inputs
+/// are scaled by some `slope` rather than zeroed.
+pub trait LeakyReLUActivation<A> {
+    type Output;
+
+    fn leaky_relu(&self, slope: A) -> Self::Output;
+
+    fn leaky_relu_derivative(&self, slope: A) -> Self::Output;
+}
+
+/// The [`EluActivation`] trait defines the exponential linear unit, parameterized by `alpha`
+/// which controls the value the function saturates to for negative inputs.
+pub trait EluActivation<A> {
+    type Output;
+
+    fn elu(&self, alpha: A) -> Self::Output;
+
+    fn elu_derivative(&self, alpha: A) -> Self::Output;
 }
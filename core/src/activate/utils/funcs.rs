@@ -61,9 +61,31 @@ where
     D: Dimension,
     S: Data<Elem = A>,
 {
-    let e = args.exp();
+    // subtract the maximum for numerical stability
+    let max = args.fold(A::neg_infinity(), |acc, &x| acc.max(x));
+    let e = args.mapv(|x| (x - max).exp());
     &e / e.sum()
 }
+/// the vector-Jacobian product of the [`softmax`] function; given the gradient `grad` of some
+/// objective w.r.t. the outputs `s`, computes the gradient w.r.t. the inputs:
+///
+/// ```math
+/// \frac{\partial L}{\partial x_i} = s_i\left(g_i - \sum_j g_j s_j\right)
+/// ```
+///
+/// Unlike the elementwise `softmax_derivative`, which only captures the diagonal of the
+/// Jacobian, this accounts for the coupling between each of the outputs.
+pub fn softmax_vjp<A, S, T, D>(args: &ArrayBase<S, D, A>, grad: &ArrayBase<T, D, A>) -> Array<A, D>
+where
+    A: Float + ScalarOperand,
+    D: Dimension,
+    S: Data<Elem = A>,
+    T: Data<Elem = A>,
+{
+    let s = softmax(args);
+    let dot = (&s * grad).sum();
+    s * &grad.mapv(|g| g - dot)
+}
 /// Softmax function along a specific axis:
 ///
 /// ```math
@@ -75,9 +97,42 @@ where
     D: RemoveAxis,
     S: Data<Elem = A>,
 {
-    let axis = Axis(axis);
-    let e = args.exp();
-    &e / &e.sum_axis(axis)
+    let mut out = args.to_owned();
+    // normalize each lane independently, subtracting its maximum for numerical stability
+    for mut lane in out.lanes_mut(Axis(axis)) {
+        let max = lane.fold(A::neg_infinity(), |acc, &x| acc.max(x));
+        lane.mapv_inplace(|x| (x - max).exp());
+        let sum = lane.sum();
+        lane.mapv_inplace(|x| x / sum);
+    }
+    out
+}
+/// the vector-Jacobian product of the [`softmax_axis`] function, where each lane along the
+/// given axis is treated as an independent distribution (see [`softmax_vjp`])
+pub fn softmax_axis_vjp<A, S, T, D>(
+    args: &ArrayBase<S, D, A>,
+    axis: usize,
+    grad: &ArrayBase<T, D, A>,
+) -> Array<A, D>
+where
+    A: Float + ScalarOperand,
+    D: RemoveAxis,
+    S: Data<Elem = A>,
+    T: Data<Elem = A>,
+{
+    let mut out = softmax_axis(args, axis);
+    for (mut s, g) in out
+        .lanes_mut(Axis(axis))
+        .into_iter()
+        .zip(grad.lanes(Axis(axis)))
+    {
+        let dot = s
+            .iter()
+            .zip(g.iter())
+            .fold(A::zero(), |acc, (&s, &g)| acc + s * g);
+        s.zip_mut_with(&g, |s, &g| *s = *s * (g - dot));
+    }
+    out
 }
 /// Hyperbolic tangent
 ///
@@ -125,3 +180,194 @@ where
 {
     if x > T::zero() { T::one() } else { T::zero() }
 }
+/// the [`erf`] method computes the Gauss error function using the rational approximation
+/// of Abramowitz and Stegun (7.1.26), whose absolute error is bounded by `1.5e-7`:
+///
+/// ```math
+/// \mbox{erf}(x) = \frac{2}{\sqrt{\pi}}\int_{0}^{x}e^{-t^2}dt
+/// ```
+pub fn erf<T>(x: T) -> T
+where
+    T: Float,
+{
+    let c = |v: f64| T::from(v).unwrap();
+    let sign = x.signum();
+    let x = x.abs();
+    let t = (T::one() + c(0.3275911) * x).recip();
+    let poly = t
+        * (c(0.254829592)
+            + t * (c(-0.284496736)
+                + t * (c(1.421413741) + t * (c(-1.453152027) + t * c(1.061405429)))));
+    sign * (T::one() - poly * (-x * x).exp())
+}
+/// the Gaussian Error Linear Unit (GELU) activation function:
+///
+/// ```math
+/// f(x) = x\Phi(x) = \frac{x}{2}\left(1 + \mbox{erf}\left(\frac{x}{\sqrt{2}}\right)\right)
+/// ```
+pub fn gelu<T>(x: T) -> T
+where
+    T: Float,
+{
+    let half = T::from(0.5).unwrap();
+    half * x * (T::one() + erf(x / T::from(core::f64::consts::SQRT_2).unwrap()))
+}
+/// the derivative of the [`gelu`] function, i.e. `Φ(x) + xφ(x)`
+pub fn gelu_derivative<T>(x: T) -> T
+where
+    T: Float,
+{
+    let half = T::from(0.5).unwrap();
+    let cdf = half * (T::one() + erf(x / T::from(core::f64::consts::SQRT_2).unwrap()));
+    let pdf = (-half * x * x).exp() / T::from((2.0 * core::f64::consts::PI).sqrt()).unwrap();
+    cdf + x * pdf
+}
+/// the tanh approximation of the [`gelu`] function:
+///
+/// ```math
+/// f(x) = \frac{x}{2}\left(1 + \tanh\left(\sqrt{2/\pi}(x + 0.044715x^3)\right)\right)
+/// ```
+pub fn gelu_tanh<T>(x: T) -> T
+where
+    T: Float,
+{
+    let half = T::from(0.5).unwrap();
+    let k = T::from((2.0 / core::f64::consts::PI).sqrt()).unwrap();
+    let u = k * (x + T::from(0.044715).unwrap() * x.powi(3));
+    half * x * (T::one() + u.tanh())
+}
+/// the derivative of the [`gelu_tanh`] function
+pub fn gelu_tanh_derivative<T>(x: T) -> T
+where
+    T: Float,
+{
+    let half = T::from(0.5).unwrap();
+    let (k, c) = (
+        T::from((2.0 / core::f64::consts::PI).sqrt()).unwrap(),
+        T::from(0.044715).unwrap(),
+    );
+    let t = (k * (x + c * x.powi(3))).tanh();
+    let du = k * (T::one() + T::from(3).unwrap() * c * x * x);
+    half * (T::one() + t) + half * x * (T::one() - t * t) * du
+}
+/// the Sigmoid Linear Unit (SiLU), also known as the swish function:
+///
+/// ```math
+/// f(x) = x\sigma(x)
+/// ```
+pub fn silu<T>(x: T) -> T
+where
+    T: Float,
+{
+    x * sigmoid(x)
+}
+/// the derivative of the [`silu`] function
+pub fn silu_derivative<T>(x: T) -> T
+where
+    T: Float,
+{
+    let s = sigmoid(x);
+    s * (T::one() + x * (T::one() - s))
+}
+/// the leaky relu activation function:
+///
+/// ```math
+/// f(x)=\begin{cases}x &x\gt{0} \\ \alpha x &x\leq{0} \end{cases}
+/// ```
+pub fn leaky_relu<T>(x: T, slope: T) -> T
+where
+    T: Float,
+{
+    if x > T::zero() { x } else { slope * x }
+}
+/// the derivative of the [`leaky_relu`] function
+pub fn leaky_relu_derivative<T>(x: T, slope: T) -> T
+where
+    T: Float,
+{
+    if x > T::zero() { T::one() } else { slope }
+}
+/// the Exponential Linear Unit (ELU) activation function:
+///
+/// ```math
+/// f(x)=\begin{cases}x &x\gt{0} \\ \alpha(e^x - 1) &x\leq{0} \end{cases}
+/// ```
+pub fn elu<T>(x: T, alpha: T) -> T
+where
+    T: Float,
+{
+    if x > T::zero() { x } else { alpha * x.exp_m1() }
+}
+/// the derivative of the [`elu`] function
+pub fn elu_derivative<T>(x: T, alpha: T) -> T
+where
+    T: Float,
+{
+    if x > T::zero() {
+        T::one()
+    } else {
+        alpha * x.exp()
+    }
+}
+/// the scaling factor (λ) of the [`selu`] function
+pub const SELU_LAMBDA: f64 = 1.050_700_987_355_480_5;
+/// the alpha (α) of the [`selu`] function
+pub const SELU_ALPHA: f64 = 1.673_263_242_354_377_3;
+/// the Scaled Exponential Linear Unit (SELU) activation function:
+///
+/// ```math
+/// f(x)=\lambda\begin{cases}x &x\gt{0} \\ \alpha(e^x - 1) &x\leq{0} \end{cases}
+/// ```
+pub fn selu<T>(x: T) -> T
+where
+    T: Float,
+{
+    let (lambda, alpha) = (T::from(SELU_LAMBDA).unwrap(), T::from(SELU_ALPHA).unwrap());
+    lambda * elu(x, alpha)
+}
+/// the derivative of the [`selu`] function
+pub fn selu_derivative<T>(x: T) -> T
+where
+    T: Float,
+{
+    let (lambda, alpha) = (T::from(SELU_LAMBDA).unwrap(), T::from(SELU_ALPHA).unwrap());
+    lambda * elu_derivative(x, alpha)
+}
+/// the softplus activation function, a smooth approximation of [`relu`]:
+///
+/// ```math
+/// f(x) = \ln(1 + e^x)
+/// ```
+pub fn softplus<T>(x: T) -> T
+where
+    T: Float,
+{
+    // rewritten as max(x, 0) + ln(1 + e^{-|x|}) to avoid overflowing for large inputs
+    x.max(T::zero()) + (-x.abs()).exp().ln_1p()
+}
+/// the derivative of the [`softplus`] function, i.e. the [`sigmoid`]
+pub fn softplus_derivative<T>(x: T) -> T
+where
+    T: Float,
+{
+    sigmoid(x)
+}
+/// the mish activation function:
+///
+/// ```math
+/// f(x) = x\tanh(\ln(1 + e^x))
+/// ```
+pub fn mish<T>(x: T) -> T
+where
+    T: Float,
+{
+    x * softplus(x).tanh()
+}
+/// the derivative of the [`mish`] function
+pub fn mish_derivative<T>(x: T) -> T
+where
+    T: Float,
+{
+    let t = softplus(x).tanh();
+    t + x * (T::one() - t * t) * sigmoid(x)
+}
@@ -0,0 +1,50 @@
+/*
+    Appellation: gradients <module>
+    Created At: 2026.10.18:12:24:13
+    Contrib: @FL03
+*/
+use super::{TrackedParams, Var};
+use concision_params::{Params, ParamsBase};
+use ndarray::{ArrayD, Dimension};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// The [`Gradients`] produced by a backward pass over a [`Tape`](super::Tape), storing the
+/// gradient of the output w.r.t. each recorded variable.
+#[derive(Clone, Debug, Default)]
+pub struct Gradients<A = f64> {
+    pub(crate) grads: Vec<Option<ArrayD<A>>>,
+}
+
+impl<A> Gradients<A> {
+    pub(crate) const fn new(grads: Vec<Option<ArrayD<A>>>) -> Self {
+        Self { grads }
+    }
+    /// returns the number of variables recorded by the tape
+    pub fn len(&self) -> usize {
+        self.grads.len()
+    }
+    /// returns true if no gradients were computed
+    pub fn is_empty(&self) -> bool {
+        self.grads.iter().all(Option::is_none)
+    }
+    /// returns the gradient w.r.t. the given variable, if it contributed to the output
+    pub fn get(&self, var: Var<'_, A>) -> Option<&ArrayD<A>> {
+        self.grads.get(var.id()).and_then(Option::as_ref)
+    }
+    /// returns the gradients w.r.t. the tracked parameters, shaped like the original
+    /// parameters
+    pub fn params<D>(&self, params: &TrackedParams<'_, A, D>) -> Option<Params<A, D>>
+    where
+        A: Clone,
+        D: Dimension,
+    {
+        let weights = self.get(params.weights())?.clone();
+        let bias = self.get(params.bias())?.clone();
+        Some(ParamsBase::new(
+            bias.into_dimensionality().ok()?,
+            weights.into_dimensionality().ok()?,
+        ))
+    }
+}
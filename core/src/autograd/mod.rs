@@ -0,0 +1,52 @@
+/*
+    Appellation: autograd <module>
+    Created At: 2026.10.18:12:04:26
+    Contrib: @FL03
+*/
+//! A stable, tape-based implementation of reverse-mode automatic differentiation over
+//! [`ndarray`] arrays.
+//!
+//! The [`Tape`] records every operation performed on its variables ([`Var`]) during the
+//! forward pass, including the forward pass of any tracked parameters
+//! ([`TrackedParams`]), activations from the [`activate`](crate::activate) module and the
+//! losses of the [`loss`](crate::loss) module. Calling [`backward`](Var::backward) on the
+//! final (scalar) output then walks the tape in reverse, producing the [`Gradients`] of every
+//! recorded variable, including each registered parameter.
+//!
+//! ```rust
+//! use concision_core::autograd::Tape;
+//! use concision_core::activate::ReLU;
+//! use concision_core::loss::MseLoss;
+//! use concision_core::params::Params;
+//! use concision_core::Forward;
+//! use ndarray::array;
+//!
+//! let params = Params::<f64>::ones((2, 1));
+//! let tape = Tape::new();
+//! let layer = tape.track(&params);
+//! let x = tape.constant(array![[1.0, 2.0], [3.0, -4.0]]);
+//! let y = layer.forward(&x).activate(ReLU);
+//! let loss = y.loss(&MseLoss::new(), &array![[2.0], [0.0]]);
+//! let grads = loss.backward().unwrap();
+//! let grad = grads.params(&layer).unwrap();
+//! assert_eq!(grad.weights().dim(), (2, 1));
+//! ```
+#[doc(inline)]
+pub use self::{
+    gradients::Gradients,
+    params::TrackedParams,
+    tape::Tape,
+    var::{TapeActivator, Var},
+};
+
+mod gradients;
+mod params;
+mod tape;
+mod var;
+
+pub(crate) mod prelude {
+    pub use super::gradients::Gradients;
+    pub use super::params::TrackedParams;
+    pub use super::tape::Tape;
+    pub use super::var::{TapeActivator, Var};
+}
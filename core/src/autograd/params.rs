@@ -0,0 +1,84 @@
+/*
+    Appellation: params <module>
+    Created At: 2026.10.18:12:21:38
+    Contrib: @FL03
+*/
+use super::Var;
+use crate::error::Result;
+use concision_traits::Forward;
+use core::marker::PhantomData;
+use ndarray::{Dimension, Ix2, ScalarOperand};
+use num_traits::Float;
+
+/// [`TrackedParams`] are the weights and bias of some [`ParamsBase`](concision_params::ParamsBase)
+/// that have been registered onto a [`Tape`](super::Tape). The forward pass of the tracked
+/// parameters is recorded onto the tape, computing $xW + b$ for some (batch of) input(s).
+///
+/// **Note:** this is the same transformation as the [`Forward`] implementation of the
+/// parameters, $W^T x + b$, applied onto each row of the input; a vector input produces an
+/// identical output while a matrix input is treated as a batch whose rows are the samples.
+pub struct TrackedParams<'t, A = f64, D = Ix2>
+where
+    D: Dimension,
+{
+    pub(crate) weights: Var<'t, A>,
+    pub(crate) bias: Var<'t, A>,
+    pub(crate) _dim: PhantomData<D>,
+}
+
+impl<'t, A, D> TrackedParams<'t, A, D>
+where
+    D: Dimension,
+{
+    pub(crate) const fn new(weights: Var<'t, A>, bias: Var<'t, A>) -> Self {
+        Self {
+            weights,
+            bias,
+            _dim: PhantomData,
+        }
+    }
+    /// returns the variable tracking the weights
+    pub const fn weights(&self) -> Var<'t, A> {
+        self.weights
+    }
+    /// returns the variable tracking the bias
+    pub const fn bias(&self) -> Var<'t, A> {
+        self.bias
+    }
+    /// record the forward pass of the parameters onto the tape, returning an error if the
+    /// input is neither a vector nor a matrix whose rows have as many features as the weights
+    pub fn try_forward(&self, input: &Var<'t, A>) -> Result<Var<'t, A>>
+    where
+        A: Float + ScalarOperand,
+    {
+        Ok(input.dot(self.weights)? + self.bias)
+    }
+}
+
+impl<A, D> Clone for TrackedParams<'_, A, D>
+where
+    D: Dimension,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<A, D> Copy for TrackedParams<'_, A, D> where D: Dimension {}
+
+impl<'t, A> Forward<Var<'t, A>> for TrackedParams<'t, A, Ix2>
+where
+    A: Float + ScalarOperand,
+{
+    type Output = Var<'t, A>;
+    /// record the forward pass of the parameters onto the tape
+    ///
+    /// ## Panics
+    ///
+    /// panics if the input is incompatible with the weights; use
+    /// [`try_forward`](TrackedParams::try_forward) to handle such errors
+    fn forward(&self, input: &Var<'t, A>) -> Self::Output {
+        self.try_forward(input)
+            .expect("the input is incompatible with the tracked parameters")
+    }
+}
@@ -0,0 +1,276 @@
+/*
+    Appellation: tape <module>
+    Created At: 2026.10.18:12:06:51
+    Contrib: @FL03
+*/
+use super::{Gradients, TrackedParams, Var};
+use crate::activate::softmax_axis_vjp;
+use crate::error::Result;
+use concision_params::ParamsBase;
+use core::cell::RefCell;
+use ndarray::{
+    Array2, ArrayBase, ArrayD, Axis, Data, Dimension, ErrorKind, Ix2, IxDyn, ScalarOperand,
+    ShapeError,
+};
+use num_traits::Float;
+
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+
+/// The [`Tape`] records the operations performed on its variables, enabling the gradients of
+/// some output to be computed using reverse-mode automatic differentiation.
+pub struct Tape<A = f64> {
+    pub(crate) nodes: RefCell<Vec<Node<A>>>,
+}
+
+/// A single entry on the [`Tape`]; the value computed during the forward pass alongside the
+/// operation that produced it.
+pub(crate) struct Node<A> {
+    pub(crate) value: ArrayD<A>,
+    pub(crate) op: Op<A>,
+}
+
+/// The operations supported by the [`Tape`]; each variant stores the identifiers of its
+/// inputs alongside any information required to compute their gradients.
+pub(crate) enum Op<A> {
+    /// a value that does not require a gradient
+    Constant,
+    /// a trainable parameter
+    Param,
+    Add(usize, usize),
+    Sub(usize, usize),
+    Mul(usize, usize),
+    Neg(usize),
+    Scale(usize, A),
+    MatMul(usize, usize),
+    Sum(usize),
+    Mean(usize),
+    /// an elementwise operation (e.g. an activation) whose local derivative was computed
+    /// during the forward pass
+    Elementwise {
+        input: usize,
+        derivative: ArrayD<A>,
+    },
+    /// the softmax of the input along the given axis
+    Softmax {
+        input: usize,
+        axis: usize,
+    },
+    /// a scalar loss whose gradient w.r.t. its input was computed during the forward pass
+    Loss {
+        input: usize,
+        grad: ArrayD<A>,
+    },
+}
+
+impl<A> Tape<A> {
+    /// returns a new, empty tape
+    pub const fn new() -> Self {
+        Self {
+            nodes: RefCell::new(Vec::new()),
+        }
+    }
+    /// returns the number of operations recorded onto the tape
+    pub fn len(&self) -> usize {
+        self.nodes.borrow().len()
+    }
+    /// returns true if no operations have been recorded
+    pub fn is_empty(&self) -> bool {
+        self.nodes.borrow().is_empty()
+    }
+    /// removes every entry from the tape so that it may be reused
+    pub fn clear(&mut self) {
+        self.nodes.get_mut().clear();
+    }
+    /// record a value that does not require a gradient (e.g. an input)
+    pub fn constant<S, D>(&self, value: ArrayBase<S, D, A>) -> Var<'_, A>
+    where
+        A: Clone,
+        D: Dimension,
+        S: Data<Elem = A>,
+    {
+        self.push(value.into_owned().into_dyn(), Op::Constant)
+    }
+    /// register a trainable value whose gradient will be computed during the backward pass
+    pub fn param<S, D>(&self, value: ArrayBase<S, D, A>) -> Var<'_, A>
+    where
+        A: Clone,
+        D: Dimension,
+        S: Data<Elem = A>,
+    {
+        self.push(value.into_owned().into_dyn(), Op::Param)
+    }
+    /// register the weights and bias of the given parameters onto the tape
+    pub fn track<S, D>(&self, params: &ParamsBase<S, D, A>) -> TrackedParams<'_, A, D>
+    where
+        A: Clone,
+        D: Dimension,
+        S: Data<Elem = A>,
+    {
+        TrackedParams::new(
+            self.param(params.weights().view()),
+            self.param(params.bias().view()),
+        )
+    }
+    /// push a new node onto the tape, returning its variable
+    pub(crate) fn push(&self, value: ArrayD<A>, op: Op<A>) -> Var<'_, A> {
+        let mut nodes = self.nodes.borrow_mut();
+        nodes.push(Node { value, op });
+        Var::new(self, nodes.len() - 1)
+    }
+    /// returns a copy of the value of the node with the given id
+    pub(crate) fn value(&self, id: usize) -> ArrayD<A>
+    where
+        A: Clone,
+    {
+        self.nodes.borrow()[id].value.clone()
+    }
+    /// walk the tape in reverse, starting from the given node, to compute the gradients of
+    /// each recorded variable
+    pub(crate) fn backward(&self, root: usize) -> Result<Gradients<A>>
+    where
+        A: Float + ScalarOperand,
+    {
+        let nodes = self.nodes.borrow();
+        let mut grads: Vec<Option<ArrayD<A>>> = vec![None; nodes.len()];
+        grads[root] = Some(ArrayD::ones(nodes[root].value.raw_dim()));
+        for id in (0..=root).rev() {
+            let Some(grad) = grads[id].take() else {
+                continue;
+            };
+            let value = |i: usize| &nodes[i].value;
+            match &nodes[id].op {
+                Op::Constant | Op::Param => {}
+                Op::Add(a, b) => {
+                    accumulate(&mut grads, *a, reduce_to(grad.clone(), value(*a).shape()));
+                    accumulate(&mut grads, *b, reduce_to(grad.clone(), value(*b).shape()));
+                }
+                Op::Sub(a, b) => {
+                    accumulate(&mut grads, *a, reduce_to(grad.clone(), value(*a).shape()));
+                    accumulate(
+                        &mut grads,
+                        *b,
+                        reduce_to(grad.mapv(|g| -g), value(*b).shape()),
+                    );
+                }
+                Op::Mul(a, b) => {
+                    let ga = &grad * value(*b);
+                    let gb = &grad * value(*a);
+                    accumulate(&mut grads, *a, reduce_to(ga, value(*a).shape()));
+                    accumulate(&mut grads, *b, reduce_to(gb, value(*b).shape()));
+                }
+                Op::Neg(a) => accumulate(&mut grads, *a, grad.mapv(|g| -g)),
+                Op::Scale(a, k) => accumulate(&mut grads, *a, &grad * *k),
+                Op::MatMul(a, b) => {
+                    let (ga, gb) = matmul_grad(value(*a), value(*b), &grad)?;
+                    accumulate(&mut grads, *a, ga);
+                    accumulate(&mut grads, *b, gb);
+                }
+                Op::Sum(a) => {
+                    let g = ArrayD::from_elem(value(*a).raw_dim(), grad.sum());
+                    accumulate(&mut grads, *a, g);
+                }
+                Op::Mean(a) => {
+                    let n = A::from(value(*a).len().max(1)).unwrap();
+                    let g = ArrayD::from_elem(value(*a).raw_dim(), grad.sum() / n);
+                    accumulate(&mut grads, *a, g);
+                }
+                Op::Elementwise { input, derivative } => {
+                    accumulate(&mut grads, *input, &grad * derivative);
+                }
+                Op::Softmax { input, axis } => {
+                    let g = softmax_axis_vjp(value(*input), *axis, &grad);
+                    accumulate(&mut grads, *input, g);
+                }
+                Op::Loss { input, grad: local } => {
+                    accumulate(&mut grads, *input, local * grad.sum());
+                }
+            }
+            grads[id] = Some(grad);
+        }
+        // ensure that every registered parameter has a gradient
+        for (node, grad) in nodes.iter().zip(grads.iter_mut()) {
+            if matches!(node.op, Op::Param) && grad.is_none() {
+                *grad = Some(ArrayD::zeros(node.value.raw_dim()));
+            }
+        }
+        Ok(Gradients::new(grads))
+    }
+}
+
+impl<A> Default for Tape<A> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// add the gradient onto any existing gradient of the node
+fn accumulate<A>(grads: &mut [Option<ArrayD<A>>], id: usize, grad: ArrayD<A>)
+where
+    A: Float,
+{
+    match &mut grads[id] {
+        Some(existing) => existing.zip_mut_with(&grad, |x, &g| *x = *x + g),
+        slot => *slot = Some(grad),
+    }
+}
+
+/// sum the gradient along any axes that were broadcast during the forward pass
+pub(crate) fn reduce_to<A>(grad: ArrayD<A>, shape: &[usize]) -> ArrayD<A>
+where
+    A: Float,
+{
+    let mut grad = grad;
+    while grad.ndim() > shape.len() {
+        grad = grad.sum_axis(Axis(0));
+    }
+    for (i, &n) in shape.iter().enumerate() {
+        if n == 1 && grad.shape()[i] != 1 {
+            grad = grad.sum_axis(Axis(i)).insert_axis(Axis(i));
+        }
+    }
+    grad
+}
+
+/// promote a vector to a matrix, treating it as a row (or column) vector
+fn as_matrix<A: Clone>(value: &ArrayD<A>, row: bool) -> Result<Array2<A>> {
+    let shape = match (value.ndim(), row) {
+        (1, true) => (1, value.len()),
+        (1, false) => (value.len(), 1),
+        (2, _) => (value.shape()[0], value.shape()[1]),
+        _ => return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into()),
+    };
+    Ok(value.to_shape(shape)?.into_owned())
+}
+
+/// compute the product of two (1D or 2D) arrays
+pub(crate) fn matmul<A>(a: &ArrayD<A>, b: &ArrayD<A>) -> Result<ArrayD<A>>
+where
+    A: Float + ScalarOperand,
+{
+    let (a2, b2) = (as_matrix(a, true)?, as_matrix(b, false)?);
+    if a2.ncols() != b2.nrows() {
+        return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+    }
+    let shape: Vec<usize> = a.shape()[..a.ndim() - 1]
+        .iter()
+        .chain(b.shape()[1..].iter())
+        .copied()
+        .collect();
+    Ok(a2.dot(&b2).into_shape_with_order(IxDyn(&shape))?)
+}
+
+/// compute the gradients of a matrix product w.r.t. each of its operands
+fn matmul_grad<A>(a: &ArrayD<A>, b: &ArrayD<A>, grad: &ArrayD<A>) -> Result<(ArrayD<A>, ArrayD<A>)>
+where
+    A: Float + ScalarOperand,
+{
+    let (a2, b2) = (as_matrix(a, true)?, as_matrix(b, false)?);
+    let g2 = grad.to_shape(Ix2(a2.nrows(), b2.ncols()))?;
+    let ga = g2.dot(&b2.t());
+    let gb = a2.t().dot(&g2);
+    Ok((
+        ga.to_shape(a.raw_dim())?.into_owned(),
+        gb.to_shape(b.raw_dim())?.into_owned(),
+    ))
+}
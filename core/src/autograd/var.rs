@@ -0,0 +1,311 @@
+/*
+    Appellation: var <module>
+    Created At: 2026.10.18:12:15:09
+    Contrib: @FL03
+*/
+use super::tape::{Node, Op, matmul};
+use super::{Gradients, Tape};
+use crate::activate::{
+    ActivationKind, Activator, ActivatorGradient, ActivatorVjp, SoftmaxLastAxis, softmax_axis,
+};
+use crate::error::Result;
+use concision_traits::{Loss, LossGradient};
+use ndarray::{ArrayBase, ArrayD, Data, Dimension, ScalarOperand};
+use num_traits::Float;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// A [`Var`] is a handle to a value recorded onto a [`Tape`]; operations performed on the
+/// variable are recorded onto the same tape so that their gradients may be computed.
+pub struct Var<'t, A = f64> {
+    pub(crate) tape: &'t Tape<A>,
+    pub(crate) id: usize,
+}
+
+impl<'t, A> Var<'t, A> {
+    pub(crate) const fn new(tape: &'t Tape<A>, id: usize) -> Self {
+        Self { tape, id }
+    }
+    /// returns the position of the variable on its tape
+    pub const fn id(&self) -> usize {
+        self.id
+    }
+    /// returns a reference to the tape the variable was recorded onto
+    pub const fn tape(&self) -> &'t Tape<A> {
+        self.tape
+    }
+    /// returns a copy of the value of the variable
+    pub fn value(&self) -> ArrayD<A>
+    where
+        A: Clone,
+    {
+        self.tape.value(self.id)
+    }
+    /// returns the shape of the variable
+    pub fn shape(&self) -> Vec<usize> {
+        self.tape.nodes.borrow()[self.id].value.shape().to_vec()
+    }
+    /// record a binary operation between two variables of the same tape
+    fn binary<F>(self, rhs: Self, f: F, op: fn(usize, usize) -> Op<A>) -> Self
+    where
+        A: Clone,
+        F: FnOnce(&ArrayD<A>, &ArrayD<A>) -> ArrayD<A>,
+    {
+        let value = {
+            let nodes = self.nodes_with(&rhs);
+            f(&nodes[self.id].value, &nodes[rhs.id].value)
+        };
+        self.tape.push(value, op(self.id, rhs.id))
+    }
+    /// record a fallible binary operation between two variables of the same tape
+    fn try_binary<F>(self, rhs: Self, f: F, op: fn(usize, usize) -> Op<A>) -> Result<Self>
+    where
+        A: Clone,
+        F: FnOnce(&ArrayD<A>, &ArrayD<A>) -> Result<ArrayD<A>>,
+    {
+        let value = {
+            let nodes = self.nodes_with(&rhs);
+            f(&nodes[self.id].value, &nodes[rhs.id].value)?
+        };
+        Ok(self.tape.push(value, op(self.id, rhs.id)))
+    }
+    /// borrow the nodes of the tape shared by the variable and another
+    fn nodes_with(&self, rhs: &Self) -> core::cell::Ref<'t, Vec<Node<A>>> {
+        assert!(
+            core::ptr::eq(self.tape, rhs.tape),
+            "variables must be recorded onto the same tape"
+        );
+        self.tape.nodes.borrow()
+    }
+    /// record a unary operation on the variable
+    fn unary<F>(self, f: F, op: Op<A>) -> Self
+    where
+        F: FnOnce(&ArrayD<A>) -> ArrayD<A>,
+    {
+        let value = f(&self.tape.nodes.borrow()[self.id].value);
+        self.tape.push(value, op)
+    }
+    /// compute the product of the variable and another, where both are either vectors or
+    /// matrices; returns an error if either operand has more than two dimensions or their
+    /// inner dimensions differ
+    pub fn dot(self, rhs: Self) -> Result<Self>
+    where
+        A: Float + ScalarOperand,
+    {
+        self.try_binary(rhs, matmul, Op::MatMul)
+    }
+    /// multiply each element of the variable by some scalar
+    pub fn scale(self, factor: A) -> Self
+    where
+        A: Float + ScalarOperand,
+    {
+        self.unary(|x| x * factor, Op::Scale(self.id, factor))
+    }
+    /// sum every element of the variable, producing a scalar
+    pub fn sum(self) -> Self
+    where
+        A: Float,
+    {
+        self.unary(
+            |x| ArrayD::from_elem(ndarray::IxDyn(&[]), x.sum()),
+            Op::Sum(self.id),
+        )
+    }
+    /// average every element of the variable, producing a scalar
+    pub fn mean(self) -> Self
+    where
+        A: Float + num_traits::FromPrimitive,
+    {
+        self.unary(
+            |x| ArrayD::from_elem(ndarray::IxDyn(&[]), x.mean().unwrap_or_else(A::zero)),
+            Op::Mean(self.id),
+        )
+    }
+    /// apply the activation function onto the variable; elementwise activations record their
+    /// derivative while the [`SoftmaxLastAxis`] is recorded along the last axis using its full
+    /// Jacobian (see [`TapeActivator`])
+    pub fn activate<F>(self, rho: F) -> Self
+    where
+        F: TapeActivator<A>,
+    {
+        rho.record(self)
+    }
+    /// apply an elementwise activation onto the variable, recording its derivative
+    fn elementwise<F>(self, rho: &F) -> Self
+    where
+        A: Clone,
+        F: Activator<ArrayD<A>, Output = ArrayD<A>>
+            + ActivatorGradient<ArrayD<A>, Delta = ArrayD<A>>,
+    {
+        let input = self.value();
+        let derivative = rho.activate_gradient(input.clone());
+        self.tape.push(
+            rho.activate(input),
+            Op::Elementwise {
+                input: self.id,
+                derivative,
+            },
+        )
+    }
+    /// apply the softmax function along the given axis; unlike [`activate`](Self::activate),
+    /// the gradient accounts for the full Jacobian of the softmax
+    pub fn softmax(self, axis: usize) -> Self
+    where
+        A: Float + ScalarOperand,
+    {
+        self.unary(
+            |x| softmax_axis(x, axis),
+            Op::Softmax {
+                input: self.id,
+                axis,
+            },
+        )
+    }
+    /// compute the loss between the variable, treated as the predictions, and the target
+    pub fn loss<L, S, D>(self, loss: &L, target: &ArrayBase<S, D, A>) -> Self
+    where
+        A: Clone,
+        D: Dimension,
+        S: Data<Elem = A>,
+        L: Loss<ArrayD<A>, ArrayD<A>, Output = A>
+            + LossGradient<ArrayD<A>, ArrayD<A>, Grad = ArrayD<A>>,
+    {
+        let pred = self.value();
+        let target = target.to_owned().into_dyn();
+        let (value, grad) = loss.loss_with_gradient(&pred, &target);
+        self.tape.push(
+            ArrayD::from_elem(ndarray::IxDyn(&[]), value),
+            Op::Loss {
+                input: self.id,
+                grad,
+            },
+        )
+    }
+    /// compute the gradients of the variable w.r.t. every variable recorded before it
+    pub fn backward(self) -> Result<Gradients<A>>
+    where
+        A: Float + ScalarOperand,
+    {
+        self.tape.backward(self.id)
+    }
+}
+
+/*
+ ************* Implementations *************
+*/
+
+impl<A> Clone for Var<'_, A> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<A> Copy for Var<'_, A> {}
+
+impl<A> core::fmt::Debug for Var<'_, A> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Var").field("id", &self.id).finish()
+    }
+}
+
+impl<'t, A> core::ops::Add for Var<'t, A>
+where
+    A: Float,
+{
+    type Output = Var<'t, A>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.binary(rhs, |a, b| a + b, Op::Add)
+    }
+}
+
+impl<'t, A> core::ops::Sub for Var<'t, A>
+where
+    A: Float,
+{
+    type Output = Var<'t, A>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.binary(rhs, |a, b| a - b, Op::Sub)
+    }
+}
+
+impl<'t, A> core::ops::Mul for Var<'t, A>
+where
+    A: Float,
+{
+    type Output = Var<'t, A>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.binary(rhs, |a, b| a * b, Op::Mul)
+    }
+}
+
+impl<'t, A> core::ops::Neg for Var<'t, A>
+where
+    A: Float,
+{
+    type Output = Var<'t, A>;
+
+    fn neg(self) -> Self::Output {
+        self.unary(|x| x.mapv(|v| -v), Op::Neg(self.id))
+    }
+}
+
+/// The [`TapeActivator`] trait describes how an activation is recorded onto the [`Tape`] of a
+/// [`Var`]. Elementwise activations, i.e. those implementing the [`ActivatorGradient`],
+/// record their local derivative whereas the [`SoftmaxLastAxis`] records the softmax along the
+/// last axis so that its gradient accounts for the full Jacobian.
+///
+/// **Note:** the whole-array [`Softmax`](crate::activate::Softmax) is recorded through its
+/// [`ActivatorGradient`], i.e. using only the diagonal of its Jacobian.
+pub trait TapeActivator<A> {
+    /// apply the activation onto the variable, recording it onto its tape
+    fn record<'t>(&self, var: Var<'t, A>) -> Var<'t, A>;
+}
+
+impl<A, F> TapeActivator<A> for F
+where
+    A: Clone,
+    F: Activator<ArrayD<A>, Output = ArrayD<A>> + ActivatorGradient<ArrayD<A>, Delta = ArrayD<A>>,
+{
+    fn record<'t>(&self, var: Var<'t, A>) -> Var<'t, A> {
+        var.elementwise(self)
+    }
+}
+
+impl<A> TapeActivator<A> for SoftmaxLastAxis
+where
+    A: Float + ScalarOperand,
+{
+    fn record<'t>(&self, var: Var<'t, A>) -> Var<'t, A> {
+        let axis = var.shape().len().saturating_sub(1);
+        var.softmax(axis)
+    }
+}
+
+impl<A> TapeActivator<A> for ActivationKind
+where
+    A: Float + ScalarOperand,
+{
+    fn record<'t>(&self, var: Var<'t, A>) -> Var<'t, A> {
+        match self {
+            Self::Softmax => SoftmaxLastAxis.record(var),
+            kind => {
+                let input = var.value();
+                // every other activation is elementwise; its derivative is recovered as the
+                // vector-Jacobian product of a unit gradient
+                let ones = ArrayD::ones(input.raw_dim());
+                let derivative = kind.activate_vjp(input.view(), &ones);
+                var.tape.push(
+                    kind.activate(input),
+                    Op::Elementwise {
+                        input: var.id,
+                        derivative,
+                    },
+                )
+            }
+        }
+    }
+}
@@ -4,6 +4,7 @@
 */
 use super::HyperParam;
 use super::{ExtendedModelConfig, ModelConfiguration, RawConfig};
+use crate::activate::ActivationKind;
 use alloc::string::{String, ToString};
 use hashbrown::DefaultHashBuilder;
 use hashbrown::hash_map::{self, HashMap};
@@ -16,6 +17,8 @@ use hashbrown::hash_map::{self, HashMap};
     serde(rename = "snake_case")
 )]
 pub struct StandardModelConfig<T> {
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) activation: Option<ActivationKind>,
     pub batch_size: usize,
     pub epochs: usize,
     pub hyperspace: HashMap<String, T>,
@@ -24,11 +27,20 @@ pub struct StandardModelConfig<T> {
 impl<T> StandardModelConfig<T> {
     pub fn new() -> Self {
         Self {
+            activation: None,
             batch_size: 0,
             epochs: 0,
             hyperspace: HashMap::new(),
         }
     }
+    /// returns a copy of the activation of the model, if any
+    pub const fn activation(&self) -> Option<ActivationKind> {
+        self.activation
+    }
+    /// returns a mutable reference to the activation of the model
+    pub const fn activation_mut(&mut self) -> &mut Option<ActivationKind> {
+        &mut self.activation
+    }
     /// returns a copy of the batch size
     pub const fn batch_size(&self) -> usize {
         self.batch_size
@@ -80,6 +92,11 @@ impl<T> StandardModelConfig<T> {
     {
         self.hyperparameters_mut().remove(key)
     }
+    /// sets the activation, returning a mutable reference to the current instance
+    pub fn set_activation(&mut self, activation: ActivationKind) -> &mut Self {
+        self.activation = Some(activation);
+        self
+    }
     /// sets the batch size, returning a mutable reference to the current instance
     pub fn set_batch_size(&mut self, batch_size: usize) -> &mut Self {
         self.batch_size = batch_size;
@@ -90,6 +107,13 @@ impl<T> StandardModelConfig<T> {
         self.epochs = epochs;
         self
     }
+    /// consumes the current instance to create another with the given activation
+    pub fn with_activation(self, activation: ActivationKind) -> Self {
+        Self {
+            activation: Some(activation),
+            ..self
+        }
+    }
     /// consumes the current instance to create another with the given batch size
     pub fn with_batch_size(self, batch_size: usize) -> Self {
         Self { batch_size, ..self }
@@ -103,10 +127,22 @@ impl<T> StandardModelConfig<T> {
 use HyperParam::*;
 
 impl<T> StandardModelConfig<T> {
+    /// sets the first-moment decay rate (`beta1`), returning the previous value if it exists
+    pub fn set_beta1(&mut self, beta1: T) -> Option<T> {
+        self.add_parameter(Beta1, beta1)
+    }
+    /// sets the second-moment decay rate (`beta2`), returning the previous value if it exists
+    pub fn set_beta2(&mut self, beta2: T) -> Option<T> {
+        self.add_parameter(Beta2, beta2)
+    }
     /// sets the decay hyperparameter, returning the previous value if it exists
     pub fn set_decay(&mut self, decay: T) -> Option<T> {
         self.add_parameter(Decay, decay)
     }
+    /// sets the epsilon hyperparameter, returning the previous value if it exists
+    pub fn set_epsilon(&mut self, epsilon: T) -> Option<T> {
+        self.add_parameter(Epsilon, epsilon)
+    }
     pub fn set_learning_rate(&mut self, learning_rate: T) -> Option<T> {
         self.add_parameter(LearningRate, learning_rate)
     }
@@ -130,6 +166,18 @@ impl<T> StandardModelConfig<T> {
     pub fn decay(&self) -> Option<&T> {
         self.get("decay")
     }
+    /// returns a reference to the epsilon hyperparameter, if it exists
+    pub fn epsilon(&self) -> Option<&T> {
+        self.get("epsilon")
+    }
+    /// returns a reference to the `beta1` hyperparameter, if it exists
+    pub fn beta1(&self) -> Option<&T> {
+        self.get("beta1")
+    }
+    /// returns a reference to the `beta2` hyperparameter, if it exists
+    pub fn beta2(&self) -> Option<&T> {
+        self.get("beta2")
+    }
     /// returns a reference to the weight decay hyperparameter, if it exists
     pub fn weight_decay(&self) -> Option<&T> {
         self.get("weight_decay")
@@ -9,7 +9,7 @@
 pub type Result<T> = core::result::Result<T, Error>;
 
 #[cfg(feature = "alloc")]
-use alloc::{boxed::Box, string::String};
+use alloc::{boxed::Box, string::String, vec::Vec};
 /// The [`Error`] type enumerates various errors that can occur within the framework.
 #[derive(Debug, thiserror::Error)]
 #[non_exhaustive]
@@ -20,6 +20,8 @@ pub enum Error {
     InvalidModelConfig,
     #[error("The model is not supported for the given input")]
     IncompatibleInput,
+    #[error("The weights of a loss must be finite, non-negative and have a positive sum")]
+    InvalidLossWeights,
     #[error("An invalid batch size was provided: {0}")]
     InvalidBatchSize(usize),
     #[error("Input is incompatible with the model: found {0} and expected {1}")]
@@ -36,6 +38,40 @@ pub enum Error {
     #[cfg(feature = "alloc")]
     #[error("An unsupported operation was attempted: {0}")]
     UnsupportedOperation(String),
+    #[error("The data does not describe a valid checkpoint")]
+    InvalidCheckpoint,
+    #[error("Unsupported checkpoint format version: found {0} and expected at most {1}")]
+    InvalidCheckpointVersion(u32, u32),
+    #[error("The checkpoint stores elements of type `{0}` but `{1}` was requested")]
+    InvalidCheckpointDtype(crate::models::Dtype, crate::models::Dtype),
+    #[error("The checkpoint has {0} hidden layers but its layout expects {1}")]
+    InvalidCheckpointLayers(usize, usize),
+    #[cfg(feature = "alloc")]
+    #[error(
+        "The {param} of the `{layer}` layer have shape {found:?} but the layout expects {expected:?}"
+    )]
+    InvalidCheckpointShape {
+        layer: String,
+        param: &'static str,
+        found: Vec<usize>,
+        expected: Vec<usize>,
+    },
+    #[cfg(feature = "alloc")]
+    #[error("Invalid safetensors data: {0}")]
+    InvalidSafeTensors(String),
+    #[cfg(feature = "alloc")]
+    #[error("The tensor `{0}` could not be found")]
+    TensorNotFound(String),
+    #[cfg(feature = "alloc")]
+    #[error("The tensor `{name}` stores elements of type {found} but {expected} was requested")]
+    InvalidTensorDtype {
+        name: String,
+        found: String,
+        expected: String,
+    },
+    #[cfg(feature = "alloc")]
+    #[error("The data of the tensor `{0}` is not aligned to its element type")]
+    UnalignedTensor(String),
     #[error("Parameter Error")]
     ParameterError(String),
     #[error(transparent)]
@@ -56,6 +92,9 @@ pub enum Error {
     #[cfg(feature = "serde_json")]
     #[error(transparent)]
     JsonError(#[from] serde_json::Error),
+    #[cfg(feature = "postcard")]
+    #[error(transparent)]
+    PostcardError(#[from] postcard::Error),
     #[cfg(feature = "std")]
     #[error(transparent)]
     IoError(#[from] std::io::Error),
@@ -2,11 +2,11 @@
     appellation: model <test>
     authors: @FL03
 */
-use crate::activate::{ReLUActivation, SigmoidActivation};
+use crate::activate::{ActivationKind, ReLUActivation, SigmoidActivation};
 use crate::config::StandardModelConfig;
 use crate::error::Error;
 use crate::models::{DeepModelParams, ModelFeatures};
-use crate::nn::Model;
+use crate::nn::{ForwardWith, Model, RawContext};
 #[cfg(feature = "rand")]
 use concision_init::{
     NdRandom,
@@ -35,7 +35,7 @@ impl<T> TestModel<T> {
     where
         T: Clone + Zero,
     {
-        let store = DeepModelParams::zeros(features);
+        let store = DeepModelParams::zeros(features.clone());
         TestModel {
             config,
             features,
@@ -47,8 +47,8 @@ impl<T> TestModel<T> {
         &self.config
     }
     /// returns a reference to the model layout
-    pub const fn features(&self) -> ModelFeatures {
-        self.features
+    pub const fn features(&self) -> &ModelFeatures {
+        &self.features
     }
     /// returns a reference to the model params
     pub const fn store(&self) -> &DeepModelParams<T> {
@@ -74,17 +74,11 @@ impl<T> TestModel<T> {
             config,
             features,
         } = self;
-        store.set_input(Params::<T>::lecun_normal((
-            features.input(),
-            features.hidden(),
-        )));
-        for layer in store.hidden_mut() {
-            *layer = Params::<T>::lecun_normal((features.hidden(), features.hidden()));
+        store.set_input(Params::<T>::lecun_normal(features.dim_input()));
+        for (i, layer) in store.hidden_mut().iter_mut().enumerate() {
+            *layer = Params::<T>::lecun_normal(features.dim_hidden_at(i));
         }
-        store.set_output(Params::<T>::lecun_normal((
-            features.hidden(),
-            features.output(),
-        )));
+        store.set_output(Params::<T>::lecun_normal(features.dim_output()));
         TestModel {
             config,
             features,
@@ -117,6 +111,15 @@ impl<T> Model<T> for TestModel<T> {
     fn params_mut(&mut self) -> &mut DeepModelParams<T> {
         &mut self.store
     }
+    /// every layer uses the ReLU activation, except for the output layer which uses the
+    /// sigmoid
+    fn activation(&self, layer: usize) -> Option<ActivationKind> {
+        match layer {
+            i if i + 1 == self.store.layers() => Some(ActivationKind::Sigmoid),
+            i if i < self.store.layers() => Some(ActivationKind::Relu),
+            _ => None,
+        }
+    }
 }
 
 impl<A, S, D> Forward<ArrayBase<S, D, A>> for TestModel<A>
@@ -141,6 +144,21 @@ where
     }
 }
 
+impl<A, S, D, C> ForwardWith<ArrayBase<S, D, A>, C> for TestModel<A>
+where
+    C: RawContext,
+    Self: Forward<ArrayBase<S, D, A>>,
+    D: Dimension,
+    S: Data<Elem = A>,
+{
+    type Output = <Self as Forward<ArrayBase<S, D, A>>>::Output;
+    /// the model does not contain any stochastic layers, hence behaves identically in every
+    /// mode of execution
+    fn forward_with(&self, input: &ArrayBase<S, D, A>, _ctx: &mut C) -> Self::Output {
+        self.forward(input)
+    }
+}
+
 impl<A, S, T> Train<ArrayBase<S, Ix1>, ArrayBase<T, Ix1>> for TestModel<A>
 where
     A: Float + FromPrimitive + NumAssign + ScalarOperand + core::fmt::Debug,
@@ -201,10 +219,12 @@ where
         let mut delta = error * output.sigmoid_derivative();
         delta /= delta.l2_norm(); // Normalize the delta to prevent exploding gradients
 
-        // Update output weights
-        self.store_mut()
-            .output_mut()
-            .backward(activations.last().unwrap(), &delta, lr);
+        // Update output weights, leaving any frozen layers unchanged
+        if !self.is_frozen(self.store().layers() - 1) {
+            self.store_mut()
+                .output_mut()
+                .backward(activations.last().unwrap(), &delta, lr);
+        }
 
         let num_hidden = self.layout().layers();
         // Iterate through hidden layers in reverse order
@@ -220,7 +240,9 @@ where
             };
             // Normalize delta to prevent exploding gradients
             delta /= delta.l2_norm();
-            self.store_mut().hidden_mut()[i].backward(&activations[i + 1], &delta, lr);
+            if !self.is_frozen(i + 1) {
+                self.store_mut().hidden_mut()[i].backward(&activations[i + 1], &delta, lr);
+            }
         }
         /*
             The delta for the input layer is computed using the weights of the first hidden layer
@@ -228,9 +250,11 @@ where
         */
         delta = self.store().hidden()[0].weights().dot(&delta) * activations[1].relu_derivative();
         delta /= delta.l2_norm(); // Normalize the delta to prevent exploding gradients
-        self.store_mut()
-            .input_mut()
-            .backward(&activations[1], &delta, lr);
+        if !self.is_frozen(0) {
+            self.store_mut()
+                .input_mut()
+                .backward(&activations[1], &delta, lr);
+        }
 
         Ok(loss)
     }
@@ -0,0 +1,20 @@
+/*
+    Appellation: testing <module>
+    Created At: 2026.10.18:23:05:12
+    Contrib: @FL03
+*/
+//! fixtures shared by the tests of the workspace
+use ndarray::{Array, Dimension, ShapeBuilder};
+
+/// generate a deterministic, non-trivial array of the given shape
+pub fn sample<D, Sh>(shape: Sh, seed: f64) -> Array<f64, D>
+where
+    D: Dimension,
+    Sh: ShapeBuilder<Dim = D>,
+{
+    let mut k = 0.0;
+    Array::from_shape_simple_fn(shape, || {
+        k += 1.0;
+        (k * 0.37 + seed).sin()
+    })
+}
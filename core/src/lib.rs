@@ -11,6 +11,7 @@
 //! their needs.
 //!
 //! - `utils`: Provides various utilities for developing machine learning models.
+//! - `safetensors`: Enables reading and writing parameters using the safetensors format.
 //!
 //! ### Dependency-specific Features
 //!
@@ -20,6 +21,8 @@
 //! - `approx`: Enables approximate equality checks for floating-point numbers.
 //! - `complex`: Enables complex number support.
 //! - `json`: Enables JSON serialization and deserialization capabilities.
+//! - `memmap2`: Enables memory-mapping safetensors files for zero-copy loading.
+//! - `postcard`: Enables the compact binary encoding of checkpoints.
 //! - `rand`: Enables random number generation
 //! - `serde`: Enables serialization and deserialization capabilities.
 //! - `tracing`: Enables tracing capabilities for debugging and logging.
@@ -60,15 +63,19 @@ pub(crate) mod macros {
 }
 
 pub mod activate;
+pub mod autograd;
 pub mod config;
 pub mod error;
+pub mod loss;
 pub mod models;
 pub mod nn;
+pub mod optim;
 pub mod utils;
 
 #[doc(hidden)]
 pub mod ex {
     pub mod sample;
+    pub mod testing;
 }
 
 mod types {
@@ -80,11 +87,13 @@ mod types {
 // re-exports
 #[doc(inline)]
 pub use self::{
-    activate::{Activate, Activator, ActivatorGradient},
+    activate::{Activate, ActivationKind, Activator, ActivatorGradient},
     config::StandardModelConfig,
     error::*,
+    loss::prelude::*,
     models::prelude::*,
     nn::prelude::*,
+    optim::prelude::*,
     types::*,
     utils::*,
 };
@@ -104,9 +113,12 @@ pub mod prelude {
     pub use concision_params::prelude::*;
     pub use concision_traits::prelude::*;
 
+    pub use crate::autograd::prelude::*;
     pub use crate::config::prelude::*;
+    pub use crate::loss::prelude::*;
     pub use crate::models::prelude::*;
     pub use crate::nn::prelude::*;
+    pub use crate::optim::prelude::*;
     pub use crate::types::*;
     pub use crate::utils::*;
 }
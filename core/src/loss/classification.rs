@@ -0,0 +1,422 @@
+/*
+    Appellation: classification <module>
+    Created At: 2026.10.18:11:18:05
+    Contrib: @FL03
+*/
+use super::reduction::{Mean, Reduction};
+use super::{ElementwiseLoss, elementwise_loss, validate_weights};
+use crate::activate::{Activator, ActivatorGradient};
+use crate::error::Result;
+use concision_traits::{Loss, LossGradient};
+use ndarray::{Array, Array1, ArrayBase, Axis, Data, RemoveAxis};
+use num_traits::Float;
+
+/// The [`BinaryCrossEntropy`] loss measures the error between predicted probabilities and
+/// binary (or soft) targets:
+///
+/// ```math
+/// \ell(p, y) = -w\left(y\ln p + (1 - y)\ln(1 - p)\right)
+/// ```
+///
+/// The predictions are clamped to the interval $[\epsilon, 1 - \epsilon]$ to keep the loss
+/// finite, while the optional weights are broadcast along the last axis of the predictions;
+/// the [`Mean`] of a weighted loss is normalized by the total weight of its terms.
+///
+/// ## Panics
+///
+/// the loss and its gradient panic if the number of weights differs from the length of the
+/// last axis of the predictions
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub struct BinaryCrossEntropy<A = f64, R = Mean> {
+    pub(crate) weights: Option<Array1<A>>,
+    pub(crate) reduction: R,
+}
+
+/// The [`CrossEntropyLoss`] computes the categorical cross-entropy between the _logits_ of a
+/// prediction and a target distribution (e.g. one-hot encoded labels) over the last axis:
+///
+/// ```math
+/// \ell(z, y) = -\sum_c w_c y_c \ln\mathrm{softmax}(z)_c
+/// ```
+///
+/// The softmax is computed internally using the log-sum-exp trick, making the loss stable
+/// for large logits; consequently the gradient w.r.t. the logits is simply
+/// $\mathrm{softmax}(z) - y$ when the loss is unweighted. The loss is computed per-sample,
+/// meaning that the [`Mean`] reduction averages over the samples rather than each element;
+/// as with the standard weighted cross-entropy, the [`Mean`] of a weighted loss is normalized
+/// by the total weight of the samples, $\sum_i \sum_c w_c y_{ic}$, rather than their number.
+///
+/// ## Panics
+///
+/// the loss and its gradient panic if the number of class weights differs from the length of
+/// the last axis of the targets
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub struct CrossEntropyLoss<A = f64, R = Mean> {
+    pub(crate) weights: Option<Array1<A>>,
+    pub(crate) reduction: R,
+}
+
+/// The [`SoftmaxCrossEntropy`] fuses the softmax activation with the [`CrossEntropyLoss`],
+/// making it suitable for the output layer of a classifier. As an [`Activator`], it maps the
+/// logits onto a probability distribution over the last axis, while, as a [`Loss`], it
+/// computes the cross-entropy directly from the logits using the log-sum-exp trick.
+///
+/// Since the Jacobian of the softmax cancels against the derivative of the cross-entropy,
+/// the gradient w.r.t. the logits is simply $p - y$ (scaled by the reduction). Accordingly,
+/// its [`ActivatorGradient`] passes the error through unchanged rather than multiplying it by
+/// the (elementwise) derivative of the softmax.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub struct SoftmaxCrossEntropy<A = f64, R = Mean> {
+    pub(crate) loss: CrossEntropyLoss<A, R>,
+}
+
+/// The [`HingeLoss`] is a margin-based loss for binary classification where the targets are
+/// expected to be either `-1` or `1`: $\ell(p, y) = \max(0, 1 - yp)$
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub struct HingeLoss<R = Mean> {
+    pub(crate) reduction: R,
+}
+
+impl<A> BinaryCrossEntropy<A> {
+    /// returns a new, unweighted instance of the loss using the [`Mean`] reduction
+    pub const fn new() -> Self {
+        Self {
+            weights: None,
+            reduction: Mean,
+        }
+    }
+}
+
+impl<A, R> BinaryCrossEntropy<A, R> {
+    /// returns the weights of the loss, if any
+    pub const fn weights(&self) -> Option<&Array1<A>> {
+        self.weights.as_ref()
+    }
+    /// returns an immutable reference to the reduction strategy
+    pub const fn reduction(&self) -> &R {
+        &self.reduction
+    }
+    /// consumes the current instance to create another with the given weights, which must be
+    /// finite and non-negative with a positive sum
+    pub fn with_weights(self, weights: Array1<A>) -> Result<Self>
+    where
+        A: Float,
+    {
+        validate_weights(&weights)?;
+        Ok(Self {
+            weights: Some(weights),
+            ..self
+        })
+    }
+    /// consumes the current instance to create another with the given reduction
+    pub fn with_reduction<R2>(self, reduction: R2) -> BinaryCrossEntropy<A, R2> {
+        BinaryCrossEntropy {
+            weights: self.weights,
+            reduction,
+        }
+    }
+}
+
+impl<A> CrossEntropyLoss<A> {
+    /// returns a new, unweighted instance of the loss using the [`Mean`] reduction
+    pub const fn new() -> Self {
+        Self {
+            weights: None,
+            reduction: Mean,
+        }
+    }
+}
+
+impl<A, R> CrossEntropyLoss<A, R> {
+    /// returns the class weights of the loss, if any
+    pub const fn weights(&self) -> Option<&Array1<A>> {
+        self.weights.as_ref()
+    }
+    /// returns an immutable reference to the reduction strategy
+    pub const fn reduction(&self) -> &R {
+        &self.reduction
+    }
+    /// consumes the current instance to create another with the given class weights, which
+    /// must be finite and non-negative with a positive sum
+    pub fn with_weights(self, weights: Array1<A>) -> Result<Self>
+    where
+        A: Float,
+    {
+        validate_weights(&weights)?;
+        Ok(Self {
+            weights: Some(weights),
+            ..self
+        })
+    }
+    /// consumes the current instance to create another with the given reduction
+    pub fn with_reduction<R2>(self, reduction: R2) -> CrossEntropyLoss<A, R2> {
+        CrossEntropyLoss {
+            weights: self.weights,
+            reduction,
+        }
+    }
+    /// returns the targets scaled by the class weights, if any
+    fn weighted_targets<T, D>(&self, target: &ArrayBase<T, D, A>) -> Array<A, D>
+    where
+        A: Float,
+        D: RemoveAxis,
+        T: Data<Elem = A>,
+    {
+        let mut wy = target.to_owned();
+        if let Some(weights) = self.weights() {
+            let weights = weights
+                .broadcast(wy.raw_dim())
+                .expect("the class weights are incompatible with the last axis of the targets");
+            wy.zip_mut_with(&weights, |y, &w| *y = *y * w);
+        }
+        wy
+    }
+    /// reduce the per-sample loss, normalizing a weighted loss by the total weight of its
+    /// samples, i.e. the sum of the weighted targets
+    fn reduce<D>(&self, loss: Array<A, D>, total: A) -> R::Output
+    where
+        D: ndarray::Dimension,
+        R: Reduction<A, D>,
+    {
+        match self.weights {
+            Some(_) => self.reduction.reduce_weighted(loss, total),
+            None => self.reduction.reduce(loss),
+        }
+    }
+}
+
+impl<A> SoftmaxCrossEntropy<A> {
+    /// returns a new, unweighted instance using the [`Mean`] reduction
+    pub const fn new() -> Self {
+        Self {
+            loss: CrossEntropyLoss::new(),
+        }
+    }
+}
+
+impl<A, R> SoftmaxCrossEntropy<A, R> {
+    /// create a new instance from the given cross-entropy loss
+    pub const fn from_loss(loss: CrossEntropyLoss<A, R>) -> Self {
+        Self { loss }
+    }
+    /// returns an immutable reference to the underlying cross-entropy loss
+    pub const fn loss(&self) -> &CrossEntropyLoss<A, R> {
+        &self.loss
+    }
+    /// consumes the current instance to create another with the given class weights, which
+    /// must be finite and non-negative with a positive sum
+    pub fn with_weights(self, weights: Array1<A>) -> Result<Self>
+    where
+        A: Float,
+    {
+        Ok(Self {
+            loss: self.loss.with_weights(weights)?,
+        })
+    }
+    /// consumes the current instance to create another with the given reduction
+    pub fn with_reduction<R2>(self, reduction: R2) -> SoftmaxCrossEntropy<A, R2> {
+        SoftmaxCrossEntropy {
+            loss: self.loss.with_reduction(reduction),
+        }
+    }
+}
+
+impl HingeLoss {
+    /// returns a new instance of the loss using the [`Mean`] reduction
+    pub const fn new() -> Self {
+        Self { reduction: Mean }
+    }
+}
+
+impl<R> HingeLoss<R> {
+    /// returns an immutable reference to the reduction strategy
+    pub const fn reduction(&self) -> &R {
+        &self.reduction
+    }
+    /// consumes the current instance to create another with the given reduction
+    pub fn with_reduction<R2>(self, reduction: R2) -> HingeLoss<R2> {
+        HingeLoss { reduction }
+    }
+}
+
+/// compute the log-softmax of the logits along the last axis
+pub(crate) fn log_softmax<A, S, D>(logits: &ArrayBase<S, D, A>) -> Array<A, D>
+where
+    A: Float,
+    D: RemoveAxis,
+    S: Data<Elem = A>,
+{
+    let mut out = logits.to_owned();
+    let axis = Axis(out.ndim().saturating_sub(1));
+    for mut lane in out.lanes_mut(axis) {
+        let max = lane.fold(A::neg_infinity(), |m, &x| m.max(x));
+        let lse = lane.fold(A::zero(), |acc, &x| acc + (x - max).exp()).ln() + max;
+        lane.mapv_inplace(|x| x - lse);
+    }
+    out
+}
+
+/*
+ ************* Implementations *************
+*/
+
+impl<A, R> ElementwiseLoss<A> for BinaryCrossEntropy<A, R>
+where
+    A: Float,
+{
+    fn value(&self, p: A, y: A) -> A {
+        let p = p.max(A::epsilon()).min(A::one() - A::epsilon());
+        -(y * p.ln() + (A::one() - y) * (A::one() - p).ln())
+    }
+
+    fn derivative(&self, p: A, y: A) -> A {
+        let p = p.max(A::epsilon()).min(A::one() - A::epsilon());
+        (p - y) / (p * (A::one() - p))
+    }
+
+    fn weights(&self) -> Option<&Array1<A>> {
+        self.weights.as_ref()
+    }
+}
+
+impl<A, R> ElementwiseLoss<A> for HingeLoss<R>
+where
+    A: Float,
+{
+    fn value(&self, p: A, y: A) -> A {
+        (A::one() - y * p).max(A::zero())
+    }
+
+    fn derivative(&self, p: A, y: A) -> A {
+        if y * p < A::one() { -y } else { A::zero() }
+    }
+}
+
+elementwise_loss! {
+    BinaryCrossEntropy<A, R>,
+    HingeLoss<R>,
+}
+
+impl<A, S, T, D, R> Loss<ArrayBase<S, D, A>, ArrayBase<T, D, A>> for CrossEntropyLoss<A, R>
+where
+    A: Float,
+    D: RemoveAxis,
+    S: Data<Elem = A>,
+    T: Data<Elem = A>,
+    R: Reduction<A, D::Smaller>,
+{
+    type Output = R::Output;
+
+    fn loss(&self, lhs: &ArrayBase<S, D, A>, rhs: &ArrayBase<T, D, A>) -> Self::Output {
+        let axis = Axis(lhs.ndim().saturating_sub(1));
+        let wy = self.weighted_targets(rhs);
+        let loss = (log_softmax(lhs) * &wy).sum_axis(axis);
+        self.reduce(loss.mapv(|x| -x), wy.sum())
+    }
+}
+
+impl<A, S, T, D, R> LossGradient<ArrayBase<S, D, A>, ArrayBase<T, D, A>> for CrossEntropyLoss<A, R>
+where
+    A: Float,
+    D: RemoveAxis,
+    S: Data<Elem = A>,
+    T: Data<Elem = A>,
+    R: Reduction<A, D::Smaller>,
+{
+    type Grad = Array<A, D>;
+
+    fn gradient(&self, lhs: &ArrayBase<S, D, A>, rhs: &ArrayBase<T, D, A>) -> Self::Grad {
+        let axis = Axis(lhs.ndim().saturating_sub(1));
+        let wy = self.weighted_targets(rhs);
+        let mut grad = log_softmax(lhs).mapv_into(A::exp);
+        let scale = match self.weights {
+            Some(_) => self.reduction.scale_weighted(wy.sum()),
+            None => self.reduction.scale(grad.lanes(axis).into_iter().len()),
+        };
+        for (mut g, y) in grad.lanes_mut(axis).into_iter().zip(wy.lanes(axis)) {
+            // the derivative w.r.t. each logit is `p_c * sum(w y) - w_c y_c`
+            let total = y.sum();
+            g.zip_mut_with(&y, |g, &y| *g = (*g * total - y) * scale);
+        }
+        grad
+    }
+}
+
+impl<A, S, D, R> Activator<ArrayBase<S, D, A>> for SoftmaxCrossEntropy<A, R>
+where
+    A: Float,
+    D: RemoveAxis,
+    S: Data<Elem = A>,
+{
+    type Output = Array<A, D>;
+
+    fn activate(&self, input: ArrayBase<S, D, A>) -> Self::Output {
+        log_softmax(&input).mapv_into(A::exp)
+    }
+}
+
+impl<A, S, D, R> ActivatorGradient<ArrayBase<S, D, A>> for SoftmaxCrossEntropy<A, R>
+where
+    A: Float,
+    D: RemoveAxis,
+    S: Data<Elem = A>,
+{
+    type Rel = Self;
+    type Delta = ArrayBase<S, D, A>;
+
+    /// the error of the output layer, i.e. `y - p`, is already the gradient w.r.t. the logits
+    fn activate_gradient(&self, input: ArrayBase<S, D, A>) -> Self::Delta {
+        input
+    }
+}
+
+impl<A, S, T, D, R> Loss<ArrayBase<S, D, A>, ArrayBase<T, D, A>> for SoftmaxCrossEntropy<A, R>
+where
+    A: Float,
+    D: RemoveAxis,
+    S: Data<Elem = A>,
+    T: Data<Elem = A>,
+    R: Reduction<A, D::Smaller>,
+{
+    type Output = R::Output;
+
+    fn loss(&self, lhs: &ArrayBase<S, D, A>, rhs: &ArrayBase<T, D, A>) -> Self::Output {
+        self.loss.loss(lhs, rhs)
+    }
+}
+
+impl<A, S, T, D, R> LossGradient<ArrayBase<S, D, A>, ArrayBase<T, D, A>>
+    for SoftmaxCrossEntropy<A, R>
+where
+    A: Float,
+    D: RemoveAxis,
+    S: Data<Elem = A>,
+    T: Data<Elem = A>,
+    R: Reduction<A, D::Smaller>,
+{
+    type Grad = Array<A, D>;
+
+    fn gradient(&self, lhs: &ArrayBase<S, D, A>, rhs: &ArrayBase<T, D, A>) -> Self::Grad {
+        self.loss.gradient(lhs, rhs)
+    }
+}
@@ -0,0 +1,68 @@
+/*
+    Appellation: divergence <module>
+    Created At: 2026.10.18:11:14:52
+    Contrib: @FL03
+*/
+use super::reduction::Mean;
+use super::{ElementwiseLoss, elementwise_loss};
+use num_traits::Float;
+
+/// The [`KlDivergence`] measures how the predicted distribution, $p$, diverges from the
+/// target distribution, $y$, where both are given as probabilities:
+///
+/// ```math
+/// \ell(p, y) = y\ln\frac{y}{p}
+/// ```
+///
+/// Terms whose target is zero contribute nothing to the loss while the predictions are
+/// clamped to be at least [`epsilon`](Float::epsilon) to avoid taking the log of zero.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub struct KlDivergence<R = Mean> {
+    pub(crate) reduction: R,
+}
+
+impl KlDivergence {
+    /// returns a new instance of the loss using the [`Mean`] reduction
+    pub const fn new() -> Self {
+        Self { reduction: Mean }
+    }
+}
+
+impl<R> KlDivergence<R> {
+    /// returns an immutable reference to the reduction strategy
+    pub const fn reduction(&self) -> &R {
+        &self.reduction
+    }
+    /// consumes the current instance to create another with the given reduction
+    pub fn with_reduction<R2>(self, reduction: R2) -> KlDivergence<R2> {
+        KlDivergence { reduction }
+    }
+}
+
+impl<A, R> ElementwiseLoss<A> for KlDivergence<R>
+where
+    A: Float,
+{
+    fn value(&self, p: A, y: A) -> A {
+        if y <= A::zero() {
+            return A::zero();
+        }
+        y * (y.ln() - p.max(A::epsilon()).ln())
+    }
+
+    fn derivative(&self, p: A, y: A) -> A {
+        if y <= A::zero() {
+            return A::zero();
+        }
+        -y / p.max(A::epsilon())
+    }
+}
+
+elementwise_loss! {
+    KlDivergence<R>,
+}
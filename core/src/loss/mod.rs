@@ -0,0 +1,179 @@
+/*
+    Appellation: loss <module>
+    Created At: 2026.10.18:11:02:47
+    Contrib: @FL03
+*/
+//! This module implements a set of first-class loss functions. Each loss implements both the
+//! [`Loss`](concision_traits::Loss) and [`LossGradient`](concision_traits::LossGradient)
+//! traits, computing the value of the loss as well as its gradient w.r.t. the predictions.
+//!
+//! The reduction applied to the loss is configured at the type-level using one of the
+//! [`reduction`] strategies, defaulting to [`Mean`](reduction::Mean):
+//!
+//! - [`Mean`](reduction::Mean): average the loss, producing a scalar; weighted losses are
+//!   normalized by the total weight of their terms
+//! - [`Sum`](reduction::Sum): sum the loss, producing a scalar
+//! - [`NoReduction`](reduction::NoReduction): return the unreduced loss as an array
+//!
+//! ### _Losses_
+//!
+//! - [`MseLoss`]: mean squared error
+//! - [`MaeLoss`]: mean absolute error
+//! - [`HuberLoss`]: the Huber (smooth L1) loss
+//! - [`BinaryCrossEntropy`]: binary cross-entropy over probabilities
+//! - [`CrossEntropyLoss`]: categorical cross-entropy computed from logits
+//! - [`SoftmaxCrossEntropy`]: a fused softmax activation and cross-entropy loss
+//! - [`KlDivergence`]: the Kullback-Leibler divergence between two distributions
+//! - [`HingeLoss`]: the (binary) hinge loss
+#[doc(inline)]
+pub use self::{classification::*, divergence::*, reduction::Reduction, regression::*};
+
+pub mod reduction;
+
+mod classification;
+mod divergence;
+mod regression;
+
+pub(crate) mod prelude {
+    pub use super::classification::*;
+    pub use super::divergence::*;
+    pub use super::reduction::Reduction;
+    pub use super::regression::*;
+}
+
+use crate::error::{Error, Result};
+use ndarray::{Array, Array1, ArrayBase, Data, Dimension, Zip};
+use num_traits::Float;
+
+/// [`ElementwiseLoss`] is a helper trait used to implement losses that are computed for each
+/// element of the predictions independently.
+pub(crate) trait ElementwiseLoss<A> {
+    /// the loss of a single prediction, `p`, given its target, `y`
+    fn value(&self, p: A, y: A) -> A;
+    /// the derivative of the loss w.r.t. a single prediction, `p`, given its target, `y`
+    fn derivative(&self, p: A, y: A) -> A;
+    /// optional weights, broadcast along the last axis of the predictions
+    fn weights(&self) -> Option<&Array1<A>> {
+        None
+    }
+}
+
+/// ensure that the weights of a loss are non-empty, finite and non-negative with a positive
+/// sum, allowing the terms of the loss to be normalized by their total weight
+pub(crate) fn validate_weights<A: Float>(weights: &Array1<A>) -> Result<()> {
+    let valid = weights.iter().all(|w| w.is_finite() && *w >= A::zero());
+    if !valid || weights.sum() <= A::zero() {
+        return Err(Error::InvalidLossWeights);
+    }
+    Ok(())
+}
+
+/// broadcast the weights of the loss onto the given shape, if any
+fn broadcast_weights<A, L, D>(loss: &L, dim: D) -> Option<ndarray::ArrayView<'_, A, D>>
+where
+    D: Dimension,
+    L: ElementwiseLoss<A>,
+{
+    loss.weights().map(|weights| {
+        weights
+            .broadcast(dim)
+            .expect("the weights are incompatible with the last axis of the predictions")
+    })
+}
+
+/// returns the total weight of the terms of a loss shaped like the given dimension, or `None`
+/// if the loss is unweighted
+pub(crate) fn total_weight<A, L, D>(loss: &L, dim: D) -> Option<A>
+where
+    A: Clone + num_traits::Zero,
+    D: Dimension,
+    L: ElementwiseLoss<A>,
+{
+    broadcast_weights(loss, dim).map(|weights| weights.sum())
+}
+
+/// apply the given function to each pair of predictions and targets, scaling the results by
+/// the loss' weights, if any.
+pub(crate) fn elementwise<A, S, T, D, L, F>(
+    loss: &L,
+    pred: &ArrayBase<S, D, A>,
+    target: &ArrayBase<T, D, A>,
+    f: F,
+) -> Array<A, D>
+where
+    A: Copy + core::ops::MulAssign,
+    D: Dimension,
+    S: Data<Elem = A>,
+    T: Data<Elem = A>,
+    L: ElementwiseLoss<A>,
+    F: Fn(&L, A, A) -> A,
+{
+    let mut out = Zip::from(pred)
+        .and(target)
+        .map_collect(|&p, &y| f(loss, p, y));
+    if let Some(weights) = broadcast_weights(loss, out.raw_dim()) {
+        out.zip_mut_with(&weights, |x, &w| *x *= w);
+    }
+    out
+}
+
+/// implement the [`Loss`](concision_traits::Loss) and
+/// [`LossGradient`](concision_traits::LossGradient) traits for some [`ElementwiseLoss`]
+macro_rules! elementwise_loss {
+    ($($loss:ty),* $(,)?) => {
+        $(elementwise_loss!(@impl $loss);)*
+    };
+    (@impl $loss:ty) => {
+        impl<A, S, T, D, R> concision_traits::Loss<ndarray::ArrayBase<S, D, A>, ndarray::ArrayBase<T, D, A>> for $loss
+        where
+            A: num_traits::Float + num_traits::FromPrimitive + core::ops::MulAssign,
+            D: ndarray::Dimension,
+            S: ndarray::Data<Elem = A>,
+            T: ndarray::Data<Elem = A>,
+            R: $crate::loss::Reduction<A, D>,
+        {
+            type Output = R::Output;
+
+            fn loss(
+                &self,
+                lhs: &ndarray::ArrayBase<S, D, A>,
+                rhs: &ndarray::ArrayBase<T, D, A>,
+            ) -> Self::Output {
+                let loss = $crate::loss::elementwise(self, lhs, rhs, |l, p, y| {
+                    $crate::loss::ElementwiseLoss::value(l, p, y)
+                });
+                match $crate::loss::total_weight(self, loss.raw_dim()) {
+                    Some(total) => self.reduction.reduce_weighted(loss, total),
+                    None => self.reduction.reduce(loss),
+                }
+            }
+        }
+
+        impl<A, S, T, D, R> concision_traits::LossGradient<ndarray::ArrayBase<S, D, A>, ndarray::ArrayBase<T, D, A>> for $loss
+        where
+            A: num_traits::Float + num_traits::FromPrimitive + core::ops::MulAssign,
+            D: ndarray::Dimension,
+            S: ndarray::Data<Elem = A>,
+            T: ndarray::Data<Elem = A>,
+            R: $crate::loss::Reduction<A, D>,
+        {
+            type Grad = ndarray::Array<A, D>;
+
+            fn gradient(
+                &self,
+                lhs: &ndarray::ArrayBase<S, D, A>,
+                rhs: &ndarray::ArrayBase<T, D, A>,
+            ) -> Self::Grad {
+                let scale = match $crate::loss::total_weight(self, lhs.raw_dim()) {
+                    Some(total) => self.reduction.scale_weighted(total),
+                    None => self.reduction.scale(lhs.len()),
+                };
+                $crate::loss::elementwise(self, lhs, rhs, |l, p, y| {
+                    $crate::loss::ElementwiseLoss::derivative(l, p, y) * scale
+                })
+            }
+        }
+    };
+}
+
+pub(crate) use elementwise_loss;
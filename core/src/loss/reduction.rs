@@ -0,0 +1,124 @@
+/*
+    Appellation: reduction <module>
+    Created At: 2026.10.18:11:05:31
+    Contrib: @FL03
+*/
+//! Strategies for reducing the (elementwise) output of a loss function.
+use ndarray::{Array, Dimension};
+use num_traits::{Float, FromPrimitive};
+
+/// The [`Reduction`] trait defines how the unreduced output of a loss is reduced along with
+/// the factor that must be applied onto the gradient of each term as a result.
+pub trait Reduction<A, D>
+where
+    D: Dimension,
+{
+    type Output;
+    /// reduce the unreduced loss
+    fn reduce(&self, loss: Array<A, D>) -> Self::Output;
+    /// returns the factor applied onto the gradient of each term given the number of terms
+    fn scale(&self, n: usize) -> A;
+    /// reduce the unreduced loss of a weighted loss whose terms have the given total weight
+    fn reduce_weighted(&self, loss: Array<A, D>, total: A) -> Self::Output;
+    /// returns the factor applied onto the gradient of each term of a weighted loss given the
+    /// total weight of its terms
+    fn scale_weighted(&self, total: A) -> A;
+}
+
+/// Average the terms of the loss; the terms of a weighted loss are normalized by their total
+/// weight rather than their number, consistent with the standard weighted cross-entropy
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Mean;
+
+/// Sum the terms of the loss
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Sum;
+
+/// Return the unreduced terms of the loss
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct NoReduction;
+
+/*
+ ************* Implementations *************
+*/
+
+impl<A, D> Reduction<A, D> for Mean
+where
+    A: Float + FromPrimitive,
+    D: Dimension,
+{
+    type Output = A;
+
+    fn reduce(&self, loss: Array<A, D>) -> Self::Output {
+        loss.mean().unwrap_or_else(A::zero)
+    }
+
+    fn scale(&self, n: usize) -> A {
+        if n == 0 {
+            return A::zero();
+        }
+        A::from_usize(n).unwrap().recip()
+    }
+
+    fn reduce_weighted(&self, loss: Array<A, D>, total: A) -> Self::Output {
+        loss.sum() * <Self as Reduction<A, D>>::scale_weighted(self, total)
+    }
+
+    fn scale_weighted(&self, total: A) -> A {
+        if total.is_zero() {
+            return A::zero();
+        }
+        total.recip()
+    }
+}
+
+impl<A, D> Reduction<A, D> for Sum
+where
+    A: Float,
+    D: Dimension,
+{
+    type Output = A;
+
+    fn reduce(&self, loss: Array<A, D>) -> Self::Output {
+        loss.sum()
+    }
+
+    fn scale(&self, _n: usize) -> A {
+        A::one()
+    }
+
+    fn reduce_weighted(&self, loss: Array<A, D>, _total: A) -> Self::Output {
+        self.reduce(loss)
+    }
+
+    fn scale_weighted(&self, _total: A) -> A {
+        A::one()
+    }
+}
+
+impl<A, D> Reduction<A, D> for NoReduction
+where
+    A: Float,
+    D: Dimension,
+{
+    type Output = Array<A, D>;
+
+    fn reduce(&self, loss: Array<A, D>) -> Self::Output {
+        loss
+    }
+
+    fn scale(&self, _n: usize) -> A {
+        A::one()
+    }
+
+    fn reduce_weighted(&self, loss: Array<A, D>, _total: A) -> Self::Output {
+        loss
+    }
+
+    fn scale_weighted(&self, _total: A) -> A {
+        A::one()
+    }
+}
@@ -0,0 +1,178 @@
+/*
+    Appellation: regression <module>
+    Created At: 2026.10.18:11:09:14
+    Contrib: @FL03
+*/
+use super::reduction::Mean;
+use super::{ElementwiseLoss, elementwise_loss};
+use num_traits::{Float, FromPrimitive};
+
+/// The [`MseLoss`] computes the squared error between the predictions and targets:
+/// $\ell(p, y) = (p - y)^2$
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub struct MseLoss<R = Mean> {
+    pub(crate) reduction: R,
+}
+
+/// The [`MaeLoss`] computes the absolute error between the predictions and targets:
+/// $\ell(p, y) = |p - y|$
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub struct MaeLoss<R = Mean> {
+    pub(crate) reduction: R,
+}
+
+/// The [`HuberLoss`] behaves quadratically for errors smaller than `delta` and linearly
+/// otherwise, making it less sensitive to outliers than the [`MseLoss`]:
+///
+/// ```math
+/// \ell(p, y) = \begin{cases}
+/// \frac{1}{2}(p - y)^2 & |p - y| \le \delta \\
+/// \delta(|p - y| - \frac{1}{2}\delta) & \text{otherwise}
+/// \end{cases}
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub struct HuberLoss<A = f64, R = Mean> {
+    pub(crate) delta: A,
+    pub(crate) reduction: R,
+}
+
+impl MseLoss {
+    /// returns a new instance of the loss using the [`Mean`] reduction
+    pub const fn new() -> Self {
+        Self { reduction: Mean }
+    }
+}
+
+impl<R> MseLoss<R> {
+    /// returns an immutable reference to the reduction strategy
+    pub const fn reduction(&self) -> &R {
+        &self.reduction
+    }
+    /// consumes the current instance to create another with the given reduction
+    pub fn with_reduction<R2>(self, reduction: R2) -> MseLoss<R2> {
+        MseLoss { reduction }
+    }
+}
+
+impl MaeLoss {
+    /// returns a new instance of the loss using the [`Mean`] reduction
+    pub const fn new() -> Self {
+        Self { reduction: Mean }
+    }
+}
+
+impl<R> MaeLoss<R> {
+    /// returns an immutable reference to the reduction strategy
+    pub const fn reduction(&self) -> &R {
+        &self.reduction
+    }
+    /// consumes the current instance to create another with the given reduction
+    pub fn with_reduction<R2>(self, reduction: R2) -> MaeLoss<R2> {
+        MaeLoss { reduction }
+    }
+}
+
+impl<A> HuberLoss<A> {
+    /// returns a new instance of the loss with the given threshold, using the [`Mean`]
+    /// reduction
+    pub const fn new(delta: A) -> Self {
+        Self {
+            delta,
+            reduction: Mean,
+        }
+    }
+}
+
+impl<A, R> HuberLoss<A, R> {
+    /// returns an immutable reference to the threshold at which the loss becomes linear
+    pub const fn delta(&self) -> &A {
+        &self.delta
+    }
+    /// returns an immutable reference to the reduction strategy
+    pub const fn reduction(&self) -> &R {
+        &self.reduction
+    }
+    /// consumes the current instance to create another with the given reduction
+    pub fn with_reduction<R2>(self, reduction: R2) -> HuberLoss<A, R2> {
+        HuberLoss {
+            delta: self.delta,
+            reduction,
+        }
+    }
+}
+
+/*
+ ************* Implementations *************
+*/
+
+impl<A, R> ElementwiseLoss<A> for MseLoss<R>
+where
+    A: Float + FromPrimitive,
+{
+    fn value(&self, p: A, y: A) -> A {
+        (p - y).powi(2)
+    }
+
+    fn derivative(&self, p: A, y: A) -> A {
+        (p - y) * A::from_u8(2).unwrap()
+    }
+}
+
+impl<A, R> ElementwiseLoss<A> for MaeLoss<R>
+where
+    A: Float,
+{
+    fn value(&self, p: A, y: A) -> A {
+        (p - y).abs()
+    }
+
+    fn derivative(&self, p: A, y: A) -> A {
+        let d = p - y;
+        if d.is_zero() { A::zero() } else { d.signum() }
+    }
+}
+
+impl<A, R> ElementwiseLoss<A> for HuberLoss<A, R>
+where
+    A: Float + FromPrimitive,
+{
+    fn value(&self, p: A, y: A) -> A {
+        let half = A::from_f64(0.5).unwrap();
+        let d = (p - y).abs();
+        if d <= self.delta {
+            half * d * d
+        } else {
+            self.delta * (d - half * self.delta)
+        }
+    }
+
+    fn derivative(&self, p: A, y: A) -> A {
+        let d = p - y;
+        if d.abs() <= self.delta {
+            d
+        } else {
+            self.delta * d.signum()
+        }
+    }
+}
+
+elementwise_loss! {
+    MseLoss<R>,
+    MaeLoss<R>,
+    HuberLoss<A, R>,
+}
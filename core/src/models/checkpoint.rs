@@ -0,0 +1,117 @@
+/*
+    Appellation: checkpoint <module>
+    Created At: 2026.10.18:22:41:09
+    Contrib: @FL03
+*/
+//! This module provides the [`Checkpoint`], a versioned snapshot bundling the parameters of a
+//! model with its [`StandardModelConfig`], [`ModelFeatures`], training step and (optionally)
+//! the state of its optimizer.
+//!
+//! Every checkpoint begins with a self-describing [`CheckpointHeader`] recording the version
+//! of the format, the element type of the parameters, the version of the crate that wrote it
+//! and the layout of the model. When loading a checkpoint, the header is inspected before the
+//! remainder of the payload, and the parameters are validated against the recorded layout;
+//! any mismatch is surfaced as a descriptive [`Error`](crate::Error).
+//!
+//! Checkpoints may be encoded as JSON, with the `json` feature, or using a compact binary
+//! encoding, with the `postcard` feature. The binary encoding is prefixed with the
+//! [`CHECKPOINT_MAGIC`] bytes and the format version so that files may be identified (and
+//! rejected) without decoding them. With the `std` feature enabled, checkpoints are written
+//! _atomically_ by writing to a temporary file within the same directory before renaming it
+//! over the destination, ensuring that an interrupted save never leaves behind a corrupt file.
+mod impl_checkpoint;
+#[cfg(feature = "serde")]
+mod impl_checkpoint_io;
+
+use crate::config::StandardModelConfig;
+use crate::models::{DeepModelParams, Dtype, ModelFeatures};
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+/// the current version of the checkpoint format
+pub const CHECKPOINT_FORMAT_VERSION: u32 = 1;
+/// the bytes prefixing every checkpoint using the binary encoding
+pub const CHECKPOINT_MAGIC: [u8; 4] = *b"CNCK";
+
+/// The [`CheckpointFormat`] enumerates the encodings a [`Checkpoint`] may be stored with.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+    strum::AsRefStr,
+    strum::Display,
+    strum::EnumCount,
+    strum::EnumIs,
+    strum::EnumIter,
+    strum::EnumString,
+    strum::VariantNames,
+)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+#[strum(serialize_all = "snake_case")]
+pub enum CheckpointFormat {
+    /// a human-readable JSON document
+    #[default]
+    Json,
+    /// a compact binary encoding prefixed by the [`CHECKPOINT_MAGIC`] bytes
+    Binary,
+}
+
+/// The [`CheckpointHeader`] describes the contents of a [`Checkpoint`], allowing readers to
+/// verify that they are able to load it before decoding its payload.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub struct CheckpointHeader {
+    /// the version of the checkpoint format
+    pub(crate) format_version: u32,
+    /// the element type of the parameters, e.g. [`F32`](Dtype::F32)
+    pub(crate) dtype: Dtype,
+    /// the version of the crate that wrote the checkpoint
+    pub(crate) crate_version: String,
+    /// the layout of the model
+    pub(crate) layout: ModelFeatures,
+}
+
+/// The [`Checkpoint`] bundles the parameters of a deep model with its configuration, layout,
+/// the current training step and the state of its optimizer, `O`, if any.
+///
+/// The optimizer state is generic, allowing any serializable state to be stored alongside the
+/// model; for instance, a `Vec<AdamState<A>>` holding the state of each layer.
+#[derive(Clone, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(
+        rename_all = "snake_case",
+        bound(
+            serialize = "A: serde::Serialize, O: serde::Serialize",
+            deserialize = "A: serde::Deserialize<'de>, O: serde::Deserialize<'de>"
+        )
+    )
+)]
+pub struct Checkpoint<A = f32, O = ()> {
+    /// the header describing the checkpoint
+    pub(crate) header: CheckpointHeader,
+    /// the parameters of the model
+    pub(crate) params: DeepModelParams<A>,
+    /// the configuration of the model
+    pub(crate) config: StandardModelConfig<A>,
+    /// the number of training steps taken
+    pub(crate) step: usize,
+    /// the state of the optimizer, if any
+    pub(crate) optimizer: Option<O>,
+}
@@ -0,0 +1,212 @@
+/*
+    Appellation: impl_checkpoint <module>
+    Created At: 2026.10.18:22:47:30
+    Contrib: @FL03
+*/
+use super::{CHECKPOINT_FORMAT_VERSION, Checkpoint, CheckpointFormat, CheckpointHeader};
+use crate::config::StandardModelConfig;
+use crate::error::{Error, Result};
+use crate::models::{DeepModelParams, Dtype, Element, ModelFeatures};
+use concision_params::Params;
+
+#[cfg(feature = "alloc")]
+use alloc::{format, string::ToString, vec::Vec};
+
+/// verify that the weights and bias of the named layer are shaped according to the layout
+fn check_layer<A>(layer: impl ToString, params: &Params<A>, (i, o): (usize, usize)) -> Result<()> {
+    if params.weights().shape() != [i, o] {
+        return Err(Error::InvalidCheckpointShape {
+            layer: layer.to_string(),
+            param: "weights",
+            found: params.weights().shape().to_vec(),
+            expected: Vec::from([i, o]),
+        });
+    }
+    if params.bias().shape() != [o] {
+        return Err(Error::InvalidCheckpointShape {
+            layer: layer.to_string(),
+            param: "bias",
+            found: params.bias().shape().to_vec(),
+            expected: Vec::from([o]),
+        });
+    }
+    Ok(())
+}
+
+impl CheckpointFormat {
+    /// returns the format of the given (encoded) checkpoint; data prefixed with the
+    /// [`CHECKPOINT_MAGIC`](super::CHECKPOINT_MAGIC) bytes is assumed to be binary while
+    /// everything else is treated as JSON
+    pub fn detect(bytes: &[u8]) -> Self {
+        if bytes.starts_with(&super::CHECKPOINT_MAGIC) {
+            Self::Binary
+        } else {
+            Self::Json
+        }
+    }
+    /// returns the name of the feature required to use the format
+    pub const fn feature(&self) -> &'static str {
+        match self {
+            Self::Json => "json",
+            Self::Binary => "postcard",
+        }
+    }
+}
+
+impl CheckpointHeader {
+    /// returns a new header describing a checkpoint of the given layout whose parameters use
+    /// the element type `A`, written using the current format and crate versions
+    pub fn new<A: Element>(layout: ModelFeatures) -> Self {
+        Self {
+            format_version: CHECKPOINT_FORMAT_VERSION,
+            dtype: A::DTYPE,
+            crate_version: env!("CARGO_PKG_VERSION").to_string(),
+            layout,
+        }
+    }
+    /// returns the version of the checkpoint format
+    pub const fn format_version(&self) -> u32 {
+        self.format_version
+    }
+    /// returns the element type of the parameters
+    pub const fn dtype(&self) -> Dtype {
+        self.dtype
+    }
+    /// returns the version of the crate that wrote the checkpoint
+    pub fn crate_version(&self) -> &str {
+        self.crate_version.as_str()
+    }
+    /// returns a reference to the layout of the model
+    pub const fn layout(&self) -> &ModelFeatures {
+        &self.layout
+    }
+    /// returns true if the format version of the checkpoint may be read by this crate
+    pub const fn is_supported(&self) -> bool {
+        self.format_version <= CHECKPOINT_FORMAT_VERSION
+    }
+    /// verify that the checkpoint uses a supported format version and stores elements of type
+    /// `A`
+    pub fn validate<A: Element>(&self) -> Result<()> {
+        if !self.is_supported() {
+            return Err(Error::InvalidCheckpointVersion(
+                self.format_version,
+                CHECKPOINT_FORMAT_VERSION,
+            ));
+        }
+        if self.dtype != A::DTYPE {
+            return Err(Error::InvalidCheckpointDtype(self.dtype, A::DTYPE));
+        }
+        Ok(())
+    }
+}
+
+impl<A, O> Checkpoint<A, O> {
+    /// returns a new checkpoint of the given model at step `0` without any optimizer state
+    pub fn new(
+        features: ModelFeatures,
+        params: DeepModelParams<A>,
+        config: StandardModelConfig<A>,
+    ) -> Self
+    where
+        A: Element,
+    {
+        Self {
+            header: CheckpointHeader::new::<A>(features),
+            params,
+            config,
+            step: 0,
+            optimizer: None,
+        }
+    }
+    /// returns a reference to the header of the checkpoint
+    pub const fn header(&self) -> &CheckpointHeader {
+        &self.header
+    }
+    /// returns a reference to the layout of the model
+    pub const fn features(&self) -> &ModelFeatures {
+        self.header().layout()
+    }
+    /// returns a reference to the parameters of the model
+    pub const fn params(&self) -> &DeepModelParams<A> {
+        &self.params
+    }
+    /// returns a mutable reference to the parameters of the model
+    pub const fn params_mut(&mut self) -> &mut DeepModelParams<A> {
+        &mut self.params
+    }
+    /// returns a reference to the configuration of the model
+    pub const fn config(&self) -> &StandardModelConfig<A> {
+        &self.config
+    }
+    /// returns a mutable reference to the configuration of the model
+    pub const fn config_mut(&mut self) -> &mut StandardModelConfig<A> {
+        &mut self.config
+    }
+    /// returns the number of training steps taken
+    pub const fn step(&self) -> usize {
+        self.step
+    }
+    /// returns a reference to the state of the optimizer, if any
+    pub const fn optimizer(&self) -> Option<&O> {
+        self.optimizer.as_ref()
+    }
+    /// returns a mutable reference to the state of the optimizer, if any
+    pub const fn optimizer_mut(&mut self) -> Option<&mut O> {
+        self.optimizer.as_mut()
+    }
+    /// update the number of training steps taken
+    pub fn set_step(&mut self, step: usize) -> &mut Self {
+        self.step = step;
+        self
+    }
+    /// update the state of the optimizer
+    pub fn set_optimizer(&mut self, state: O) -> &mut Self {
+        self.optimizer = Some(state);
+        self
+    }
+    /// consumes the current instance to create another with the given training step
+    pub fn with_step(self, step: usize) -> Self {
+        Self { step, ..self }
+    }
+    /// consumes the current instance to create another storing the given optimizer state
+    pub fn with_optimizer<O2>(self, state: O2) -> Checkpoint<A, O2> {
+        Checkpoint {
+            header: self.header,
+            params: self.params,
+            config: self.config,
+            step: self.step,
+            optimizer: Some(state),
+        }
+    }
+    /// consumes the checkpoint, returning its parameters, configuration and optimizer state
+    pub fn into_parts(self) -> (DeepModelParams<A>, StandardModelConfig<A>, Option<O>) {
+        (self.params, self.config, self.optimizer)
+    }
+    /// verify the checkpoint; the header must be supported and describe elements of type `A`
+    /// while the parameters of each layer must be shaped according to the recorded layout
+    pub fn validate(&self) -> Result<()>
+    where
+        A: Element,
+    {
+        self.header().validate::<A>()?;
+        let features = self.features();
+        let params = self.params();
+        if params.hidden().len() != features.layers() {
+            return Err(Error::InvalidCheckpointLayers(
+                params.hidden().len(),
+                features.layers(),
+            ));
+        }
+        check_layer("input", params.input(), features.dim_input())?;
+        for (i, layer) in params.hidden().iter().enumerate() {
+            check_layer(format!("hidden.{i}"), layer, features.dim_hidden_at(i))?;
+        }
+        check_layer("output", params.output(), features.dim_output())
+    }
+}
+
+impl<A, O> From<Checkpoint<A, O>> for DeepModelParams<A> {
+    fn from(checkpoint: Checkpoint<A, O>) -> Self {
+        checkpoint.params
+    }
+}
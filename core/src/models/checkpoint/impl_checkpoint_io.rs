@@ -0,0 +1,153 @@
+/*
+    Appellation: impl_checkpoint_io <module>
+    Created At: 2026.10.18:23:04:52
+    Contrib: @FL03
+*/
+use super::{Checkpoint, CheckpointFormat};
+use crate::error::{Error, Result};
+use crate::models::Element;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+#[cfg(feature = "json")]
+use alloc::string::String;
+#[cfg(feature = "alloc")]
+use alloc::{format, vec::Vec};
+
+/// a partial view of a [`Checkpoint`] used to inspect its header before decoding the remainder
+/// of the payload
+#[cfg(any(feature = "json", feature = "postcard"))]
+#[derive(serde::Deserialize)]
+struct HeaderOnly {
+    header: super::CheckpointHeader,
+}
+
+impl<A, O> Checkpoint<A, O> {
+    /// encode the checkpoint as a (pretty-printed) JSON document
+    #[cfg(feature = "json")]
+    pub fn to_json(&self) -> Result<String>
+    where
+        A: Serialize,
+        O: Serialize,
+    {
+        serde_json::to_string_pretty(self).map_err(Into::into)
+    }
+    /// decode a checkpoint from a JSON document; the header is inspected before decoding the
+    /// remainder of the document, after which the checkpoint is [validated](Self::validate)
+    #[cfg(feature = "json")]
+    pub fn from_json(data: &str) -> Result<Self>
+    where
+        A: DeserializeOwned + Element,
+        O: DeserializeOwned,
+    {
+        let HeaderOnly { header } = serde_json::from_str(data)?;
+        header.validate::<A>()?;
+        let checkpoint: Self = serde_json::from_str(data)?;
+        checkpoint.validate()?;
+        Ok(checkpoint)
+    }
+    /// encode the checkpoint using the compact binary encoding; the payload is prefixed with
+    /// the [`CHECKPOINT_MAGIC`](super::CHECKPOINT_MAGIC) bytes followed by the format version
+    /// as a little-endian `u32`
+    #[cfg(feature = "postcard")]
+    pub fn to_bytes(&self) -> Result<Vec<u8>>
+    where
+        A: Serialize,
+        O: Serialize,
+    {
+        let mut buf = Vec::from(super::CHECKPOINT_MAGIC);
+        buf.extend_from_slice(&self.header().format_version().to_le_bytes());
+        postcard::to_extend(self, buf).map_err(Into::into)
+    }
+    /// decode a checkpoint from its binary encoding; the magic bytes and format version are
+    /// verified before decoding the payload, after which the checkpoint is
+    /// [validated](Self::validate)
+    #[cfg(feature = "postcard")]
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self>
+    where
+        A: DeserializeOwned + Element,
+        O: DeserializeOwned,
+    {
+        let (version, payload) = bytes
+            .strip_prefix(&super::CHECKPOINT_MAGIC)
+            .and_then(|rest| rest.split_first_chunk::<4>())
+            .ok_or(Error::InvalidCheckpoint)?;
+        let version = u32::from_le_bytes(*version);
+        if version > super::CHECKPOINT_FORMAT_VERSION {
+            return Err(Error::InvalidCheckpointVersion(
+                version,
+                super::CHECKPOINT_FORMAT_VERSION,
+            ));
+        }
+        let (HeaderOnly { header }, _) = postcard::take_from_bytes(payload)?;
+        header.validate::<A>()?;
+        let checkpoint: Self = postcard::from_bytes(payload)?;
+        checkpoint.validate()?;
+        Ok(checkpoint)
+    }
+    /// encode the checkpoint using the given format
+    pub fn to_vec(&self, format: CheckpointFormat) -> Result<Vec<u8>>
+    where
+        A: Serialize,
+        O: Serialize,
+    {
+        match format {
+            #[cfg(feature = "json")]
+            CheckpointFormat::Json => self.to_json().map(String::into_bytes),
+            #[cfg(feature = "postcard")]
+            CheckpointFormat::Binary => self.to_bytes(),
+            #[allow(unreachable_patterns)]
+            _ => Err(Error::UnsupportedOperation(format!(
+                "the `{format}` checkpoint format requires the `{}` feature",
+                format.feature()
+            ))),
+        }
+    }
+    /// decode a checkpoint from the given bytes, detecting its format using the
+    /// [`CHECKPOINT_MAGIC`](super::CHECKPOINT_MAGIC) bytes
+    pub fn from_slice(bytes: &[u8]) -> Result<Self>
+    where
+        A: DeserializeOwned + Element,
+        O: DeserializeOwned,
+    {
+        match CheckpointFormat::detect(bytes) {
+            #[cfg(feature = "json")]
+            CheckpointFormat::Json => {
+                let data = core::str::from_utf8(bytes).map_err(|_| Error::InvalidCheckpoint)?;
+                Self::from_json(data)
+            }
+            #[cfg(feature = "postcard")]
+            CheckpointFormat::Binary => Self::from_bytes(bytes),
+            #[allow(unreachable_patterns)]
+            format => Err(Error::UnsupportedOperation(format!(
+                "the `{format}` checkpoint format requires the `{}` feature",
+                format.feature()
+            ))),
+        }
+    }
+    /// validate the checkpoint before _atomically_ writing it to the given path using the
+    /// given format; the checkpoint is first written to a temporary file within the same
+    /// directory which is then renamed over the destination.
+    #[cfg(feature = "std")]
+    pub fn save<P>(&self, path: P, format: CheckpointFormat) -> Result<()>
+    where
+        A: Serialize + Element,
+        O: Serialize,
+        P: AsRef<std::path::Path>,
+    {
+        self.validate()?;
+        let bytes = self.to_vec(format)?;
+        crate::utils::write_atomic(path, &bytes)
+    }
+    /// load a checkpoint from the given path, detecting its format
+    #[cfg(feature = "std")]
+    pub fn load<P>(path: P) -> Result<Self>
+    where
+        A: DeserializeOwned + Element,
+        O: DeserializeOwned,
+        P: AsRef<std::path::Path>,
+    {
+        let bytes = std::fs::read(path)?;
+        Self::from_slice(&bytes)
+    }
+}
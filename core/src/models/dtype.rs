@@ -0,0 +1,97 @@
+/*
+    Appellation: dtype <module>
+    Created At: 2026.10.19:09:12:41
+    Contrib: @FL03
+*/
+//! This module defines the [`Dtype`] enumerating the element types that may be persisted
+//! alongside the sealed [`Element`] trait mapping a scalar onto its (stable) [`Dtype`] tag.
+
+/// The [`Dtype`] enumerates the element types supported by the persisted formats of the
+/// crate, e.g. checkpoints and safetensors. The names of its variants follow the safetensors
+/// convention and, unlike [`type_name`](core::any::type_name), are stable across compiler
+/// versions.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+    strum::AsRefStr,
+    strum::Display,
+    strum::EnumCount,
+    strum::EnumIs,
+    strum::EnumIter,
+    strum::EnumString,
+    strum::VariantNames,
+)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "UPPERCASE")
+)]
+#[strum(serialize_all = "UPPERCASE")]
+pub enum Dtype {
+    Bool,
+    U8,
+    I8,
+    #[cfg_attr(feature = "serde", serde(rename = "F8_E5M2"))]
+    #[strum(serialize = "F8_E5M2")]
+    F8E5M2,
+    #[cfg_attr(feature = "serde", serde(rename = "F8_E4M3"))]
+    #[strum(serialize = "F8_E4M3")]
+    F8E4M3,
+    I16,
+    U16,
+    F16,
+    BF16,
+    I32,
+    U32,
+    F32,
+    F64,
+    I64,
+    U64,
+}
+
+/// The [`Element`] trait is a sealed trait associating the scalar types that may be persisted
+/// by the crate with their [`Dtype`].
+pub trait Element: Copy + 'static {
+    private!();
+    /// the [`Dtype`] used to store the element
+    const DTYPE: Dtype;
+}
+
+impl Dtype {
+    /// returns the [`Dtype`] of the element type `A`
+    pub const fn of<A: Element>() -> Self {
+        A::DTYPE
+    }
+    /// returns the number of bytes used to store a single element
+    pub const fn size(&self) -> usize {
+        match self {
+            Self::Bool | Self::U8 | Self::I8 | Self::F8E5M2 | Self::F8E4M3 => 1,
+            Self::I16 | Self::U16 | Self::F16 | Self::BF16 => 2,
+            Self::I32 | Self::U32 | Self::F32 => 4,
+            Self::F64 | Self::I64 | Self::U64 => 8,
+        }
+    }
+}
+
+macro_rules! impl_element {
+    ($($t:ty => $dtype:ident),* $(,)?) => {
+        $(
+            impl Element for $t {
+                seal!();
+
+                const DTYPE: Dtype = Dtype::$dtype;
+            }
+        )*
+    };
+}
+
+impl_element! {
+    f32 => F32,
+    f64 => F64,
+}
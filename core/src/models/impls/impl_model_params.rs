@@ -8,6 +8,9 @@ use crate::{DeepModelRepr, RawHidden};
 use concision_params::ParamsBase;
 use ndarray::{ArrayBase, Data, Dimension, RawData, RawDataClone};
 
+#[cfg(feature = "alloc")]
+use alloc::collections::BTreeSet;
+
 /// The base implementation for the [`ModelParamsBase`] type, which is generic over the
 /// storage type `S`, the dimension `D`, and the hidden layer type `H`. This implementation
 /// focuses on providing basic initialization routines and accessors for the various layers
@@ -24,6 +27,7 @@ where
             input,
             hidden,
             output,
+            frozen: BTreeSet::new(),
         }
     }
     /// returns an immutable reference to the input layer of the model
@@ -141,6 +145,47 @@ where
     pub fn is_deep(&self) -> bool {
         self.count_hidden() > 1
     }
+    /// returns true if the layer at the given index is excluded from training; the input
+    /// layer is at index zero and the output layer is last
+    pub fn is_frozen(&self, layer: usize) -> bool {
+        self.frozen.contains(&layer)
+    }
+    /// returns an iterator over the indices of the frozen layers, in ascending order
+    pub fn frozen(&self) -> impl Iterator<Item = usize> + '_ {
+        self.frozen.iter().copied()
+    }
+    /// exclude the layer at the given index from training
+    pub fn freeze(&mut self, layer: usize) -> &mut Self {
+        self.set_frozen(layer, true)
+    }
+    /// include the layer at the given index in training
+    pub fn unfreeze(&mut self, layer: usize) -> &mut Self {
+        self.set_frozen(layer, false)
+    }
+    /// set whether or not the layer at the given index is excluded from training
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the index is out of bounds
+    pub fn set_frozen(&mut self, layer: usize, frozen: bool) -> &mut Self {
+        assert!(
+            layer < self.layers(),
+            "the layer index ({layer}) is out of bounds ({n})",
+            n = self.layers()
+        );
+        if frozen {
+            self.frozen.insert(layer);
+        } else {
+            self.frozen.remove(&layer);
+        }
+        self
+    }
+    /// consumes the current instance to create another with the layer at the given index
+    /// excluded from training
+    pub fn with_frozen(mut self, layer: usize) -> Self {
+        self.freeze(layer);
+        self
+    }
 }
 
 impl<A, S, D, H> Clone for ModelParamsBase<S, D, H, A>
@@ -155,6 +200,7 @@ where
             input: self.input().clone(),
             hidden: self.hidden().clone(),
             output: self.output().clone(),
+            frozen: self.frozen.clone(),
         }
     }
 }
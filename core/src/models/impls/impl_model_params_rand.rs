@@ -26,11 +26,11 @@ where
         Ds: Clone + Distribution<A>,
         S: DataOwned,
     {
-        Self {
-            input: ParamsBase::rand(features.dim_input(), distr(features.dim_input())),
-            hidden: ParamsBase::rand(features.dim_hidden(), distr(features.dim_hidden())),
-            output: ParamsBase::rand(features.dim_output(), distr(features.dim_output())),
-        }
+        Self::new(
+            ParamsBase::rand(features.dim_input(), distr(features.dim_input())),
+            ParamsBase::rand(features.dim_hidden(), distr(features.dim_hidden())),
+            ParamsBase::rand(features.dim_output(), distr(features.dim_output())),
+        )
     }
     /// initialize the model parameters using a glorot normal distribution
     pub fn glorot_normal(features: ModelFeatures) -> Self
@@ -64,11 +64,7 @@ where
         // initialize the output layers
         let output = ParamsBase::glorot_normal(self.output().dim());
         // return a new instance with the initialized layers
-        Self {
-            hidden,
-            input,
-            output,
-        }
+        Self::new(input, hidden, output)
     }
 }
 
@@ -86,7 +82,10 @@ where
     {
         let input = ParamsBase::rand(features.dim_input(), distr(features.dim_input()));
         let hidden = (0..features.layers())
-            .map(|_| ParamsBase::rand(features.dim_hidden(), distr(features.dim_hidden())))
+            .map(|i| {
+                let dim = features.dim_hidden_at(i);
+                ParamsBase::rand(dim, distr(dim))
+            })
             .collect::<Vec<_>>();
 
         let output = ParamsBase::rand(features.dim_output(), distr(features.dim_output()));
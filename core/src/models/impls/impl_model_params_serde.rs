@@ -14,6 +14,17 @@ use serde::ser::{Serialize, SerializeStruct, Serializer};
 /// serialization and deserialization.
 const FIELDS: [&str; 3] = ["input", "hidden", "output"];
 
+/// the fields of the [`ModelParamsBase`]; deserializing the keys into an identifier (rather
+/// than a borrowed string) supports any deserializer, including those that can only provide
+/// owned or escaped keys
+#[derive(serde::Deserialize)]
+#[serde(field_identifier, rename_all = "lowercase")]
+enum Field {
+    Input,
+    Hidden,
+    Output,
+}
+
 struct ModelParamsBaseVisitor<S, D, H, A = <S as RawData>::Elem>
 where
     D: Dimension,
@@ -51,11 +62,33 @@ where
             .next_element()?
             .ok_or_else(|| Error::invalid_length(3, &self))?;
 
-        Ok(ModelParamsBase {
-            input,
-            hidden,
-            output,
-        })
+        Ok(ModelParamsBase::new(input, hidden, output))
+    }
+
+    fn visit_map<M>(self, mut map: M) -> Result<Self::Value, M::Error>
+    where
+        M: serde::de::MapAccess<'a>,
+    {
+        let (mut input, mut hidden, mut output) = (None, None, None);
+        while let Some(key) = map.next_key::<Field>()? {
+            match key {
+                Field::Input if input.is_some() => return Err(Error::duplicate_field("input")),
+                Field::Input => input = Some(map.next_value()?),
+                Field::Hidden if hidden.is_some() => {
+                    return Err(Error::duplicate_field("hidden"));
+                }
+                Field::Hidden => hidden = Some(map.next_value()?),
+                Field::Output if output.is_some() => {
+                    return Err(Error::duplicate_field("output"));
+                }
+                Field::Output => output = Some(map.next_value()?),
+            }
+        }
+        Ok(ModelParamsBase::new(
+            input.ok_or_else(|| Error::missing_field("input"))?,
+            hidden.ok_or_else(|| Error::missing_field("hidden"))?,
+            output.ok_or_else(|| Error::missing_field("output"))?,
+        ))
     }
 }
 
@@ -6,11 +6,15 @@ use crate::{DeepParamsBase, ModelParamsBase};
 
 use crate::ModelFeatures;
 use crate::models::traits::DeepModelRepr;
+use crate::models::{LayerSummary, ModelSummary};
 use concision_params::ParamsBase;
 use concision_traits::Forward;
 use ndarray::{Data, DataOwned, Dimension, Ix2, RawData};
 use num_traits::{One, Zero};
 
+#[cfg(feature = "alloc")]
+use alloc::{format, vec::Vec};
+
 impl<S, D, H, A> ModelParamsBase<S, D, H, A>
 where
     D: Dimension,
@@ -19,11 +23,7 @@ where
 {
     /// create a new instance of the [`ModelParamsBase`] instance
     pub const fn deep(input: ParamsBase<S, D>, hidden: H, output: ParamsBase<S, D>) -> Self {
-        Self {
-            input,
-            hidden,
-            output,
-        }
+        Self::new(input, hidden, output)
     }
 }
 
@@ -47,25 +47,48 @@ where
     /// ## Panics
     ///
     /// Panics if the index is out of bounds or if the dimension of the provided layer is
-    /// inconsistent with the others in the stack.
+    /// inconsistent with that of the layer it replaces.
     #[inline]
     pub fn set_hidden_layer(&mut self, idx: usize, layer: ParamsBase<S, D>) -> &mut Self {
-        if layer.dim() != self.dim_hidden() {
+        if layer.dim() != self.dim_hidden_at(idx) {
             panic!(
-                "the dimension of the layer ({:?}) does not match the dimension of the hidden layers ({:?})",
+                "the dimension of the layer ({:?}) does not match the dimension of the hidden layer ({:?})",
                 layer.dim(),
-                self.dim_hidden()
+                self.dim_hidden_at(idx)
             );
         }
         self.hidden_mut()[idx] = layer;
         self
     }
+    /// returns a [`ModelSummary`] describing each layer of the model; the layers are named
+    /// `input`, `hidden.<i>` and `output`
+    pub fn summary(&self) -> ModelSummary {
+        let mut layers = Vec::with_capacity(self.layers());
+        layers.push(LayerSummary::from_params("input", self.input()));
+        for (i, layer) in self.hidden().iter().enumerate() {
+            layers.push(LayerSummary::from_params(format!("hidden.{i}"), layer));
+        }
+        layers.push(LayerSummary::from_params("output", self.output()));
+        for (i, layer) in layers.iter_mut().enumerate() {
+            layer.set_trainable(!self.is_frozen(i));
+        }
+        ModelSummary::new::<A>(layers)
+    }
     /// returns the dimension of the input layer
     #[inline]
     pub fn dim_input(&self) -> <D as Dimension>::Pattern {
         self.input().dim()
     }
-    /// returns the dimension of the hidden layers
+    /// returns the dimension of the hidden layer at the given index
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the index is out of bounds
+    #[inline]
+    pub fn dim_hidden_at(&self, idx: usize) -> <D as Dimension>::Pattern {
+        self.hidden()[idx].dim()
+    }
+    /// returns the dimension of the hidden layers, asserting that they are all the same
     #[inline]
     pub fn dim_hidden(&self) -> <D as Dimension>::Pattern {
         // verify that all hidden layers have the same dimension
@@ -130,13 +153,13 @@ where
     {
         let input = ParamsBase::default(features.dim_input());
         let hidden = (0..features.layers())
-            .map(|_| ParamsBase::default(features.dim_hidden()))
+            .map(|i| ParamsBase::default(features.dim_hidden_at(i)))
             .collect::<Vec<_>>();
         let output = ParamsBase::default(features.dim_output());
         Self::new(input, hidden, output)
     }
     /// create a new instance of the model;
-    /// all parameters are initialized to zero
+    /// all parameters are initialized to one
     pub fn ones(features: ModelFeatures) -> Self
     where
         A: Clone + One,
@@ -144,7 +167,7 @@ where
     {
         let input = ParamsBase::ones(features.dim_input());
         let hidden = (0..features.layers())
-            .map(|_| ParamsBase::ones(features.dim_hidden()))
+            .map(|i| ParamsBase::ones(features.dim_hidden_at(i)))
             .collect::<Vec<_>>();
         let output = ParamsBase::ones(features.dim_output());
         Self::new(input, hidden, output)
@@ -158,7 +181,7 @@ where
     {
         let input = ParamsBase::zeros(features.dim_input());
         let hidden = (0..features.layers())
-            .map(|_| ParamsBase::zeros(features.dim_hidden()))
+            .map(|i| ParamsBase::zeros(features.dim_hidden_at(i)))
             .collect::<Vec<_>>();
         let output = ParamsBase::zeros(features.dim_output());
         Self::new(input, hidden, output)
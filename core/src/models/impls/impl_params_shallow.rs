@@ -7,12 +7,16 @@ use crate::models::{ModelParamsBase, ShallowParamsBase};
 use crate::ModelFeatures;
 use crate::activate::{ReLUActivation, SigmoidActivation};
 use crate::models::traits::ShallowModelRepr;
+use crate::models::{LayerSummary, ModelSummary};
 use concision_params::ParamsBase;
 use ndarray::{
     Array1, ArrayBase, Data, DataOwned, Dimension, Ix2, RawData, RemoveAxis, ScalarOperand,
 };
 use num_traits::Float;
 
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
 impl<S, D, H, A> ModelParamsBase<S, D, H, A>
 where
     D: Dimension,
@@ -21,11 +25,7 @@ where
 {
     /// create a new instance of the [`ModelParamsBase`] instance
     pub const fn shallow(input: ParamsBase<S, D>, hidden: H, output: ParamsBase<S, D>) -> Self {
-        Self {
-            input,
-            hidden,
-            output,
-        }
+        Self::new(input, hidden, output)
     }
 }
 
@@ -43,11 +43,11 @@ where
         S: DataOwned,
         D: RemoveAxis,
     {
-        Self {
-            hidden: ParamsBase::default(hidden),
-            input: ParamsBase::default(input),
-            output: ParamsBase::default(output),
-        }
+        Self::new(
+            ParamsBase::default(input),
+            ParamsBase::default(hidden),
+            ParamsBase::default(output),
+        )
     }
     /// returns the total number parameters within the model, including the input and output layers
     #[inline]
@@ -56,6 +56,19 @@ where
         size += self.hidden().count_weights();
         size + self.output().count_weights()
     }
+    /// returns a [`ModelSummary`] describing each layer of the model; the layers are named
+    /// `input`, `hidden` and `output`
+    pub fn summary(&self) -> ModelSummary {
+        let mut layers = Vec::from([
+            LayerSummary::from_params("input", self.input()),
+            LayerSummary::from_params("hidden", self.hidden()),
+            LayerSummary::from_params("output", self.output()),
+        ]);
+        for (i, layer) in layers.iter_mut().enumerate() {
+            layer.set_trainable(!self.is_frozen(i));
+        }
+        ModelSummary::new::<A>(layers)
+    }
     /// returns an immutable reference to the hidden weights
     pub const fn hidden_weights(&self) -> &ArrayBase<S, D, A> {
         self.hidden().weights()
@@ -75,11 +88,11 @@ where
         A: Clone + Default,
         S: DataOwned,
     {
-        Self {
-            hidden: ParamsBase::default(features.dim_hidden()),
-            input: ParamsBase::default(features.dim_input()),
-            output: ParamsBase::default(features.dim_output()),
-        }
+        Self::new(
+            ParamsBase::default(features.dim_input()),
+            ParamsBase::default(features.dim_hidden()),
+            ParamsBase::default(features.dim_output()),
+        )
     }
     /// forward input through the controller network
     pub fn forward(&self, input: &Array1<A>) -> Array1<A>
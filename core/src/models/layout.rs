@@ -4,6 +4,9 @@
 */
 use super::{Deep, NetworkDepth, RawModelLayout};
 
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
 mod impl_model_features;
 mod impl_model_format;
 mod impl_model_layout;
@@ -18,20 +21,24 @@ pub trait IntoModelFeatures {
 /// and layers in the model. This is primarily used to generalize the allowed formats of a
 /// neural network without introducing any additional complexity with typing or other
 /// constructs.
-#[derive(
-    Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, strum::EnumCount, strum::EnumIs,
-)]
+///
+/// The [`Custom`](ModelFormat::Custom) variant describes a network whose hidden layers may
+/// each have a different width (e.g. `784 → 512 → 256 → 64 → 10`); the first width is that of
+/// the output of the input layer, with each subsequent width being that of the output of the
+/// next hidden layer. Hence, a network with `n` widths has `n - 1` hidden layers.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, strum::EnumCount, strum::EnumIs)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub enum ModelFormat {
     Layer,
     Shallow { hidden: usize },
     Deep { hidden: usize, layers: usize },
+    Custom { widths: Vec<usize> },
 }
 
 /// The [`ModelFeatures`] provides a common way of defining the layout of a model. This is
 /// used to define the number of input features, the number of hidden layers, the number of
 /// hidden features, and the number of output features.
-#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct ModelFeatures {
     /// the number of input features
@@ -107,3 +114,26 @@ impl IntoModelFeatures for [usize; 4] {
         }
     }
 }
+
+/// the slice is interpreted as the number of input features, followed by the width of each
+/// hidden layer, and finally the number of output features
+///
+/// ## Panics
+///
+/// Panics if the slice has fewer than two elements
+impl IntoModelFeatures for &[usize] {
+    fn into_model_features(self) -> ModelFeatures {
+        assert!(
+            self.len() >= 2,
+            "the layout must contain at least the input and output features"
+        );
+        let (input, output) = (self[0], self[self.len() - 1]);
+        ModelFeatures::custom(input, self[1..self.len() - 1].to_vec(), output)
+    }
+}
+
+impl IntoModelFeatures for Vec<usize> {
+    fn into_model_features(self) -> ModelFeatures {
+        self.as_slice().into_model_features()
+    }
+}
@@ -5,6 +5,9 @@
 use super::ModelFeatures;
 use crate::models::{ModelFormat, RawModelLayout, RawModelLayoutMut};
 
+#[cfg(feature = "alloc")]
+use alloc::{format, vec::Vec};
+
 /// verify if the input and hidden dimensions are compatible by checking:
 ///
 /// 1. they have the same dimensionality
@@ -26,6 +29,9 @@ impl ModelFeatures {
     pub fn from_shape_and_size(shape: &[usize], size: usize) -> Self {
         let input = shape[0];
         let output = *shape.last().unwrap();
+        if shape.len() > 2 && shape[1..shape.len() - 1].iter().any(|&w| w != shape[1]) {
+            return Self::custom(input, shape[1..shape.len() - 1].iter().copied(), output);
+        }
         let hidden = if shape.len() > 2 {
             shape[1]
         } else {
@@ -63,13 +69,31 @@ impl ModelFeatures {
             inner: ModelFormat::Shallow { hidden },
         }
     }
+    /// returns a new instance of [`ModelFeatures`] for a deep neural network whose hidden
+    /// layers have the given widths; see [`ModelFormat::custom`] for more details
+    pub fn custom<I>(input: usize, widths: I, output: usize) -> Self
+    where
+        I: IntoIterator<Item = usize>,
+    {
+        Self {
+            input,
+            output,
+            inner: ModelFormat::custom(widths),
+        }
+    }
     pub fn from_layout<L>(layout: L) -> Self
     where
         L: RawModelLayout,
     {
+        let widths = layout.widths();
+        let inner = if widths.iter().all(|&w| w == layout.hidden()) {
+            ModelFormat::new(layout.hidden(), layout.depth())
+        } else {
+            ModelFormat::custom(widths)
+        };
         Self {
             input: layout.input(),
-            inner: ModelFormat::new(layout.hidden(), layout.depth()),
+            inner,
             output: layout.output(),
         }
     }
@@ -81,16 +105,16 @@ impl ModelFeatures {
     pub const fn input_mut(&mut self) -> &mut usize {
         &mut self.input
     }
-    /// returns a copy of the inner format for the model
-    pub const fn inner(&self) -> ModelFormat {
-        self.inner
+    /// returns an immutable reference to the inner format for the model
+    pub const fn inner(&self) -> &ModelFormat {
+        &self.inner
     }
     /// returns a mutable reference to the inner format for the model
     pub const fn inner_mut(&mut self) -> &mut ModelFormat {
         &mut self.inner
     }
     /// returns a copy of the hidden features for the model
-    pub const fn hidden(&self) -> usize {
+    pub fn hidden(&self) -> usize {
         self.inner().hidden()
     }
     /// returns a mutable reference to the hidden features for the model
@@ -98,13 +122,21 @@ impl ModelFeatures {
         self.inner_mut().hidden_mut()
     }
     /// returns a copy of the number of hidden layers for the model
-    pub const fn layers(&self) -> usize {
+    pub fn layers(&self) -> usize {
         self.inner().layers()
     }
     /// returns a mutable reference to the number of hidden layers for the model
     pub const fn layers_mut(&mut self) -> &mut usize {
         self.inner_mut().layers_mut()
     }
+    /// returns the width of the hidden layer at the given index; see [`ModelFormat::width`]
+    pub fn width(&self, index: usize) -> usize {
+        self.inner().width(index)
+    }
+    /// returns the width of each hidden layer, in order
+    pub fn widths(&self) -> Vec<usize> {
+        self.inner().widths()
+    }
     /// returns a copy of the output features for the model
     pub const fn output(&self) -> usize {
         self.output
@@ -164,15 +196,22 @@ impl ModelFeatures {
     }
     /// the dimension of the input layer; (input, hidden)
     pub fn dim_input(&self) -> (usize, usize) {
-        (self.input(), self.hidden())
+        (self.input(), self.width(0))
     }
     /// the dimension of the hidden layers; (hidden, hidden)
+    ///
+    /// **note:** this assumes that every hidden layer shares the same width; see
+    /// [`dim_hidden_at`](ModelFeatures::dim_hidden_at) for models whose widths vary
     pub fn dim_hidden(&self) -> (usize, usize) {
         (self.hidden(), self.hidden())
     }
+    /// the dimension of the hidden layer at the given index; (width(i), width(i + 1))
+    pub fn dim_hidden_at(&self, index: usize) -> (usize, usize) {
+        (self.width(index), self.width(index + 1))
+    }
     /// the dimension of the output layer; (hidden, output)
     pub fn dim_output(&self) -> (usize, usize) {
-        (self.hidden(), self.output())
+        (self.width(self.layers()), self.output())
     }
     /// the total number of parameters in the model
     pub fn size(&self) -> usize {
@@ -180,15 +219,17 @@ impl ModelFeatures {
     }
     /// the total number of input parameters in the model
     pub fn size_input(&self) -> usize {
-        self.input() * self.hidden()
+        self.input() * self.width(0)
     }
     /// the total number of hidden parameters in the model
     pub fn size_hidden(&self) -> usize {
-        self.hidden() * self.hidden() * self.layers()
+        (0..self.layers())
+            .map(|i| self.width(i) * self.width(i + 1))
+            .sum()
     }
     /// the total number of output parameters in the model
     pub fn size_output(&self) -> usize {
-        self.hidden() * self.output()
+        self.width(self.layers()) * self.output()
     }
 }
 
@@ -205,6 +246,10 @@ impl RawModelLayout for ModelFeatures {
         self.layers()
     }
 
+    fn width(&self, index: usize) -> usize {
+        self.width(index)
+    }
+
     fn output(&self) -> usize {
         self.output()
     }
@@ -243,6 +288,14 @@ impl Default for ModelFeatures {
 
 impl core::fmt::Display for ModelFeatures {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if let ModelFormat::Custom { widths } = self.inner() {
+            return f.write_str(&format!(
+                "{{ input: {}, widths: {:?}, output: {} }}",
+                self.input(),
+                widths,
+                self.output()
+            ));
+        }
         f.write_str(&format!(
             "{{ input: {}, hidden: {}, layers: {}, output: {} }}",
             self.input(),
@@ -4,6 +4,9 @@
 */
 use super::ModelFormat;
 
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
 impl ModelFormat {
     pub const fn new(hidden: usize, layers: usize) -> Self {
         match layers {
@@ -26,42 +29,98 @@ impl ModelFormat {
     pub const fn shallow(hidden: usize) -> Self {
         ModelFormat::Shallow { hidden }
     }
-    /// returns a copy of the number of hidden features
-    pub const fn hidden(&self) -> usize {
+    /// create a new [`Custom`](ModelFormat::Custom) variant for a deep neural network whose
+    /// hidden layers have the given widths; returns the [`Layer`](ModelFormat::Layer) variant
+    /// if no widths are provided
+    pub fn custom<I>(widths: I) -> Self
+    where
+        I: IntoIterator<Item = usize>,
+    {
+        let widths = widths.into_iter().collect::<Vec<_>>();
+        if widths.is_empty() {
+            ModelFormat::Layer
+        } else {
+            ModelFormat::Custom { widths }
+        }
+    }
+    /// returns a copy of the number of hidden features; for the
+    /// [`Custom`](ModelFormat::Custom) variant, this is the width of the first hidden layer
+    pub fn hidden(&self) -> usize {
         match self {
             ModelFormat::Shallow { hidden } => *hidden,
             ModelFormat::Deep { hidden, .. } => *hidden,
+            ModelFormat::Custom { widths } => widths[0],
             ModelFormat::Layer => 0,
         }
     }
-    /// returns a mutable reference to the hidden features for the model
+    /// returns a mutable reference to the hidden features for the model; this will panic on
+    /// [`Custom`](ModelFormat::Custom) variants as their widths may differ
     pub const fn hidden_mut(&mut self) -> &mut usize {
         match self {
             ModelFormat::Shallow { hidden } => hidden,
             ModelFormat::Deep { hidden, .. } => hidden,
+            ModelFormat::Custom { .. } => {
+                panic!("Cannot mutate hidden features of a model with varying widths")
+            }
             ModelFormat::Layer => panic!("Cannot mutate hidden features of a layout model"),
         }
     }
     /// returns a copy of the number of layers for the model; if the variant is
     /// [`Shallow`](ModelFormat::Shallow), it returns 1
     /// returns `n` if the variant is [`Deep`](ModelFormat::Deep)
-    pub const fn layers(&self) -> usize {
+    /// returns one less than the number of widths if the variant is
+    /// [`Custom`](ModelFormat::Custom)
+    pub fn layers(&self) -> usize {
         match self {
             ModelFormat::Shallow { .. } => 1,
             ModelFormat::Deep { layers, .. } => *layers,
+            ModelFormat::Custom { widths } => widths.len() - 1,
             ModelFormat::Layer => 0,
         }
     }
     /// returns a mutable reference to the number of layers for the model; this will panic on
-    /// [`Shallow`](ModelFormat::Shallow) variants
+    /// [`Shallow`](ModelFormat::Shallow) and [`Custom`](ModelFormat::Custom) variants
     pub const fn layers_mut(&mut self) -> &mut usize {
         match self {
             ModelFormat::Shallow { .. } => panic!("Cannot mutate layers of a shallow model"),
             ModelFormat::Deep { layers, .. } => layers,
+            ModelFormat::Custom { .. } => {
+                panic!("Cannot mutate layers of a model with varying widths")
+            }
             ModelFormat::Layer => panic!("Cannot mutate layers of a layout model"),
         }
     }
-    /// update the number of hidden features for the model
+    /// returns the width of the hidden layer at the given index, where the input layer
+    /// produces the first and each hidden layer produces the next; uniform formats return the
+    /// number of hidden features for every index
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the index is greater than the number of layers of a
+    /// [`Custom`](ModelFormat::Custom) variant
+    pub fn width(&self, index: usize) -> usize {
+        match self {
+            ModelFormat::Custom { widths } => widths[index],
+            _ => self.hidden(),
+        }
+    }
+    /// returns the width of each hidden layer, in order
+    pub fn widths(&self) -> Vec<usize> {
+        match self {
+            ModelFormat::Custom { widths } => widths.clone(),
+            ModelFormat::Layer => Vec::new(),
+            _ => (0..=self.layers()).map(|i| self.width(i)).collect(),
+        }
+    }
+    /// returns true if every hidden layer has the same width
+    pub fn is_uniform(&self) -> bool {
+        match self {
+            ModelFormat::Custom { widths } => widths.iter().all(|&w| w == widths[0]),
+            _ => true,
+        }
+    }
+    /// update the number of hidden features for the model; every hidden layer of a
+    /// [`Custom`](ModelFormat::Custom) variant is set to the given width
     pub fn set_hidden(&mut self, value: usize) -> &mut Self {
         match self {
             ModelFormat::Shallow { hidden } => {
@@ -70,6 +129,9 @@ impl ModelFormat {
             ModelFormat::Deep { hidden, .. } => {
                 *hidden = value;
             }
+            ModelFormat::Custom { widths } => {
+                widths.fill(value);
+            }
             ModelFormat::Layer => {
                 panic!("Cannot mutate hidden features of a layout model");
             }
@@ -80,7 +142,8 @@ impl ModelFormat {
     ///
     /// **note:** this method will automatically convert the model to a [`Deep`](ModelFormat::Deep)
     /// variant if it is currently a [`Shallow`](ModelFormat::Shallow) variant and the number
-    /// of layers becomes greater than 1
+    /// of layers becomes greater than 1; the widths of a [`Custom`](ModelFormat::Custom)
+    /// variant are truncated, or extended using the final width, accordingly
     pub fn set_layers(&mut self, value: usize) -> &mut Self {
         match self {
             ModelFormat::Shallow { hidden } => {
@@ -95,6 +158,10 @@ impl ModelFormat {
             ModelFormat::Deep { layers, .. } => {
                 *layers = value;
             }
+            ModelFormat::Custom { widths } => {
+                let last = widths[widths.len() - 1];
+                widths.resize(value + 1, last);
+            }
             ModelFormat::Layer => {
                 panic!("Cannot mutate layers of a layout model");
             }
@@ -107,6 +174,10 @@ impl ModelFormat {
         match self {
             ModelFormat::Shallow { .. } => ModelFormat::Shallow { hidden },
             ModelFormat::Deep { layers, .. } => ModelFormat::Deep { hidden, layers },
+            ModelFormat::Custom { mut widths } => {
+                widths.fill(hidden);
+                ModelFormat::Custom { widths }
+            }
             ModelFormat::Layer => ModelFormat::Shallow { hidden },
         }
     }
@@ -116,18 +187,22 @@ impl ModelFormat {
     /// **note:** this method will automatically convert the model to a [`Deep`](ModelFormat::Deep)
     /// variant if it is currently a [`Shallow`](ModelFormat::Shallow) variant and the number
     /// of layers becomes greater than 1
-    pub fn with_layers(self, layers: usize) -> Self {
+    pub fn with_layers(mut self, layers: usize) -> Self {
         match layers {
             0 => ModelFormat::Layer,
+            _ if self.is_custom() => {
+                self.set_layers(layers);
+                self
+            }
             1 => match self {
                 ModelFormat::Shallow { hidden } => ModelFormat::Shallow { hidden },
                 ModelFormat::Deep { hidden, .. } => ModelFormat::Shallow { hidden },
-                ModelFormat::Layer => ModelFormat::Layer,
+                _ => ModelFormat::Layer,
             },
             _ => match self {
                 ModelFormat::Shallow { hidden } => ModelFormat::Deep { hidden, layers },
                 ModelFormat::Deep { hidden, .. } => ModelFormat::Deep { hidden, layers },
-                ModelFormat::Layer => ModelFormat::Deep { hidden: 16, layers },
+                _ => ModelFormat::Deep { hidden: 16, layers },
             },
         }
     }
@@ -144,6 +219,9 @@ impl Default for ModelFormat {
 
 impl core::fmt::Display for ModelFormat {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if let ModelFormat::Custom { widths } = self {
+            return write!(f, "{{ widths: {widths:?} }}");
+        }
         write!(
             f,
             "{{ hidden: {}, layers: {} }}",
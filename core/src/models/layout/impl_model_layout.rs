@@ -7,6 +7,9 @@ use super::ModelLayout;
 
 use crate::models::{Deep, NetworkDepth, RawModelLayout, Shallow};
 
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
 impl<F, D> ModelLayout<F, D>
 where
     F: RawModelLayout,
@@ -43,6 +46,14 @@ where
     pub fn layers(&self) -> usize {
         self.features().depth()
     }
+    /// returns the width of the hidden layer at the given index
+    pub fn width(&self, index: usize) -> usize {
+        self.features().width(index)
+    }
+    /// returns the width of each hidden layer, in order
+    pub fn widths(&self) -> Vec<usize> {
+        self.features().widths()
+    }
 }
 
 impl<F, D> core::ops::Deref for ModelLayout<F, D>
@@ -7,10 +7,20 @@
 //! parameter storage, relying on the [`ParamsBase`](concision_params::ParamsBase) instance to represent
 //! individual layers within the network.
 #[doc(inline)]
-pub use self::{layout::*, model_params::*, traits::*, types::*};
+#[cfg(feature = "safetensors")]
+pub use self::safetensors::*;
+#[doc(inline)]
+pub use self::{
+    checkpoint::*, dtype::*, layout::*, model_params::*, summary::*, traits::*, types::*,
+};
 
+pub mod checkpoint;
+pub mod dtype;
 pub mod layout;
 pub mod model_params;
+#[cfg(feature = "safetensors")]
+pub mod safetensors;
+pub mod summary;
 
 mod impls {
     mod impl_model_params;
@@ -38,8 +48,13 @@ mod traits {
 
 #[doc(hidden)]
 pub(crate) mod prelude {
+    pub use super::checkpoint::*;
+    pub use super::dtype::*;
     pub use super::layout::*;
     pub use super::model_params::*;
+    #[cfg(feature = "safetensors")]
+    pub use super::safetensors::*;
+    pub use super::summary::*;
     pub use super::traits::*;
     pub use super::types::*;
 }
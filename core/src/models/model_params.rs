@@ -6,6 +6,9 @@
 use concision_params::ParamsBase;
 use ndarray::{Dimension, RawData};
 
+#[cfg(feature = "alloc")]
+use alloc::collections::BTreeSet;
+
 use crate::RawHidden;
 
 pub struct DeepNeuralNetworkStore<X, Y, Z> {
@@ -22,6 +25,10 @@ pub struct DeepNeuralNetworkStore<X, Y, Z> {
 ///
 /// With that in mind, we don't reccomend using the implementation directly, rather, leverage
 /// a type alias that best suites your use case (e.g. owned parameters, arc parameters, etc.).
+///
+/// The container also records which of its layers are _frozen_, i.e. excluded from training;
+/// the layers are indexed in order, with the input layer at zero and the output layer last.
+/// The frozen layers are a training-time setting and are not serialized with the parameters.
 pub struct ModelParamsBase<S, D, H, A = <S as RawData>::Elem>
 where
     D: Dimension,
@@ -34,4 +41,6 @@ where
     pub(crate) hidden: H,
     /// the output layer of the model
     pub(crate) output: ParamsBase<S, D, A>,
+    /// the indices of the layers excluded from training
+    pub(crate) frozen: BTreeSet<usize>,
 }
@@ -0,0 +1,155 @@
+/*
+    Appellation: safetensors <module>
+    Created At: 2026.10.18:23:58:40
+    Contrib: @FL03
+*/
+//! This module implements support for the [safetensors](https://github.com/huggingface/safetensors)
+//! format, enabling parameters to be exchanged with other tooling.
+//!
+//! A safetensors file begins with the length of its header, stored as a little-endian `u64`,
+//! followed by the header itself; a JSON object mapping the name of each tensor onto its
+//! [`Dtype`], shape and the offsets of its data within the buffer that follows. The optional
+//! `__metadata__` entry of the header stores a set of free-form string key-value pairs. The
+//! data of each tensor is stored in row-major order using little-endian values.
+//!
+//! - [`SafeTensors`]: a parsed view of the format, borrowing its data; tensors may be copied
+//!   into owned arrays or, for aligned data on little-endian targets, viewed without copying
+//! - [`SafeTensorsWriter`]: accumulates a set of tensors before encoding them
+//!
+//! Parameters are stored as a pair of tensors named `<prefix>.weights` and `<prefix>.bias`
+//! while the layers of a [`DeepModelParams`](crate::models::DeepModelParams) use the prefixes
+//! `input`, `hidden.<i>` and `output`, e.g. `hidden.3.weights`.
+//!
+//! With the `memmap2` feature enabled, the unsafe `mmap` function may be used to memory-map a
+//! file whose parameters can then be loaded as a [`ParamsView`](concision_params::ParamsView)
+//! without copying them.
+mod impl_model_params;
+mod impl_reader;
+mod impl_writer;
+
+use super::{Dtype, Element};
+use hashbrown::HashMap;
+
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+
+/// the key of the header entry storing the metadata of a safetensors file
+pub const SAFETENSORS_METADATA_KEY: &str = "__metadata__";
+
+/// The [`TensorInfo`] describes a single tensor stored within a safetensors file.
+#[derive(Clone, Debug, Eq, Hash, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct TensorInfo {
+    /// the element type of the tensor
+    pub(crate) dtype: Dtype,
+    /// the shape of the tensor
+    pub(crate) shape: Vec<usize>,
+    /// the start and end of the data of the tensor, relative to the end of the header
+    pub(crate) data_offsets: [usize; 2],
+}
+
+/// The [`SafeTensors`] is a parsed view of a safetensors file, borrowing its data so that
+/// tensors may be loaded without copying them.
+#[derive(Clone, Debug)]
+pub struct SafeTensors<'a> {
+    /// the tensors described by the header
+    pub(crate) tensors: HashMap<String, TensorInfo>,
+    /// the metadata stored within the header
+    pub(crate) metadata: HashMap<String, String>,
+    /// the data following the header
+    pub(crate) data: &'a [u8],
+}
+
+/// The [`SafeTensorsWriter`] accumulates a set of named tensors, alongside any metadata,
+/// before encoding them using the safetensors format.
+#[derive(Clone, Debug, Default)]
+pub struct SafeTensorsWriter {
+    /// the tensors to be written, in order of insertion
+    pub(crate) tensors: Vec<TensorData>,
+    /// the metadata to be stored within the header
+    pub(crate) metadata: HashMap<String, String>,
+}
+
+/// an encoded tensor awaiting to be written
+#[derive(Clone, Debug)]
+pub(crate) struct TensorData {
+    pub(crate) name: String,
+    pub(crate) dtype: Dtype,
+    pub(crate) shape: Vec<usize>,
+    pub(crate) bytes: Vec<u8>,
+}
+
+/// The [`SafeTensorsElement`] trait is a sealed trait implemented for the element types that
+/// may be read from, and written to, the safetensors format; each is stored using the
+/// [`Dtype`] of its [`Element`] implementation.
+pub trait SafeTensorsElement: Element {
+    private!();
+    /// decode an element from its little-endian representation
+    fn from_le_slice(bytes: &[u8]) -> Self;
+    /// append the little-endian representation of the element onto the buffer
+    fn extend_le_bytes(&self, buf: &mut Vec<u8>);
+}
+
+/// memory-map the file at the given path, returning a buffer that may be parsed using
+/// [`SafeTensors::parse`] to load its tensors without copying them.
+///
+/// ## Safety
+///
+/// The caller must ensure that the file is neither modified nor truncated, by this or any
+/// other process, for as long as the returned mapping (or anything borrowing from it) is
+/// alive; doing so results in undefined behavior.
+#[cfg(feature = "memmap2")]
+pub unsafe fn mmap<P>(path: P) -> crate::Result<memmap2::Mmap>
+where
+    P: AsRef<std::path::Path>,
+{
+    let file = std::fs::File::open(path)?;
+    // SAFETY: the mapping is read-only and the caller upholds the contract that the
+    // underlying file is not modified while it is mapped
+    let mmap = unsafe { memmap2::Mmap::map(&file)? };
+    Ok(mmap)
+}
+
+macro_rules! impl_safetensors_element {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl SafeTensorsElement for $t {
+                seal!();
+
+                fn from_le_slice(bytes: &[u8]) -> Self {
+                    let mut buf = [0u8; core::mem::size_of::<$t>()];
+                    buf.copy_from_slice(bytes);
+                    <$t>::from_le_bytes(buf)
+                }
+
+                fn extend_le_bytes(&self, buf: &mut Vec<u8>) {
+                    buf.extend_from_slice(&self.to_le_bytes());
+                }
+            }
+        )*
+    };
+}
+
+impl_safetensors_element! { f32, f64 }
+
+impl TensorInfo {
+    /// returns the element type of the tensor
+    pub const fn dtype(&self) -> Dtype {
+        self.dtype
+    }
+    /// returns the shape of the tensor
+    pub fn shape(&self) -> &[usize] {
+        self.shape.as_slice()
+    }
+    /// returns the start and end of the data of the tensor
+    pub const fn data_offsets(&self) -> [usize; 2] {
+        self.data_offsets
+    }
+    /// returns the number of elements in the tensor
+    pub fn len(&self) -> usize {
+        self.shape.iter().product()
+    }
+    /// returns true if the tensor has no elements
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
@@ -0,0 +1,73 @@
+/*
+    Appellation: impl_model_params <module>
+    Created At: 2026.10.19:00:52:18
+    Contrib: @FL03
+*/
+use super::{SafeTensors, SafeTensorsElement, SafeTensorsWriter};
+use crate::error::Result;
+use crate::models::{DeepModelParams, DeepParamsBase};
+use ndarray::{Data, RemoveAxis, ViewRepr};
+
+#[cfg(feature = "alloc")]
+use alloc::{format, vec::Vec};
+
+impl<A, S, D> DeepParamsBase<S, D, A>
+where
+    A: SafeTensorsElement,
+    D: RemoveAxis,
+    S: Data<Elem = A>,
+{
+    /// returns a [`SafeTensorsWriter`] containing every layer of the model; the layers are
+    /// stored using the prefixes `input`, `hidden.<i>` and `output`
+    pub fn to_safetensors(&self) -> SafeTensorsWriter {
+        let mut writer = SafeTensorsWriter::new();
+        writer.insert_params("input", self.input());
+        for (i, layer) in self.hidden().iter().enumerate() {
+            writer.insert_params(&format!("hidden.{i}"), layer);
+        }
+        writer.insert_params("output", self.output());
+        writer
+    }
+}
+
+/// returns the number of hidden layers stored within the tensors, i.e. the number of
+/// consecutive `hidden.<i>.weights` tensors
+fn count_hidden(tensors: &SafeTensors<'_>) -> usize {
+    (0..)
+        .take_while(|i| tensors.contains(&format!("hidden.{i}.weights")))
+        .count()
+}
+
+impl<A, D> DeepModelParams<A, D>
+where
+    A: SafeTensorsElement,
+    D: RemoveAxis,
+{
+    /// load a copy of the model from the given tensors; see
+    /// [`to_safetensors`](DeepParamsBase::to_safetensors) for the naming scheme
+    pub fn from_safetensors(tensors: &SafeTensors<'_>) -> Result<Self> {
+        let input = tensors.params("input")?;
+        let hidden = (0..count_hidden(tensors))
+            .map(|i| tensors.params(&format!("hidden.{i}")))
+            .collect::<Result<Vec<_>>>()?;
+        let output = tensors.params("output")?;
+        Ok(Self::new(input, hidden, output))
+    }
+}
+
+impl<'a, A, D> DeepParamsBase<ViewRepr<&'a A>, D, A>
+where
+    A: SafeTensorsElement,
+    D: RemoveAxis,
+{
+    /// load a view of the model from the given tensors without copying them; see
+    /// [`SafeTensors::view`] for the requirements
+    pub fn from_safetensors_view(tensors: &SafeTensors<'a>) -> Result<Self> {
+        let input = tensors.params_view("input")?;
+        let hidden = (0..count_hidden(tensors))
+            .map(|i| tensors.params_view(&format!("hidden.{i}")))
+            .collect::<Result<Vec<_>>>()?;
+        let output = tensors.params_view("output")?;
+        Ok(Self::new(input, hidden, output))
+    }
+}
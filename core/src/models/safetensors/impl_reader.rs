@@ -0,0 +1,215 @@
+/*
+    Appellation: impl_reader <module>
+    Created At: 2026.10.19:00:09:27
+    Contrib: @FL03
+*/
+use super::{SAFETENSORS_METADATA_KEY, SafeTensors, SafeTensorsElement, TensorInfo};
+use crate::error::{Error, Result};
+use concision_params::{Params, ParamsBase, ParamsView, extract_bias_dim};
+use hashbrown::HashMap;
+use ndarray::{Array, ArrayView, Dimension, IxDyn, RawData, RemoveAxis};
+
+#[cfg(feature = "alloc")]
+use alloc::{
+    format,
+    string::{String, ToString},
+};
+
+/// returns a new [`InvalidSafeTensors`](Error::InvalidSafeTensors) error with the given message
+fn invalid(msg: impl ToString) -> Error {
+    Error::InvalidSafeTensors(msg.to_string())
+}
+
+/// verify that the bias of the named parameters is shaped according to their weights
+fn check_bias<S, D, A>(prefix: &str, params: &ParamsBase<S, D, A>) -> Result<()>
+where
+    D: RemoveAxis,
+    S: RawData<Elem = A>,
+{
+    let expected = extract_bias_dim(params.weights());
+    if params.bias().raw_dim() != expected {
+        return Err(invalid(format!(
+            "the bias of `{prefix}` has shape {:?} but its weights expect {:?}",
+            params.bias().shape(),
+            expected.slice()
+        )));
+    }
+    Ok(())
+}
+
+impl TensorInfo {
+    /// verify that the tensor describes a region of the buffer with the given length whose
+    /// size agrees with the shape and element type of the tensor
+    fn check(&self, name: &str, len: usize) -> Result<()> {
+        let [start, end] = self.data_offsets;
+        if start > end || end > len {
+            return Err(invalid(format!(
+                "the offsets [{start}, {end}] of the tensor `{name}` exceed the {len} bytes of data"
+            )));
+        }
+        let size = self
+            .shape
+            .iter()
+            .try_fold(self.dtype.size(), |acc, &n| acc.checked_mul(n))
+            .ok_or_else(|| invalid(format!("the size of the tensor `{name}` overflows")))?;
+        if end - start != size {
+            return Err(invalid(format!(
+                "the tensor `{name}` occupies {} bytes but its shape {:?} requires {size}",
+                end - start,
+                self.shape
+            )));
+        }
+        Ok(())
+    }
+    /// returns the shape of the tensor as a dimension of type `D`
+    fn dim<D: Dimension>(&self, name: &str) -> Result<D> {
+        D::from_dimension(&IxDyn(&self.shape)).ok_or_else(|| {
+            invalid(format!(
+                "the tensor `{name}` has {} dimensions but {} were expected",
+                self.shape.len(),
+                D::NDIM.unwrap_or_default()
+            ))
+        })
+    }
+}
+
+impl<'a> SafeTensors<'a> {
+    /// parse the given buffer, borrowing the data of its tensors
+    pub fn parse(bytes: &'a [u8]) -> Result<Self> {
+        let (len, rest) = bytes
+            .split_first_chunk::<8>()
+            .ok_or_else(|| invalid("the data is too short to contain a header"))?;
+        let len = u64::from_le_bytes(*len);
+        let len = usize::try_from(len)
+            .ok()
+            .filter(|&n| n <= rest.len())
+            .ok_or_else(|| {
+                invalid(format!(
+                    "the length of the header ({len}) exceeds the {} bytes available",
+                    rest.len()
+                ))
+            })?;
+        let (header, data) = rest.split_at(len);
+        let header =
+            core::str::from_utf8(header).map_err(|_| invalid("the header is not valid UTF-8"))?;
+        let mut entries: HashMap<String, serde_json::Value> = serde_json::from_str(header)?;
+        let metadata = match entries.remove(SAFETENSORS_METADATA_KEY) {
+            Some(value) => serde_json::from_value(value)?,
+            None => HashMap::new(),
+        };
+        let tensors = entries
+            .into_iter()
+            .map(|(name, value)| {
+                let info: TensorInfo = serde_json::from_value(value)?;
+                info.check(&name, data.len())?;
+                Ok((name, info))
+            })
+            .collect::<Result<HashMap<_, _>>>()?;
+        Ok(Self {
+            tensors,
+            metadata,
+            data,
+        })
+    }
+    /// returns the number of tensors
+    pub fn len(&self) -> usize {
+        self.tensors.len()
+    }
+    /// returns true if there are no tensors
+    pub fn is_empty(&self) -> bool {
+        self.tensors.is_empty()
+    }
+    /// returns true if a tensor with the given name exists
+    pub fn contains(&self, name: &str) -> bool {
+        self.tensors.contains_key(name)
+    }
+    /// returns an iterator over the names of the tensors, in no particular order
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.tensors.keys().map(String::as_str)
+    }
+    /// returns a reference to the metadata stored within the header
+    pub const fn metadata(&self) -> &HashMap<String, String> {
+        &self.metadata
+    }
+    /// returns the information describing the tensor with the given name, if any
+    pub fn tensor(&self, name: &str) -> Option<&TensorInfo> {
+        self.tensors.get(name)
+    }
+    /// returns the information and raw data of the given tensor, verifying that it stores
+    /// elements of type `A`
+    fn raw<A: SafeTensorsElement>(&self, name: &str) -> Result<(&TensorInfo, &'a [u8])> {
+        let info = self
+            .tensor(name)
+            .ok_or_else(|| Error::TensorNotFound(name.to_string()))?;
+        if info.dtype != A::DTYPE {
+            return Err(Error::InvalidTensorDtype {
+                name: name.to_string(),
+                found: info.dtype.to_string(),
+                expected: A::DTYPE.to_string(),
+            });
+        }
+        let [start, end] = info.data_offsets;
+        Ok((info, &self.data[start..end]))
+    }
+    /// returns a copy of the tensor with the given name
+    pub fn array<A, D>(&self, name: &str) -> Result<Array<A, D>>
+    where
+        A: SafeTensorsElement,
+        D: Dimension,
+    {
+        let (info, bytes) = self.raw::<A>(name)?;
+        let data = bytes
+            .chunks_exact(core::mem::size_of::<A>())
+            .map(A::from_le_slice)
+            .collect();
+        Array::from_shape_vec(info.dim::<D>(name)?, data).map_err(Into::into)
+    }
+    /// returns a view of the tensor with the given name without copying its data; this
+    /// requires the data to be aligned to the element type `A` and the target to be
+    /// little-endian.
+    pub fn view<A, D>(&self, name: &str) -> Result<ArrayView<'a, A, D>>
+    where
+        A: SafeTensorsElement,
+        D: Dimension,
+    {
+        if cfg!(target_endian = "big") {
+            return Err(Error::UnsupportedOperation(
+                "tensors may only be viewed on little-endian targets".to_string(),
+            ));
+        }
+        let (info, bytes) = self.raw::<A>(name)?;
+        // SAFETY: the trait is sealed and only implemented for primitive floats, for which
+        // every (little-endian) bit pattern is a valid value
+        let (head, data, tail) = unsafe { bytes.align_to::<A>() };
+        if !head.is_empty() || !tail.is_empty() {
+            return Err(Error::UnalignedTensor(name.to_string()));
+        }
+        ArrayView::from_shape(info.dim::<D>(name)?, data).map_err(Into::into)
+    }
+    /// returns a copy of the parameters stored using the given prefix, i.e. the tensors
+    /// `<prefix>.weights` and `<prefix>.bias`
+    pub fn params<A, D>(&self, prefix: &str) -> Result<Params<A, D>>
+    where
+        A: SafeTensorsElement,
+        D: RemoveAxis,
+    {
+        let weights = self.array(&format!("{prefix}.weights"))?;
+        let bias = self.array(&format!("{prefix}.bias"))?;
+        let params = Params::new(bias, weights);
+        check_bias(prefix, &params)?;
+        Ok(params)
+    }
+    /// returns a view of the parameters stored using the given prefix without copying them;
+    /// see [`view`](Self::view) for the requirements
+    pub fn params_view<A, D>(&self, prefix: &str) -> Result<ParamsView<'a, A, D>>
+    where
+        A: SafeTensorsElement,
+        D: RemoveAxis,
+    {
+        let weights = self.view(&format!("{prefix}.weights"))?;
+        let bias = self.view(&format!("{prefix}.bias"))?;
+        let params = ParamsBase::new(bias, weights);
+        check_bias(prefix, &params)?;
+        Ok(params)
+    }
+}
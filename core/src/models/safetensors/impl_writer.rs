@@ -0,0 +1,159 @@
+/*
+    Appellation: impl_writer <module>
+    Created At: 2026.10.19:00:31:05
+    Contrib: @FL03
+*/
+use super::{
+    SAFETENSORS_METADATA_KEY, SafeTensorsElement, SafeTensorsWriter, TensorData, TensorInfo,
+};
+use crate::error::Result;
+use concision_params::ParamsBase;
+use ndarray::{ArrayBase, Data, Dimension};
+
+#[cfg(feature = "alloc")]
+use alloc::{format, string::ToString, vec::Vec};
+
+impl SafeTensorsWriter {
+    /// returns a new, empty writer
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// returns the number of tensors
+    pub fn len(&self) -> usize {
+        self.tensors.len()
+    }
+    /// returns true if there are no tensors
+    pub fn is_empty(&self) -> bool {
+        self.tensors.is_empty()
+    }
+    /// returns an iterator over the names of the tensors, in order of insertion
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.tensors.iter().map(|t| t.name.as_str())
+    }
+    /// insert a key-value pair into the metadata of the header
+    pub fn add_metadata<K, V>(&mut self, key: K, value: V) -> &mut Self
+    where
+        K: ToString,
+        V: ToString,
+    {
+        self.metadata.insert(key.to_string(), value.to_string());
+        self
+    }
+    /// insert the array using the given name, replacing any tensor of the same name
+    pub fn insert_array<A, S, D>(
+        &mut self,
+        name: impl ToString,
+        array: &ArrayBase<S, D, A>,
+    ) -> &mut Self
+    where
+        A: SafeTensorsElement,
+        D: Dimension,
+        S: Data<Elem = A>,
+    {
+        let mut bytes = Vec::with_capacity(array.len() * core::mem::size_of::<A>());
+        // iterating over the array yields its elements in logical (row-major) order
+        array.iter().for_each(|x| x.extend_le_bytes(&mut bytes));
+        let tensor = TensorData {
+            name: name.to_string(),
+            dtype: A::DTYPE,
+            shape: array.shape().to_vec(),
+            bytes,
+        };
+        match self.tensors.iter_mut().find(|t| t.name == tensor.name) {
+            Some(prev) => *prev = tensor,
+            None => self.tensors.push(tensor),
+        }
+        self
+    }
+    /// insert the parameters using the given prefix, storing them as the tensors
+    /// `<prefix>.weights` and `<prefix>.bias`
+    pub fn insert_params<A, S, D>(
+        &mut self,
+        prefix: &str,
+        params: &ParamsBase<S, D, A>,
+    ) -> &mut Self
+    where
+        A: SafeTensorsElement,
+        D: Dimension,
+        S: Data<Elem = A>,
+    {
+        self.insert_array(format!("{prefix}.weights"), params.weights())
+            .insert_array(format!("{prefix}.bias"), params.bias())
+    }
+    /// consumes the current instance to create another with the given metadata
+    pub fn with_metadata<K, V>(mut self, key: K, value: V) -> Self
+    where
+        K: ToString,
+        V: ToString,
+    {
+        self.add_metadata(key, value);
+        self
+    }
+    /// consumes the current instance to create another containing the given array
+    pub fn with_array<A, S, D>(mut self, name: impl ToString, array: &ArrayBase<S, D, A>) -> Self
+    where
+        A: SafeTensorsElement,
+        D: Dimension,
+        S: Data<Elem = A>,
+    {
+        self.insert_array(name, array);
+        self
+    }
+    /// consumes the current instance to create another containing the given parameters
+    pub fn with_params<A, S, D>(mut self, prefix: &str, params: &ParamsBase<S, D, A>) -> Self
+    where
+        A: SafeTensorsElement,
+        D: Dimension,
+        S: Data<Elem = A>,
+    {
+        self.insert_params(prefix, params);
+        self
+    }
+    /// encode the tensors using the safetensors format.
+    ///
+    /// The data of the tensors is ordered by the size of their elements, largest first, and
+    /// the header is padded to a multiple of eight bytes, ensuring that every tensor is aligned
+    /// to its element type whenever the buffer itself is aligned.
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        let mut order = (0..self.tensors.len()).collect::<Vec<_>>();
+        order.sort_by_key(|&i| core::cmp::Reverse(self.tensors[i].dtype.size()));
+        let mut header = serde_json::Map::new();
+        if !self.metadata.is_empty() {
+            header.insert(
+                SAFETENSORS_METADATA_KEY.to_string(),
+                serde_json::to_value(&self.metadata)?,
+            );
+        }
+        let mut offset = 0;
+        for &i in &order {
+            let tensor = &self.tensors[i];
+            let info = TensorInfo {
+                dtype: tensor.dtype,
+                shape: tensor.shape.clone(),
+                data_offsets: [offset, offset + tensor.bytes.len()],
+            };
+            offset += tensor.bytes.len();
+            header.insert(tensor.name.clone(), serde_json::to_value(info)?);
+        }
+        let mut header = serde_json::to_string(&header)?;
+        // pad the header with whitespace so that the data begins on an 8-byte boundary
+        let padded = header.len().next_multiple_of(8);
+        header.extend(core::iter::repeat_n(' ', padded - header.len()));
+        let mut buf = Vec::with_capacity(8 + header.len() + offset);
+        buf.extend_from_slice(&(header.len() as u64).to_le_bytes());
+        buf.extend_from_slice(header.as_bytes());
+        for &i in &order {
+            buf.extend_from_slice(&self.tensors[i].bytes);
+        }
+        Ok(buf)
+    }
+    /// _atomically_ write the encoded tensors to the given path
+    #[cfg(feature = "std")]
+    pub fn save<P>(&self, path: P) -> Result<()>
+    where
+        P: AsRef<std::path::Path>,
+    {
+        let bytes = self.to_bytes()?;
+        crate::utils::write_atomic(path, &bytes)
+    }
+}
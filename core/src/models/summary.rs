@@ -0,0 +1,58 @@
+/*
+    Appellation: summary <module>
+    Created At: 2026.10.18:21:58:12
+    Contrib: @FL03
+*/
+//! This module provides the [`ModelSummary`], a report describing each layer of a model
+//! alongside the totals of the entire network.
+//!
+//! For each layer, the summary records the shape and number of its weights and bias, whether
+//! the layer is trainable or frozen, its activation, the memory occupied by its parameters,
+//! and an estimate of the floating point operations (FLOPs) required to forward a single
+//! sample through it. The summary may be rendered as a text table using its [`Display`]
+//! implementation or, with the `serde` feature enabled, serialized for use by other tooling.
+//!
+//! [`Display`]: core::fmt::Display
+mod impl_summary;
+
+use crate::activate::ActivationKind;
+
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+
+/// The [`LayerSummary`] describes a single layer of a model.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub struct LayerSummary {
+    /// the name of the layer, e.g. `input`, `hidden.0` or `output`
+    pub(crate) name: String,
+    /// the shape of the weights of the layer
+    pub(crate) weight_shape: Vec<usize>,
+    /// the shape of the bias of the layer
+    pub(crate) bias_shape: Vec<usize>,
+    /// the activation applied to the output of the layer, if known
+    pub(crate) activation: Option<ActivationKind>,
+    /// true if the parameters of the layer are updated during training
+    pub(crate) trainable: bool,
+    /// the number of bytes used by a single parameter
+    pub(crate) elem_size: usize,
+}
+
+/// The [`ModelSummary`] describes each layer of a model, providing the totals of the entire
+/// network alongside the name of its element type.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub struct ModelSummary {
+    /// the name of the element type of the parameters
+    pub(crate) dtype: String,
+    /// the summary of each layer, in order
+    pub(crate) layers: Vec<LayerSummary>,
+}
@@ -0,0 +1,271 @@
+/*
+    Appellation: impl_summary <module>
+    Created At: 2026.10.18:22:03:41
+    Contrib: @FL03
+*/
+use super::{LayerSummary, ModelSummary};
+use crate::activate::ActivationKind;
+use concision_params::ParamsBase;
+use core::fmt;
+use ndarray::{Dimension, RawData};
+
+#[cfg(feature = "alloc")]
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+/// format the given number of bytes using binary units
+fn fmt_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{bytes} B")
+    } else {
+        format!("{value:.2} {}", UNITS[unit])
+    }
+}
+
+/// format the given shape as a tuple, e.g. `(3, 4)`
+fn fmt_shape(shape: &[usize]) -> String {
+    match shape {
+        [n] => format!("({n},)"),
+        _ => format!(
+            "({})",
+            shape
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    }
+}
+
+impl LayerSummary {
+    /// create a new summary for the layer with the given name and parameters; the layer is
+    /// trainable and has no activation by default
+    pub fn from_params<A, S, D>(name: impl ToString, params: &ParamsBase<S, D, A>) -> Self
+    where
+        D: Dimension,
+        S: RawData<Elem = A>,
+    {
+        Self {
+            name: name.to_string(),
+            weight_shape: params.weights().shape().to_vec(),
+            bias_shape: params.bias().shape().to_vec(),
+            activation: None,
+            trainable: true,
+            elem_size: core::mem::size_of::<A>(),
+        }
+    }
+    /// returns the name of the layer
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    /// returns the shape of the weights of the layer
+    pub fn weight_shape(&self) -> &[usize] {
+        &self.weight_shape
+    }
+    /// returns the shape of the bias of the layer
+    pub fn bias_shape(&self) -> &[usize] {
+        &self.bias_shape
+    }
+    /// returns the activation of the layer, if any
+    pub const fn activation(&self) -> Option<ActivationKind> {
+        self.activation
+    }
+    /// returns true if the layer is trainable
+    pub const fn is_trainable(&self) -> bool {
+        self.trainable
+    }
+    /// returns true if the layer is frozen, i.e. not trainable
+    pub const fn is_frozen(&self) -> bool {
+        !self.trainable
+    }
+    /// returns the number of bytes used by a single parameter of the layer
+    pub const fn elem_size(&self) -> usize {
+        self.elem_size
+    }
+    /// returns the number of weights of the layer
+    pub fn count_weights(&self) -> usize {
+        self.weight_shape.iter().product()
+    }
+    /// returns the number of biases of the layer
+    pub fn count_bias(&self) -> usize {
+        self.bias_shape.iter().product()
+    }
+    /// returns the total number of parameters of the layer
+    pub fn count_params(&self) -> usize {
+        self.count_weights() + self.count_bias()
+    }
+    /// returns the number of bytes occupied by the parameters of the layer
+    pub fn bytes(&self) -> usize {
+        self.count_params() * self.elem_size
+    }
+    /// returns an estimate of the number of floating point operations required to forward a
+    /// single sample through the layer; each weight contributes a multiplication and an
+    /// addition, the latter of which accounts for the bias
+    pub fn flops(&self) -> usize {
+        2 * self.count_weights()
+    }
+    /// set the activation of the layer
+    pub fn set_activation(&mut self, activation: Option<ActivationKind>) -> &mut Self {
+        self.activation = activation;
+        self
+    }
+    /// set whether or not the layer is trainable
+    pub fn set_trainable(&mut self, trainable: bool) -> &mut Self {
+        self.trainable = trainable;
+        self
+    }
+    /// consumes the current instance to create another with the given activation
+    pub fn with_activation(self, activation: ActivationKind) -> Self {
+        Self {
+            activation: Some(activation),
+            ..self
+        }
+    }
+    /// consumes the current instance to create another with the given trainable status
+    pub fn with_trainable(self, trainable: bool) -> Self {
+        Self { trainable, ..self }
+    }
+}
+
+impl ModelSummary {
+    /// create a new summary from the given layers, whose parameters are of type `A`
+    pub fn new<A>(layers: Vec<LayerSummary>) -> Self {
+        Self {
+            dtype: core::any::type_name::<A>().to_string(),
+            layers,
+        }
+    }
+    /// returns the name of the element type of the parameters
+    pub fn dtype(&self) -> &str {
+        &self.dtype
+    }
+    /// returns an immutable slice of the summaries of each layer
+    pub fn layers(&self) -> &[LayerSummary] {
+        &self.layers
+    }
+    /// returns a mutable slice of the summaries of each layer
+    pub fn layers_mut(&mut self) -> &mut [LayerSummary] {
+        &mut self.layers
+    }
+    /// returns the number of layers in the summary
+    pub fn len(&self) -> usize {
+        self.layers.len()
+    }
+    /// returns true if the summary has no layers
+    pub fn is_empty(&self) -> bool {
+        self.layers.is_empty()
+    }
+    /// returns the total number of parameters of the model
+    pub fn count_params(&self) -> usize {
+        self.layers.iter().map(LayerSummary::count_params).sum()
+    }
+    /// returns the number of parameters belonging to trainable layers
+    pub fn count_trainable(&self) -> usize {
+        self.layers
+            .iter()
+            .filter(|layer| layer.is_trainable())
+            .map(LayerSummary::count_params)
+            .sum()
+    }
+    /// returns the number of parameters belonging to frozen layers
+    pub fn count_frozen(&self) -> usize {
+        self.count_params() - self.count_trainable()
+    }
+    /// returns the total number of bytes occupied by the parameters of the model
+    pub fn bytes(&self) -> usize {
+        self.layers.iter().map(LayerSummary::bytes).sum()
+    }
+    /// returns an estimate of the number of floating point operations required to forward a
+    /// single sample through the model
+    pub fn flops(&self) -> usize {
+        self.layers.iter().map(LayerSummary::flops).sum()
+    }
+}
+
+impl fmt::Display for LayerSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{name}: weights {w}, bias {b}, params {p}",
+            name = self.name,
+            w = fmt_shape(&self.weight_shape),
+            b = fmt_shape(&self.bias_shape),
+            p = self.count_params()
+        )
+    }
+}
+
+/// renders the summary as a table with one row per layer followed by the totals
+impl fmt::Display for ModelSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const HEADER: [&str; 8] = [
+            "Layer",
+            "Weights",
+            "Bias",
+            "Params",
+            "Trainable",
+            "Activation",
+            "Memory",
+            "FLOPs",
+        ];
+        let rows = self
+            .layers
+            .iter()
+            .map(|layer| {
+                [
+                    layer.name.clone(),
+                    fmt_shape(&layer.weight_shape),
+                    fmt_shape(&layer.bias_shape),
+                    layer.count_params().to_string(),
+                    if layer.trainable { "yes" } else { "no" }.to_string(),
+                    layer
+                        .activation
+                        .map_or_else(|| "-".to_string(), |rho| rho.to_string()),
+                    fmt_bytes(layer.bytes()),
+                    layer.flops().to_string(),
+                ]
+            })
+            .collect::<Vec<_>>();
+        let mut widths = HEADER.map(str::len);
+        for row in &rows {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.len());
+            }
+        }
+        let total = widths.iter().sum::<usize>() + 3 * (widths.len() - 1);
+        let rule = "-".repeat(total);
+        for (i, (cell, width)) in HEADER.iter().zip(widths).enumerate() {
+            let sep = if i == 0 { "" } else { " | " };
+            write!(f, "{sep}{cell:<width$}")?;
+        }
+        writeln!(f)?;
+        writeln!(f, "{rule}")?;
+        for row in &rows {
+            for (i, (cell, width)) in row.iter().zip(widths).enumerate() {
+                let sep = if i == 0 { "" } else { " | " };
+                write!(f, "{sep}{cell:<width$}")?;
+            }
+            writeln!(f)?;
+        }
+        writeln!(f, "{rule}")?;
+        writeln!(
+            f,
+            "Total params: {} (trainable: {}, frozen: {})",
+            self.count_params(),
+            self.count_trainable(),
+            self.count_frozen()
+        )?;
+        writeln!(f, "Memory: {} ({})", fmt_bytes(self.bytes()), self.dtype)?;
+        write!(f, "FLOPs (per sample): {}", self.flops())
+    }
+}
@@ -3,6 +3,9 @@
     Created At: 2026.01.06:14:38:32
     Contrib: @FL03
 */
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
 /// The [`RawModelLayout`] trait defines a minimal interface for objects capable of representing
 /// the _layout_; i.e. the number of input, hidden, and output features of a neural network
 /// model containing some number of hidden layers.
@@ -20,18 +23,35 @@ pub trait RawModelLayout {
     fn output(&self) -> usize;
     /// returns the number of hidden layers within the network
     fn depth(&self) -> usize;
-
+    /// returns the width of the hidden layer at the given index, where the input layer
+    /// produces the first and each hidden layer produces the next; layouts whose hidden layers
+    /// share a single width return the number of hidden features for every index
+    fn width(&self, index: usize) -> usize {
+        let _ = index;
+        self.hidden()
+    }
+    /// returns the width of each hidden layer, in order
+    fn widths(&self) -> Vec<usize> {
+        (0..=self.depth()).map(|i| self.width(i)).collect()
+    }
     /// the dimension of the input layer; (input, hidden)
     fn dim_input(&self) -> (usize, usize) {
-        (self.input(), self.hidden())
+        (self.input(), self.width(0))
     }
     /// the dimension of the hidden layers; (hidden, hidden)
+    ///
+    /// **note:** this assumes that every hidden layer shares the same width; see
+    /// [`dim_hidden_at`](RawModelLayout::dim_hidden_at) for layouts whose widths vary
     fn dim_hidden(&self) -> (usize, usize) {
         (self.hidden(), self.hidden())
     }
+    /// the dimension of the hidden layer at the given index; (width(i), width(i + 1))
+    fn dim_hidden_at(&self, index: usize) -> (usize, usize) {
+        (self.width(index), self.width(index + 1))
+    }
     /// the dimension of the output layer; (hidden, output)
     fn dim_output(&self) -> (usize, usize) {
-        (self.hidden(), self.output())
+        (self.width(self.depth()), self.output())
     }
     /// the total number of parameters in the model
     fn size(&self) -> usize {
@@ -39,15 +59,22 @@ pub trait RawModelLayout {
     }
     /// the total number of input parameters in the model
     fn size_input(&self) -> usize {
-        self.input() * self.hidden()
+        let (rows, cols) = self.dim_input();
+        rows * cols
     }
     /// the total number of hidden parameters in the model
     fn size_hidden(&self) -> usize {
-        self.hidden() * self.hidden() * self.depth()
+        (0..self.depth())
+            .map(|i| {
+                let (rows, cols) = self.dim_hidden_at(i);
+                rows * cols
+            })
+            .sum()
     }
     /// the total number of output parameters in the model
     fn size_output(&self) -> usize {
-        self.hidden() * self.output()
+        let (rows, cols) = self.dim_output();
+        rows * cols
     }
 }
 /// The [`RawModelLayoutMut`] trait defines a mutable interface for objects capable of representing
@@ -154,6 +181,9 @@ where
     fn depth(&self) -> usize {
         <T as RawModelLayout>::depth(self)
     }
+    fn width(&self, index: usize) -> usize {
+        <T as RawModelLayout>::width(self, index)
+    }
     fn output(&self) -> usize {
         <T as RawModelLayout>::output(self)
     }
@@ -172,12 +202,15 @@ where
     fn depth(&self) -> usize {
         <T as RawModelLayout>::depth(self)
     }
+    fn width(&self, index: usize) -> usize {
+        <T as RawModelLayout>::width(self, index)
+    }
     fn output(&self) -> usize {
         <T as RawModelLayout>::output(self)
     }
 }
 
-impl<T> LayoutExt for T where T: RawModelLayoutMut + Copy + core::fmt::Debug {}
+impl<T> LayoutExt for T where T: RawModelLayoutMut + Clone + core::fmt::Debug {}
 
 impl RawModelLayout for (usize, usize, usize) {
     fn input(&self) -> usize {
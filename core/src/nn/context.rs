@@ -0,0 +1,135 @@
+/*
+    Appellation: context <module>
+    Created At: 2026.10.18:19:48:22
+    Contrib: @FL03
+*/
+//! This module implements the [`Context`] used to execute a model, recording whether the
+//! model is being trained or evaluated alongside the number of completed steps and, when the
+//! `rand` feature is enabled, a random number generator used by stochastic layers.
+
+/// The [`Mode`] enumerates the modes of execution of a model.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+    strum::AsRefStr,
+    strum::Display,
+    strum::EnumCount,
+    strum::EnumIs,
+    strum::VariantNames,
+)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+#[strum(serialize_all = "snake_case")]
+pub enum Mode {
+    /// the model is being trained; stochastic layers are active and batch statistics are used
+    #[default]
+    Train,
+    /// the model is being evaluated, i.e. used for inference
+    Eval,
+}
+
+/// The [`Context`] is the standard execution context threaded through the
+/// [`forward_with`](crate::nn::ForwardWith::forward_with) method of each layer.
+#[derive(Clone, Debug)]
+pub struct Context {
+    pub(crate) mode: Mode,
+    pub(crate) step: usize,
+    #[cfg(feature = "rand")]
+    pub(crate) rng: concision_init::rand::rngs::SmallRng,
+}
+
+impl Mode {
+    /// returns the mode corresponding to the given training flag
+    pub const fn from_training(training: bool) -> Self {
+        if training { Self::Train } else { Self::Eval }
+    }
+}
+
+impl Context {
+    /// create a new context in the given mode
+    pub fn new(mode: Mode) -> Self {
+        Self {
+            mode,
+            step: 0,
+            #[cfg(feature = "rand")]
+            rng: {
+                use concision_init::rand::SeedableRng;
+                concision_init::rand::rngs::SmallRng::from_rng(&mut concision_init::rand::rng())
+            },
+        }
+    }
+    /// create a new context for training
+    pub fn train() -> Self {
+        Self::new(Mode::Train)
+    }
+    /// create a new context for evaluation (inference)
+    pub fn eval() -> Self {
+        Self::new(Mode::Eval)
+    }
+    #[cfg(feature = "rand")]
+    /// consumes the current instance to create another whose random number generator is
+    /// seeded with the given value, making any stochastic layers reproducible
+    pub fn with_seed(self, seed: u64) -> Self {
+        use concision_init::rand::SeedableRng;
+        Self {
+            rng: concision_init::rand::rngs::SmallRng::seed_from_u64(seed),
+            ..self
+        }
+    }
+    /// returns the current mode of execution
+    pub const fn mode(&self) -> Mode {
+        self.mode
+    }
+    /// returns the number of steps completed within the context
+    pub const fn step(&self) -> usize {
+        self.step
+    }
+    /// returns true if the context is training
+    pub const fn is_training(&self) -> bool {
+        matches!(self.mode, Mode::Train)
+    }
+    /// returns true if the context is evaluating
+    pub const fn is_eval(&self) -> bool {
+        matches!(self.mode, Mode::Eval)
+    }
+    #[cfg(feature = "rand")]
+    /// returns a mutable reference to the random number generator of the context
+    pub const fn rng_mut(&mut self) -> &mut concision_init::rand::rngs::SmallRng {
+        &mut self.rng
+    }
+    /// set the mode of execution
+    pub fn set_mode(&mut self, mode: Mode) -> &mut Self {
+        self.mode = mode;
+        self
+    }
+    /// consumes the current instance to create another in the given mode
+    pub fn with_mode(self, mode: Mode) -> Self {
+        Self { mode, ..self }
+    }
+    /// increment the step counter, returning the number of the completed step
+    pub fn next_step(&mut self) -> usize {
+        self.step += 1;
+        self.step
+    }
+    /// reset the step counter
+    pub fn reset_step(&mut self) -> &mut Self {
+        self.step = 0;
+        self
+    }
+}
+
+impl Default for Context {
+    fn default() -> Self {
+        Self::train()
+    }
+}
@@ -0,0 +1,61 @@
+/*
+    Appellation: conv <module>
+    Created At: 2026.10.18:14:02:19
+    Contrib: @FL03
+*/
+//! This module implements the one and two-dimensional convolution layers, [`Conv1d`] and
+//! [`Conv2d`], supporting strides, dilations, grouped channels and each of the [`PadMode`]s
+//! provided by the [`pad`](crate::utils::pad) utilities.
+//!
+//! Each layer operates on a _batch_ of inputs laid out as `(batch, channels, ...spatial)`,
+//! computing the convolution by unfolding the receptive fields of the input into a matrix
+//! (im2col) before multiplying it with the flattened kernel.
+//!
+//! [`PadMode`]: crate::utils::PadMode
+mod impl_conv1d;
+mod impl_conv2d;
+
+pub(crate) mod utils;
+
+use crate::utils::Padding;
+use concision_params::Params;
+use ndarray::{Ix3, Ix4};
+
+/// The [`Conv1d`] layer convolves a batch of sequences, shaped `(batch, in_channels, length)`,
+/// with a kernel of shape `(out_channels, in_channels / groups, kernel_size)`.
+///
+/// The bias of each output channel is stored with a trailing axis of length one, i.e.
+/// `(out_channels, 1)`, so that it broadcasts over the length of the output.
+#[derive(Clone, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub struct Conv1d<A = f32> {
+    pub(crate) params: Params<A, Ix3>,
+    pub(crate) stride: usize,
+    pub(crate) dilation: usize,
+    pub(crate) groups: usize,
+    pub(crate) padding: Padding<A>,
+}
+
+/// The [`Conv2d`] layer convolves a batch of images, shaped
+/// `(batch, in_channels, height, width)`, with a kernel of shape
+/// `(out_channels, in_channels / groups, kernel_height, kernel_width)`.
+///
+/// The bias of each output channel is stored with two trailing axes of length one, i.e.
+/// `(out_channels, 1, 1)`, so that it broadcasts over the spatial axes of the output.
+#[derive(Clone, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub struct Conv2d<A = f32> {
+    pub(crate) params: Params<A, Ix4>,
+    pub(crate) stride: (usize, usize),
+    pub(crate) dilation: (usize, usize),
+    pub(crate) groups: usize,
+    pub(crate) padding: Padding<A>,
+}
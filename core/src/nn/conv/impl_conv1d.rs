@@ -0,0 +1,238 @@
+/*
+    Appellation: impl_conv1d <module>
+    Created At: 2026.10.18:14:21:05
+    Contrib: @FL03
+*/
+use super::Conv1d;
+use super::utils::{ConvSpec, output_len};
+use crate::error::{Error, Result};
+use crate::utils::{Padding, pad, pad_gradient};
+use concision_params::Params;
+use concision_traits::{Backward, Forward};
+use ndarray::{Array2, Array3, ArrayBase, Axis, Data, Ix3, ScalarOperand};
+use num_traits::{Float, FromPrimitive, Zero};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+impl<A> Conv1d<A> {
+    /// create a new layer from the given parameters; the weights are the kernel, shaped
+    /// `(out_channels, in_channels / groups, kernel_size)`, and the bias is shaped
+    /// `(out_channels, 1)`. the layer starts with a single group, a stride and dilation of one
+    /// and no padding; an error is returned if the bias does not match the kernel or the
+    /// kernel has an axis of length zero
+    pub fn from_params(params: Params<A, Ix3>) -> Result<Self>
+    where
+        A: Clone + Zero,
+    {
+        if params.bias().dim() != (params.weights().len_of(Axis(0)), 1)
+            || params.weights().is_empty()
+        {
+            return Err(Error::InvalidModelConfig);
+        }
+        Ok(Self {
+            params,
+            stride: 1,
+            dilation: 1,
+            groups: 1,
+            padding: Padding::zeros(0),
+        })
+    }
+    /// create a new layer from the given kernel with a bias of zero
+    ///
+    /// ## Panics
+    ///
+    /// panics if the kernel has an axis of length zero
+    pub fn from_kernel(kernel: Array3<A>) -> Self
+    where
+        A: Clone + Zero,
+    {
+        let bias = Array2::zeros((kernel.len_of(Axis(0)), 1));
+        Self::from_params(Params::new(bias, kernel)).expect("the kernel has no axis of length zero")
+    }
+    /// create a new layer whose kernel and bias are zero
+    pub fn zeros(in_channels: usize, out_channels: usize, kernel_size: usize) -> Self
+    where
+        A: Clone + Zero,
+    {
+        Self::from_kernel(Array3::zeros((out_channels, in_channels, kernel_size)))
+    }
+    /// returns an immutable reference to the parameters of the layer
+    pub const fn params(&self) -> &Params<A, Ix3> {
+        &self.params
+    }
+    /// returns a mutable reference to the parameters of the layer
+    pub const fn params_mut(&mut self) -> &mut Params<A, Ix3> {
+        &mut self.params
+    }
+    /// returns an immutable reference to the kernel
+    pub const fn kernel(&self) -> &Array3<A> {
+        self.params.weights()
+    }
+    /// returns an immutable reference to the bias
+    pub const fn bias(&self) -> &Array2<A> {
+        self.params.bias()
+    }
+    /// returns the stride of the kernel
+    pub const fn stride(&self) -> usize {
+        self.stride
+    }
+    /// returns the spacing between the elements of the kernel
+    pub const fn dilation(&self) -> usize {
+        self.dilation
+    }
+    /// returns the number of groups the channels are split into
+    pub const fn groups(&self) -> usize {
+        self.groups
+    }
+    /// returns an immutable reference to the padding of the layer
+    pub const fn padding(&self) -> &Padding<A> {
+        &self.padding
+    }
+    /// returns the number of input channels expected by the layer
+    pub fn in_channels(&self) -> usize {
+        self.kernel().len_of(Axis(1)) * self.groups
+    }
+    /// returns the number of output channels produced by the layer
+    pub fn out_channels(&self) -> usize {
+        self.kernel().len_of(Axis(0))
+    }
+    /// returns the size of the kernel
+    pub fn kernel_size(&self) -> usize {
+        self.kernel().len_of(Axis(2))
+    }
+    /// returns the length of the output given an input of length `len`
+    pub fn output_len(&self, len: usize) -> usize {
+        let [lo, hi] = self.padding.pad_width(1)[0];
+        output_len(
+            len + lo + hi,
+            self.kernel_size(),
+            self.stride,
+            self.dilation,
+        )
+    }
+    /// set the stride of the kernel, returning an error if it is zero
+    pub fn set_stride(&mut self, stride: usize) -> Result<&mut Self> {
+        if stride == 0 {
+            return Err(Error::InvalidModelConfig);
+        }
+        self.stride = stride;
+        Ok(self)
+    }
+    /// set the dilation of the kernel, returning an error if it is zero
+    pub fn set_dilation(&mut self, dilation: usize) -> Result<&mut Self> {
+        if dilation == 0 {
+            return Err(Error::InvalidModelConfig);
+        }
+        self.dilation = dilation;
+        Ok(self)
+    }
+    /// set the number of groups; the kernel holds the `in_channels / groups` channels of a
+    /// single group along its second axis, so an error is returned unless `groups` is non-zero
+    /// and divides the number of output channels
+    pub fn set_groups(&mut self, groups: usize) -> Result<&mut Self> {
+        if groups == 0 || self.out_channels() % groups != 0 {
+            return Err(Error::InvalidModelConfig);
+        }
+        self.groups = groups;
+        Ok(self)
+    }
+    /// set the padding of the layer
+    pub fn set_padding(&mut self, padding: Padding<A>) -> &mut Self {
+        self.padding = padding;
+        self
+    }
+    /// consumes the current instance to create another with the given stride
+    pub fn with_stride(mut self, stride: usize) -> Result<Self> {
+        self.set_stride(stride)?;
+        Ok(self)
+    }
+    /// consumes the current instance to create another with the given dilation
+    pub fn with_dilation(mut self, dilation: usize) -> Result<Self> {
+        self.set_dilation(dilation)?;
+        Ok(self)
+    }
+    /// consumes the current instance to create another with the given number of groups
+    pub fn with_groups(mut self, groups: usize) -> Result<Self> {
+        self.set_groups(groups)?;
+        Ok(self)
+    }
+    /// consumes the current instance to create another with the given padding
+    pub fn with_padding(self, padding: Padding<A>) -> Self {
+        Self { padding, ..self }
+    }
+    /// compute the gradients of the layer w.r.t. its parameters and its input given the
+    /// gradient `delta` w.r.t. its output
+    pub fn gradients<S, T>(
+        &self,
+        input: &ArrayBase<S, Ix3, A>,
+        delta: &ArrayBase<T, Ix3, A>,
+    ) -> (Params<A, Ix3>, Array3<A>)
+    where
+        A: Float + FromPrimitive + ScalarOperand,
+        S: Data<Elem = A>,
+        T: Data<Elem = A>,
+    {
+        let width = self.pad_width();
+        let padded = pad(input, &width, *self.padding.mode());
+        let (kernel, bias, grad) = self.spec().backward(
+            padded.view().insert_axis(Axis(2)),
+            self.kernel().view().insert_axis(Axis(2)),
+            delta.view().insert_axis(Axis(2)),
+        );
+        let grad = pad_gradient(&grad.remove_axis(Axis(2)), &width, self.padding.mode());
+        let params = Params::new(bias.insert_axis(Axis(1)), kernel.remove_axis(Axis(2)));
+        (params, grad)
+    }
+    /// returns the widths used to pad each axis of the input
+    fn pad_width(&self) -> Vec<[usize; 2]> {
+        let mut width = Vec::from([[0, 0]; 2]);
+        width.extend(self.padding.pad_width(1));
+        width
+    }
+
+    fn spec(&self) -> ConvSpec {
+        ConvSpec {
+            stride: [1, self.stride],
+            dilation: [1, self.dilation],
+            groups: self.groups,
+        }
+    }
+}
+
+impl<A, S> Forward<ArrayBase<S, Ix3, A>> for Conv1d<A>
+where
+    A: Float + FromPrimitive + ScalarOperand,
+    S: Data<Elem = A>,
+{
+    type Output = Array3<A>;
+
+    fn forward(&self, input: &ArrayBase<S, Ix3, A>) -> Self::Output {
+        let padded = pad(input, &self.pad_width(), *self.padding.mode());
+        let output = self.spec().forward(
+            padded.view().insert_axis(Axis(2)),
+            self.kernel().view().insert_axis(Axis(2)),
+        );
+        output.remove_axis(Axis(2)) + self.bias()
+    }
+}
+
+impl<A, S, T> Backward<ArrayBase<S, Ix3, A>, ArrayBase<T, Ix3, A>> for Conv1d<A>
+where
+    A: Float + FromPrimitive + ScalarOperand,
+    S: Data<Elem = A>,
+    T: Data<Elem = A>,
+{
+    type Elem = A;
+
+    fn backward(
+        &mut self,
+        input: &ArrayBase<S, Ix3, A>,
+        delta: &ArrayBase<T, Ix3, A>,
+        gamma: Self::Elem,
+    ) {
+        let (grad, _) = self.gradients(input, delta);
+        self.params.weights_mut().scaled_add(gamma, grad.weights());
+        self.params.bias_mut().scaled_add(gamma, grad.bias());
+    }
+}
@@ -0,0 +1,208 @@
+/*
+    Appellation: utils <module>
+    Created At: 2026.10.18:14:10:37
+    Contrib: @FL03
+*/
+//! the shared, im2col-based implementation of the convolution layers; the one-dimensional
+//! layer is treated as a two-dimensional layer whose spatial height is one.
+use ndarray::{Array2, Array4, ArrayView2, ArrayView4, Axis, LinalgScalar, s};
+
+/// the hyperparameters of a two-dimensional convolution
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct ConvSpec {
+    pub(crate) stride: [usize; 2],
+    pub(crate) dilation: [usize; 2],
+    pub(crate) groups: usize,
+}
+
+/// compute the length of the output along an axis of (padded) length `len`
+pub(crate) fn output_len(len: usize, kernel: usize, stride: usize, dilation: usize) -> usize {
+    let span = dilation * (kernel - 1) + 1;
+    assert!(
+        len >= span,
+        "the (padded) input of length {len} is smaller than the dilated kernel ({span})"
+    );
+    (len - span) / stride + 1
+}
+
+impl ConvSpec {
+    /// returns the spatial shape of the output given the padded input and the kernel
+    fn output_shape(&self, input: (usize, usize), kernel: (usize, usize)) -> (usize, usize) {
+        (
+            output_len(input.0, kernel.0, self.stride[0], self.dilation[0]),
+            output_len(input.1, kernel.1, self.stride[1], self.dilation[1]),
+        )
+    }
+    /// the slice of the input read by the kernel offset `(i, j)` for every output position
+    fn window(
+        &self,
+        (i, j): (usize, usize),
+        (oh, ow): (usize, usize),
+    ) -> [(usize, usize, isize); 2] {
+        let [sh, sw] = self.stride;
+        let [dh, dw] = self.dilation;
+        let (y, x) = (i * dh, j * dw);
+        [
+            (y, y + sh * (oh - 1) + 1, sh as isize),
+            (x, x + sw * (ow - 1) + 1, sw as isize),
+        ]
+    }
+    /// unfold the `(batch, channels, height, width)` input into a matrix of shape
+    /// `(channels * kh * kw, batch * oh * ow)` whose columns are the receptive fields
+    fn im2col<A>(
+        &self,
+        x: ArrayView4<'_, A>,
+        (kh, kw): (usize, usize),
+        out: (usize, usize),
+    ) -> Array2<A>
+    where
+        A: LinalgScalar,
+    {
+        let (batch, channels, ..) = x.dim();
+        let mut cols = Array2::zeros((channels * kh * kw, batch * out.0 * out.1));
+        for c in 0..channels {
+            for i in 0..kh {
+                for j in 0..kw {
+                    let [(y0, y1, sy), (x0, x1, sx)] = self.window((i, j), out);
+                    let patch = x.slice(s![.., c, y0..y1;sy, x0..x1;sx]);
+                    let row = (c * kh + i) * kw + j;
+                    cols.row_mut(row)
+                        .into_shape_with_order((batch, out.0, out.1))
+                        .expect("the rows of the matrix are contiguous")
+                        .assign(&patch);
+                }
+            }
+        }
+        cols
+    }
+    /// the adjoint of [`im2col`](Self::im2col); accumulate the columns onto an array of the
+    /// given `(batch, channels, height, width)` shape
+    fn col2im<A>(
+        &self,
+        cols: ArrayView2<'_, A>,
+        shape: (usize, usize, usize, usize),
+        (kh, kw): (usize, usize),
+        out: (usize, usize),
+    ) -> Array4<A>
+    where
+        A: LinalgScalar,
+    {
+        let (batch, channels, ..) = shape;
+        let mut x = Array4::zeros(shape);
+        for c in 0..channels {
+            for i in 0..kh {
+                for j in 0..kw {
+                    let [(y0, y1, sy), (x0, x1, sx)] = self.window((i, j), out);
+                    let row = cols.row((c * kh + i) * kw + j);
+                    let row = row
+                        .to_shape((batch, out.0, out.1))
+                        .expect("the columns are incompatible with the input");
+                    x.slice_mut(s![.., c, y0..y1;sy, x0..x1;sx])
+                        .zip_mut_with(&row, |a, &b| *a = *a + b);
+                }
+            }
+        }
+        x
+    }
+    /// convolve the (padded) input of shape `(batch, in_channels, height, width)` with the
+    /// kernel of shape `(out_channels, in_channels / groups, kh, kw)`, returning an output of
+    /// shape `(batch, out_channels, oh, ow)`; the bias is left for the caller to add.
+    pub(crate) fn forward<A>(&self, x: ArrayView4<'_, A>, kernel: ArrayView4<'_, A>) -> Array4<A>
+    where
+        A: LinalgScalar,
+    {
+        let (batch, channels, h, w) = x.dim();
+        let (out_channels, group_channels, kh, kw) = kernel.dim();
+        let (cg, og) = self.group_sizes(channels, out_channels, group_channels);
+        let (oh, ow) = self.output_shape((h, w), (kh, kw));
+        let mut output = Array4::zeros((batch, out_channels, oh, ow));
+        for g in 0..self.groups {
+            let cols = self.im2col(
+                x.slice(s![.., g * cg..(g + 1) * cg, .., ..]),
+                (kh, kw),
+                (oh, ow),
+            );
+            let weights = kernel
+                .slice(s![g * og..(g + 1) * og, .., .., ..])
+                .to_shape((og, cg * kh * kw))
+                .expect("failed to flatten the kernel")
+                .into_owned();
+            let y = weights
+                .dot(&cols)
+                .into_shape_with_order((og, batch, oh, ow))
+                .expect("failed to reshape the output");
+            output
+                .slice_mut(s![.., g * og..(g + 1) * og, .., ..])
+                .assign(&y.permuted_axes([1, 0, 2, 3]));
+        }
+        output
+    }
+    /// compute the gradients of the convolution w.r.t. its kernel, bias and (padded) input
+    /// given the gradient `delta` w.r.t. its output
+    pub(crate) fn backward<A>(
+        &self,
+        x: ArrayView4<'_, A>,
+        kernel: ArrayView4<'_, A>,
+        delta: ArrayView4<'_, A>,
+    ) -> (Array4<A>, ndarray::Array1<A>, Array4<A>)
+    where
+        A: LinalgScalar,
+    {
+        let (batch, channels, h, w) = x.dim();
+        let (out_channels, group_channels, kh, kw) = kernel.dim();
+        let (cg, og) = self.group_sizes(channels, out_channels, group_channels);
+        let (oh, ow) = self.output_shape((h, w), (kh, kw));
+        assert_eq!(
+            delta.dim(),
+            (batch, out_channels, oh, ow),
+            "the gradient is incompatible with the output of the convolution"
+        );
+        let mut grad_kernel = Array4::zeros(kernel.raw_dim());
+        let mut grad_input = Array4::zeros(x.raw_dim());
+        for g in 0..self.groups {
+            let (ci, co) = (g * cg..(g + 1) * cg, g * og..(g + 1) * og);
+            let cols = self.im2col(x.slice(s![.., ci.clone(), .., ..]), (kh, kw), (oh, ow));
+            let d = delta
+                .slice(s![.., co.clone(), .., ..])
+                .permuted_axes([1, 0, 2, 3])
+                .to_shape((og, batch * oh * ow))
+                .expect("failed to flatten the gradient")
+                .into_owned();
+            let weights = kernel
+                .slice(s![co.clone(), .., .., ..])
+                .to_shape((og, cg * kh * kw))
+                .expect("failed to flatten the kernel")
+                .into_owned();
+            let gk = d.dot(&cols.t());
+            grad_kernel.slice_mut(s![co, .., .., ..]).assign(
+                &gk.to_shape((og, cg, kh, kw))
+                    .expect("failed to reshape the gradient"),
+            );
+            let dcols = weights.t().dot(&d);
+            let gx = self.col2im(dcols.view(), (batch, cg, h, w), (kh, kw), (oh, ow));
+            grad_input.slice_mut(s![.., ci, .., ..]).assign(&gx);
+        }
+        let grad_bias = delta.sum_axis(Axis(3)).sum_axis(Axis(2)).sum_axis(Axis(0));
+        (grad_kernel, grad_bias, grad_input)
+    }
+    /// returns the number of input and output channels of each group
+    fn group_sizes(
+        &self,
+        channels: usize,
+        out_channels: usize,
+        group_channels: usize,
+    ) -> (usize, usize) {
+        let groups = self.groups;
+        assert!(
+            groups > 0 && out_channels % groups == 0,
+            "the output channels ({out_channels}) must be divisible by the groups ({groups})"
+        );
+        assert_eq!(
+            channels,
+            group_channels * groups,
+            "the input has {channels} channels but the convolution expects {}",
+            group_channels * groups
+        );
+        (group_channels, out_channels / groups)
+    }
+}
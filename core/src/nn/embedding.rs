@@ -0,0 +1,51 @@
+/*
+    Appellation: embedding <module>
+    Created At: 2026.10.18:17:41:09
+    Contrib: @FL03
+*/
+//! This module implements the [`Embedding`] layer, a lookup table mapping categorical inputs
+//! (e.g. tokens) onto dense vectors.
+//!
+//! Since each input only touches a handful of rows of the table, the gradients of the layer
+//! are _row-sparse_; the [`EmbeddingGrad`] records the gradient of each row that was looked up
+//! so that the [`Backward`](concision_traits::Backward) implementations and the
+//! [`step`](Embedding::step) method only ever update those rows.
+mod impl_embedding;
+
+use ndarray::Array2;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// The [`Embedding`] layer stores a table, shaped `(vocab_size, embedding_dim)`, whose rows
+/// are the embeddings of each index.
+///
+/// - `padding_idx`: an optional index whose embedding is never updated; it is initialized to
+///   zero, making it suitable for padding sequences of varying length
+/// - `max_norm`: an optional threshold on the L2 norm of each embedding; rows exceeding it are
+///   rescaled when looked up and renormalized after each update
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub struct Embedding<A = f32> {
+    pub(crate) table: Array2<A>,
+    pub(crate) padding_idx: Option<usize>,
+    pub(crate) max_norm: Option<A>,
+}
+
+/// The [`EmbeddingGrad`] is the row-sparse gradient of an [`Embedding`]; the `i`-th row of
+/// `values` is the gradient of the row of the table at `indices[i]`. The indices are unique
+/// and sorted in ascending order.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub struct EmbeddingGrad<A = f32> {
+    pub(crate) indices: Vec<usize>,
+    pub(crate) values: Array2<A>,
+}
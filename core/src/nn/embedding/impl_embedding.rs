@@ -0,0 +1,385 @@
+/*
+    Appellation: impl_embedding <module>
+    Created At: 2026.10.18:17:52:30
+    Contrib: @FL03
+*/
+use super::{Embedding, EmbeddingGrad};
+use crate::error::{Error, Result};
+use crate::optim::Optimizer;
+use concision_params::ParamsBase;
+use concision_traits::{Backward, Forward, L2Norm};
+use ndarray::{
+    Array0, Array1, Array2, Array3, ArrayBase, ArrayView1, Data, Ix1, Ix2, Ix3, ScalarOperand,
+};
+use num_traits::{Float, Zero};
+
+#[cfg(feature = "alloc")]
+use alloc::{collections::BTreeMap, vec::Vec};
+
+impl<A> Embedding<A> {
+    /// create a new layer from the given table, shaped `(vocab_size, embedding_dim)`
+    pub const fn new(table: Array2<A>) -> Self {
+        Self {
+            table,
+            padding_idx: None,
+            max_norm: None,
+        }
+    }
+    /// create a new layer whose embeddings are all zero
+    pub fn zeros(vocab_size: usize, embedding_dim: usize) -> Self
+    where
+        A: Clone + Zero,
+    {
+        Self::new(Array2::zeros((vocab_size, embedding_dim)))
+    }
+    #[cfg(feature = "rand")]
+    /// create a new layer whose embeddings are drawn from the standard normal distribution
+    pub fn stdnorm(vocab_size: usize, embedding_dim: usize) -> Self
+    where
+        concision_init::rand_distr::StandardNormal: concision_init::rand_distr::Distribution<A>,
+    {
+        use concision_init::NdRandom;
+        Self::new(Array2::stdnorm((vocab_size, embedding_dim)))
+    }
+    /// returns an immutable reference to the table of embeddings
+    pub const fn table(&self) -> &Array2<A> {
+        &self.table
+    }
+    /// returns a mutable reference to the table of embeddings
+    pub const fn table_mut(&mut self) -> &mut Array2<A> {
+        &mut self.table
+    }
+    /// returns the number of embeddings stored in the table
+    pub fn vocab_size(&self) -> usize {
+        self.table.nrows()
+    }
+    /// returns the dimension of each embedding
+    pub fn embedding_dim(&self) -> usize {
+        self.table.ncols()
+    }
+    /// returns the index of the padding embedding, if any
+    pub const fn padding_idx(&self) -> Option<usize> {
+        self.padding_idx
+    }
+    /// returns the maximum L2 norm of each embedding, if any
+    pub const fn max_norm(&self) -> Option<&A> {
+        self.max_norm.as_ref()
+    }
+    /// set the index of the padding embedding, zeroing its row
+    pub fn set_padding_idx(&mut self, padding_idx: Option<usize>) -> Result<&mut Self>
+    where
+        A: Clone + Zero,
+    {
+        if let Some(idx) = padding_idx {
+            if idx >= self.vocab_size() {
+                return Err(Error::InvalidModelConfig);
+            }
+            self.table.row_mut(idx).fill(A::zero());
+        }
+        self.padding_idx = padding_idx;
+        Ok(self)
+    }
+    /// set the maximum L2 norm of each embedding
+    pub fn set_max_norm(&mut self, max_norm: Option<A>) -> &mut Self {
+        self.max_norm = max_norm;
+        self
+    }
+    /// consumes the current instance to create another with the given padding index, zeroing
+    /// its row
+    pub fn with_padding_idx(mut self, padding_idx: usize) -> Result<Self>
+    where
+        A: Clone + Zero,
+    {
+        self.set_padding_idx(Some(padding_idx))?;
+        Ok(self)
+    }
+    /// consumes the current instance to create another with the given maximum norm
+    pub fn with_max_norm(self, max_norm: A) -> Self {
+        Self {
+            max_norm: Some(max_norm),
+            ..self
+        }
+    }
+    /// verify that each of the given indices is within the bounds of the table
+    pub fn check_indices<'a, I>(&self, indices: I) -> Result<()>
+    where
+        I: IntoIterator<Item = &'a usize>,
+    {
+        let vocab = self.vocab_size();
+        match indices.into_iter().find(|&&i| i >= vocab) {
+            Some(_) => Err(Error::IncompatibleInput),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<A> Embedding<A>
+where
+    A: 'static + Float + ScalarOperand,
+{
+    /// returns the scale applied to an embedding to bring its norm within the `max_norm`
+    fn scale(&self, row: ArrayView1<'_, A>) -> A {
+        match self.max_norm {
+            Some(max_norm) => {
+                let norm = row.l2_norm();
+                if norm > max_norm {
+                    max_norm / norm
+                } else {
+                    A::one()
+                }
+            }
+            None => A::one(),
+        }
+    }
+    /// returns the embeddings of the given indices as the rows of a new array, shaped
+    /// `(indices.len(), embedding_dim)`; embeddings whose norm exceeds the `max_norm` are
+    /// rescaled onto it.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if any of the indices are out of bounds.
+    pub fn lookup<'a, I>(&self, indices: I) -> Array2<A>
+    where
+        I: IntoIterator<Item = &'a usize>,
+        I::IntoIter: ExactSizeIterator,
+    {
+        let indices = indices.into_iter();
+        let mut output = Array2::zeros((indices.len(), self.embedding_dim()));
+        for (mut out, &idx) in output.rows_mut().into_iter().zip(indices) {
+            let row = self.table.row(idx);
+            out.assign(&(&row * self.scale(row)));
+        }
+        output
+    }
+    /// renormalize the embeddings of the given indices whose norm exceeds the `max_norm`
+    pub fn renormalize<'a, I>(&mut self, indices: I)
+    where
+        I: IntoIterator<Item = &'a usize>,
+    {
+        if self.max_norm.is_none() {
+            return;
+        }
+        for &idx in indices {
+            let scale = self.scale(self.table.row(idx));
+            if scale < A::one() {
+                self.table.row_mut(idx).mapv_inplace(|x| x * scale);
+            }
+        }
+    }
+    /// compute the row-sparse gradient of the table given the indices that were looked up and
+    /// the gradient `delta`, shaped `(indices.len(), embedding_dim)`, w.r.t. the output. The
+    /// gradients of repeated indices are accumulated, while the padding index never receives
+    /// a gradient.
+    ///
+    /// Rows whose norm exceeds the `max_norm` are rescaled by [`lookup`](Embedding::lookup),
+    /// i.e. $y = m\,r / \lVert r\rVert$, hence their gradient is that of the rescaling:
+    ///
+    /// ```math
+    /// \frac{\partial L}{\partial r} = \frac{m}{\lVert r\rVert}\left(\delta - \frac{r \cdot \delta}{\lVert r\rVert^2} r\right)
+    /// ```
+    pub fn gradient<'a, I, S>(&self, indices: I, delta: &ArrayBase<S, Ix2, A>) -> EmbeddingGrad<A>
+    where
+        I: IntoIterator<Item = &'a usize>,
+        S: Data<Elem = A>,
+    {
+        let indices = indices.into_iter().collect::<Vec<_>>();
+        assert_eq!(
+            delta.dim(),
+            (indices.len(), self.embedding_dim()),
+            "the gradient is incompatible with the output of the layer"
+        );
+        let mut rows = BTreeMap::<usize, Array1<A>>::new();
+        for (&idx, d) in indices.into_iter().zip(delta.rows()) {
+            if Some(idx) == self.padding_idx {
+                continue;
+            }
+            let entry = rows
+                .entry(idx)
+                .or_insert_with(|| Array1::zeros(self.embedding_dim()));
+            let row = self.table.row(idx);
+            let scale = self.scale(row);
+            if scale < A::one() {
+                // project out the radial component of the gradient before rescaling it
+                let norm_sq = row.dot(&row);
+                entry.scaled_add(scale, &d);
+                entry.scaled_add(-scale * row.dot(&d) / norm_sq, &row);
+            } else {
+                entry.scaled_add(A::one(), &d);
+            }
+        }
+        let mut values = Array2::zeros((rows.len(), self.embedding_dim()));
+        for (mut row, g) in values.rows_mut().into_iter().zip(rows.values()) {
+            row.assign(g);
+        }
+        let indices = rows.into_keys().collect();
+        EmbeddingGrad { indices, values }
+    }
+    /// add `gamma` times the sparse gradient onto the rows of the table it touches before
+    /// renormalizing those rows
+    pub fn apply_gradient(&mut self, gamma: A, grad: &EmbeddingGrad<A>) {
+        for (&idx, g) in grad.iter() {
+            self.table.row_mut(idx).scaled_add(gamma, &g);
+        }
+        self.renormalize(grad.indices());
+    }
+    /// update the rows of the table touched by the sparse gradient using the given optimizer.
+    ///
+    /// Each row maintains its own optimizer state, initialized _lazily_ upon receiving its
+    /// first gradient, hence rows that are never looked up neither allocate nor update any
+    /// state. The `state` must contain an entry for every row of the table, see
+    /// [`init_state`](Embedding::init_state).
+    pub fn step<O>(
+        &mut self,
+        optimizer: &O,
+        grad: &EmbeddingGrad<A>,
+        state: &mut [Option<O::State>],
+    ) -> Result<()>
+    where
+        O: Optimizer<A, Ix1>,
+    {
+        if state.len() != self.vocab_size() {
+            return Err(Error::InvalidModelConfig);
+        }
+        // the rows of the table are treated as parameters without a bias
+        let mut bias = Array0::zeros(());
+        let zero = Array0::zeros(());
+        for (&idx, g) in grad.iter() {
+            let mut params = ParamsBase::new(bias.view_mut(), self.table.row_mut(idx));
+            let state = state[idx].get_or_insert_with(|| optimizer.init_state(&params));
+            optimizer.step(&mut params, &ParamsBase::new(zero.view(), g), state)?;
+        }
+        self.renormalize(grad.indices());
+        Ok(())
+    }
+    /// returns a new optimizer state for the [`step`](Embedding::step) method; the state of
+    /// each row is initialized upon receiving its first gradient
+    pub fn init_state<S>(&self) -> Vec<Option<S>> {
+        (0..self.vocab_size()).map(|_| None).collect()
+    }
+}
+
+impl<A> EmbeddingGrad<A> {
+    /// returns the (sorted) indices of the rows with a gradient
+    pub fn indices(&self) -> &[usize] {
+        &self.indices
+    }
+    /// returns an immutable reference to the gradient of each row, stacked into an array
+    pub const fn values(&self) -> &Array2<A> {
+        &self.values
+    }
+    /// returns the number of rows with a gradient
+    pub fn len(&self) -> usize {
+        self.indices.len()
+    }
+    /// returns true if no row received a gradient
+    pub fn is_empty(&self) -> bool {
+        self.indices.is_empty()
+    }
+    /// returns an iterator over the indices of the rows alongside their gradients
+    pub fn iter(&self) -> impl Iterator<Item = (&usize, ArrayView1<'_, A>)> {
+        self.indices.iter().zip(self.values.rows())
+    }
+    /// returns the gradient as a dense array shaped `(vocab_size, embedding_dim)`
+    pub fn to_dense(&self, vocab_size: usize) -> Array2<A>
+    where
+        A: Clone + Zero,
+    {
+        let mut dense = Array2::zeros((vocab_size, self.values.ncols()));
+        for (&idx, g) in self.iter() {
+            dense.row_mut(idx).assign(&g);
+        }
+        dense
+    }
+}
+
+impl<A, S> Forward<ArrayBase<S, Ix1, usize>> for Embedding<A>
+where
+    A: 'static + Float + ScalarOperand,
+    S: Data<Elem = usize>,
+{
+    type Output = Array2<A>;
+
+    fn forward(&self, input: &ArrayBase<S, Ix1, usize>) -> Self::Output {
+        self.lookup(input)
+    }
+}
+
+impl<A, S> Forward<ArrayBase<S, Ix2, usize>> for Embedding<A>
+where
+    A: 'static + Float + ScalarOperand,
+    S: Data<Elem = usize>,
+{
+    type Output = Array3<A>;
+
+    fn forward(&self, input: &ArrayBase<S, Ix2, usize>) -> Self::Output {
+        let (batch, len) = input.dim();
+        self.lookup(input)
+            .into_shape_with_order((batch, len, self.embedding_dim()))
+            .expect("the embeddings are laid out in the order of the input")
+    }
+}
+
+impl<A> Forward<Vec<usize>> for Embedding<A>
+where
+    A: 'static + Float + ScalarOperand,
+{
+    type Output = Array2<A>;
+
+    fn forward(&self, input: &Vec<usize>) -> Self::Output {
+        self.lookup(input)
+    }
+}
+
+impl<A, S, T> Backward<ArrayBase<S, Ix1, usize>, ArrayBase<T, Ix2, A>> for Embedding<A>
+where
+    A: 'static + Float + ScalarOperand,
+    S: Data<Elem = usize>,
+    T: Data<Elem = A>,
+{
+    type Elem = A;
+
+    fn backward(
+        &mut self,
+        input: &ArrayBase<S, Ix1, usize>,
+        delta: &ArrayBase<T, Ix2, A>,
+        gamma: A,
+    ) {
+        let grad = self.gradient(input, delta);
+        self.apply_gradient(gamma, &grad);
+    }
+}
+
+impl<A, S, T> Backward<ArrayBase<S, Ix2, usize>, ArrayBase<T, Ix3, A>> for Embedding<A>
+where
+    A: 'static + Float + ScalarOperand,
+    S: Data<Elem = usize>,
+    T: Data<Elem = A>,
+{
+    type Elem = A;
+
+    fn backward(
+        &mut self,
+        input: &ArrayBase<S, Ix2, usize>,
+        delta: &ArrayBase<T, Ix3, A>,
+        gamma: A,
+    ) {
+        let delta = delta
+            .to_shape((input.len(), self.embedding_dim()))
+            .expect("the gradient is incompatible with the output of the layer");
+        let grad = self.gradient(input, &delta);
+        self.apply_gradient(gamma, &grad);
+    }
+}
+
+impl<A, T> Backward<Vec<usize>, ArrayBase<T, Ix2, A>> for Embedding<A>
+where
+    A: 'static + Float + ScalarOperand,
+    T: Data<Elem = A>,
+{
+    type Elem = A;
+
+    fn backward(&mut self, input: &Vec<usize>, delta: &ArrayBase<T, Ix2, A>, gamma: A) {
+        let grad = self.gradient(input, delta);
+        self.apply_gradient(gamma, &grad);
+    }
+}
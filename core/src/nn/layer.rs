@@ -23,7 +23,10 @@ pub struct LayerBase<F, P> {
 
 mod types {
     use super::LayerBase;
-    use crate::activate::{HeavySide, HyperbolicTangent, Linear, ReLU, Sigmoid};
+    use crate::activate::{
+        Elu, Gelu, GeluTanh, HeavySide, HyperbolicTangent, LeakyReLU, Linear, Mish, ReLU, Selu,
+        Sigmoid, Silu, Softplus,
+    };
     #[cfg(feature = "alloc")]
     use alloc::boxed::Box;
     use concision_params::{Params, ParamsBase};
@@ -42,6 +45,22 @@ mod types {
     pub type ReluLayer<T> = LayerBase<ReLU, T>;
     /// A [`Layer`] type using the heavyside activation function.
     pub type HeavySideLayer<T> = LayerBase<HeavySide, T>;
+    /// A [`Layer`] type using the exact GELU activation function.
+    pub type GeluLayer<T> = LayerBase<Gelu, T>;
+    /// A [`Layer`] type using the tanh approximation of the GELU activation function.
+    pub type GeluTanhLayer<T> = LayerBase<GeluTanh, T>;
+    /// A [`Layer`] type using the SiLU (swish) activation function.
+    pub type SiluLayer<T> = LayerBase<Silu, T>;
+    /// A [`Layer`] type using the leaky ReLU activation function with a slope of type `A`.
+    pub type LeakyReluLayer<T, A = f64> = LayerBase<LeakyReLU<A>, T>;
+    /// A [`Layer`] type using the ELU activation function with an alpha of type `A`.
+    pub type EluLayer<T, A = f64> = LayerBase<Elu<A>, T>;
+    /// A [`Layer`] type using the SELU activation function.
+    pub type SeluLayer<T> = LayerBase<Selu, T>;
+    /// A [`Layer`] type using the softplus activation function.
+    pub type SoftplusLayer<T> = LayerBase<Softplus, T>;
+    /// A [`Layer`] type using the mish activation function.
+    pub type MishLayer<T> = LayerBase<Mish, T>;
 
     #[cfg(feature = "alloc")]
     /// A dynamic instance of the layer using a boxed activator.
@@ -5,7 +5,7 @@
 */
 use crate::activate::Activator;
 use crate::nn::layer::LayerBase;
-use crate::nn::{RawLayer, RawLayerMut};
+use crate::nn::{LearnableLayer, RawLayer, RawLayerMut};
 use concision_params::RawParams;
 use concision_traits::Forward;
 
@@ -35,7 +35,6 @@ where
 
 impl<F, P, A> RawLayer<F, A> for LayerBase<F, P>
 where
-    F: Activator<A>,
     P: RawParams<Elem = A>,
 {
     type Params<_T> = P;
@@ -50,10 +49,18 @@ where
 }
 impl<F, P, A> RawLayerMut<F, A> for LayerBase<F, P>
 where
-    F: Activator<A>,
     P: RawParams<Elem = A>,
 {
     fn params_mut(&mut self) -> &mut P {
         &mut self.params
     }
 }
+
+impl<F, P, A> LearnableLayer<F, A> for LayerBase<F, P>
+where
+    P: RawParams<Elem = A>,
+{
+    fn rho_mut(&mut self) -> &mut F {
+        &mut self.rho
+    }
+}
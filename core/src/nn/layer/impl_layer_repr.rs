@@ -2,7 +2,7 @@
     appellation: impl_layer_repr <module>
     authors: @FL03
 */
-use crate::activate::{Activator, HyperbolicTangent, Linear, ReLU, Sigmoid};
+use crate::activate::{ActivationKind, Activator, HyperbolicTangent, Linear, ReLU, Sigmoid};
 use crate::nn::layer::LayerBase;
 use concision_params::{ParamsBase, RawParams};
 use ndarray::{ArrayBase, DataOwned, Dimension, RawData, RemoveAxis, ShapeBuilder};
@@ -146,3 +146,17 @@ where
         Self { rho: ReLU, params }
     }
 }
+
+impl<A, P> LayerBase<ActivationKind, P>
+where
+    P: RawParams<Elem = A>,
+{
+    /// initialize a layer using the activation specified by the given configuration, falling
+    /// back onto the [`Linear`] activation if none was provided.
+    pub fn from_config<T>(config: &crate::StandardModelConfig<T>, params: P) -> Self {
+        Self {
+            rho: config.activation().unwrap_or_default(),
+            params,
+        }
+    }
+}
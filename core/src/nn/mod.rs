@@ -7,22 +7,39 @@
 //! of neural network models.
 //!
 #[doc(inline)]
-pub use self::{layer::*, traits::*};
+pub use self::{
+    context::*, conv::*, embedding::*, layer::*, norm::*, pool::*, rnn::*, sequential::*, traits::*,
+};
 
+pub mod context;
+pub mod conv;
+pub mod embedding;
 pub mod layer;
+pub mod norm;
+pub mod pool;
+pub mod rnn;
+pub mod sequential;
 
 mod traits {
     #[doc(inline)]
-    pub use self::{context::*, layer::*, model::*, neural_network::*};
+    pub use self::{context::*, layer::*, model::*, module::*, neural_network::*};
 
     mod context;
     mod layer;
     mod model;
+    mod module;
     mod neural_network;
 }
 
 pub(crate) mod prelude {
+    pub use super::context::*;
+    pub use super::conv::*;
+    pub use super::embedding::*;
     pub use super::layer::*;
+    pub use super::norm::*;
+    pub use super::pool::*;
+    pub use super::rnn::*;
+    pub use super::sequential::*;
     pub use super::traits::*;
 }
 
@@ -0,0 +1,111 @@
+/*
+    Appellation: norm <module>
+    Created At: 2026.10.18:18:31:56
+    Contrib: @FL03
+*/
+//! This module implements the normalization layers, each of which standardizes its input
+//! before applying a learnable, element-wise affine transformation `gamma * x + beta`.
+//!
+//! - [`BatchNorm1d`] and [`BatchNorm2d`]: normalize each channel over the batch (and spatial
+//!   axes), tracking running estimates of the mean and variance used during evaluation
+//! - [`LayerNorm`]: normalize the features (i.e. the last axis) of each sample
+//! - [`RmsNorm`]: rescale the features of each sample by their root mean square
+//!
+//! The batch normalization layers behave differently when training and evaluating; while
+//! training, the statistics of the batch are used to normalize the input and the running
+//! estimates are updated by [`ForwardMut`](concision_traits::ForwardMut) (or, within a
+//! context, by `forward_mut_with`), whereas the running estimates are used when evaluating.
+//! Since [`ForwardWith`](crate::nn::ForwardWith) only borrows the layer, it never updates the
+//! running estimates; a layer trained solely through it is evaluated using the initial
+//! estimates. The [`Backward`](concision_traits::Backward)
+//! implementations of each layer only ever update the affine parameters.
+mod impl_batch_norm;
+mod impl_layer_norm;
+
+pub(crate) mod utils;
+
+use ndarray::Array1;
+
+/// The [`BatchNorm1d`] layer normalizes each channel of a batch shaped `(batch, channels)` or
+/// `(batch, channels, length)`.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub struct BatchNorm1d<A = f32> {
+    pub(crate) gamma: Array1<A>,
+    pub(crate) beta: Array1<A>,
+    pub(crate) running_mean: Array1<A>,
+    pub(crate) running_var: Array1<A>,
+    /// the weight of each new batch in the running estimates
+    pub(crate) momentum: A,
+    pub(crate) eps: A,
+    pub(crate) training: bool,
+}
+
+/// The [`BatchNorm2d`] layer normalizes each channel of a batch of images shaped
+/// `(batch, channels, height, width)`.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub struct BatchNorm2d<A = f32> {
+    pub(crate) gamma: Array1<A>,
+    pub(crate) beta: Array1<A>,
+    pub(crate) running_mean: Array1<A>,
+    pub(crate) running_var: Array1<A>,
+    /// the weight of each new batch in the running estimates
+    pub(crate) momentum: A,
+    pub(crate) eps: A,
+    pub(crate) training: bool,
+}
+
+/// The [`LayerNorm`] layer normalizes the features, i.e. the last axis, of each sample to
+/// have zero mean and unit variance.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub struct LayerNorm<A = f32> {
+    pub(crate) gamma: Array1<A>,
+    pub(crate) beta: Array1<A>,
+    pub(crate) eps: A,
+}
+
+/// The [`RmsNorm`] layer rescales the features, i.e. the last axis, of each sample by their
+/// root mean square:
+///
+/// ```math
+/// y = \gamma \odot \frac{x}{\sqrt{\frac{1}{n}\sum_i x_i^2 + \epsilon}} + \beta
+/// ```
+///
+/// The shift `beta` is initialized to zero, recovering the standard formulation.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub struct RmsNorm<A = f32> {
+    pub(crate) gamma: Array1<A>,
+    pub(crate) beta: Array1<A>,
+    pub(crate) eps: A,
+}
+
+/// The [`NormGrad`] stores the gradients of the affine parameters of a normalization layer.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub struct NormGrad<A = f32> {
+    pub gamma: Array1<A>,
+    pub beta: Array1<A>,
+}
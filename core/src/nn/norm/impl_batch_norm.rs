@@ -0,0 +1,358 @@
+/*
+    Appellation: impl_batch_norm <module>
+    Created At: 2026.10.18:18:52:41
+    Contrib: @FL03
+*/
+use super::utils::{
+    Standardized, channels_to_rows, normalize, rows_to_channels, standardize, standardize_backward,
+};
+use super::{BatchNorm1d, BatchNorm2d, NormGrad};
+use crate::error::{Error, Result};
+use crate::nn::{ForwardWith, Mode, RawContext};
+use concision_traits::{Backward, Forward, ForwardMut};
+use ndarray::{
+    Array, Array1, Array2, ArrayBase, Axis, Data, Dimension, Ix2, Ix3, Ix4, ScalarOperand, Zip,
+};
+use num_traits::{Float, FromPrimitive, One, Zero};
+
+/// apply the affine transformation of each channel onto the rows of the array
+fn affine<A: Float>(xhat: &Array2<A>, gamma: &Array1<A>, beta: &Array1<A>) -> Array2<A> {
+    let mut y = xhat.to_owned();
+    Zip::from(y.rows_mut())
+        .and(gamma)
+        .and(beta)
+        .for_each(|mut row, &g, &b| row.mapv_inplace(|v| g * v + b));
+    y
+}
+
+macro_rules! impl_batch_norm {
+    ($($name:ident<$($D:ty),*>),* $(,)?) => {
+        $(impl_batch_norm!(@impl $name<$($D),*>);)*
+    };
+    (@impl $name:ident<$($D:ty),*>) => {
+        impl<A> $name<A> {
+            /// create a new layer for the given number of channels; the scale `gamma` is
+            /// initialized to one, the shift `beta` to zero, and the running estimates to a
+            /// mean of zero and variance of one. The layer is created in training mode using a
+            /// momentum of `0.1` and an `eps` of `1e-5`.
+            pub fn new(channels: usize) -> Self
+            where
+                A: Clone + FromPrimitive + One + Zero,
+            {
+                Self {
+                    gamma: Array1::ones(channels),
+                    beta: Array1::zeros(channels),
+                    running_mean: Array1::zeros(channels),
+                    running_var: Array1::ones(channels),
+                    momentum: A::from_f64(0.1).unwrap(),
+                    eps: A::from_f64(1e-5).unwrap(),
+                    training: true,
+                }
+            }
+            /// create a new layer from the given affine parameters
+            pub fn from_affine(gamma: Array1<A>, beta: Array1<A>) -> Result<Self>
+            where
+                A: Clone + FromPrimitive + One + Zero,
+            {
+                if gamma.len() != beta.len() {
+                    return Err(Error::InvalidModelConfig);
+                }
+                let channels = gamma.len();
+                Ok(Self {
+                    gamma,
+                    beta,
+                    running_mean: Array1::zeros(channels),
+                    running_var: Array1::ones(channels),
+                    ..Self::new(0)
+                })
+            }
+            /// returns the number of channels normalized by the layer
+            pub fn channels(&self) -> usize {
+                self.gamma.len()
+            }
+            /// returns an immutable reference to the scale of each channel
+            pub const fn gamma(&self) -> &Array1<A> {
+                &self.gamma
+            }
+            /// returns a mutable reference to the scale of each channel
+            pub const fn gamma_mut(&mut self) -> &mut Array1<A> {
+                &mut self.gamma
+            }
+            /// returns an immutable reference to the shift of each channel
+            pub const fn beta(&self) -> &Array1<A> {
+                &self.beta
+            }
+            /// returns a mutable reference to the shift of each channel
+            pub const fn beta_mut(&mut self) -> &mut Array1<A> {
+                &mut self.beta
+            }
+            /// returns an immutable reference to the running estimate of the mean
+            pub const fn running_mean(&self) -> &Array1<A> {
+                &self.running_mean
+            }
+            /// returns an immutable reference to the running estimate of the variance
+            pub const fn running_var(&self) -> &Array1<A> {
+                &self.running_var
+            }
+            /// returns a copy of the momentum used to update the running estimates
+            pub const fn momentum(&self) -> A
+            where
+                A: Copy,
+            {
+                self.momentum
+            }
+            /// returns a copy of the value added to the variance for numerical stability
+            pub const fn eps(&self) -> A
+            where
+                A: Copy,
+            {
+                self.eps
+            }
+            /// returns true if the layer is in training mode
+            pub const fn is_training(&self) -> bool {
+                self.training
+            }
+            /// set the momentum used to update the running estimates
+            pub fn set_momentum(&mut self, momentum: A) -> &mut Self {
+                self.momentum = momentum;
+                self
+            }
+            /// set the value added to the variance for numerical stability
+            pub fn set_eps(&mut self, eps: A) -> &mut Self {
+                self.eps = eps;
+                self
+            }
+            /// toggle between training and evaluation mode
+            pub fn set_training(&mut self, training: bool) -> &mut Self {
+                self.training = training;
+                self
+            }
+            /// switch the layer into training mode
+            pub fn train(&mut self) -> &mut Self {
+                self.set_training(true)
+            }
+            /// switch the layer into evaluation mode
+            pub fn eval(&mut self) -> &mut Self {
+                self.set_training(false)
+            }
+            /// consumes the current instance to create another with the given momentum
+            pub fn with_momentum(self, momentum: A) -> Self {
+                Self { momentum, ..self }
+            }
+            /// consumes the current instance to create another with the given `eps`
+            pub fn with_eps(self, eps: A) -> Self {
+                Self { eps, ..self }
+            }
+            /// consumes the current instance to create another in the given mode
+            pub fn with_training(self, training: bool) -> Self {
+                Self { training, ..self }
+            }
+            /// reset the running estimates to a mean of zero and variance of one
+            pub fn reset_running_stats(&mut self)
+            where
+                A: Clone + One + Zero,
+            {
+                self.running_mean = Array1::zeros(self.channels());
+                self.running_var = Array1::ones(self.channels());
+            }
+        }
+
+        impl<A> $name<A>
+        where
+            A: Float + FromPrimitive + ScalarOperand,
+        {
+            /// normalize the rows (i.e. channels) of the input, using the statistics of the
+            /// batch when training and the running estimates otherwise
+            fn standardize(&self, rows: &Array2<A>, training: bool) -> Standardized<A> {
+                assert_eq!(
+                    rows.nrows(),
+                    self.channels(),
+                    "the input must have {} channels",
+                    self.channels()
+                );
+                if training {
+                    standardize(rows.view(), self.eps)
+                } else {
+                    let inv_std = self.running_var.mapv(|v| (v + self.eps).sqrt().recip());
+                    Standardized {
+                        xhat: normalize(rows.view(), &self.running_mean, &inv_std),
+                        mean: self.running_mean.clone(),
+                        var: self.running_var.clone(),
+                        inv_std,
+                    }
+                }
+            }
+            /// compute the gradients of the affine parameters alongside the gradient w.r.t.
+            /// the input given the gradient `delta` w.r.t. the output. While training, the
+            /// gradient accounts for the dependence of the batch statistics on the input.
+            pub fn gradients<S, T, D>(
+                &self,
+                input: &ArrayBase<S, D, A>,
+                delta: &ArrayBase<T, D, A>,
+            ) -> (NormGrad<A>, Array<A, D>)
+            where
+                D: Dimension,
+                S: Data<Elem = A>,
+                T: Data<Elem = A>,
+            {
+                self.gradients_in_mode(input, delta, self.training)
+            }
+            /// compute the gradients of the layer as if it were in the given mode
+            pub(crate) fn gradients_in_mode<S, T, D>(
+                &self,
+                input: &ArrayBase<S, D, A>,
+                delta: &ArrayBase<T, D, A>,
+                training: bool,
+            ) -> (NormGrad<A>, Array<A, D>)
+            where
+                D: Dimension,
+                S: Data<Elem = A>,
+                T: Data<Elem = A>,
+            {
+                assert_eq!(
+                    input.shape(),
+                    delta.shape(),
+                    "the gradient is incompatible with the output of the layer"
+                );
+                let stats = self.standardize(&channels_to_rows(input), training);
+                let dy = channels_to_rows(delta);
+                let grad = NormGrad {
+                    gamma: (&dy * &stats.xhat).sum_axis(Axis(1)),
+                    beta: dy.sum_axis(Axis(1)),
+                };
+                let mut dxhat = dy;
+                Zip::from(dxhat.rows_mut())
+                    .and(&self.gamma)
+                    .for_each(|mut row, &g| row.mapv_inplace(|d| d * g));
+                let dx = if training {
+                    standardize_backward(&dxhat, &stats.xhat, &stats.inv_std)
+                } else {
+                    Zip::from(dxhat.rows_mut())
+                        .and(&stats.inv_std)
+                        .for_each(|mut row, &s| row.mapv_inplace(|d| d * s));
+                    dxhat
+                };
+                (grad, rows_to_channels(dx, input.raw_dim()))
+            }
+            /// the forward pass using the mode of the context which, when training,
+            /// additionally folds the statistics of the batch into the running estimates.
+            ///
+            /// Unlike [`forward_with`](crate::nn::ForwardWith::forward_with), which only
+            /// borrows the layer and therefore leaves the running estimates unchanged, this
+            /// method should be used when training the layer within a context so that it is
+            /// later evaluated using the statistics observed during training.
+            pub fn forward_mut_with<S, D, C>(
+                &mut self,
+                input: &ArrayBase<S, D, A>,
+                ctx: &mut C,
+            ) -> Array<A, D>
+            where
+                C: RawContext,
+                D: Dimension,
+                S: Data<Elem = A>,
+            {
+                let rows = channels_to_rows(input);
+                let stats = self.standardize(&rows, ctx.is_training());
+                if ctx.is_training() {
+                    self.update_running_stats(&stats, rows.ncols());
+                }
+                let y = affine(&stats.xhat, &self.gamma, &self.beta);
+                rows_to_channels(y, input.raw_dim())
+            }
+            /// update the running estimates using the statistics of the given batch
+            pub(crate) fn track_running_stats<S, D>(&mut self, input: &ArrayBase<S, D, A>)
+            where
+                D: Dimension,
+                S: Data<Elem = A>,
+            {
+                let rows = channels_to_rows(input);
+                let stats = self.standardize(&rows, true);
+                self.update_running_stats(&stats, rows.ncols());
+            }
+            /// fold the statistics of a batch, computed over `n` elements, into the running
+            /// estimates; the variance is corrected using Bessel's correction
+            fn update_running_stats(&mut self, stats: &Standardized<A>, n: usize) {
+                let m = self.momentum;
+                let correction = A::from_usize(n).unwrap()
+                    / A::from_usize(n.saturating_sub(1).max(1)).unwrap();
+                self.running_mean = &self.running_mean * (A::one() - m) + &stats.mean * m;
+                self.running_var =
+                    &self.running_var * (A::one() - m) + &stats.var * (m * correction);
+            }
+        }
+
+        $(
+            impl<A, S> Forward<ArrayBase<S, $D, A>> for $name<A>
+            where
+                A: Float + FromPrimitive + ScalarOperand,
+                S: Data<Elem = A>,
+            {
+                type Output = Array<A, $D>;
+
+                fn forward(&self, input: &ArrayBase<S, $D, A>) -> Self::Output {
+                    self.forward_with(input, &mut Mode::from_training(self.training))
+                }
+            }
+
+            impl<A, S, C> ForwardWith<ArrayBase<S, $D, A>, C> for $name<A>
+            where
+                A: Float + FromPrimitive + ScalarOperand,
+                C: RawContext,
+                S: Data<Elem = A>,
+            {
+                type Output = Array<A, $D>;
+                /// the forward pass using the mode of the context, rather than that of the
+                /// layer.
+                ///
+                /// **Note:** the running estimates are left unchanged, even when the context
+                /// is training; a model trained solely through this method is evaluated
+                /// using the initial estimates (a mean of zero and variance of one). Use
+                /// [`forward_mut_with`](Self::forward_mut_with) (or
+                /// [`ForwardMut::forward_mut`]) to track the running estimates while training.
+                fn forward_with(&self, input: &ArrayBase<S, $D, A>, ctx: &mut C) -> Self::Output {
+                    let stats = self.standardize(&channels_to_rows(input), ctx.is_training());
+                    let y = affine(&stats.xhat, &self.gamma, &self.beta);
+                    rows_to_channels(y, input.raw_dim())
+                }
+            }
+
+            impl<A, S> ForwardMut<ArrayBase<S, $D, A>> for $name<A>
+            where
+                A: Float + FromPrimitive + ScalarOperand,
+                S: Data<Elem = A>,
+            {
+                type Output = Array<A, $D>;
+                /// the forward pass which, when training, additionally updates the running
+                /// estimates using the statistics of the batch
+                fn forward_mut(&mut self, input: &ArrayBase<S, $D, A>) -> Self::Output {
+                    self.forward_mut_with(input, &mut Mode::from_training(self.training))
+                }
+            }
+
+            impl<A, S, T> Backward<ArrayBase<S, $D, A>, ArrayBase<T, $D, A>> for $name<A>
+            where
+                A: Float + FromPrimitive + ScalarOperand,
+                S: Data<Elem = A>,
+                T: Data<Elem = A>,
+            {
+                type Elem = A;
+
+                fn backward(
+                    &mut self,
+                    input: &ArrayBase<S, $D, A>,
+                    delta: &ArrayBase<T, $D, A>,
+                    gamma: A,
+                ) {
+                    let (grad, _) = self.gradients(input, delta);
+                    self.gamma.scaled_add(gamma, &grad.gamma);
+                    self.beta.scaled_add(gamma, &grad.beta);
+                }
+            }
+        )*
+    };
+}
+
+impl_batch_norm! {
+    BatchNorm1d<Ix2, Ix3>,
+    BatchNorm2d<Ix4>,
+}
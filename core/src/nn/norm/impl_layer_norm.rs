@@ -0,0 +1,174 @@
+/*
+    Appellation: impl_layer_norm <module>
+    Created At: 2026.10.18:19:08:17
+    Contrib: @FL03
+*/
+use super::utils::{
+    Standardized, features_to_rows, rms_normalize, rms_normalize_backward, standardize,
+    standardize_backward,
+};
+use super::{LayerNorm, NormGrad, RmsNorm};
+use crate::error::{Error, Result};
+use concision_traits::{Backward, Forward};
+use ndarray::{Array, Array1, Array2, ArrayBase, Axis, Data, Dimension, ScalarOperand};
+use num_traits::{Float, FromPrimitive, One, Zero};
+
+macro_rules! impl_layer_norm {
+    ($($name:ident::$normalize:ident($backward:ident)),* $(,)?) => {
+        $(
+            impl<A> $name<A> {
+                /// create a new layer for the given number of features; the scale `gamma` is
+                /// initialized to one and the shift `beta` to zero, using an `eps` of `1e-5`
+                pub fn new(features: usize) -> Self
+                where
+                    A: Clone + FromPrimitive + One + Zero,
+                {
+                    Self {
+                        gamma: Array1::ones(features),
+                        beta: Array1::zeros(features),
+                        eps: A::from_f64(1e-5).unwrap(),
+                    }
+                }
+                /// create a new layer from the given affine parameters
+                pub fn from_affine(gamma: Array1<A>, beta: Array1<A>) -> Result<Self>
+                where
+                    A: FromPrimitive,
+                {
+                    if gamma.len() != beta.len() {
+                        return Err(Error::InvalidModelConfig);
+                    }
+                    Ok(Self {
+                        gamma,
+                        beta,
+                        eps: A::from_f64(1e-5).unwrap(),
+                    })
+                }
+                /// returns the number of features normalized by the layer
+                pub fn features(&self) -> usize {
+                    self.gamma.len()
+                }
+                /// returns an immutable reference to the scale of each feature
+                pub const fn gamma(&self) -> &Array1<A> {
+                    &self.gamma
+                }
+                /// returns a mutable reference to the scale of each feature
+                pub const fn gamma_mut(&mut self) -> &mut Array1<A> {
+                    &mut self.gamma
+                }
+                /// returns an immutable reference to the shift of each feature
+                pub const fn beta(&self) -> &Array1<A> {
+                    &self.beta
+                }
+                /// returns a mutable reference to the shift of each feature
+                pub const fn beta_mut(&mut self) -> &mut Array1<A> {
+                    &mut self.beta
+                }
+                /// returns a copy of the value added for numerical stability
+                pub const fn eps(&self) -> A
+                where
+                    A: Copy,
+                {
+                    self.eps
+                }
+                /// set the value added for numerical stability
+                pub fn set_eps(&mut self, eps: A) -> &mut Self {
+                    self.eps = eps;
+                    self
+                }
+                /// consumes the current instance to create another with the given `eps`
+                pub fn with_eps(self, eps: A) -> Self {
+                    Self { eps, ..self }
+                }
+            }
+
+            impl<A> $name<A>
+            where
+                A: Float + FromPrimitive + ScalarOperand,
+            {
+                /// normalize the features of each sample, laid out as the rows of the input
+                fn normalize(&self, rows: &Array2<A>) -> Standardized<A> {
+                    assert_eq!(
+                        rows.ncols(),
+                        self.features(),
+                        "the input must have {} features",
+                        self.features()
+                    );
+                    $normalize(rows.view(), self.eps)
+                }
+                /// compute the gradients of the affine parameters alongside the gradient w.r.t.
+                /// the input given the gradient `delta` w.r.t. the output
+                pub fn gradients<S, T, D>(
+                    &self,
+                    input: &ArrayBase<S, D, A>,
+                    delta: &ArrayBase<T, D, A>,
+                ) -> (NormGrad<A>, Array<A, D>)
+                where
+                    D: Dimension,
+                    S: Data<Elem = A>,
+                    T: Data<Elem = A>,
+                {
+                    assert_eq!(
+                        input.shape(),
+                        delta.shape(),
+                        "the gradient is incompatible with the output of the layer"
+                    );
+                    let stats = self.normalize(&features_to_rows(input));
+                    let dy = features_to_rows(delta);
+                    let grad = NormGrad {
+                        gamma: (&dy * &stats.xhat).sum_axis(Axis(0)),
+                        beta: dy.sum_axis(Axis(0)),
+                    };
+                    let dx = $backward(&(dy * &self.gamma), &stats.xhat, &stats.inv_std);
+                    let dx = dx
+                        .to_shape(input.raw_dim())
+                        .expect("the gradient is shaped like the input")
+                        .into_owned();
+                    (grad, dx)
+                }
+            }
+
+            impl<A, S, D> Forward<ArrayBase<S, D, A>> for $name<A>
+            where
+                A: Float + FromPrimitive + ScalarOperand,
+                D: Dimension,
+                S: Data<Elem = A>,
+            {
+                type Output = Array<A, D>;
+
+                fn forward(&self, input: &ArrayBase<S, D, A>) -> Self::Output {
+                    let stats = self.normalize(&features_to_rows(input));
+                    (stats.xhat * &self.gamma + &self.beta)
+                        .to_shape(input.raw_dim())
+                        .expect("the output is shaped like the input")
+                        .into_owned()
+                }
+            }
+
+            impl<A, S, T, D> Backward<ArrayBase<S, D, A>, ArrayBase<T, D, A>> for $name<A>
+            where
+                A: Float + FromPrimitive + ScalarOperand,
+                D: Dimension,
+                S: Data<Elem = A>,
+                T: Data<Elem = A>,
+            {
+                type Elem = A;
+
+                fn backward(
+                    &mut self,
+                    input: &ArrayBase<S, D, A>,
+                    delta: &ArrayBase<T, D, A>,
+                    gamma: A,
+                ) {
+                    let (grad, _) = self.gradients(input, delta);
+                    self.gamma.scaled_add(gamma, &grad.gamma);
+                    self.beta.scaled_add(gamma, &grad.beta);
+                }
+            }
+        )*
+    };
+}
+
+impl_layer_norm! {
+    LayerNorm::standardize(standardize_backward),
+    RmsNorm::rms_normalize(rms_normalize_backward),
+}
@@ -0,0 +1,177 @@
+/*
+    Appellation: utils <module>
+    Created At: 2026.10.18:18:40:12
+    Contrib: @FL03
+*/
+//! the shared implementation of the normalization layers; each normalizes the _rows_ of a
+//! two-dimensional array, with the batch normalization layers first gathering the elements of
+//! each channel into a row.
+use ndarray::{Array, Array1, Array2, ArrayBase, ArrayView2, Axis, Data, Dimension, Zip};
+use num_traits::{Float, FromPrimitive};
+
+/// the values recorded while standardizing the rows of an array that are required by the
+/// backward pass
+pub(crate) struct Standardized<A> {
+    /// the standardized input
+    pub(crate) xhat: Array2<A>,
+    /// the mean of each row
+    pub(crate) mean: Array1<A>,
+    /// the (biased) variance of each row
+    pub(crate) var: Array1<A>,
+    /// the reciprocal of the standard deviation (or root mean square) of each row
+    pub(crate) inv_std: Array1<A>,
+}
+
+/// standardize each row of the array to have zero mean and unit variance
+pub(crate) fn standardize<A>(x: ArrayView2<'_, A>, eps: A) -> Standardized<A>
+where
+    A: Float + FromPrimitive,
+{
+    let mean = x
+        .mean_axis(Axis(1))
+        .expect("the normalized axis cannot be empty");
+    let var = x.var_axis(Axis(1), A::zero());
+    let inv_std = var.mapv(|v| (v + eps).sqrt().recip());
+    let xhat = normalize(x, &mean, &inv_std);
+    Standardized {
+        xhat,
+        mean,
+        var,
+        inv_std,
+    }
+}
+
+/// rescale each row of the array by the reciprocal of its root mean square
+pub(crate) fn rms_normalize<A>(x: ArrayView2<'_, A>, eps: A) -> Standardized<A>
+where
+    A: Float + FromPrimitive,
+{
+    let ms = x
+        .mapv(|v| v * v)
+        .mean_axis(Axis(1))
+        .expect("the normalized axis cannot be empty");
+    let inv_std = ms.mapv(|v| (v + eps).sqrt().recip());
+    let mean = Array1::zeros(x.nrows());
+    let xhat = normalize(x, &mean, &inv_std);
+    Standardized {
+        xhat,
+        mean,
+        var: ms,
+        inv_std,
+    }
+}
+
+/// compute `(x - mean) * inv_std` for each row of the array
+pub(crate) fn normalize<A>(x: ArrayView2<'_, A>, mean: &Array1<A>, inv_std: &Array1<A>) -> Array2<A>
+where
+    A: Float,
+{
+    let mut xhat = x.to_owned();
+    Zip::from(xhat.rows_mut())
+        .and(mean)
+        .and(inv_std)
+        .for_each(|mut row, &m, &s| row.mapv_inplace(|v| (v - m) * s));
+    xhat
+}
+
+/// compute the gradient w.r.t. the input of [`standardize`] given the gradient w.r.t. its
+/// output
+pub(crate) fn standardize_backward<A>(
+    dxhat: &Array2<A>,
+    xhat: &Array2<A>,
+    inv_std: &Array1<A>,
+) -> Array2<A>
+where
+    A: Float + FromPrimitive,
+{
+    let n = A::from_usize(xhat.ncols()).unwrap();
+    let mut dx = dxhat.to_owned();
+    Zip::from(dx.rows_mut())
+        .and(xhat.rows())
+        .and(inv_std)
+        .for_each(|mut dx, xhat, &s| {
+            let sum = dx.sum();
+            let dot = dx
+                .iter()
+                .zip(&xhat)
+                .fold(A::zero(), |acc, (&d, &xh)| acc + d * xh);
+            Zip::from(&mut dx)
+                .and(&xhat)
+                .for_each(|d, &xh| *d = s * (*d - (sum + xh * dot) / n));
+        });
+    dx
+}
+
+/// compute the gradient w.r.t. the input of [`rms_normalize`] given the gradient w.r.t. its
+/// output
+pub(crate) fn rms_normalize_backward<A>(
+    dxhat: &Array2<A>,
+    xhat: &Array2<A>,
+    inv_std: &Array1<A>,
+) -> Array2<A>
+where
+    A: Float + FromPrimitive,
+{
+    let n = A::from_usize(xhat.ncols()).unwrap();
+    let mut dx = dxhat.to_owned();
+    Zip::from(dx.rows_mut())
+        .and(xhat.rows())
+        .and(inv_std)
+        .for_each(|mut dx, xhat, &s| {
+            let dot = dx
+                .iter()
+                .zip(&xhat)
+                .fold(A::zero(), |acc, (&d, &xh)| acc + d * xh);
+            Zip::from(&mut dx)
+                .and(&xhat)
+                .for_each(|d, &xh| *d = s * (*d - xh * dot / n));
+        });
+    dx
+}
+
+/// gather the elements of each channel (i.e. axis `1`) of the input into the rows of a new
+/// array shaped `(channels, elements)`
+pub(crate) fn channels_to_rows<A, S, D>(x: &ArrayBase<S, D, A>) -> Array2<A>
+where
+    A: Clone,
+    D: Dimension,
+    S: Data<Elem = A>,
+{
+    let mut view = x.view();
+    view.swap_axes(0, 1);
+    let channels = view.len_of(Axis(0));
+    let elements = view.len() / channels.max(1);
+    view.as_standard_layout()
+        .into_owned()
+        .into_shape_with_order((channels, elements))
+        .expect("the elements of each channel are contiguous")
+}
+
+/// the inverse of [`channels_to_rows`], restoring an array of the given shape
+pub(crate) fn rows_to_channels<A, D>(rows: Array2<A>, dim: D) -> Array<A, D>
+where
+    A: Clone,
+    D: Dimension,
+{
+    let mut swapped = dim.clone();
+    swapped.slice_mut().swap(0, 1);
+    let mut array = rows
+        .into_shape_with_order(swapped)
+        .expect("the rows are shaped by the channels of the input");
+    array.swap_axes(0, 1);
+    array.as_standard_layout().into_owned()
+}
+
+/// flatten all but the last axis of the input, returning a view shaped `(rows, features)`
+pub(crate) fn features_to_rows<A, S, D>(x: &ArrayBase<S, D, A>) -> Array2<A>
+where
+    A: Clone,
+    D: Dimension,
+    S: Data<Elem = A>,
+{
+    let features = x.shape().last().copied().unwrap_or(1);
+    let rows = x.len() / features.max(1);
+    x.to_shape((rows, features))
+        .expect("the input is compatible with its flattened shape")
+        .into_owned()
+}
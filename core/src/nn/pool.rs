@@ -0,0 +1,93 @@
+/*
+    Appellation: pool <module>
+    Created At: 2026.10.18:15:01:27
+    Contrib: @FL03
+*/
+//! This module implements the pooling layers used to downsample the feature maps produced
+//! by the convolution layers.
+//!
+//! - [`MaxPool1d`] and [`MaxPool2d`]: select the maximum of each window, recording its
+//!   position so that the gradient may be routed back onto it
+//! - [`AvgPool1d`] and [`AvgPool2d`]: average the elements of each window
+//! - [`GlobalAvgPool`]: average over every spatial axis of the input
+//!
+//! Each layer operates on a _batch_ of inputs laid out as `(batch, channels, ...spatial)`
+//! and, with the exception of the [`GlobalAvgPool`], pads its input according to its
+//! [`Padding`] before pooling. The average pooling layers include the padded elements when
+//! averaging, while the max pooling layers should generally be padded using a constant of
+//! negative infinity.
+//!
+//! The pooling layers do not have any trainable parameters, hence their [`Backward`]
+//! implementations leave them unchanged; instead, the `gradient` method of each layer
+//! computes the gradient w.r.t. its input given the gradient w.r.t. its output, while the max
+//! pooling layers also provide a `gradient_with_indices` method reusing the positions
+//! recorded by `forward_with_indices`.
+//!
+//! [`Backward`]: concision_traits::Backward
+mod impl_pool;
+
+pub(crate) mod utils;
+
+use crate::utils::Padding;
+
+/// The [`MaxPool1d`] layer selects the maximum of each window of a batch of sequences shaped
+/// `(batch, channels, length)`.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub struct MaxPool1d<A = f32> {
+    pub(crate) kernel_size: usize,
+    pub(crate) stride: usize,
+    pub(crate) padding: Padding<A>,
+}
+
+/// The [`MaxPool2d`] layer selects the maximum of each window of a batch of images shaped
+/// `(batch, channels, height, width)`.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub struct MaxPool2d<A = f32> {
+    pub(crate) kernel_size: (usize, usize),
+    pub(crate) stride: (usize, usize),
+    pub(crate) padding: Padding<A>,
+}
+
+/// The [`AvgPool1d`] layer averages each window of a batch of sequences shaped
+/// `(batch, channels, length)`.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub struct AvgPool1d<A = f32> {
+    pub(crate) kernel_size: usize,
+    pub(crate) stride: usize,
+    pub(crate) padding: Padding<A>,
+}
+
+/// The [`AvgPool2d`] layer averages each window of a batch of images shaped
+/// `(batch, channels, height, width)`.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub struct AvgPool2d<A = f32> {
+    pub(crate) kernel_size: (usize, usize),
+    pub(crate) stride: (usize, usize),
+    pub(crate) padding: Padding<A>,
+}
+
+/// The [`GlobalAvgPool`] layer averages every spatial axis of its input, reducing a batch
+/// shaped `(batch, channels, ...spatial)` to one of shape `(batch, channels)`.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct GlobalAvgPool;
@@ -0,0 +1,362 @@
+/*
+    Appellation: impl_pool <module>
+    Created At: 2026.10.18:15:18:09
+    Contrib: @FL03
+*/
+use super::utils::{PoolSpec, Window};
+use super::{AvgPool1d, AvgPool2d, GlobalAvgPool, MaxPool1d, MaxPool2d};
+use crate::error::{Error, Result};
+use crate::utils::{Padding, pad, pad_gradient};
+use concision_traits::{Backward, Forward};
+use ndarray::{
+    Array, Array2, Array4, ArrayBase, ArrayView, ArrayView4, Axis, Data, Dimension, Ix2, Ix3, Ix4,
+};
+use num_traits::{Float, FromPrimitive, Zero};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// the dimensions of the inputs supported by the pooling layers; each is _lifted_ into the
+/// four-dimensional layout used by the shared implementation
+trait PoolDim: Dimension {
+    /// returns the shape of the lifted array
+    fn shape4(&self) -> (usize, usize, usize, usize);
+
+    fn lift<T>(view: ArrayView<'_, T, Self>) -> ArrayView4<'_, T>;
+
+    fn lower<T>(array: Array4<T>) -> Array<T, Self>;
+}
+
+impl PoolDim for Ix3 {
+    fn shape4(&self) -> (usize, usize, usize, usize) {
+        (self[0], self[1], 1, self[2])
+    }
+
+    fn lift<T>(view: ArrayView<'_, T, Self>) -> ArrayView4<'_, T> {
+        view.insert_axis(Axis(2))
+    }
+
+    fn lower<T>(array: Array4<T>) -> Array<T, Self> {
+        array.remove_axis(Axis(2))
+    }
+}
+
+impl PoolDim for Ix4 {
+    fn shape4(&self) -> (usize, usize, usize, usize) {
+        (self[0], self[1], self[2], self[3])
+    }
+
+    fn lift<T>(view: ArrayView<'_, T, Self>) -> ArrayView4<'_, T> {
+        view
+    }
+
+    fn lower<T>(array: Array4<T>) -> Array<T, Self> {
+        array
+    }
+}
+
+/// returns the widths used to pad each axis of an input with `W::NDIM` spatial axes
+fn pad_width<A, W: Window>(padding: &Padding<A>) -> Vec<[usize; 2]> {
+    let mut width = Vec::from([[0, 0]; 2]);
+    width.extend(padding.pad_width(W::NDIM));
+    width
+}
+
+/// returns the shape of the given input once padded by the given widths
+fn padded_dim<D: Dimension>(dim: &D, width: &[[usize; 2]]) -> D {
+    let mut padded = dim.clone();
+    for (ax, [lo, hi]) in width.iter().enumerate() {
+        padded[ax] += lo + hi;
+    }
+    padded
+}
+
+macro_rules! impl_pool {
+    ($($name:ident<$W:ty, $D:ty>),* $(,)?) => {
+        $(impl_pool!(@impl $name<$W, $D>);)*
+    };
+    (@impl $name:ident<$W:ty, $D:ty>) => {
+        impl<A> $name<A> {
+            /// create a new layer with the given kernel size, using a stride equal to the
+            /// kernel size and no padding
+            ///
+            /// ## Panics
+            ///
+            /// panics if the kernel size is zero along any axis
+            pub fn new(kernel_size: $W) -> Self
+            where
+                A: Zero,
+            {
+                assert!(
+                    !kernel_size.pair().contains(&0),
+                    "the kernel size must be non-zero"
+                );
+                Self {
+                    kernel_size,
+                    stride: kernel_size,
+                    padding: Padding::zeros(0),
+                }
+            }
+            /// returns the size of the pooling window
+            pub const fn kernel_size(&self) -> $W {
+                self.kernel_size
+            }
+            /// returns the stride of the pooling window
+            pub const fn stride(&self) -> $W {
+                self.stride
+            }
+            /// returns an immutable reference to the padding of the layer
+            pub const fn padding(&self) -> &Padding<A> {
+                &self.padding
+            }
+            /// set the stride of the pooling window, returning an error if it is zero along
+            /// any axis
+            pub fn set_stride(&mut self, stride: $W) -> Result<&mut Self> {
+                if stride.pair().contains(&0) {
+                    return Err(Error::InvalidModelConfig);
+                }
+                self.stride = stride;
+                Ok(self)
+            }
+            /// set the padding of the layer
+            pub fn set_padding(&mut self, padding: Padding<A>) -> &mut Self {
+                self.padding = padding;
+                self
+            }
+            /// consumes the current instance to create another with the given stride
+            pub fn with_stride(mut self, stride: $W) -> Result<Self> {
+                self.set_stride(stride)?;
+                Ok(self)
+            }
+            /// consumes the current instance to create another with the given padding
+            pub fn with_padding(self, padding: Padding<A>) -> Self {
+                Self { padding, ..self }
+            }
+
+            fn spec(&self) -> PoolSpec {
+                PoolSpec::new(self.kernel_size, self.stride)
+            }
+            /// pad the input according to the padding of the layer
+            fn pad_input<S>(&self, input: &ArrayBase<S, $D, A>) -> Array<A, $D>
+            where
+                A: Float + FromPrimitive,
+                S: Data<Elem = A>,
+            {
+                pad(input, &pad_width::<A, $W>(&self.padding), *self.padding.mode())
+            }
+        }
+
+        impl<A, S, T> Backward<ArrayBase<S, $D, A>, ArrayBase<T, $D, A>> for $name<A>
+        where
+            A: Float + FromPrimitive,
+            S: Data<Elem = A>,
+            T: Data<Elem = A>,
+        {
+            type Elem = A;
+            /// the layer has no trainable parameters, hence the update leaves it unchanged;
+            /// the gradient w.r.t. the input, needed to continue the backward pass through the
+            /// preceding layers, is computed by the `gradient` method of the layer
+            fn backward(&mut self, _: &ArrayBase<S, $D, A>, _: &ArrayBase<T, $D, A>, _: A) {}
+        }
+    };
+}
+
+impl_pool! {
+    MaxPool1d<usize, Ix3>,
+    MaxPool2d<(usize, usize), Ix4>,
+    AvgPool1d<usize, Ix3>,
+    AvgPool2d<(usize, usize), Ix4>,
+}
+
+macro_rules! impl_max_pool {
+    ($($name:ident<$W:ty, $D:ty>),* $(,)?) => {
+        $(
+            impl<A> $name<A>
+            where
+                A: Float + FromPrimitive,
+            {
+                /// compute the maximum of each window alongside its position, flattened over
+                /// the spatial axes of the padded input
+                pub fn forward_with_indices<S>(
+                    &self,
+                    input: &ArrayBase<S, $D, A>,
+                ) -> (Array<A, $D>, Array<usize, $D>)
+                where
+                    S: Data<Elem = A>,
+                {
+                    let padded = self.pad_input(input);
+                    let (output, indices) = self.spec().max(<$D>::lift(padded.view()));
+                    (<$D>::lower(output), <$D>::lower(indices))
+                }
+                /// compute the gradient w.r.t. the input, of the given shape, by routing the
+                /// gradient of each output onto the position of its maximum
+                pub fn gradient_with_indices<T>(
+                    &self,
+                    indices: &Array<usize, $D>,
+                    delta: &ArrayBase<T, $D, A>,
+                    dim: $D,
+                ) -> Array<A, $D>
+                where
+                    T: Data<Elem = A>,
+                {
+                    let width = pad_width::<A, $W>(&self.padding);
+                    let shape = padded_dim(&dim, &width).shape4();
+                    let grad = self.spec().max_gradient(
+                        <$D>::lift(indices.view()),
+                        <$D>::lift(delta.view()),
+                        shape,
+                    );
+                    pad_gradient(&<$D>::lower(grad), &width, self.padding.mode())
+                }
+                /// compute the gradient w.r.t. the input given the gradient `delta` w.r.t.
+                /// the output
+                pub fn gradient<S, T>(
+                    &self,
+                    input: &ArrayBase<S, $D, A>,
+                    delta: &ArrayBase<T, $D, A>,
+                ) -> Array<A, $D>
+                where
+                    S: Data<Elem = A>,
+                    T: Data<Elem = A>,
+                {
+                    let (_, indices) = self.forward_with_indices(input);
+                    self.gradient_with_indices(&indices, delta, input.raw_dim())
+                }
+            }
+
+            impl<A, S> Forward<ArrayBase<S, $D, A>> for $name<A>
+            where
+                A: Float + FromPrimitive,
+                S: Data<Elem = A>,
+            {
+                type Output = Array<A, $D>;
+
+                fn forward(&self, input: &ArrayBase<S, $D, A>) -> Self::Output {
+                    self.forward_with_indices(input).0
+                }
+            }
+        )*
+    };
+}
+
+impl_max_pool! {
+    MaxPool1d<usize, Ix3>,
+    MaxPool2d<(usize, usize), Ix4>,
+}
+
+macro_rules! impl_avg_pool {
+    ($($name:ident<$W:ty, $D:ty>),* $(,)?) => {
+        $(
+            impl<A> $name<A>
+            where
+                A: Float + FromPrimitive,
+            {
+                /// compute the gradient w.r.t. the input given the gradient `delta` w.r.t.
+                /// the output; each element receives an equal share of the gradient of every
+                /// window it belongs to
+                pub fn gradient<S, T>(
+                    &self,
+                    input: &ArrayBase<S, $D, A>,
+                    delta: &ArrayBase<T, $D, A>,
+                ) -> Array<A, $D>
+                where
+                    S: Data<Elem = A>,
+                    T: Data<Elem = A>,
+                {
+                    let width = pad_width::<A, $W>(&self.padding);
+                    let shape = padded_dim(&input.raw_dim(), &width).shape4();
+                    let grad = self.spec().avg_gradient(<$D>::lift(delta.view()), shape);
+                    pad_gradient(&<$D>::lower(grad), &width, self.padding.mode())
+                }
+            }
+
+            impl<A, S> Forward<ArrayBase<S, $D, A>> for $name<A>
+            where
+                A: Float + FromPrimitive,
+                S: Data<Elem = A>,
+            {
+                type Output = Array<A, $D>;
+
+                fn forward(&self, input: &ArrayBase<S, $D, A>) -> Self::Output {
+                    let padded = self.pad_input(input);
+                    <$D>::lower(self.spec().avg(<$D>::lift(padded.view())))
+                }
+            }
+        )*
+    };
+}
+
+impl_avg_pool! {
+    AvgPool1d<usize, Ix3>,
+    AvgPool2d<(usize, usize), Ix4>,
+}
+
+impl GlobalAvgPool {
+    /// create a new instance of the layer
+    pub const fn new() -> Self {
+        Self
+    }
+    /// compute the gradient w.r.t. the input given the gradient `delta` w.r.t. the output,
+    /// spreading the gradient of each channel evenly over its spatial axes
+    pub fn gradient<A, S, T, D>(
+        &self,
+        input: &ArrayBase<S, D, A>,
+        delta: &ArrayBase<T, Ix2, A>,
+    ) -> Array<A, D>
+    where
+        A: Float + FromPrimitive,
+        D: Dimension,
+        S: Data<Elem = A>,
+        T: Data<Elem = A>,
+    {
+        let (batch, channels) = delta.dim();
+        let n = A::from_usize(input.len() / (batch * channels).max(1)).unwrap();
+        let mut shape = input.raw_dim();
+        for ax in 2..shape.ndim() {
+            shape[ax] = 1;
+        }
+        let delta = delta
+            .to_shape(shape)
+            .expect("the gradient is incompatible with the output of the pooling layer")
+            .mapv(|d| d / n);
+        delta
+            .broadcast(input.raw_dim())
+            .expect("the gradient is incompatible with the input")
+            .to_owned()
+    }
+}
+
+impl<A, S, D> Forward<ArrayBase<S, D, A>> for GlobalAvgPool
+where
+    A: Float + FromPrimitive,
+    D: Dimension,
+    S: Data<Elem = A>,
+{
+    type Output = Array2<A>;
+
+    fn forward(&self, input: &ArrayBase<S, D, A>) -> Self::Output {
+        assert!(
+            input.ndim() > 2,
+            "the input must be shaped (batch, channels, ...spatial)"
+        );
+        let shape = (input.len_of(Axis(0)), input.len_of(Axis(1)));
+        let flat = input
+            .to_shape((shape.0, shape.1, input.len() / (shape.0 * shape.1).max(1)))
+            .expect("failed to flatten the spatial axes");
+        flat.mean_axis(Axis(2))
+            .expect("the spatial axes cannot be empty")
+    }
+}
+
+impl<A, S, T, D> Backward<ArrayBase<S, D, A>, ArrayBase<T, Ix2, A>> for GlobalAvgPool
+where
+    A: Float + FromPrimitive,
+    D: Dimension,
+    S: Data<Elem = A>,
+    T: Data<Elem = A>,
+{
+    type Elem = A;
+    /// the layer has no trainable parameters, hence the update leaves it unchanged; the
+    /// gradient w.r.t. the input is computed by [`gradient`](GlobalAvgPool::gradient)
+    fn backward(&mut self, _: &ArrayBase<S, D, A>, _: &ArrayBase<T, Ix2, A>, _: A) {}
+}
@@ -0,0 +1,150 @@
+/*
+    Appellation: utils <module>
+    Created At: 2026.10.18:15:04:51
+    Contrib: @FL03
+*/
+//! the shared implementation of the pooling layers; as with the convolutions, the
+//! one-dimensional layers are treated as two-dimensional layers whose spatial height is one.
+use crate::nn::conv::utils::output_len;
+use ndarray::{Array4, ArrayView4, s};
+use num_traits::{Float, FromPrimitive};
+
+/// a trait for converting the size of a window (i.e. its kernel or stride) into the
+/// `(height, width)` pair used by the pooling routines
+pub(crate) trait Window: Copy {
+    /// the number of spatial axes described by the window
+    const NDIM: usize;
+
+    fn pair(self) -> [usize; 2];
+}
+
+impl Window for usize {
+    const NDIM: usize = 1;
+
+    fn pair(self) -> [usize; 2] {
+        [1, self]
+    }
+}
+
+impl Window for (usize, usize) {
+    const NDIM: usize = 2;
+
+    fn pair(self) -> [usize; 2] {
+        [self.0, self.1]
+    }
+}
+
+/// the hyperparameters of a two-dimensional pooling operation
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct PoolSpec {
+    pub(crate) kernel: [usize; 2],
+    pub(crate) stride: [usize; 2],
+}
+
+impl PoolSpec {
+    pub(crate) fn new<W: Window>(kernel: W, stride: W) -> Self {
+        Self {
+            kernel: kernel.pair(),
+            stride: stride.pair(),
+        }
+    }
+    /// returns the spatial shape of the output given the (padded) input
+    fn output_shape(&self, (h, w): (usize, usize)) -> (usize, usize) {
+        let ([kh, kw], [sh, sw]) = (self.kernel, self.stride);
+        (output_len(h, kh, sh, 1), output_len(w, kw, sw, 1))
+    }
+    /// returns the origin of the window producing the output at `(i, j)`
+    fn origin(&self, i: usize, j: usize) -> (usize, usize) {
+        (i * self.stride[0], j * self.stride[1])
+    }
+    /// compute the maximum of each window of the (padded) input alongside the position of
+    /// the maximum, flattened over the spatial axes of the input (i.e. `y * width + x`)
+    pub(crate) fn max<A>(&self, x: ArrayView4<'_, A>) -> (Array4<A>, Array4<usize>)
+    where
+        A: Float,
+    {
+        let (batch, channels, h, w) = x.dim();
+        let (oh, ow) = self.output_shape((h, w));
+        let [kh, kw] = self.kernel;
+        let mut output = Array4::zeros((batch, channels, oh, ow));
+        let mut indices = Array4::zeros((batch, channels, oh, ow));
+        for ((b, c, i, j), out) in output.indexed_iter_mut() {
+            let (y0, x0) = self.origin(i, j);
+            let window = x.slice(s![b, c, y0..y0 + kh, x0..x0 + kw]);
+            let (mut pos, mut value) = ((0, 0), window[[0, 0]]);
+            for (idx, &v) in window.indexed_iter() {
+                if v > value {
+                    (pos, value) = (idx, v);
+                }
+            }
+            let (y, x) = pos;
+            *out = value;
+            indices[[b, c, i, j]] = (y0 + y) * w + x0 + x;
+        }
+        (output, indices)
+    }
+    /// route the gradient of each output onto the position of its maximum, producing the
+    /// gradient w.r.t. a (padded) input of the given shape
+    pub(crate) fn max_gradient<A>(
+        &self,
+        indices: ArrayView4<'_, usize>,
+        delta: ArrayView4<'_, A>,
+        shape: (usize, usize, usize, usize),
+    ) -> Array4<A>
+    where
+        A: Float,
+    {
+        assert_eq!(
+            indices.dim(),
+            delta.dim(),
+            "the gradient is incompatible with the output of the pooling layer"
+        );
+        let w = shape.3;
+        let mut grad = Array4::zeros(shape);
+        for ((b, c, i, j), &idx) in indices.indexed_iter() {
+            let cell = &mut grad[[b, c, idx / w, idx % w]];
+            *cell = *cell + delta[[b, c, i, j]];
+        }
+        grad
+    }
+    /// compute the average of each window of the (padded) input
+    pub(crate) fn avg<A>(&self, x: ArrayView4<'_, A>) -> Array4<A>
+    where
+        A: Float + FromPrimitive,
+    {
+        let (batch, channels, h, w) = x.dim();
+        let (oh, ow) = self.output_shape((h, w));
+        let [kh, kw] = self.kernel;
+        let n = A::from_usize(kh * kw).unwrap();
+        Array4::from_shape_fn((batch, channels, oh, ow), |(b, c, i, j)| {
+            let (y0, x0) = self.origin(i, j);
+            x.slice(s![b, c, y0..y0 + kh, x0..x0 + kw]).sum() / n
+        })
+    }
+    /// distribute the gradient of each output evenly over its window, producing the gradient
+    /// w.r.t. a (padded) input of the given shape
+    pub(crate) fn avg_gradient<A>(
+        &self,
+        delta: ArrayView4<'_, A>,
+        shape: (usize, usize, usize, usize),
+    ) -> Array4<A>
+    where
+        A: Float + FromPrimitive,
+    {
+        let (oh, ow) = self.output_shape((shape.2, shape.3));
+        assert_eq!(
+            delta.dim(),
+            (shape.0, shape.1, oh, ow),
+            "the gradient is incompatible with the output of the pooling layer"
+        );
+        let [kh, kw] = self.kernel;
+        let n = A::from_usize(kh * kw).unwrap();
+        let mut grad = Array4::zeros(shape);
+        for ((b, c, i, j), &d) in delta.indexed_iter() {
+            let (y0, x0) = self.origin(i, j);
+            grad.slice_mut(s![b, c, y0..y0 + kh, x0..x0 + kw])
+                .mapv_inplace(|g| g + d / n);
+        }
+        grad
+    }
+}
@@ -0,0 +1,121 @@
+/*
+    Appellation: rnn <module>
+    Created At: 2026.10.18:16:02:44
+    Contrib: @FL03
+*/
+//! This module implements recurrent neural networks, providing the [`RnnCell`], [`GruCell`]
+//! and [`LstmCell`] alongside the [`Recurrent`] layer which unrolls a cell over the time axis
+//! of a sequence.
+//!
+//! Sequences are laid out with their time axis preceding their features, i.e. a single
+//! sequence is shaped `(time, features)` while a batch is shaped `(batch, time, features)`.
+//!
+//! The gates of each cell are stacked along the columns of two sets of parameters: the
+//! `input` parameters, shaped `(input_size, gates * hidden_size)`, which transform the input
+//! of each step and the `recurrent` parameters, shaped `(hidden_size, gates * hidden_size)`,
+//! which transform the previous hidden state.
+//!
+//! ## Training
+//!
+//! The [`Recurrent`] layer computes its gradients using backpropagation through time (BPTT),
+//! optionally truncating the sequence into windows of a fixed length whose gradients are not
+//! propagated into the preceding window, and clipping the L2 norm of each gradient.
+#[doc(inline)]
+pub use self::impl_cell::RecurrentCell;
+
+#[macro_use]
+mod impl_cell;
+mod impl_gru;
+mod impl_lstm;
+mod impl_recurrent;
+mod impl_rnn;
+
+use concision_params::Params;
+use ndarray::Array2;
+
+/// The [`RnnCell`] implements the vanilla (Elman) recurrent cell:
+///
+/// ```math
+/// h_t = \tanh(x_t W_x + b_x + h_{t-1} W_h + b_h)
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub struct RnnCell<A = f32> {
+    pub(crate) input: Params<A>,
+    pub(crate) recurrent: Params<A>,
+}
+
+/// The [`GruCell`] implements the gated recurrent unit, stacking the reset (`r`), update
+/// (`z`) and candidate (`n`) gates, in that order:
+///
+/// ```math
+/// \begin{aligned}
+/// r_t &= \sigma(x_t W_{xr} + b_{xr} + h_{t-1} W_{hr} + b_{hr}) \\
+/// z_t &= \sigma(x_t W_{xz} + b_{xz} + h_{t-1} W_{hz} + b_{hz}) \\
+/// n_t &= \tanh(x_t W_{xn} + b_{xn} + r_t \odot (h_{t-1} W_{hn} + b_{hn})) \\
+/// h_t &= (1 - z_t) \odot n_t + z_t \odot h_{t-1}
+/// \end{aligned}
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub struct GruCell<A = f32> {
+    pub(crate) input: Params<A>,
+    pub(crate) recurrent: Params<A>,
+}
+
+/// The [`LstmCell`] implements the long short-term memory cell, stacking the input (`i`),
+/// forget (`f`), cell (`g`) and output (`o`) gates, in that order:
+///
+/// ```math
+/// \begin{aligned}
+/// c_t &= f_t \odot c_{t-1} + i_t \odot g_t \\
+/// h_t &= o_t \odot \tanh(c_t)
+/// \end{aligned}
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub struct LstmCell<A = f32> {
+    pub(crate) input: Params<A>,
+    pub(crate) recurrent: Params<A>,
+}
+
+/// The [`LstmState`] is the state of an [`LstmCell`]; its hidden state alongside the state of
+/// its memory cell, each shaped `(batch, hidden_size)`.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub struct LstmState<A = f32> {
+    pub hidden: Array2<A>,
+    pub cell: Array2<A>,
+}
+
+/// The [`Recurrent`] layer unrolls a [`RecurrentCell`] over the time axis of a sequence,
+/// producing the hidden state of every step.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub struct Recurrent<C, A = f32> {
+    pub(crate) cell: C,
+    /// the length of the windows used by truncated backpropagation through time
+    pub(crate) truncation: Option<usize>,
+    /// the maximum L2 norm of each gradient
+    pub(crate) clip: Option<A>,
+}
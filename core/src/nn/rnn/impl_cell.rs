@@ -0,0 +1,228 @@
+/*
+    Appellation: impl_cell <module>
+    Created At: 2026.10.18:16:11:20
+    Contrib: @FL03
+*/
+use super::{GruCell, LstmCell, RnnCell};
+use crate::error::{Error, Result};
+use concision_params::Params;
+use concision_traits::ClipMut;
+use ndarray::{Array2, ArrayView2, Axis, ScalarOperand, s};
+use num_traits::{Float, Zero};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// The [`RecurrentCell`] trait defines a single step of a recurrent network, alongside the
+/// backward pass of that step used by backpropagation through time.
+///
+/// The gradients of a cell are stored in another instance of the cell, enabling them to be
+/// accumulated over the steps of a sequence before being applied.
+pub trait RecurrentCell<A>: Sized {
+    /// the state carried between the steps of a sequence
+    type State: Clone;
+    /// the values recorded during a step that are required to compute its gradients
+    type Cache;
+
+    /// returns an immutable reference to the parameters transforming the input
+    fn input(&self) -> &Params<A>;
+    /// returns a mutable reference to the parameters transforming the input
+    fn input_mut(&mut self) -> &mut Params<A>;
+    /// returns an immutable reference to the parameters transforming the previous state
+    fn recurrent(&self) -> &Params<A>;
+    /// returns a mutable reference to the parameters transforming the previous state
+    fn recurrent_mut(&mut self) -> &mut Params<A>;
+    /// returns the initial (zero) state for a batch of the given size
+    fn init_state(&self, batch: usize) -> Self::State;
+    /// returns a reference to the hidden state, i.e. the output, of the given state
+    fn hidden(state: &Self::State) -> &Array2<A>;
+    /// returns a mutable reference to the hidden state of the given state
+    fn hidden_mut(state: &mut Self::State) -> &mut Array2<A>;
+    /// compute the next state given the input, shaped `(batch, input_size)`, and the
+    /// previous state
+    fn step(&self, input: ArrayView2<'_, A>, state: &Self::State) -> (Self::State, Self::Cache);
+    /// given the gradient w.r.t. the state produced by a step, accumulate the gradients of
+    /// the parameters onto `grad` and return the gradients w.r.t. the input and previous
+    /// state of the step
+    fn step_backward(
+        &self,
+        cache: &Self::Cache,
+        delta: &Self::State,
+        grad: &mut Self,
+    ) -> (Array2<A>, Self::State);
+    /// returns the number of features expected by the cell
+    fn input_size(&self) -> usize {
+        self.input().weights().nrows()
+    }
+    /// returns the number of features of the hidden state
+    fn hidden_size(&self) -> usize {
+        self.recurrent().weights().nrows()
+    }
+    /// returns a copy of the cell whose parameters are all zero; used to accumulate gradients
+    fn zeros_like(&self) -> Self
+    where
+        A: Clone + Zero,
+        Self: Clone,
+    {
+        let zero = |params: &mut Params<A>| {
+            params.weights_mut().fill(A::zero());
+            params.bias_mut().fill(A::zero());
+        };
+        let mut cell = self.clone();
+        zero(cell.input_mut());
+        zero(cell.recurrent_mut());
+        cell
+    }
+    /// add `gamma` times the parameters of `other` onto the parameters of the cell
+    fn scaled_add(&mut self, gamma: A, other: &Self)
+    where
+        A: Float + ScalarOperand,
+    {
+        self.input_mut()
+            .weights_mut()
+            .scaled_add(gamma, other.input().weights());
+        self.input_mut()
+            .bias_mut()
+            .scaled_add(gamma, other.input().bias());
+        self.recurrent_mut()
+            .weights_mut()
+            .scaled_add(gamma, other.recurrent().weights());
+        self.recurrent_mut()
+            .bias_mut()
+            .scaled_add(gamma, other.recurrent().bias());
+    }
+    /// clip the L2 norm of each of the parameters of the cell to the given threshold
+    fn clip_norm_l2(&mut self, threshold: A)
+    where
+        A: 'static + Float,
+    {
+        let clip = |params: &mut Params<A>| {
+            params.weights_mut().clip_norm_l2(threshold);
+            params.bias_mut().clip_norm_l2(threshold);
+        };
+        clip(self.input_mut());
+        clip(self.recurrent_mut());
+    }
+}
+
+/// compute the affine transformation `x W + b` of the given parameters
+pub(crate) fn affine<A>(params: &Params<A>, x: &ArrayView2<'_, A>) -> Array2<A>
+where
+    A: Float + ScalarOperand,
+{
+    x.dot(params.weights()) + params.bias()
+}
+
+/// accumulate the gradients of an affine transformation, given its input and the gradient
+/// w.r.t. its output, returning the gradient w.r.t. its input
+pub(crate) fn affine_backward<A>(
+    params: &Params<A>,
+    grad: &mut Params<A>,
+    x: &Array2<A>,
+    delta: &Array2<A>,
+) -> Array2<A>
+where
+    A: Float + ScalarOperand,
+{
+    grad.weights_mut().scaled_add(A::one(), &x.t().dot(delta));
+    grad.bias_mut()
+        .scaled_add(A::one(), &delta.sum_axis(Axis(0)));
+    delta.dot(&params.weights().t())
+}
+
+/// returns a view of the given gate of the stacked array
+pub(crate) fn gate<A>(array: &Array2<A>, index: usize, hidden: usize) -> ArrayView2<'_, A> {
+    array.slice(s![.., index * hidden..(index + 1) * hidden])
+}
+
+/// stack the given gates along the columns of a single array
+pub(crate) fn stack<A: Clone>(gates: &[&Array2<A>]) -> Array2<A> {
+    let views = gates.iter().map(|g| g.view()).collect::<Vec<_>>();
+    ndarray::concatenate(Axis(1), &views).expect("the gates must share their shape")
+}
+
+/// implements the accessors of the [`RecurrentCell`] trait for a cell storing its
+/// parameters in the `input` and `recurrent` fields
+macro_rules! cell_accessors {
+    () => {
+        fn input(&self) -> &concision_params::Params<A> {
+            &self.input
+        }
+
+        fn input_mut(&mut self) -> &mut concision_params::Params<A> {
+            &mut self.input
+        }
+
+        fn recurrent(&self) -> &concision_params::Params<A> {
+            &self.recurrent
+        }
+
+        fn recurrent_mut(&mut self) -> &mut concision_params::Params<A> {
+            &mut self.recurrent
+        }
+    };
+}
+
+macro_rules! impl_cell {
+    ($($name:ident($gates:literal)),* $(,)?) => {
+        $(
+            impl<A> $name<A> {
+                /// the number of gates stacked along the columns of the parameters
+                pub const GATES: usize = $gates;
+                /// create a new cell from the given input and recurrent parameters, validating
+                /// their shapes
+                pub fn from_params(input: Params<A>, recurrent: Params<A>) -> Result<Self> {
+                    let (_, cols) = input.weights().dim();
+                    let (hidden, rcols) = recurrent.weights().dim();
+                    let gates = Self::GATES;
+                    if cols != gates * hidden
+                        || rcols != cols
+                        || input.bias().len() != cols
+                        || recurrent.bias().len() != cols
+                    {
+                        return Err(Error::InvalidModelConfig);
+                    }
+                    Ok(Self { input, recurrent })
+                }
+                /// create a new cell whose parameters are all zero
+                pub fn zeros(input_size: usize, hidden_size: usize) -> Self
+                where
+                    A: Clone + Zero,
+                {
+                    let cols = Self::GATES * hidden_size;
+                    Self {
+                        input: Params::zeros((input_size, cols)),
+                        recurrent: Params::zeros((hidden_size, cols)),
+                    }
+                }
+                #[cfg(feature = "rand")]
+                /// create a new cell whose input weights are initialized using the Glorot
+                /// (Xavier) normal distribution and whose recurrent weights are orthogonal
+                /// for each gate; the biases are initialized to zero
+                pub fn orthogonal(input_size: usize, hidden_size: usize) -> Self
+                where
+                    A: 'static + Float + num_traits::FromPrimitive,
+                    concision_init::rand_distr::StandardNormal:
+                        concision_init::rand_distr::Distribution<A>,
+                {
+                    use concision_init::NdRandom;
+                    let gates = Self::GATES;
+                    let cols = gates * hidden_size;
+                    let blocks = (0..gates)
+                        .map(|_| concision_init::orthogonal((hidden_size, hidden_size), A::one()))
+                        .collect::<Vec<_>>();
+                    let recurrent = stack(&blocks.iter().collect::<Vec<_>>());
+                    Self {
+                        input: Params::new(
+                            ndarray::Array1::zeros(cols),
+                            Array2::glorot_normal((input_size, cols)),
+                        ),
+                        recurrent: Params::new(ndarray::Array1::zeros(cols), recurrent),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_cell!(RnnCell(1), GruCell(3), LstmCell(4));
@@ -0,0 +1,94 @@
+/*
+    Appellation: impl_gru <module>
+    Created At: 2026.10.18:16:34:12
+    Contrib: @FL03
+*/
+use super::GruCell;
+use super::impl_cell::{RecurrentCell, affine, affine_backward, gate, stack};
+use crate::activate::sigmoid;
+use ndarray::{Array2, ArrayView2, ScalarOperand};
+use num_traits::Float;
+
+/// the values recorded by a step of the [`GruCell`]
+#[doc(hidden)]
+pub struct GruCache<A> {
+    input: Array2<A>,
+    prev: Array2<A>,
+    reset: Array2<A>,
+    update: Array2<A>,
+    candidate: Array2<A>,
+    /// the recurrent contribution to the candidate gate, prior to being reset
+    recurrent: Array2<A>,
+}
+
+impl<A> RecurrentCell<A> for GruCell<A>
+where
+    A: Float + ScalarOperand,
+{
+    type State = Array2<A>;
+    type Cache = GruCache<A>;
+
+    cell_accessors!();
+
+    fn init_state(&self, batch: usize) -> Self::State {
+        Array2::zeros((batch, self.hidden_size()))
+    }
+
+    fn hidden(state: &Self::State) -> &Array2<A> {
+        state
+    }
+
+    fn hidden_mut(state: &mut Self::State) -> &mut Array2<A> {
+        state
+    }
+
+    fn step(&self, input: ArrayView2<'_, A>, state: &Self::State) -> (Self::State, Self::Cache) {
+        let n = self.hidden_size();
+        let gi = affine(&self.input, &input);
+        let gh = affine(&self.recurrent, &state.view());
+        let reset = (&gate(&gi, 0, n) + &gate(&gh, 0, n)).mapv(sigmoid);
+        let update = (&gate(&gi, 1, n) + &gate(&gh, 1, n)).mapv(sigmoid);
+        let recurrent = gate(&gh, 2, n).to_owned();
+        let candidate = (&gate(&gi, 2, n) + &(&reset * &recurrent)).tanh();
+        let hidden = &candidate + &(&update * &(state - &candidate));
+        let cache = GruCache {
+            input: input.to_owned(),
+            prev: state.clone(),
+            reset,
+            update,
+            candidate,
+            recurrent,
+        };
+        (hidden, cache)
+    }
+
+    fn step_backward(
+        &self,
+        cache: &Self::Cache,
+        delta: &Self::State,
+        grad: &mut Self,
+    ) -> (Array2<A>, Self::State) {
+        let GruCache {
+            input,
+            prev,
+            reset: r,
+            update: z,
+            candidate: c,
+            recurrent: hn,
+        } = cache;
+        let one = A::one();
+        // h = (1 - z) * n + z * h_prev
+        let dn = delta * &z.mapv(|z| one - z) * &c.mapv(|n| one - n * n);
+        let dz = delta * &(prev - c) * &z.mapv(|z| z * (one - z));
+        let dr = &dn * hn * &r.mapv(|r| r * (one - r));
+        let dgi = stack(&[&dr, &dz, &dn]);
+        let dgh = stack(&[&dr, &dz, &(&dn * r)]);
+        let Self {
+            input: gx,
+            recurrent: gh,
+        } = grad;
+        let dx = affine_backward(&self.input, gx, input, &dgi);
+        let dh = affine_backward(&self.recurrent, gh, prev, &dgh) + delta * z;
+        (dx, dh)
+    }
+}
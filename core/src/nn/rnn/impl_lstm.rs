@@ -0,0 +1,110 @@
+/*
+    Appellation: impl_lstm <module>
+    Created At: 2026.10.18:16:45:37
+    Contrib: @FL03
+*/
+use super::impl_cell::{RecurrentCell, affine, affine_backward, gate, stack};
+use super::{LstmCell, LstmState};
+use crate::activate::sigmoid;
+use ndarray::{Array2, ArrayView2, ScalarOperand};
+use num_traits::{Float, Zero};
+
+/// the values recorded by a step of the [`LstmCell`]
+#[doc(hidden)]
+pub struct LstmCache<A> {
+    input: Array2<A>,
+    prev: LstmState<A>,
+    gates: [Array2<A>; 4],
+    cell: Array2<A>,
+}
+
+impl<A> LstmState<A> {
+    /// create a new state from the given hidden and cell states
+    pub const fn new(hidden: Array2<A>, cell: Array2<A>) -> Self {
+        Self { hidden, cell }
+    }
+    /// returns a new state of zeros for a batch of the given size
+    pub fn zeros(batch: usize, hidden_size: usize) -> Self
+    where
+        A: Clone + Zero,
+    {
+        Self::new(
+            Array2::zeros((batch, hidden_size)),
+            Array2::zeros((batch, hidden_size)),
+        )
+    }
+    /// returns an immutable reference to the hidden state
+    pub const fn hidden(&self) -> &Array2<A> {
+        &self.hidden
+    }
+    /// returns an immutable reference to the state of the memory cell
+    pub const fn cell(&self) -> &Array2<A> {
+        &self.cell
+    }
+}
+
+impl<A> RecurrentCell<A> for LstmCell<A>
+where
+    A: Float + ScalarOperand,
+{
+    type State = LstmState<A>;
+    type Cache = LstmCache<A>;
+
+    cell_accessors!();
+
+    fn init_state(&self, batch: usize) -> Self::State {
+        LstmState::zeros(batch, self.hidden_size())
+    }
+
+    fn hidden(state: &Self::State) -> &Array2<A> {
+        &state.hidden
+    }
+
+    fn hidden_mut(state: &mut Self::State) -> &mut Array2<A> {
+        &mut state.hidden
+    }
+
+    fn step(&self, input: ArrayView2<'_, A>, state: &Self::State) -> (Self::State, Self::Cache) {
+        let n = self.hidden_size();
+        let z = affine(&self.input, &input) + affine(&self.recurrent, &state.hidden.view());
+        let [i, f, g, o] = [0, 1, 2, 3].map(|k| {
+            let z = gate(&z, k, n);
+            if k == 2 { z.tanh() } else { z.mapv(sigmoid) }
+        });
+        let cell = &f * &state.cell + &i * &g;
+        let hidden = &o * &cell.tanh();
+        let cache = LstmCache {
+            input: input.to_owned(),
+            prev: state.clone(),
+            gates: [i, f, g, o],
+            cell: cell.clone(),
+        };
+        (LstmState::new(hidden, cell), cache)
+    }
+
+    fn step_backward(
+        &self,
+        cache: &Self::Cache,
+        delta: &Self::State,
+        grad: &mut Self,
+    ) -> (Array2<A>, Self::State) {
+        let [i, f, g, o] = &cache.gates;
+        let one = A::one();
+        let tc = cache.cell.tanh();
+        // the gradient w.r.t. the cell state accounts for its contribution to the hidden state
+        let dc = &delta.cell + &(&delta.hidden * o * &tc.mapv(|t| one - t * t));
+        let sig = |s: &Array2<A>| s.mapv(|s| s * (one - s));
+        let di = &dc * g * &sig(i);
+        let df = &dc * &cache.prev.cell * &sig(f);
+        let dg = &dc * i * &g.mapv(|g| one - g * g);
+        let dout = &delta.hidden * &tc * &sig(o);
+        let dz = stack(&[&di, &df, &dg, &dout]);
+        let Self {
+            input: gx,
+            recurrent: gh,
+        } = grad;
+        let dx = affine_backward(&self.input, gx, &cache.input, &dz);
+        let dh = affine_backward(&self.recurrent, gh, &cache.prev.hidden, &dz);
+        (dx, LstmState::new(dh, dc * f))
+    }
+}
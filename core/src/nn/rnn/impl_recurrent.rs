@@ -0,0 +1,225 @@
+/*
+    Appellation: impl_recurrent <module>
+    Created At: 2026.10.18:16:58:02
+    Contrib: @FL03
+*/
+use super::{Recurrent, RecurrentCell};
+use concision_traits::{Backward, Forward};
+use ndarray::{Array2, Array3, ArrayBase, Axis, Data, Ix2, Ix3, ScalarOperand};
+use num_traits::Float;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+impl<C, A> Recurrent<C, A> {
+    /// create a new layer from the given cell without truncation or gradient clipping
+    pub const fn new(cell: C) -> Self {
+        Self {
+            cell,
+            truncation: None,
+            clip: None,
+        }
+    }
+    /// returns an immutable reference to the cell
+    pub const fn cell(&self) -> &C {
+        &self.cell
+    }
+    /// returns a mutable reference to the cell
+    pub const fn cell_mut(&mut self) -> &mut C {
+        &mut self.cell
+    }
+    /// returns the length of the windows used by truncated backpropagation through time, if
+    /// any
+    pub const fn truncation(&self) -> Option<usize> {
+        self.truncation
+    }
+    /// returns the maximum L2 norm of each gradient, if any
+    pub const fn clip(&self) -> Option<&A> {
+        self.clip.as_ref()
+    }
+    /// set the cell of the layer
+    pub fn set_cell(&mut self, cell: C) -> &mut Self {
+        self.cell = cell;
+        self
+    }
+    /// set the length of the windows used by truncated backpropagation through time
+    pub fn set_truncation(&mut self, truncation: Option<usize>) -> &mut Self {
+        self.truncation = truncation;
+        self
+    }
+    /// set the maximum L2 norm of each gradient
+    pub fn set_clip(&mut self, clip: Option<A>) -> &mut Self {
+        self.clip = clip;
+        self
+    }
+    /// consumes the current instance to create another with the given cell
+    pub fn with_cell<C2>(self, cell: C2) -> Recurrent<C2, A> {
+        Recurrent {
+            cell,
+            truncation: self.truncation,
+            clip: self.clip,
+        }
+    }
+    /// consumes the current instance to create another with the given truncation length
+    pub fn with_truncation(self, truncation: usize) -> Self {
+        Self {
+            truncation: Some(truncation),
+            ..self
+        }
+    }
+    /// consumes the current instance to create another with the given clipping threshold
+    pub fn with_clip(self, clip: A) -> Self {
+        Self {
+            clip: Some(clip),
+            ..self
+        }
+    }
+}
+
+impl<C, A> Recurrent<C, A>
+where
+    A: Float + ScalarOperand,
+    C: RecurrentCell<A>,
+{
+    /// unroll the cell over a batch of sequences shaped `(batch, time, features)` starting
+    /// from the given state, returning the hidden state of every step, shaped
+    /// `(batch, time, hidden_size)`, alongside the final state
+    pub fn forward_with_state<S>(
+        &self,
+        input: &ArrayBase<S, Ix3, A>,
+        state: C::State,
+    ) -> (Array3<A>, C::State)
+    where
+        S: Data<Elem = A>,
+    {
+        let (state, _, output) = self.unroll(input, state, false);
+        (output, state)
+    }
+    /// compute the gradients of the cell using backpropagation through time given a batch of
+    /// sequences and the gradient `delta` w.r.t. the hidden state of every step, returning
+    /// the gradients of the parameters (stored as a cell) alongside the gradient w.r.t. the
+    /// input.
+    ///
+    /// When a truncation length is set, the gradient w.r.t. the state is not propagated
+    /// across the boundaries of consecutive windows of that length and, when a clipping
+    /// threshold is set, the L2 norm of each gradient of the parameters is clipped to it.
+    pub fn gradients<S, T>(
+        &self,
+        input: &ArrayBase<S, Ix3, A>,
+        delta: &ArrayBase<T, Ix3, A>,
+    ) -> (C, Array3<A>)
+    where
+        C: Clone,
+        S: Data<Elem = A>,
+        T: Data<Elem = A>,
+    {
+        let (batch, time, _) = input.dim();
+        assert_eq!(
+            delta.dim(),
+            (batch, time, self.cell.hidden_size()),
+            "the gradient is incompatible with the output of the layer"
+        );
+        let (_, caches, _) = self.unroll(input, self.cell.init_state(batch), true);
+        let mut grad = self.cell.zeros_like();
+        let mut dx = Array3::zeros(input.raw_dim());
+        let mut dstate = self.cell.init_state(batch);
+        for (t, cache) in caches.iter().enumerate().rev() {
+            if let Some(k) = self.truncation.filter(|&k| k > 0) {
+                if t + 1 < time && (t + 1) % k == 0 {
+                    dstate = self.cell.init_state(batch);
+                }
+            }
+            C::hidden_mut(&mut dstate).scaled_add(A::one(), &delta.index_axis(Axis(1), t));
+            let (dxt, dprev) = self.cell.step_backward(cache, &dstate, &mut grad);
+            dx.index_axis_mut(Axis(1), t).assign(&dxt);
+            dstate = dprev;
+        }
+        if let Some(threshold) = self.clip {
+            grad.clip_norm_l2(threshold);
+        }
+        (grad, dx)
+    }
+    /// unroll the cell over the time axis of the input, optionally recording the cache of
+    /// each step
+    fn unroll<S>(
+        &self,
+        input: &ArrayBase<S, Ix3, A>,
+        mut state: C::State,
+        record: bool,
+    ) -> (C::State, Vec<C::Cache>, Array3<A>)
+    where
+        S: Data<Elem = A>,
+    {
+        let (batch, time, _) = input.dim();
+        let mut output = Array3::zeros((batch, time, self.cell.hidden_size()));
+        let mut caches = Vec::with_capacity(if record { time } else { 0 });
+        for (t, x) in input.axis_iter(Axis(1)).enumerate() {
+            let (next, cache) = self.cell.step(x, &state);
+            output.index_axis_mut(Axis(1), t).assign(C::hidden(&next));
+            if record {
+                caches.push(cache);
+            }
+            state = next;
+        }
+        (state, caches, output)
+    }
+}
+
+impl<C, A, S> Forward<ArrayBase<S, Ix3, A>> for Recurrent<C, A>
+where
+    A: Float + ScalarOperand,
+    C: RecurrentCell<A>,
+    S: Data<Elem = A>,
+{
+    type Output = Array3<A>;
+
+    fn forward(&self, input: &ArrayBase<S, Ix3, A>) -> Self::Output {
+        let state = self.cell.init_state(input.len_of(Axis(0)));
+        self.forward_with_state(input, state).0
+    }
+}
+
+impl<C, A, S> Forward<ArrayBase<S, Ix2, A>> for Recurrent<C, A>
+where
+    A: Float + ScalarOperand,
+    C: RecurrentCell<A>,
+    S: Data<Elem = A>,
+{
+    type Output = Array2<A>;
+
+    fn forward(&self, input: &ArrayBase<S, Ix2, A>) -> Self::Output {
+        let input = input.view().insert_axis(Axis(0));
+        self.forward(&input).remove_axis(Axis(0))
+    }
+}
+
+impl<C, A, S, T> Backward<ArrayBase<S, Ix3, A>, ArrayBase<T, Ix3, A>> for Recurrent<C, A>
+where
+    A: Float + ScalarOperand,
+    C: RecurrentCell<A> + Clone,
+    S: Data<Elem = A>,
+    T: Data<Elem = A>,
+{
+    type Elem = A;
+
+    fn backward(&mut self, input: &ArrayBase<S, Ix3, A>, delta: &ArrayBase<T, Ix3, A>, gamma: A) {
+        let (grad, _) = self.gradients(input, delta);
+        self.cell.scaled_add(gamma, &grad);
+    }
+}
+
+impl<C, A, S, T> Backward<ArrayBase<S, Ix2, A>, ArrayBase<T, Ix2, A>> for Recurrent<C, A>
+where
+    A: Float + ScalarOperand,
+    C: RecurrentCell<A> + Clone,
+    S: Data<Elem = A>,
+    T: Data<Elem = A>,
+{
+    type Elem = A;
+
+    fn backward(&mut self, input: &ArrayBase<S, Ix2, A>, delta: &ArrayBase<T, Ix2, A>, gamma: A) {
+        let input = input.view().insert_axis(Axis(0));
+        let delta = delta.view().insert_axis(Axis(0));
+        self.backward(&input, &delta, gamma);
+    }
+}
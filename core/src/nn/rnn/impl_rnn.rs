@@ -0,0 +1,63 @@
+/*
+    Appellation: impl_rnn <module>
+    Created At: 2026.10.18:16:26:50
+    Contrib: @FL03
+*/
+use super::RnnCell;
+use super::impl_cell::{RecurrentCell, affine, affine_backward};
+use ndarray::{Array2, ArrayView2, ScalarOperand};
+use num_traits::Float;
+
+/// the values recorded by a step of the [`RnnCell`]
+#[doc(hidden)]
+pub struct RnnCache<A> {
+    input: Array2<A>,
+    prev: Array2<A>,
+    hidden: Array2<A>,
+}
+
+impl<A> RecurrentCell<A> for RnnCell<A>
+where
+    A: Float + ScalarOperand,
+{
+    type State = Array2<A>;
+    type Cache = RnnCache<A>;
+
+    cell_accessors!();
+
+    fn init_state(&self, batch: usize) -> Self::State {
+        Array2::zeros((batch, self.hidden_size()))
+    }
+
+    fn hidden(state: &Self::State) -> &Array2<A> {
+        state
+    }
+
+    fn hidden_mut(state: &mut Self::State) -> &mut Array2<A> {
+        state
+    }
+
+    fn step(&self, input: ArrayView2<'_, A>, state: &Self::State) -> (Self::State, Self::Cache) {
+        let z = affine(&self.input, &input) + affine(&self.recurrent, &state.view());
+        let hidden = z.tanh();
+        let cache = RnnCache {
+            input: input.to_owned(),
+            prev: state.clone(),
+            hidden: hidden.clone(),
+        };
+        (hidden, cache)
+    }
+
+    fn step_backward(
+        &self,
+        cache: &Self::Cache,
+        delta: &Self::State,
+        grad: &mut Self,
+    ) -> (Array2<A>, Self::State) {
+        let dz = delta * &cache.hidden.mapv(|h| A::one() - h * h);
+        let Self { input, recurrent } = grad;
+        let dx = affine_backward(&self.input, input, &cache.input, &dz);
+        let dh = affine_backward(&self.recurrent, recurrent, &cache.prev, &dz);
+        (dx, dh)
+    }
+}
@@ -0,0 +1,93 @@
+/*
+    Appellation: sequential <module>
+    Created At: 2026.10.18:20:44:02
+    Contrib: @FL03
+*/
+//! This module implements the [`Sequential`] container, a heterogeneous stack of boxed
+//! layers, each implementing the object-safe [`Module`] trait, that are applied in order.
+//!
+//! Unlike the [`DeepModelParams`](crate::models::DeepModelParams), whose hidden layers
+//! share a single shape and whose activations are fixed by the model, a [`Sequential`] may
+//! freely mix dense, convolution, pooling, normalization, dropout and activation layers.
+//! Layers described by a [`LayerKind`] may be serialized alongside their weights, allowing
+//! the entire architecture of the container to be saved and restored.
+mod impl_module;
+mod impl_sequential;
+
+#[cfg(feature = "serde")]
+mod impl_serde;
+
+use crate::activate::ActivationKind;
+use crate::nn::{
+    AvgPool1d, AvgPool2d, BatchNorm1d, BatchNorm2d, Conv1d, Conv2d, LayerNorm, MaxPool1d,
+    MaxPool2d, Module, RmsNorm,
+};
+use crate::utils::Dropout;
+use concision_params::Params;
+use ndarray::ArrayD;
+
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, vec::Vec};
+
+/// The [`Sequential`] container holds a stack of boxed layers, of possibly different kinds,
+/// which are applied to the input in order. The backward pass walks the stack in reverse,
+/// updating each layer before handing the gradient w.r.t. its input onto the previous one.
+///
+/// The backward pass consumes the [`SequentialTape`] recorded by a training forward pass,
+/// ensuring that the gradient flows through the same dropout masks and batch statistics used
+/// to produce the output; the tape is either handled explicitly, using
+/// [`forward_tape`](Sequential::forward_tape) and [`backward_with`](Sequential::backward_with),
+/// or retained by the container when using the [`ForwardMut`](concision_traits::ForwardMut)
+/// and [`Backward`](concision_traits::Backward) traits.
+///
+/// Since each layer may expect an input of a different dimensionality, the container
+/// operates on dynamically-dimensioned arrays.
+#[derive(Debug)]
+pub struct Sequential<A = f32> {
+    pub(crate) layers: Vec<Box<dyn Module<A>>>,
+    pub(crate) tape: Option<SequentialTape<A>>,
+}
+
+/// The [`SequentialTape`] records the input and cache of each layer of a [`Sequential`]
+/// container during a forward pass, alongside its output, for use by the backward pass.
+#[derive(Clone, Debug)]
+pub struct SequentialTape<A = f32> {
+    pub(crate) inputs: Vec<ArrayD<A>>,
+    pub(crate) caches: Vec<Option<ArrayD<A>>>,
+    pub(crate) output: ArrayD<A>,
+}
+
+/// The [`Flatten`] layer collapses every axis of its input, except the first (i.e. batch)
+/// axis, into one; it is typically placed between the convolution and dense layers.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Flatten;
+
+/// The [`LayerKind`] enumerates the layers that may be serialized as part of a
+/// [`Sequential`] container, each variant holding the configuration and weights of the
+/// layer.
+#[derive(Clone, Debug, strum::AsRefStr, strum::Display, strum::EnumIs, strum::VariantNames)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+#[strum(serialize_all = "snake_case")]
+#[non_exhaustive]
+pub enum LayerKind<A = f32> {
+    Dense(Params<A>),
+    Activation(ActivationKind),
+    Dropout(Dropout),
+    Flatten,
+    Conv1d(Conv1d<A>),
+    Conv2d(Conv2d<A>),
+    MaxPool1d(MaxPool1d<A>),
+    MaxPool2d(MaxPool2d<A>),
+    AvgPool1d(AvgPool1d<A>),
+    AvgPool2d(AvgPool2d<A>),
+    GlobalAvgPool,
+    BatchNorm1d(BatchNorm1d<A>),
+    BatchNorm2d(BatchNorm2d<A>),
+    LayerNorm(LayerNorm<A>),
+    RmsNorm(RmsNorm<A>),
+}
@@ -0,0 +1,503 @@
+/*
+    Appellation: impl_module <module>
+    Created At: 2026.10.18:20:52:37
+    Contrib: @FL03
+*/
+use super::{Flatten, LayerKind};
+use crate::activate::{ActivationKind, Activator, ActivatorVjp};
+use crate::error::Result;
+use crate::nn::{
+    AvgPool1d, AvgPool2d, BatchNorm1d, BatchNorm2d, Conv1d, Conv2d, GlobalAvgPool, LayerNorm,
+    MaxPool1d, MaxPool2d, Module, RawContext, RmsNorm,
+};
+use crate::utils::Dropout;
+use concision_params::Params;
+use concision_traits::Forward;
+use core::any::Any;
+use core::fmt::Debug;
+use ndarray::{ArrayD, ArrayView, Axis, Dimension, Ix2, Ix3, Ix4, ScalarOperand};
+use num_traits::{Float, FromPrimitive};
+
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+
+/// view the input as an array of the given dimensionality
+fn view<A, D: Dimension>(input: &ArrayD<A>) -> Result<ArrayView<'_, A, D>> {
+    Ok(input.view().into_dimensionality::<D>()?)
+}
+
+impl<A> LayerKind<A>
+where
+    A: Float + FromPrimitive + ScalarOperand + Debug + 'static,
+{
+    /// convert the layer into a boxed [`Module`]
+    pub fn into_module(self) -> Box<dyn Module<A>> {
+        match self {
+            Self::Dense(layer) => Box::new(layer),
+            Self::Activation(layer) => Box::new(layer),
+            Self::Dropout(layer) => Box::new(layer),
+            Self::Flatten => Box::new(Flatten),
+            Self::Conv1d(layer) => Box::new(layer),
+            Self::Conv2d(layer) => Box::new(layer),
+            Self::MaxPool1d(layer) => Box::new(layer),
+            Self::MaxPool2d(layer) => Box::new(layer),
+            Self::AvgPool1d(layer) => Box::new(layer),
+            Self::AvgPool2d(layer) => Box::new(layer),
+            Self::GlobalAvgPool => Box::new(GlobalAvgPool),
+            Self::BatchNorm1d(layer) => Box::new(layer),
+            Self::BatchNorm2d(layer) => Box::new(layer),
+            Self::LayerNorm(layer) => Box::new(layer),
+            Self::RmsNorm(layer) => Box::new(layer),
+        }
+    }
+}
+
+/// implements the boilerplate shared by each [`Module`] implementation
+macro_rules! module_any {
+    () => {
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn as_any_mut(&mut self) -> &mut dyn Any {
+            self
+        }
+    };
+}
+
+/// the dense layer computes `x · W + b` for an input of shape `(batch, in_features)`
+impl<A> Module<A> for Params<A>
+where
+    A: Float + FromPrimitive + ScalarOperand + Debug + 'static,
+{
+    fn name(&self) -> &'static str {
+        "dense"
+    }
+
+    fn num_params(&self) -> usize {
+        self.size()
+    }
+
+    fn forward_dyn(
+        &self,
+        input: &ArrayD<A>,
+        _: &mut dyn RawContext,
+    ) -> Result<(ArrayD<A>, Option<ArrayD<A>>)> {
+        let x = view::<A, Ix2>(input)?;
+        let y = x.dot(self.weights()) + self.bias();
+        Ok((y.into_dyn(), None))
+    }
+
+    fn backward_dyn(
+        &mut self,
+        input: &ArrayD<A>,
+        _: Option<&ArrayD<A>>,
+        delta: &ArrayD<A>,
+        gamma: A,
+        _: &mut dyn RawContext,
+    ) -> Result<ArrayD<A>> {
+        let x = view::<A, Ix2>(input)?;
+        let dy = view::<A, Ix2>(delta)?;
+        let dx = dy.dot(&self.weights().t());
+        self.weights_mut().scaled_add(gamma, &x.t().dot(&dy));
+        self.bias_mut().scaled_add(gamma, &dy.sum_axis(Axis(0)));
+        Ok(dx.into_dyn())
+    }
+
+    fn kind(&self) -> Option<LayerKind<A>> {
+        Some(LayerKind::Dense(self.clone()))
+    }
+
+    module_any!();
+}
+
+/// each activation is applied elementwise, except for the softmax which normalizes over the
+/// last axis of its input, i.e. the features of each sample within the batch
+impl<A> Module<A> for ActivationKind
+where
+    A: Float + FromPrimitive + ScalarOperand + Debug + 'static,
+{
+    fn name(&self) -> &'static str {
+        "activation"
+    }
+
+    fn forward_dyn(
+        &self,
+        input: &ArrayD<A>,
+        _: &mut dyn RawContext,
+    ) -> Result<(ArrayD<A>, Option<ArrayD<A>>)> {
+        Ok((self.activate(input.view()), None))
+    }
+    /// the gradient of the softmax couples each of its outputs, hence the gradient of the
+    /// activation is computed using its vector-Jacobian product
+    fn backward_dyn(
+        &mut self,
+        input: &ArrayD<A>,
+        _: Option<&ArrayD<A>>,
+        delta: &ArrayD<A>,
+        _: A,
+        _: &mut dyn RawContext,
+    ) -> Result<ArrayD<A>> {
+        Ok(self.activate_vjp(input.view(), delta))
+    }
+
+    fn kind(&self) -> Option<LayerKind<A>> {
+        Some(LayerKind::Activation(*self))
+    }
+
+    module_any!();
+}
+
+/// the mask applied during the forward pass is cached so that the backward pass routes the
+/// gradient through the same elements
+impl<A> Module<A> for Dropout
+where
+    A: Float + FromPrimitive + ScalarOperand + Debug + 'static,
+{
+    fn name(&self) -> &'static str {
+        "dropout"
+    }
+
+    fn forward_dyn(
+        &self,
+        input: &ArrayD<A>,
+        ctx: &mut dyn RawContext,
+    ) -> Result<(ArrayD<A>, Option<ArrayD<A>>)> {
+        if ctx.is_eval() || self.p <= 0.0 {
+            return Ok((input.clone(), None));
+        }
+        #[cfg(feature = "rand")]
+        {
+            use crate::nn::ForwardWith;
+            let mask = self.forward_with(&ArrayD::<A>::ones(input.raw_dim()), &mut &mut *ctx);
+            Ok((input * &mask, Some(mask)))
+        }
+        #[cfg(not(feature = "rand"))]
+        {
+            Err(crate::error::Error::UnsupportedOperation(
+                "training with dropout requires the `rand` feature".into(),
+            ))
+        }
+    }
+
+    fn backward_dyn(
+        &mut self,
+        _: &ArrayD<A>,
+        cache: Option<&ArrayD<A>>,
+        delta: &ArrayD<A>,
+        _: A,
+        _: &mut dyn RawContext,
+    ) -> Result<ArrayD<A>> {
+        match cache {
+            Some(mask) => Ok(delta * mask),
+            None => Ok(delta.clone()),
+        }
+    }
+
+    fn kind(&self) -> Option<LayerKind<A>> {
+        Some(LayerKind::Dropout(*self))
+    }
+
+    module_any!();
+}
+
+impl<A> Module<A> for Flatten
+where
+    A: Float + FromPrimitive + ScalarOperand + Debug + 'static,
+{
+    fn name(&self) -> &'static str {
+        "flatten"
+    }
+
+    fn forward_dyn(
+        &self,
+        input: &ArrayD<A>,
+        _: &mut dyn RawContext,
+    ) -> Result<(ArrayD<A>, Option<ArrayD<A>>)> {
+        let batch = input.shape().first().copied().unwrap_or(1);
+        let features = input.len() / batch.max(1);
+        let y = input.to_shape((batch, features))?.to_owned();
+        Ok((y.into_dyn(), None))
+    }
+
+    fn backward_dyn(
+        &mut self,
+        input: &ArrayD<A>,
+        _: Option<&ArrayD<A>>,
+        delta: &ArrayD<A>,
+        _: A,
+        _: &mut dyn RawContext,
+    ) -> Result<ArrayD<A>> {
+        Ok(delta.to_shape(input.raw_dim())?.to_owned())
+    }
+
+    fn kind(&self) -> Option<LayerKind<A>> {
+        Some(LayerKind::Flatten)
+    }
+
+    module_any!();
+}
+
+macro_rules! impl_conv_module {
+    ($($name:ident<$D:ty>),* $(,)?) => {
+        $(
+            impl<A> Module<A> for $name<A>
+            where
+                A: Float + FromPrimitive + ScalarOperand + Debug + 'static,
+            {
+                fn name(&self) -> &'static str {
+                    paste::paste! { stringify!([<$name:snake>]) }
+                }
+
+                fn num_params(&self) -> usize {
+                    self.params().size()
+                }
+
+                fn forward_dyn(
+                    &self,
+                    input: &ArrayD<A>,
+                    _: &mut dyn RawContext,
+                ) -> Result<(ArrayD<A>, Option<ArrayD<A>>)> {
+                    let y = self.forward(&view::<A, $D>(input)?);
+                    Ok((y.into_dyn(), None))
+                }
+
+                fn backward_dyn(
+                    &mut self,
+                    input: &ArrayD<A>,
+                    _: Option<&ArrayD<A>>,
+                    delta: &ArrayD<A>,
+                    gamma: A,
+                    _: &mut dyn RawContext,
+                ) -> Result<ArrayD<A>> {
+                    let (grad, dx) =
+                        self.gradients(&view::<A, $D>(input)?, &view::<A, $D>(delta)?);
+                    self.params.weights_mut().scaled_add(gamma, grad.weights());
+                    self.params.bias_mut().scaled_add(gamma, grad.bias());
+                    Ok(dx.into_dyn())
+                }
+
+                fn kind(&self) -> Option<LayerKind<A>> {
+                    Some(LayerKind::$name(self.clone()))
+                }
+
+                module_any!();
+            }
+        )*
+    };
+}
+
+impl_conv_module! {
+    Conv1d<Ix3>,
+    Conv2d<Ix4>,
+}
+
+macro_rules! impl_pool_module {
+    ($($name:ident<$D:ty>),* $(,)?) => {
+        $(
+            impl<A> Module<A> for $name<A>
+            where
+                A: Float + FromPrimitive + ScalarOperand + Debug + 'static,
+            {
+                fn name(&self) -> &'static str {
+                    paste::paste! { stringify!([<$name:snake>]) }
+                }
+
+                fn forward_dyn(
+                    &self,
+                    input: &ArrayD<A>,
+                    _: &mut dyn RawContext,
+                ) -> Result<(ArrayD<A>, Option<ArrayD<A>>)> {
+                    let y = self.forward(&view::<A, $D>(input)?);
+                    Ok((y.into_dyn(), None))
+                }
+
+                fn backward_dyn(
+                    &mut self,
+                    input: &ArrayD<A>,
+                    _: Option<&ArrayD<A>>,
+                    delta: &ArrayD<A>,
+                    _: A,
+                    _: &mut dyn RawContext,
+                ) -> Result<ArrayD<A>> {
+                    let dx = self.gradient(&view::<A, $D>(input)?, &view::<A, $D>(delta)?);
+                    Ok(dx.into_dyn())
+                }
+
+                fn kind(&self) -> Option<LayerKind<A>> {
+                    Some(LayerKind::$name(self.clone()))
+                }
+
+                module_any!();
+            }
+        )*
+    };
+}
+
+impl_pool_module! {
+    MaxPool1d<Ix3>,
+    MaxPool2d<Ix4>,
+    AvgPool1d<Ix3>,
+    AvgPool2d<Ix4>,
+}
+
+impl<A> Module<A> for GlobalAvgPool
+where
+    A: Float + FromPrimitive + ScalarOperand + Debug + 'static,
+{
+    fn name(&self) -> &'static str {
+        "global_avg_pool"
+    }
+
+    fn forward_dyn(
+        &self,
+        input: &ArrayD<A>,
+        _: &mut dyn RawContext,
+    ) -> Result<(ArrayD<A>, Option<ArrayD<A>>)> {
+        if input.ndim() <= 2 {
+            return Err(crate::error::Error::IncompatibleInput);
+        }
+        Ok((self.forward(input).into_dyn(), None))
+    }
+
+    fn backward_dyn(
+        &mut self,
+        input: &ArrayD<A>,
+        _: Option<&ArrayD<A>>,
+        delta: &ArrayD<A>,
+        _: A,
+        _: &mut dyn RawContext,
+    ) -> Result<ArrayD<A>> {
+        Ok(self.gradient(input, &view::<A, Ix2>(delta)?))
+    }
+
+    fn kind(&self) -> Option<LayerKind<A>> {
+        Some(LayerKind::GlobalAvgPool)
+    }
+
+    module_any!();
+}
+
+/// the batch normalization layers use the mode of the context, rather than their own, and
+/// fold the statistics of each batch into their running estimates during the backward pass
+/// whenever the context is training
+macro_rules! impl_batch_norm_module {
+    ($($name:ident<$($D:ty),*>),* $(,)?) => {
+        $(
+            impl<A> Module<A> for $name<A>
+            where
+                A: Float + FromPrimitive + ScalarOperand + Debug + 'static,
+            {
+                fn name(&self) -> &'static str {
+                    paste::paste! { stringify!([<$name:snake>]) }
+                }
+
+                fn num_params(&self) -> usize {
+                    self.gamma().len() + self.beta().len()
+                }
+
+                fn forward_dyn(
+                    &self,
+                    input: &ArrayD<A>,
+                    ctx: &mut dyn RawContext,
+                ) -> Result<(ArrayD<A>, Option<ArrayD<A>>)> {
+                    use crate::nn::ForwardWith;
+                    $(
+                        if input.ndim() == <$D>::NDIM.unwrap() {
+                            let y = self.forward_with(&view::<A, $D>(input)?, &mut &mut *ctx);
+                            return Ok((y.into_dyn(), None));
+                        }
+                    )*
+                    Err(crate::error::Error::IncompatibleInput)
+                }
+
+                fn backward_dyn(
+                    &mut self,
+                    input: &ArrayD<A>,
+                    _: Option<&ArrayD<A>>,
+                    delta: &ArrayD<A>,
+                    gamma: A,
+                    ctx: &mut dyn RawContext,
+                ) -> Result<ArrayD<A>> {
+                    if input.shape() != delta.shape() {
+                        return Err(crate::error::Error::IncompatibleInput);
+                    }
+                    let training = ctx.is_training();
+                    let (grad, dx) = self.gradients_in_mode(input, delta, training);
+                    if training {
+                        self.track_running_stats(input);
+                    }
+                    self.gamma.scaled_add(gamma, &grad.gamma);
+                    self.beta.scaled_add(gamma, &grad.beta);
+                    Ok(dx)
+                }
+
+                fn kind(&self) -> Option<LayerKind<A>> {
+                    Some(LayerKind::$name(self.clone()))
+                }
+
+                module_any!();
+            }
+        )*
+    };
+}
+
+impl_batch_norm_module! {
+    BatchNorm1d<Ix2, Ix3>,
+    BatchNorm2d<Ix4>,
+}
+
+macro_rules! impl_layer_norm_module {
+    ($($name:ident),* $(,)?) => {
+        $(
+            impl<A> Module<A> for $name<A>
+            where
+                A: Float + FromPrimitive + ScalarOperand + Debug + 'static,
+            {
+                fn name(&self) -> &'static str {
+                    paste::paste! { stringify!([<$name:snake>]) }
+                }
+
+                fn num_params(&self) -> usize {
+                    self.gamma().len() + self.beta().len()
+                }
+
+                fn forward_dyn(
+                    &self,
+                    input: &ArrayD<A>,
+                    _: &mut dyn RawContext,
+                ) -> Result<(ArrayD<A>, Option<ArrayD<A>>)> {
+                    Ok((self.forward(input), None))
+                }
+
+                fn backward_dyn(
+                    &mut self,
+                    input: &ArrayD<A>,
+                    _: Option<&ArrayD<A>>,
+                    delta: &ArrayD<A>,
+                    gamma: A,
+                    _: &mut dyn RawContext,
+                ) -> Result<ArrayD<A>> {
+                    if input.shape() != delta.shape() {
+                        return Err(crate::error::Error::IncompatibleInput);
+                    }
+                    let (grad, dx) = self.gradients(input, delta);
+                    self.gamma.scaled_add(gamma, &grad.gamma);
+                    self.beta.scaled_add(gamma, &grad.beta);
+                    Ok(dx)
+                }
+
+                fn kind(&self) -> Option<LayerKind<A>> {
+                    Some(LayerKind::$name(self.clone()))
+                }
+
+                module_any!();
+            }
+        )*
+    };
+}
+
+impl_layer_norm_module! {
+    LayerNorm,
+    RmsNorm,
+}
@@ -0,0 +1,310 @@
+/*
+    Appellation: impl_sequential <module>
+    Created At: 2026.10.18:21:03:49
+    Contrib: @FL03
+*/
+use super::{LayerKind, Sequential, SequentialTape};
+use crate::error::{Error, Result};
+use crate::nn::{Context, ForwardWith, Module, RawContext};
+use concision_traits::{Backward, Forward, ForwardMut};
+use core::fmt::Debug;
+use ndarray::{ArrayBase, ArrayD, Data, Dimension, ScalarOperand};
+use num_traits::{Float, FromPrimitive};
+
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, vec::Vec};
+
+impl<A> Sequential<A> {
+    /// create a new, empty container
+    pub const fn new() -> Self {
+        Self {
+            layers: Vec::new(),
+            tape: None,
+        }
+    }
+    /// create a new container from the given layers
+    pub fn from_kinds<I>(layers: I) -> Self
+    where
+        A: Float + FromPrimitive + ScalarOperand + Debug + 'static,
+        I: IntoIterator<Item = LayerKind<A>>,
+    {
+        layers.into_iter().map(LayerKind::into_module).collect()
+    }
+    /// returns an immutable slice of the layers of the container
+    pub fn layers(&self) -> &[Box<dyn Module<A>>] {
+        &self.layers
+    }
+    /// returns a mutable slice of the layers of the container
+    pub fn layers_mut(&mut self) -> &mut [Box<dyn Module<A>>] {
+        &mut self.layers
+    }
+    /// returns the number of layers within the container
+    pub fn len(&self) -> usize {
+        self.layers.len()
+    }
+    /// returns true if the container has no layers
+    pub fn is_empty(&self) -> bool {
+        self.layers.is_empty()
+    }
+    /// returns an immutable reference to the layer at the given index, if any
+    pub fn get(&self, index: usize) -> Option<&dyn Module<A>> {
+        self.layers.get(index).map(|layer| layer.as_ref())
+    }
+    /// returns a mutable reference to the layer at the given index, if any
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut (dyn Module<A> + 'static)> {
+        self.layers.get_mut(index).map(|layer| layer.as_mut())
+    }
+    /// returns an immutable reference to the layer at the given index if it is of type `T`
+    pub fn downcast_ref<T: 'static>(&self, index: usize) -> Option<&T> {
+        self.get(index)?.as_any().downcast_ref::<T>()
+    }
+    /// returns a mutable reference to the layer at the given index if it is of type `T`
+    pub fn downcast_mut<T: 'static>(&mut self, index: usize) -> Option<&mut T> {
+        self.get_mut(index)?.as_any_mut().downcast_mut::<T>()
+    }
+    /// returns the name of each layer, in order
+    pub fn names(&self) -> Vec<&'static str> {
+        self.layers.iter().map(|layer| layer.name()).collect()
+    }
+    /// returns the total number of trainable parameters of the container
+    pub fn num_params(&self) -> usize {
+        self.layers.iter().map(|layer| layer.num_params()).sum()
+    }
+    /// returns the serializable representation of each layer, or `None` if any layer lacks
+    /// one
+    pub fn kinds(&self) -> Option<Vec<LayerKind<A>>> {
+        self.layers.iter().map(|layer| layer.kind()).collect()
+    }
+    /// append the given layer onto the end of the container
+    pub fn push<L>(&mut self, layer: L) -> &mut Self
+    where
+        L: Module<A> + 'static,
+    {
+        self.layers.push(Box::new(layer));
+        self
+    }
+    /// append the given, boxed layer onto the end of the container
+    pub fn push_boxed(&mut self, layer: Box<dyn Module<A>>) -> &mut Self {
+        self.layers.push(layer);
+        self
+    }
+    /// consumes the current instance to create another with the given layer appended
+    pub fn with_layer<L>(mut self, layer: L) -> Self
+    where
+        L: Module<A> + 'static,
+    {
+        self.push(layer);
+        self
+    }
+    /// remove and return the last layer of the container, if any
+    pub fn pop(&mut self) -> Option<Box<dyn Module<A>>> {
+        self.layers.pop()
+    }
+    /// apply each layer onto the input, in order, within the given context
+    pub fn try_forward<C>(&self, input: &ArrayD<A>, ctx: &mut C) -> Result<ArrayD<A>>
+    where
+        A: Clone,
+        C: RawContext,
+    {
+        let mut output = input.clone();
+        for layer in &self.layers {
+            output = layer.forward_dyn(&output, ctx)?.0;
+        }
+        Ok(output)
+    }
+    /// apply each layer onto the input, in order, within the given context while recording
+    /// the input and cache of each layer onto a [`SequentialTape`]
+    pub fn forward_tape<C>(&self, input: &ArrayD<A>, ctx: &mut C) -> Result<SequentialTape<A>>
+    where
+        A: Clone,
+        C: RawContext,
+    {
+        let mut inputs = Vec::with_capacity(self.len());
+        let mut caches = Vec::with_capacity(self.len());
+        let mut output = input.clone();
+        for layer in &self.layers {
+            let (next, cache) = layer.forward_dyn(&output, ctx)?;
+            inputs.push(output);
+            caches.push(cache);
+            output = next;
+        }
+        Ok(SequentialTape {
+            inputs,
+            caches,
+            output,
+        })
+    }
+    /// consume the tape recorded by [`forward_tape`](Sequential::forward_tape), walking the
+    /// layers in reverse, updating each by `gamma` times its gradient and propagating the
+    /// gradient w.r.t. its input onto the previous layer; returns the gradient w.r.t. the
+    /// input of the container
+    pub fn backward_with<C>(
+        &mut self,
+        tape: SequentialTape<A>,
+        delta: &ArrayD<A>,
+        gamma: A,
+        ctx: &mut C,
+    ) -> Result<ArrayD<A>>
+    where
+        A: Copy,
+        C: RawContext,
+    {
+        if tape.len() != self.len() || tape.output.shape() != delta.shape() {
+            return Err(Error::IncompatibleInput);
+        }
+        let SequentialTape { inputs, caches, .. } = tape;
+        let mut delta = delta.clone();
+        for ((layer, input), cache) in self.layers.iter_mut().zip(inputs).zip(caches).rev() {
+            delta = layer.backward_dyn(&input, cache.as_ref(), &delta, gamma, ctx)?;
+        }
+        Ok(delta)
+    }
+    /// returns an immutable reference to the tape retained by the last call to
+    /// [`forward_mut`](ForwardMut::forward_mut), if any
+    pub const fn tape(&self) -> Option<&SequentialTape<A>> {
+        self.tape.as_ref()
+    }
+    /// remove and return the retained tape, if any
+    pub fn take_tape(&mut self) -> Option<SequentialTape<A>> {
+        self.tape.take()
+    }
+}
+
+impl<A> SequentialTape<A> {
+    /// returns the number of layers recorded by the tape
+    pub fn len(&self) -> usize {
+        self.inputs.len()
+    }
+    /// returns true if the tape did not record any layers
+    pub fn is_empty(&self) -> bool {
+        self.inputs.is_empty()
+    }
+    /// returns an immutable reference to the input of the container, if any layers were
+    /// recorded
+    pub fn input(&self) -> Option<&ArrayD<A>> {
+        self.inputs.first()
+    }
+    /// returns an immutable slice of the inputs of each layer
+    pub fn inputs(&self) -> &[ArrayD<A>] {
+        &self.inputs
+    }
+    /// returns an immutable reference to the output of the container
+    pub const fn output(&self) -> &ArrayD<A> {
+        &self.output
+    }
+    /// consumes the tape, returning the output of the container
+    pub fn into_output(self) -> ArrayD<A> {
+        self.output
+    }
+}
+
+impl<A> Default for Sequential<A> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<A> Extend<Box<dyn Module<A>>> for Sequential<A> {
+    fn extend<I: IntoIterator<Item = Box<dyn Module<A>>>>(&mut self, iter: I) {
+        self.layers.extend(iter)
+    }
+}
+
+impl<A> FromIterator<Box<dyn Module<A>>> for Sequential<A> {
+    fn from_iter<I: IntoIterator<Item = Box<dyn Module<A>>>>(iter: I) -> Self {
+        Self {
+            layers: iter.into_iter().collect(),
+            tape: None,
+        }
+    }
+}
+
+impl<A> FromIterator<LayerKind<A>> for Sequential<A>
+where
+    A: Float + FromPrimitive + ScalarOperand + Debug + 'static,
+{
+    fn from_iter<I: IntoIterator<Item = LayerKind<A>>>(iter: I) -> Self {
+        Self::from_kinds(iter)
+    }
+}
+
+impl<A, S, D> Forward<ArrayBase<S, D, A>> for Sequential<A>
+where
+    A: Clone,
+    D: Dimension,
+    S: Data<Elem = A>,
+{
+    type Output = ArrayD<A>;
+    /// the forward pass of the container, evaluated in [`Mode::Eval`](crate::nn::Mode::Eval)
+    fn forward(&self, input: &ArrayBase<S, D, A>) -> Self::Output {
+        self.forward_with(input, &mut Context::eval())
+    }
+}
+
+impl<A, S, D, C> ForwardWith<ArrayBase<S, D, A>, C> for Sequential<A>
+where
+    A: Clone,
+    C: RawContext,
+    D: Dimension,
+    S: Data<Elem = A>,
+{
+    type Output = ArrayD<A>;
+
+    fn forward_with(&self, input: &ArrayBase<S, D, A>, ctx: &mut C) -> Self::Output {
+        self.try_forward(&input.to_owned().into_dyn(), ctx)
+            .expect("the input is incompatible with the layers of the container")
+    }
+}
+
+impl<A, S, D> ForwardMut<ArrayBase<S, D, A>> for Sequential<A>
+where
+    A: Clone,
+    D: Dimension,
+    S: Data<Elem = A>,
+{
+    type Output = ArrayD<A>;
+    /// the forward pass of the container, evaluated in [`Mode::Train`](crate::nn::Mode::Train),
+    /// which retains its tape for the following call to [`Backward::backward`]
+    fn forward_mut(&mut self, input: &ArrayBase<S, D, A>) -> Self::Output {
+        let tape = self
+            .forward_tape(&input.to_owned().into_dyn(), &mut Context::train())
+            .expect("the input is incompatible with the layers of the container");
+        let output = tape.output().clone();
+        self.tape = Some(tape);
+        output
+    }
+}
+
+impl<A, S, T, D, E> Backward<ArrayBase<S, D, A>, ArrayBase<T, E, A>> for Sequential<A>
+where
+    A: Copy + PartialEq,
+    D: Dimension,
+    E: Dimension,
+    S: Data<Elem = A>,
+    T: Data<Elem = A>,
+{
+    type Elem = A;
+    /// the backward pass of the container, consuming the tape retained by the previous call
+    /// to [`ForwardMut::forward_mut`] with the same input; when no such tape exists (e.g. the
+    /// container was evaluated using [`Forward::forward`]), the tape is recorded afresh by
+    /// repeating the forward pass in [`Mode::Train`](crate::nn::Mode::Train).
+    ///
+    /// ## Panics
+    ///
+    /// panics if the input or gradient are incompatible with the layers of the container;
+    /// use [`forward_tape`](Sequential::forward_tape) and
+    /// [`backward_with`](Sequential::backward_with) to handle such errors
+    fn backward(&mut self, input: &ArrayBase<S, D, A>, delta: &ArrayBase<T, E, A>, gamma: A) {
+        let input = input.to_owned().into_dyn();
+        let mut ctx = Context::train();
+        let tape = match self.tape.take() {
+            Some(tape) if tape.input().is_none_or(|x| *x == input) => tape,
+            _ => self
+                .forward_tape(&input, &mut ctx)
+                .expect("the input is incompatible with the layers of the container"),
+        };
+        let delta = delta.to_owned().into_dyn();
+        self.backward_with(tape, &delta, gamma, &mut ctx)
+            .expect("the gradient is incompatible with the output of the container");
+    }
+}
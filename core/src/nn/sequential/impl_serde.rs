@@ -0,0 +1,59 @@
+/*
+    Appellation: impl_serde <module>
+    Created At: 2026.10.18:21:12:26
+    Contrib: @FL03
+*/
+use super::{LayerKind, Sequential};
+use core::fmt::Debug;
+use ndarray::ScalarOperand;
+use num_traits::{Float, FromPrimitive};
+use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Error as _, Serialize, Serializer};
+
+#[cfg(feature = "alloc")]
+use alloc::{format, vec::Vec};
+
+/// the serialized representation of a [`Sequential`] container
+#[derive(serde::Deserialize, serde::Serialize)]
+#[serde(rename = "Sequential")]
+struct SequentialRepr<A> {
+    layers: Vec<LayerKind<A>>,
+}
+
+/// serializing the container fails if any of its layers does not provide a [`LayerKind`]
+impl<A> Serialize for Sequential<A>
+where
+    A: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut layers = Vec::with_capacity(self.len());
+        for layer in &self.layers {
+            match layer.kind() {
+                Some(kind) => layers.push(kind),
+                None => {
+                    return Err(S::Error::custom(format!(
+                        "the `{}` layer cannot be serialized",
+                        layer.name()
+                    )));
+                }
+            }
+        }
+        SequentialRepr { layers }.serialize(serializer)
+    }
+}
+
+impl<'de, A> Deserialize<'de> for Sequential<A>
+where
+    A: Float + FromPrimitive + ScalarOperand + Debug + Deserialize<'de> + 'static,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let repr = SequentialRepr::<A>::deserialize(deserializer)?;
+        Ok(Self::from_kinds(repr.layers))
+    }
+}
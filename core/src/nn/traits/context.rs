@@ -3,9 +3,44 @@
     Created At: 2025.12.14:06:17:36
     Contrib: @FL03
 */
+use crate::nn::{Context, Mode};
 
+/// The [`RawContext`] trait defines the interface for the _execution context_ threaded
+/// through the forward pass of a model, enabling layers whose behaviour differs between
+/// training and inference (e.g. dropout or batch normalization) to act accordingly.
+///
+/// The unit type `()` is a context that is always evaluating, while a bare [`Mode`] is a
+/// context without a random number generator.
 pub trait RawContext {
-    private! {}
+    /// returns the current mode of execution
+    fn mode(&self) -> Mode;
+    /// returns the number of steps completed within the context
+    fn step(&self) -> usize;
+    #[cfg(feature = "rand")]
+    /// returns a mutable reference to the random number generator of the context, if any
+    fn rng(&mut self) -> Option<&mut dyn concision_init::rand::RngCore> {
+        None
+    }
+    /// returns true if the context is training
+    fn is_training(&self) -> bool {
+        self.mode().is_train()
+    }
+    /// returns true if the context is evaluating
+    fn is_eval(&self) -> bool {
+        self.mode().is_eval()
+    }
+}
+
+/// The [`ForwardWith`] trait describes a forward pass that is aware of the execution
+/// context, enabling stochastic layers to draw from the random number generator of the
+/// context and each layer to adjust its behaviour to the mode of execution.
+pub trait ForwardWith<Rhs, C = Context>
+where
+    C: RawContext,
+{
+    type Output;
+    /// a single forward step within the given context
+    fn forward_with(&self, input: &Rhs, ctx: &mut C) -> Self::Output;
 }
 
 /*
@@ -13,5 +48,54 @@ pub trait RawContext {
 */
 
 impl RawContext for () {
-    seal! {}
+    fn mode(&self) -> Mode {
+        Mode::Eval
+    }
+
+    fn step(&self) -> usize {
+        0
+    }
+}
+
+impl RawContext for Mode {
+    fn mode(&self) -> Mode {
+        *self
+    }
+
+    fn step(&self) -> usize {
+        0
+    }
+}
+
+impl RawContext for Context {
+    fn mode(&self) -> Mode {
+        self.mode
+    }
+
+    fn step(&self) -> usize {
+        self.step
+    }
+
+    #[cfg(feature = "rand")]
+    fn rng(&mut self) -> Option<&mut dyn concision_init::rand::RngCore> {
+        Some(&mut self.rng)
+    }
+}
+
+impl<C> RawContext for &mut C
+where
+    C: RawContext + ?Sized,
+{
+    fn mode(&self) -> Mode {
+        (**self).mode()
+    }
+
+    fn step(&self) -> usize {
+        (**self).step()
+    }
+
+    #[cfg(feature = "rand")]
+    fn rng(&mut self) -> Option<&mut dyn concision_init::rand::RngCore> {
+        (**self).rng()
+    }
 }
@@ -3,16 +3,16 @@
     Created At: 2025.12.10:16:50:03
     Contrib: @FL03
 */
-use crate::activate::{Activator, ActivatorGradient};
+use crate::activate::{Activator, ActivatorGradient, ActivatorVjp, LearnableActivator};
 use concision_params::RawParams;
 use concision_traits::{Backward, Forward};
+use num_traits::Float;
 
 /// The [`RawLayer`] trait establishes a common interface for all _layers_ within a given
 /// model. Implementors will need to define the type of parameters they utilize, as well as
 /// provide methods to access both the activation function and the parameters of the layer.
 pub trait RawLayer<F, A>
 where
-    F: Activator<A>,
     Self::Params<A>: RawParams<Elem = A>,
 {
     type Params<_T>;
@@ -35,7 +35,6 @@ where
 /// propagation and parameter updates.
 pub trait RawLayerMut<F, A>: RawLayer<F, A>
 where
-    F: Activator<A>,
     Self::Params<A>: RawParams<Elem = A>,
 {
     /// returns a mutable reference to the parameters of the layer
@@ -50,6 +49,19 @@ where
         let delta = self.rho().activate_gradient(error);
         self.params_mut().backward(&input, &delta, gamma)
     }
+    /// backward propagate the `error` w.r.t. the output of the layer, where `z` is the input of
+    /// the activation (i.e. the output of the parameters) recorded during the forward pass.
+    /// Unlike [`backward`](RawLayerMut::backward), the gradient of the activation is computed
+    /// using its vector-Jacobian product, accounting for activations whose outputs are
+    /// coupled such as the [`SoftmaxLastAxis`](crate::activate::SoftmaxLastAxis).
+    fn backward_vjp<X, Y, Dt>(&mut self, input: &X, z: Y, error: &Y, gamma: A)
+    where
+        F: ActivatorVjp<Y, Output = Dt>,
+        Self::Params<A>: Backward<X, Dt, Elem = A>,
+    {
+        let delta = self.rho().activate_vjp(z, error);
+        self.params_mut().backward(input, &delta, gamma)
+    }
     /// update the layer parameters
     fn set_params(&mut self, params: Self::Params<A>) {
         *self.params_mut() = params;
@@ -63,3 +75,30 @@ where
         core::mem::swap(self.params_mut(), other);
     }
 }
+
+/// The [`LearnableLayer`] trait extends the [`RawLayerMut`] trait for layers whose activation
+/// has trainable parameters (see [`LearnableActivator`]), providing mutable access to the
+/// activation so that its parameters may be updated alongside those of the layer.
+pub trait LearnableLayer<F, A>: RawLayerMut<F, A>
+where
+    Self::Params<A>: RawParams<Elem = A>,
+{
+    /// returns a mutable reference to the activation function of the layer
+    fn rho_mut(&mut self) -> &mut F;
+    /// backward propagate the error through a layer whose activation is learnable, where `z`
+    /// is the input of the activation (i.e. the output of the parameters) recorded during the
+    /// forward pass. Both the parameters of the activation and those of the layer are updated
+    /// using the same `gamma`; see [`LearnableParameters::step`](crate::activate::LearnableParameters::step) to update the parameters of the
+    /// activation using an [`Optimizer`](crate::optim::Optimizer) instead.
+    fn backward_learnable<X, Y, Dt>(&mut self, input: &X, z: &Y, error: &Y, gamma: A)
+    where
+        A: Float,
+        F: LearnableActivator<Y, Elem = A, Delta = Dt>,
+        Self::Params<A>: Backward<X, Dt, Elem = A>,
+    {
+        let delta = self.rho().input_gradient(z, error);
+        let grad = self.rho().parameter_gradient(z, error);
+        self.rho_mut().apply_parameter_gradient(&grad, gamma);
+        self.params_mut().backward(input, &delta, gamma)
+    }
+}
@@ -2,7 +2,10 @@
     appellation: models <module>
     authors: @FL03
 */
+use crate::activate::ActivationKind;
 use crate::config::ModelConfiguration;
+use crate::models::ModelSummary;
+use crate::nn::{Context, ForwardWith, RawContext};
 use crate::{DeepModelParams, LayoutExt, RawModelLayout};
 use concision_params::Params;
 use concision_traits::Predict;
@@ -38,13 +41,57 @@ pub trait Model<T = f32> {
     ///
     /// By default, the trait simply passes each output from one layer to the next, however,
     /// custom models will likely override this method to inject activation methods and other
-    /// related logic
+    /// related logic. See [`predict_eval`](Model::predict_eval) for models whose behaviour
+    /// depends upon the mode of execution.
     fn predict<U, V>(&self, inputs: &U) -> V
     where
         Self: Predict<U, Output = V>,
     {
         Predict::predict(self, inputs)
     }
+    /// propagates the input through the model within an evaluation [`Context`], disabling any
+    /// stochastic layers (e.g. dropout) and using the running statistics of any batch
+    /// normalization
+    fn predict_eval<U, V>(&self, inputs: &U) -> V
+    where
+        Self: ForwardWith<U, Context, Output = V>,
+    {
+        self.forward_with(inputs, &mut Context::eval())
+    }
+    /// propagates the input through the model within the given context; use an evaluation
+    /// [`Context`] to disable any stochastic layers and use the running statistics of any
+    /// batch normalization
+    fn predict_with<U, V, C>(&self, inputs: &U, ctx: &mut C) -> V
+    where
+        C: RawContext,
+        Self: ForwardWith<U, C, Output = V>,
+    {
+        self.forward_with(inputs, ctx)
+    }
+    /// returns the activation applied to the output of the layer at the given index, where
+    /// the input layer is at index zero and the output layer is the last; the default
+    /// implementation returns `None`, indicating that the activation is unknown
+    fn activation(&self, layer: usize) -> Option<ActivationKind> {
+        let _ = layer;
+        None
+    }
+    /// returns true if the parameters of the layer at the given index are excluded from
+    /// training; by default, the frozen layers are those recorded by the parameters of the
+    /// model (see [`ModelParamsBase::freeze`](crate::models::ModelParamsBase::freeze))
+    fn is_frozen(&self, layer: usize) -> bool {
+        self.params().is_frozen(layer)
+    }
+    /// returns a [`ModelSummary`] describing each layer of the model, including its
+    /// activation and whether it is frozen
+    fn summary(&self) -> ModelSummary {
+        let mut summary = self.params().summary();
+        for (i, layer) in summary.layers_mut().iter_mut().enumerate() {
+            layer
+                .set_activation(self.activation(i))
+                .set_trainable(!self.is_frozen(i));
+        }
+        summary
+    }
 }
 
 pub trait ModelExt<T>: Model<T> {
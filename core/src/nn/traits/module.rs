@@ -0,0 +1,51 @@
+/*
+    Appellation: module <module>
+    Created At: 2026.10.18:20:41:15
+    Contrib: @FL03
+*/
+use crate::error::Result;
+use crate::nn::{LayerKind, RawContext};
+use core::any::Any;
+use ndarray::ArrayD;
+
+/// The [`Module`] trait is the object-safe interface used by the
+/// [`Sequential`](crate::nn::Sequential) container to chain layers of different kinds.
+///
+/// Each module operates on dynamically-dimensioned arrays, validating the dimensionality of
+/// its input at runtime, and may record a _cache_ during the forward pass (e.g. the mask of a
+/// dropout layer) that is handed back to it during the backward pass.
+pub trait Module<A>: core::fmt::Debug {
+    /// returns the name of the module, e.g. `"dense"` or `"conv2d"`
+    fn name(&self) -> &'static str;
+    /// returns the number of trainable parameters of the module
+    fn num_params(&self) -> usize {
+        0
+    }
+    /// complete a forward pass within the given context, returning the output alongside an
+    /// optional cache required by the backward pass
+    fn forward_dyn(
+        &self,
+        input: &ArrayD<A>,
+        ctx: &mut dyn RawContext,
+    ) -> Result<(ArrayD<A>, Option<ArrayD<A>>)>;
+    /// given the input and cache of the forward pass alongside the gradient `delta` w.r.t.
+    /// the output, update the parameters of the module by `gamma` times their gradient and
+    /// return the gradient w.r.t. the input
+    fn backward_dyn(
+        &mut self,
+        input: &ArrayD<A>,
+        cache: Option<&ArrayD<A>>,
+        delta: &ArrayD<A>,
+        gamma: A,
+        ctx: &mut dyn RawContext,
+    ) -> Result<ArrayD<A>>;
+    /// returns the serializable representation of the module, if any; modules without one
+    /// prevent the [`Sequential`](crate::nn::Sequential) containing them from being serialized
+    fn kind(&self) -> Option<LayerKind<A>> {
+        None
+    }
+    /// returns the module as a reference to [`Any`], enabling it to be downcast
+    fn as_any(&self) -> &dyn Any;
+    /// returns the module as a mutable reference to [`Any`], enabling it to be downcast
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+}
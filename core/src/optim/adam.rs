@@ -0,0 +1,387 @@
+/*
+    Appellation: adam <module>
+    Created At: 2026.10.18:09:19:36
+    Contrib: @FL03
+*/
+use super::{Optimizer, check_shapes, zeros_like};
+use crate::config::ExtendedModelConfig;
+use concision_params::{Params, ParamsBase};
+use ndarray::{Array, ArrayBase, Data, DataMut, Dimension, Ix2, RawData, Zip};
+use num_traits::{Float, FromPrimitive};
+
+/// The [`Adam`] optimizer maintains exponentially decaying averages of both the gradients
+/// (first moment) and the squared gradients (second moment) of each parameter, using their
+/// bias-corrected estimates to adapt the step size:
+///
+/// ```math
+/// \begin{aligned}
+/// m_t &= \beta_1 m_{t-1} + (1 - \beta_1) g_t \\
+/// v_t &= \beta_2 v_{t-1} + (1 - \beta_2) g_t^2 \\
+/// \theta_t &= \theta_{t-1} - \eta \frac{m_t / (1 - \beta_1^t)}{\sqrt{v_t / (1 - \beta_2^t)} + \epsilon}
+/// \end{aligned}
+/// ```
+///
+/// Any weight decay is applied as an L2 penalty, i.e. added onto the gradient; see [`AdamW`]
+/// for the decoupled variant.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub struct Adam<A = f64> {
+    pub(crate) learning_rate: A,
+    pub(crate) beta1: A,
+    pub(crate) beta2: A,
+    pub(crate) epsilon: A,
+    pub(crate) weight_decay: A,
+}
+
+/// The [`AdamW`] optimizer is a variant of [`Adam`] whose weight decay is _decoupled_ from the
+/// gradient, shrinking the parameters directly before each update:
+///
+/// ```math
+/// \theta_t \leftarrow \theta_{t-1} - \eta\lambda\theta_{t-1}
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(transparent)
+)]
+#[repr(transparent)]
+pub struct AdamW<A = f64>(pub(crate) Adam<A>);
+
+/// The [`AdamState`] stores the timestep alongside the first and second moment estimates of
+/// the [`Adam`] (and [`AdamW`]) optimizer for a single set of parameters.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(
+        rename_all = "snake_case",
+        bound(
+            serialize = "A: serde::Serialize, D: serde::Serialize, D::Smaller: serde::Serialize",
+            deserialize = "A: serde::Deserialize<'de>, D: serde::Deserialize<'de>, D::Smaller: serde::Deserialize<'de>"
+        )
+    )
+)]
+pub struct AdamState<A = f64, D = Ix2>
+where
+    D: Dimension,
+{
+    pub step: usize,
+    pub m: Params<A, D>,
+    pub v: Params<A, D>,
+}
+
+impl<A> Adam<A> {
+    /// returns a new instance of the optimizer using the given learning rate alongside the
+    /// conventional defaults; $\beta_1 = 0.9$, $\beta_2 = 0.999$ and $\epsilon = 10^{-8}$
+    pub fn new(learning_rate: A) -> Self
+    where
+        A: FromPrimitive + num_traits::Zero,
+    {
+        Self {
+            learning_rate,
+            beta1: A::from_f64(0.9).unwrap(),
+            beta2: A::from_f64(0.999).unwrap(),
+            epsilon: A::from_f64(1e-8).unwrap(),
+            weight_decay: A::zero(),
+        }
+    }
+    /// create a new instance of the optimizer using the hyperparameters of the given config;
+    /// the `learning_rate`, `beta1`, `beta2`, `epsilon` and `weight_decay` are used when
+    /// present, falling back onto the defaults of [`Adam::new`] (with a learning rate of
+    /// `0.001`) otherwise.
+    pub fn from_config<C>(config: &C) -> Self
+    where
+        A: Copy + FromPrimitive + num_traits::Zero,
+        C: ExtendedModelConfig<A>,
+    {
+        let lr = config
+            .learning_rate()
+            .copied()
+            .unwrap_or_else(|| A::from_f64(0.001).unwrap());
+        let base = Self::new(lr);
+        Self {
+            beta1: config.beta1().copied().unwrap_or(base.beta1),
+            beta2: config.beta2().copied().unwrap_or(base.beta2),
+            epsilon: config.epsilon().copied().unwrap_or(base.epsilon),
+            weight_decay: config.weight_decay().copied().unwrap_or(base.weight_decay),
+            ..base
+        }
+    }
+    /// returns a copy of the decay rate of the first moment estimates
+    pub const fn beta1(&self) -> A
+    where
+        A: Copy,
+    {
+        self.beta1
+    }
+    /// returns a copy of the decay rate of the second moment estimates
+    pub const fn beta2(&self) -> A
+    where
+        A: Copy,
+    {
+        self.beta2
+    }
+    /// returns a copy of the epsilon used to avoid division by zero
+    pub const fn epsilon(&self) -> A
+    where
+        A: Copy,
+    {
+        self.epsilon
+    }
+    /// returns a copy of the weight decay
+    pub const fn weight_decay(&self) -> A
+    where
+        A: Copy,
+    {
+        self.weight_decay
+    }
+    /// consumes the current instance to create another with the given betas
+    pub fn with_betas(self, beta1: A, beta2: A) -> Self {
+        Self {
+            beta1,
+            beta2,
+            ..self
+        }
+    }
+    /// consumes the current instance to create another with the given epsilon
+    pub fn with_epsilon(self, epsilon: A) -> Self {
+        Self { epsilon, ..self }
+    }
+    /// consumes the current instance to create another with the given weight decay
+    pub fn with_weight_decay(self, weight_decay: A) -> Self {
+        Self {
+            weight_decay,
+            ..self
+        }
+    }
+    /// update a single tensor of the parameters; when `decoupled` is true the weight decay is
+    /// applied directly onto the parameters rather than the gradient
+    fn update<S, T, D>(
+        &self,
+        param: &mut ArrayBase<S, D, A>,
+        grad: &ArrayBase<T, D, A>,
+        (m, v): (&mut Array<A, D>, &mut Array<A, D>),
+        step: usize,
+        decoupled: bool,
+    ) where
+        A: Float,
+        D: Dimension,
+        S: DataMut<Elem = A>,
+        T: Data<Elem = A>,
+    {
+        let Self {
+            learning_rate: lr,
+            beta1: b1,
+            beta2: b2,
+            epsilon: eps,
+            weight_decay: wd,
+        } = *self;
+        let t = step.min(i32::MAX as usize) as i32;
+        let (c1, c2) = (A::one() - b1.powi(t), A::one() - b2.powi(t));
+        Zip::from(param)
+            .and(grad)
+            .and(m)
+            .and(v)
+            .for_each(|p, &g, m, v| {
+                let g = if decoupled {
+                    *p = *p - lr * wd * *p;
+                    g
+                } else {
+                    g + wd * *p
+                };
+                *m = b1 * *m + (A::one() - b1) * g;
+                *v = b2 * *v + (A::one() - b2) * g * g;
+                *p = *p - lr * (*m / c1) / ((*v / c2).sqrt() + eps);
+            });
+    }
+    /// perform a single step, optionally decoupling the weight decay
+    fn step_with<S, T, D>(
+        &self,
+        params: &mut ParamsBase<S, D, A>,
+        grad: &ParamsBase<T, D, A>,
+        state: &mut AdamState<A, D>,
+        decoupled: bool,
+    ) -> crate::Result<()>
+    where
+        A: Float,
+        D: Dimension,
+        S: DataMut<Elem = A>,
+        T: Data<Elem = A>,
+    {
+        check_shapes(params, grad)?;
+        check_shapes(params, &state.m)?;
+        check_shapes(params, &state.v)?;
+        state.step += 1;
+        let AdamState { step, m, v } = state;
+        self.update(
+            &mut params.bias,
+            grad.bias(),
+            (&mut m.bias, &mut v.bias),
+            *step,
+            decoupled,
+        );
+        self.update(
+            &mut params.weights,
+            grad.weights(),
+            (&mut m.weights, &mut v.weights),
+            *step,
+            decoupled,
+        );
+        Ok(())
+    }
+}
+
+impl<A> AdamW<A> {
+    /// returns a new instance of the optimizer using the given learning rate, the defaults of
+    /// [`Adam::new`] and a weight decay of `0.01`
+    pub fn new(learning_rate: A) -> Self
+    where
+        A: FromPrimitive + num_traits::Zero,
+    {
+        Self(Adam {
+            weight_decay: A::from_f64(0.01).unwrap(),
+            ..Adam::new(learning_rate)
+        })
+    }
+    /// create a new instance of the optimizer using the hyperparameters of the given config;
+    /// see [`Adam::from_config`] for more information, noting that the weight decay falls
+    /// back onto `0.01` when missing.
+    pub fn from_config<C>(config: &C) -> Self
+    where
+        A: Copy + FromPrimitive + num_traits::Zero,
+        C: ExtendedModelConfig<A>,
+    {
+        let weight_decay = config
+            .weight_decay()
+            .copied()
+            .unwrap_or_else(|| A::from_f64(0.01).unwrap());
+        Self(Adam {
+            weight_decay,
+            ..Adam::from_config(config)
+        })
+    }
+    /// returns an immutable reference to the underlying [`Adam`] configuration
+    pub const fn as_adam(&self) -> &Adam<A> {
+        &self.0
+    }
+    /// returns a copy of the weight decay
+    pub const fn weight_decay(&self) -> A
+    where
+        A: Copy,
+    {
+        self.0.weight_decay
+    }
+    /// consumes the current instance to create another with the given betas
+    pub fn with_betas(self, beta1: A, beta2: A) -> Self {
+        Self(self.0.with_betas(beta1, beta2))
+    }
+    /// consumes the current instance to create another with the given epsilon
+    pub fn with_epsilon(self, epsilon: A) -> Self {
+        Self(self.0.with_epsilon(epsilon))
+    }
+    /// consumes the current instance to create another with the given weight decay
+    pub fn with_weight_decay(self, weight_decay: A) -> Self {
+        Self(self.0.with_weight_decay(weight_decay))
+    }
+}
+
+impl<A> Default for Adam<A>
+where
+    A: FromPrimitive + num_traits::Zero,
+{
+    /// uses a learning rate of `0.001` alongside the defaults of [`Adam::new`]
+    fn default() -> Self {
+        Self::new(A::from_f64(0.001).unwrap())
+    }
+}
+
+impl<A> Default for AdamW<A>
+where
+    A: FromPrimitive + num_traits::Zero,
+{
+    /// uses a learning rate of `0.001` alongside the defaults of [`AdamW::new`]
+    fn default() -> Self {
+        Self::new(A::from_f64(0.001).unwrap())
+    }
+}
+
+impl<A, D> Optimizer<A, D> for Adam<A>
+where
+    A: Float,
+    D: Dimension,
+{
+    type State = AdamState<A, D>;
+
+    fn learning_rate(&self) -> A {
+        self.learning_rate
+    }
+
+    fn set_learning_rate(&mut self, learning_rate: A) {
+        self.learning_rate = learning_rate;
+    }
+
+    fn init_state<S>(&self, params: &ParamsBase<S, D, A>) -> Self::State
+    where
+        S: RawData<Elem = A>,
+    {
+        AdamState {
+            step: 0,
+            m: zeros_like(params),
+            v: zeros_like(params),
+        }
+    }
+
+    fn step<S, T>(
+        &self,
+        params: &mut ParamsBase<S, D, A>,
+        grad: &ParamsBase<T, D, A>,
+        state: &mut Self::State,
+    ) -> crate::Result<()>
+    where
+        S: DataMut<Elem = A>,
+        T: Data<Elem = A>,
+    {
+        self.step_with(params, grad, state, false)
+    }
+}
+
+impl<A, D> Optimizer<A, D> for AdamW<A>
+where
+    A: Float,
+    D: Dimension,
+{
+    type State = AdamState<A, D>;
+
+    fn learning_rate(&self) -> A {
+        self.0.learning_rate
+    }
+
+    fn set_learning_rate(&mut self, learning_rate: A) {
+        self.0.learning_rate = learning_rate;
+    }
+
+    fn init_state<S>(&self, params: &ParamsBase<S, D, A>) -> Self::State
+    where
+        S: RawData<Elem = A>,
+    {
+        <Adam<A> as Optimizer<A, D>>::init_state(&self.0, params)
+    }
+
+    fn step<S, T>(
+        &self,
+        params: &mut ParamsBase<S, D, A>,
+        grad: &ParamsBase<T, D, A>,
+        state: &mut Self::State,
+    ) -> crate::Result<()>
+    where
+        S: DataMut<Elem = A>,
+        T: Data<Elem = A>,
+    {
+        self.0.step_with(params, grad, state, true)
+    }
+}
@@ -0,0 +1,74 @@
+/*
+    Appellation: optim <module>
+    Created At: 2026.10.18:09:12:40
+    Contrib: @FL03
+*/
+//! This module implements a set of stateful optimizers used to update the parameters of a
+//! model given the gradient of some loss function w.r.t. those parameters.
+//!
+//! Each optimizer is a lightweight, configurable object implementing the [`Optimizer`] trait
+//! whose _per-parameter_ state (e.g. momentum or moment buffers) is initialized from, and
+//! shaped like, the [`ParamsBase`](concision_params::ParamsBase) it is responsible for.
+//!
+//! - [`Sgd`]: stochastic gradient descent with optional (Nesterov) momentum
+//! - [`RmsProp`]: root mean square propagation
+//! - [`Adam`]: adaptive moment estimation
+//! - [`AdamW`]: adaptive moment estimation with decoupled weight decay
+//!
+//! The [`scheduler`] module additionally provides a set of learning-rate schedulers.
+#[doc(inline)]
+pub use self::{adam::*, rmsprop::*, scheduler::*, sgd::*, traits::*};
+
+pub mod adam;
+pub mod rmsprop;
+pub mod scheduler;
+pub mod sgd;
+
+mod traits {
+    #[doc(inline)]
+    pub use self::optimizer::*;
+
+    mod optimizer;
+}
+
+pub(crate) mod prelude {
+    pub use super::adam::*;
+    pub use super::rmsprop::*;
+    pub use super::scheduler::*;
+    pub use super::sgd::*;
+    pub use super::traits::*;
+}
+
+use concision_params::ParamsBase;
+use ndarray::{Data, Dimension, ErrorKind, RawData, ShapeError};
+
+/// verify that the gradient is shaped like the parameters it is meant to update
+pub(crate) fn check_shapes<A, S, T, D>(
+    params: &ParamsBase<S, D, A>,
+    grad: &ParamsBase<T, D, A>,
+) -> crate::Result<()>
+where
+    D: Dimension,
+    S: RawData<Elem = A>,
+    T: Data<Elem = A>,
+{
+    if params.weights().shape() != grad.weights().shape()
+        || params.bias().shape() != grad.bias().shape()
+    {
+        return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+    }
+    Ok(())
+}
+
+/// returns a new set of parameters, filled with zeros, that are shaped like the given params
+pub(crate) fn zeros_like<A, S, D>(params: &ParamsBase<S, D, A>) -> concision_params::Params<A, D>
+where
+    A: Clone + num_traits::Zero,
+    D: Dimension,
+    S: RawData<Elem = A>,
+{
+    ParamsBase::new(
+        ndarray::Array::zeros(params.bias().raw_dim()),
+        ndarray::Array::zeros(params.weights().raw_dim()),
+    )
+}
@@ -0,0 +1,246 @@
+/*
+    Appellation: rmsprop <module>
+    Created At: 2026.10.18:09:16:51
+    Contrib: @FL03
+*/
+use super::{Optimizer, check_shapes, zeros_like};
+use crate::config::ExtendedModelConfig;
+use concision_params::{Params, ParamsBase};
+use ndarray::{Array, ArrayBase, Data, DataMut, Dimension, Ix2, RawData, Zip};
+use num_traits::{Float, FromPrimitive};
+
+/// The [`RmsProp`] optimizer scales the learning rate of each parameter by a running average
+/// of the magnitude of its recent gradients:
+///
+/// ```math
+/// \begin{aligned}
+/// s_t &= \alpha s_{t-1} + (1 - \alpha) g_t^2 \\
+/// \theta_t &= \theta_{t-1} - \eta \frac{g_t}{\sqrt{s_t} + \epsilon}
+/// \end{aligned}
+/// ```
+///
+/// When the momentum $\mu$ is non-zero, the scaled gradient is accumulated into a buffer
+/// $b_t = \mu b_{t-1} + g_t / (\sqrt{s_t} + \epsilon)$ which is then used to update the
+/// parameters.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub struct RmsProp<A = f64> {
+    pub(crate) learning_rate: A,
+    pub(crate) decay: A,
+    pub(crate) epsilon: A,
+    pub(crate) momentum: A,
+    pub(crate) weight_decay: A,
+}
+
+/// The [`RmsPropState`] stores the running average of the squared gradients, alongside the
+/// momentum buffer, of the [`RmsProp`] optimizer for a single set of parameters.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(
+        rename_all = "snake_case",
+        bound(
+            serialize = "A: serde::Serialize, D: serde::Serialize, D::Smaller: serde::Serialize",
+            deserialize = "A: serde::Deserialize<'de>, D: serde::Deserialize<'de>, D::Smaller: serde::Deserialize<'de>"
+        )
+    )
+)]
+pub struct RmsPropState<A = f64, D = Ix2>
+where
+    D: Dimension,
+{
+    pub square_avg: Params<A, D>,
+    pub buffer: Params<A, D>,
+}
+
+impl<A> RmsProp<A> {
+    /// returns a new instance of the optimizer using the given learning rate, a decay of
+    /// `0.99` and an epsilon of `1e-8`
+    pub fn new(learning_rate: A) -> Self
+    where
+        A: FromPrimitive + num_traits::Zero,
+    {
+        Self {
+            learning_rate,
+            decay: A::from_f64(0.99).unwrap(),
+            epsilon: A::from_f64(1e-8).unwrap(),
+            momentum: A::zero(),
+            weight_decay: A::zero(),
+        }
+    }
+    /// create a new instance of the optimizer using the hyperparameters of the given config;
+    /// the `learning_rate`, `decay`, `epsilon`, `momentum` and `weight_decay` are used when
+    /// present, falling back onto the defaults of [`RmsProp::new`] (with a learning rate of
+    /// `0.01`) otherwise.
+    pub fn from_config<C>(config: &C) -> Self
+    where
+        A: Copy + FromPrimitive + num_traits::Zero,
+        C: ExtendedModelConfig<A>,
+    {
+        let lr = config
+            .learning_rate()
+            .copied()
+            .unwrap_or_else(|| A::from_f64(0.01).unwrap());
+        let base = Self::new(lr);
+        Self {
+            decay: config.decay().copied().unwrap_or(base.decay),
+            epsilon: config.epsilon().copied().unwrap_or(base.epsilon),
+            momentum: config.momentum().copied().unwrap_or(base.momentum),
+            weight_decay: config.weight_decay().copied().unwrap_or(base.weight_decay),
+            ..base
+        }
+    }
+    /// returns a copy of the smoothing constant (decay) of the running average
+    pub const fn decay(&self) -> A
+    where
+        A: Copy,
+    {
+        self.decay
+    }
+    /// returns a copy of the epsilon used to avoid division by zero
+    pub const fn epsilon(&self) -> A
+    where
+        A: Copy,
+    {
+        self.epsilon
+    }
+    /// returns a copy of the momentum factor
+    pub const fn momentum(&self) -> A
+    where
+        A: Copy,
+    {
+        self.momentum
+    }
+    /// returns a copy of the weight decay (L2 penalty)
+    pub const fn weight_decay(&self) -> A
+    where
+        A: Copy,
+    {
+        self.weight_decay
+    }
+    /// consumes the current instance to create another with the given decay
+    pub fn with_decay(self, decay: A) -> Self {
+        Self { decay, ..self }
+    }
+    /// consumes the current instance to create another with the given epsilon
+    pub fn with_epsilon(self, epsilon: A) -> Self {
+        Self { epsilon, ..self }
+    }
+    /// consumes the current instance to create another with the given momentum
+    pub fn with_momentum(self, momentum: A) -> Self {
+        Self { momentum, ..self }
+    }
+    /// consumes the current instance to create another with the given weight decay
+    pub fn with_weight_decay(self, weight_decay: A) -> Self {
+        Self {
+            weight_decay,
+            ..self
+        }
+    }
+    /// update a single tensor of the parameters
+    fn update<S, T, D>(
+        &self,
+        param: &mut ArrayBase<S, D, A>,
+        grad: &ArrayBase<T, D, A>,
+        square_avg: &mut Array<A, D>,
+        buffer: &mut Array<A, D>,
+    ) where
+        A: Float,
+        D: Dimension,
+        S: DataMut<Elem = A>,
+        T: Data<Elem = A>,
+    {
+        let Self {
+            learning_rate: lr,
+            decay: alpha,
+            epsilon: eps,
+            momentum: mu,
+            weight_decay: wd,
+        } = *self;
+        Zip::from(param)
+            .and(grad)
+            .and(square_avg)
+            .and(buffer)
+            .for_each(|p, &g, s, b| {
+                let g = g + wd * *p;
+                *s = alpha * *s + (A::one() - alpha) * g * g;
+                let scaled = g / (s.sqrt() + eps);
+                if mu.is_zero() {
+                    *p = *p - lr * scaled;
+                } else {
+                    *b = mu * *b + scaled;
+                    *p = *p - lr * *b;
+                }
+            });
+    }
+}
+
+impl<A> Default for RmsProp<A>
+where
+    A: FromPrimitive + num_traits::Zero,
+{
+    /// uses a learning rate of `0.01` alongside the defaults of [`RmsProp::new`]
+    fn default() -> Self {
+        Self::new(A::from_f64(0.01).unwrap())
+    }
+}
+
+impl<A, D> Optimizer<A, D> for RmsProp<A>
+where
+    A: Float,
+    D: Dimension,
+{
+    type State = RmsPropState<A, D>;
+
+    fn learning_rate(&self) -> A {
+        self.learning_rate
+    }
+
+    fn set_learning_rate(&mut self, learning_rate: A) {
+        self.learning_rate = learning_rate;
+    }
+
+    fn init_state<S>(&self, params: &ParamsBase<S, D, A>) -> Self::State
+    where
+        S: RawData<Elem = A>,
+    {
+        RmsPropState {
+            square_avg: zeros_like(params),
+            buffer: zeros_like(params),
+        }
+    }
+
+    fn step<S, T>(
+        &self,
+        params: &mut ParamsBase<S, D, A>,
+        grad: &ParamsBase<T, D, A>,
+        state: &mut Self::State,
+    ) -> crate::Result<()>
+    where
+        S: DataMut<Elem = A>,
+        T: Data<Elem = A>,
+    {
+        check_shapes(params, grad)?;
+        check_shapes(params, &state.square_avg)?;
+        check_shapes(params, &state.buffer)?;
+        let RmsPropState { square_avg, buffer } = state;
+        self.update(
+            &mut params.bias,
+            grad.bias(),
+            &mut square_avg.bias,
+            &mut buffer.bias,
+        );
+        self.update(
+            &mut params.weights,
+            grad.weights(),
+            &mut square_avg.weights,
+            &mut buffer.weights,
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,54 @@
+/*
+    Appellation: scheduler <module>
+    Created At: 2026.10.18:10:02:18
+    Contrib: @FL03
+*/
+//! Learning-rate schedulers used to adjust the learning rate of an optimizer (or model) over
+//! the course of training.
+//!
+//! Each scheduler implements the [`LrScheduler`] trait and is advanced once per step (e.g.
+//! an epoch) using the most recent loss; only the [`ReduceOnPlateau`] scheduler actually
+//! inspects the loss, while the remaining schedules are deterministic functions of the number
+//! of steps taken and are therefore fully reproducible.
+#[doc(inline)]
+pub use self::{cosine::*, decay::*, plateau::*, warmup::*};
+
+mod cosine;
+mod decay;
+mod plateau;
+mod warmup;
+
+/// The [`LrScheduler`] trait defines the interface for learning-rate schedulers.
+pub trait LrScheduler<A> {
+    /// returns the learning rate for the current step
+    fn learning_rate(&self) -> A;
+    /// returns the number of steps taken by the scheduler
+    fn current_step(&self) -> usize;
+    /// advance the scheduler by a single step given the most recent loss, returning the
+    /// updated learning rate
+    fn step(&mut self, loss: A) -> A;
+}
+
+/*
+ ************* Implementations *************
+*/
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+
+#[cfg(feature = "alloc")]
+impl<A, S> LrScheduler<A> for Box<S>
+where
+    S: LrScheduler<A> + ?Sized,
+{
+    fn learning_rate(&self) -> A {
+        (**self).learning_rate()
+    }
+
+    fn current_step(&self) -> usize {
+        (**self).current_step()
+    }
+
+    fn step(&mut self, loss: A) -> A {
+        (**self).step(loss)
+    }
+}
@@ -0,0 +1,113 @@
+/*
+    Appellation: cosine <module>
+    Created At: 2026.10.18:10:09:12
+    Contrib: @FL03
+*/
+use super::LrScheduler;
+use num_traits::{Float, FromPrimitive};
+
+/// The [`CosineAnnealing`] scheduler anneals the learning rate from its base value down to
+/// some minimum following a cosine curve, restarting the schedule at the end of each cycle
+/// (i.e. SGDR):
+///
+/// ```math
+/// \eta_t = \eta_{min} + \frac{1}{2}(\eta_0 - \eta_{min})\left(1 + \cos\left(\pi\frac{T_{cur}}{T_i}\right)\right)
+/// ```
+///
+/// where $T_{cur}$ is the number of steps since the last restart and $T_i$ is the length of
+/// the current cycle; the length of each cycle is multiplied by `mult` after every restart.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub struct CosineAnnealing<A = f64> {
+    pub(crate) base_lr: A,
+    pub(crate) min_lr: A,
+    pub(crate) period: usize,
+    pub(crate) mult: usize,
+    pub(crate) step: usize,
+}
+
+impl<A> CosineAnnealing<A> {
+    /// create a new scheduler annealing from the base learning rate to the minimum over a
+    /// cycle of `period` steps before restarting; a period of zero is treated as one.
+    pub const fn new(base_lr: A, min_lr: A, period: usize) -> Self {
+        Self {
+            base_lr,
+            min_lr,
+            period: if period == 0 { 1 } else { period },
+            mult: 1,
+            step: 0,
+        }
+    }
+    /// consumes the current instance to create another whose cycles grow by a factor of
+    /// `mult` after each restart; a factor of zero is treated as one.
+    pub fn with_mult(self, mult: usize) -> Self {
+        Self {
+            mult: if mult == 0 { 1 } else { mult },
+            ..self
+        }
+    }
+    /// returns a copy of the initial learning rate
+    pub const fn base_lr(&self) -> A
+    where
+        A: Copy,
+    {
+        self.base_lr
+    }
+    /// returns a copy of the minimum learning rate
+    pub const fn min_lr(&self) -> A
+    where
+        A: Copy,
+    {
+        self.min_lr
+    }
+    /// returns the length of the first cycle
+    pub const fn period(&self) -> usize {
+        self.period
+    }
+    /// returns the factor by which the length of each cycle grows after a restart
+    pub const fn mult(&self) -> usize {
+        self.mult
+    }
+    /// returns the position within, and length of, the cycle containing the given step
+    pub fn cycle(&self, step: usize) -> (usize, usize) {
+        let (mut t_cur, mut t_i) = (step, self.period);
+        while t_cur >= t_i {
+            t_cur -= t_i;
+            t_i = t_i.saturating_mul(self.mult);
+        }
+        (t_cur, t_i)
+    }
+    /// returns the learning rate at the given step
+    pub fn learning_rate_at(&self, step: usize) -> A
+    where
+        A: Float + FromPrimitive,
+    {
+        let (t_cur, t_i) = self.cycle(step);
+        let progress = A::from_usize(t_cur).unwrap() / A::from_usize(t_i).unwrap();
+        let half = A::from_f64(0.5).unwrap();
+        let cos = (A::from_f64(core::f64::consts::PI).unwrap() * progress).cos();
+        self.min_lr + half * (self.base_lr - self.min_lr) * (A::one() + cos)
+    }
+}
+
+impl<A> LrScheduler<A> for CosineAnnealing<A>
+where
+    A: Float + FromPrimitive,
+{
+    fn learning_rate(&self) -> A {
+        self.learning_rate_at(self.step)
+    }
+
+    fn current_step(&self) -> usize {
+        self.step
+    }
+
+    fn step(&mut self, _loss: A) -> A {
+        self.step += 1;
+        self.learning_rate()
+    }
+}
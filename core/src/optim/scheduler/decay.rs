@@ -0,0 +1,155 @@
+/*
+    Appellation: decay <module>
+    Created At: 2026.10.18:10:05:44
+    Contrib: @FL03
+*/
+use super::LrScheduler;
+use num_traits::Float;
+
+/// The [`StepDecay`] scheduler multiplies the learning rate by `gamma` every `step_size`
+/// steps:
+///
+/// ```math
+/// \eta_t = \eta_0\gamma^{\lfloor t / s \rfloor}
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub struct StepDecay<A = f64> {
+    pub(crate) base_lr: A,
+    pub(crate) gamma: A,
+    pub(crate) step_size: usize,
+    pub(crate) step: usize,
+}
+
+/// The [`ExponentialDecay`] scheduler multiplies the learning rate by `gamma` after every
+/// step:
+///
+/// ```math
+/// \eta_t = \eta_0\gamma^t
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub struct ExponentialDecay<A = f64> {
+    pub(crate) base_lr: A,
+    pub(crate) gamma: A,
+    pub(crate) step: usize,
+}
+
+impl<A> StepDecay<A> {
+    /// create a new scheduler decaying the base learning rate by `gamma` every `step_size`
+    /// steps; a step size of zero is treated as one.
+    pub const fn new(base_lr: A, gamma: A, step_size: usize) -> Self {
+        Self {
+            base_lr,
+            gamma,
+            step_size: if step_size == 0 { 1 } else { step_size },
+            step: 0,
+        }
+    }
+    /// returns a copy of the initial learning rate
+    pub const fn base_lr(&self) -> A
+    where
+        A: Copy,
+    {
+        self.base_lr
+    }
+    /// returns a copy of the multiplicative decay factor
+    pub const fn gamma(&self) -> A
+    where
+        A: Copy,
+    {
+        self.gamma
+    }
+    /// returns the number of steps between each decay
+    pub const fn step_size(&self) -> usize {
+        self.step_size
+    }
+    /// returns the learning rate at the given step
+    pub fn learning_rate_at(&self, step: usize) -> A
+    where
+        A: Float,
+    {
+        self.base_lr * powu(self.gamma, step / self.step_size)
+    }
+}
+
+impl<A> ExponentialDecay<A> {
+    /// create a new scheduler decaying the base learning rate by `gamma` every step
+    pub const fn new(base_lr: A, gamma: A) -> Self {
+        Self {
+            base_lr,
+            gamma,
+            step: 0,
+        }
+    }
+    /// returns a copy of the initial learning rate
+    pub const fn base_lr(&self) -> A
+    where
+        A: Copy,
+    {
+        self.base_lr
+    }
+    /// returns a copy of the multiplicative decay factor
+    pub const fn gamma(&self) -> A
+    where
+        A: Copy,
+    {
+        self.gamma
+    }
+    /// returns the learning rate at the given step
+    pub fn learning_rate_at(&self, step: usize) -> A
+    where
+        A: Float,
+    {
+        self.base_lr * powu(self.gamma, step)
+    }
+}
+
+/// raise the value to some unsigned power, saturating the exponent to fit within an `i32`
+fn powu<A: Float>(value: A, exp: usize) -> A {
+    value.powi(exp.min(i32::MAX as usize) as i32)
+}
+
+impl<A> LrScheduler<A> for StepDecay<A>
+where
+    A: Float,
+{
+    fn learning_rate(&self) -> A {
+        self.learning_rate_at(self.step)
+    }
+
+    fn current_step(&self) -> usize {
+        self.step
+    }
+
+    fn step(&mut self, _loss: A) -> A {
+        self.step += 1;
+        self.learning_rate()
+    }
+}
+
+impl<A> LrScheduler<A> for ExponentialDecay<A>
+where
+    A: Float,
+{
+    fn learning_rate(&self) -> A {
+        self.learning_rate_at(self.step)
+    }
+
+    fn current_step(&self) -> usize {
+        self.step
+    }
+
+    fn step(&mut self, _loss: A) -> A {
+        self.step += 1;
+        self.learning_rate()
+    }
+}
@@ -0,0 +1,112 @@
+/*
+    Appellation: plateau <module>
+    Created At: 2026.10.18:10:17:20
+    Contrib: @FL03
+*/
+use super::LrScheduler;
+use num_traits::{Float, FromPrimitive};
+
+/// The [`ReduceOnPlateau`] scheduler watches the loss, multiplying the learning rate by
+/// `factor` whenever it fails to improve (by more than a relative `threshold`) for more than
+/// `patience` consecutive steps. The learning rate never falls below `min_lr`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub struct ReduceOnPlateau<A = f64> {
+    pub(crate) lr: A,
+    pub(crate) factor: A,
+    pub(crate) min_lr: A,
+    pub(crate) threshold: A,
+    pub(crate) patience: usize,
+    pub(crate) best: Option<A>,
+    pub(crate) bad_steps: usize,
+    pub(crate) step: usize,
+}
+
+impl<A> ReduceOnPlateau<A> {
+    /// create a new scheduler reducing the learning rate by `factor` after `patience` steps
+    /// without improvement; the minimum learning rate is zero and the threshold is `1e-4`.
+    pub fn new(lr: A, factor: A, patience: usize) -> Self
+    where
+        A: FromPrimitive + num_traits::Zero,
+    {
+        Self {
+            lr,
+            factor,
+            min_lr: A::zero(),
+            threshold: A::from_f64(1e-4).unwrap(),
+            patience,
+            best: None,
+            bad_steps: 0,
+            step: 0,
+        }
+    }
+    /// consumes the current instance to create another with the given minimum learning rate
+    pub fn with_min_lr(self, min_lr: A) -> Self {
+        Self { min_lr, ..self }
+    }
+    /// consumes the current instance to create another with the given relative threshold
+    pub fn with_threshold(self, threshold: A) -> Self {
+        Self { threshold, ..self }
+    }
+    /// returns a copy of the multiplicative factor used to reduce the learning rate
+    pub const fn factor(&self) -> A
+    where
+        A: Copy,
+    {
+        self.factor
+    }
+    /// returns a copy of the minimum learning rate
+    pub const fn min_lr(&self) -> A
+    where
+        A: Copy,
+    {
+        self.min_lr
+    }
+    /// returns the number of steps without improvement tolerated before reducing the rate
+    pub const fn patience(&self) -> usize {
+        self.patience
+    }
+    /// returns the best loss observed by the scheduler, if any
+    pub const fn best(&self) -> Option<&A> {
+        self.best.as_ref()
+    }
+    /// returns the number of consecutive steps without improvement
+    pub const fn bad_steps(&self) -> usize {
+        self.bad_steps
+    }
+}
+
+impl<A> LrScheduler<A> for ReduceOnPlateau<A>
+where
+    A: Float,
+{
+    fn learning_rate(&self) -> A {
+        self.lr
+    }
+
+    fn current_step(&self) -> usize {
+        self.step
+    }
+
+    fn step(&mut self, loss: A) -> A {
+        self.step += 1;
+        match self.best {
+            Some(best) if loss >= best - best.abs() * self.threshold => {
+                self.bad_steps += 1;
+            }
+            _ => {
+                self.best = Some(loss);
+                self.bad_steps = 0;
+            }
+        }
+        if self.bad_steps > self.patience {
+            self.lr = (self.lr * self.factor).max(self.min_lr);
+            self.bad_steps = 0;
+        }
+        self.lr
+    }
+}
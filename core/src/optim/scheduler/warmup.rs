@@ -0,0 +1,93 @@
+/*
+    Appellation: warmup <module>
+    Created At: 2026.10.18:10:13:37
+    Contrib: @FL03
+*/
+use super::LrScheduler;
+use num_traits::{Float, FromPrimitive};
+
+/// The [`LinearWarmup`] scheduler linearly scales the learning rate of the wrapped scheduler
+/// from `start_factor` up to one over the first `warmup_steps` steps; the wrapped scheduler is
+/// only advanced once the warmup has completed.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub struct LinearWarmup<S, A = f64> {
+    pub(crate) inner: S,
+    pub(crate) start_factor: A,
+    pub(crate) warmup_steps: usize,
+    pub(crate) step: usize,
+}
+
+impl<S, A> LinearWarmup<S, A> {
+    /// wrap the given scheduler, warming up from `start_factor` times its learning rate over
+    /// the given number of steps
+    pub const fn new(inner: S, start_factor: A, warmup_steps: usize) -> Self {
+        Self {
+            inner,
+            start_factor,
+            warmup_steps,
+            step: 0,
+        }
+    }
+    /// returns an immutable reference to the wrapped scheduler
+    pub const fn inner(&self) -> &S {
+        &self.inner
+    }
+    /// returns a mutable reference to the wrapped scheduler
+    pub const fn inner_mut(&mut self) -> &mut S {
+        &mut self.inner
+    }
+    /// returns a copy of the initial scaling factor
+    pub const fn start_factor(&self) -> A
+    where
+        A: Copy,
+    {
+        self.start_factor
+    }
+    /// returns the number of warmup steps
+    pub const fn warmup_steps(&self) -> usize {
+        self.warmup_steps
+    }
+    /// returns true if the scheduler is still warming up
+    pub const fn is_warming_up(&self) -> bool {
+        self.step < self.warmup_steps
+    }
+    /// returns the factor applied to the wrapped scheduler's learning rate
+    pub fn factor(&self) -> A
+    where
+        A: Float + FromPrimitive,
+    {
+        if !self.is_warming_up() {
+            return A::one();
+        }
+        let progress =
+            A::from_usize(self.step).unwrap() / A::from_usize(self.warmup_steps).unwrap();
+        self.start_factor + (A::one() - self.start_factor) * progress
+    }
+}
+
+impl<S, A> LrScheduler<A> for LinearWarmup<S, A>
+where
+    A: Float + FromPrimitive,
+    S: LrScheduler<A>,
+{
+    fn learning_rate(&self) -> A {
+        self.inner.learning_rate() * self.factor()
+    }
+
+    fn current_step(&self) -> usize {
+        self.step
+    }
+
+    fn step(&mut self, loss: A) -> A {
+        if !self.is_warming_up() {
+            self.inner.step(loss);
+        }
+        self.step += 1;
+        self.learning_rate()
+    }
+}
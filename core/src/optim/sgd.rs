@@ -0,0 +1,209 @@
+/*
+    Appellation: sgd <module>
+    Created At: 2026.10.18:09:14:27
+    Contrib: @FL03
+*/
+use super::{Optimizer, check_shapes, zeros_like};
+use crate::config::ExtendedModelConfig;
+use concision_params::{Params, ParamsBase};
+use ndarray::{Array, ArrayBase, Data, DataMut, Dimension, Ix2, RawData, Zip};
+use num_traits::{Float, FromPrimitive};
+
+/// The [`Sgd`] optimizer implements stochastic gradient descent with optional momentum,
+/// Nesterov momentum and (L2) weight decay. Given the gradient $g_t$ of the loss w.r.t. the
+/// parameters $\theta$, each step computes:
+///
+/// ```math
+/// \begin{aligned}
+/// g_t &\leftarrow g_t + \lambda\theta_{t-1} \\
+/// v_t &= \mu v_{t-1} + g_t \\
+/// \theta_t &= \theta_{t-1} - \eta v_t
+/// \end{aligned}
+/// ```
+///
+/// where Nesterov momentum replaces the final update with $\theta_{t-1} - \eta(g_t + \mu v_t)$.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub struct Sgd<A = f64> {
+    pub(crate) learning_rate: A,
+    pub(crate) momentum: A,
+    pub(crate) weight_decay: A,
+    pub(crate) nesterov: bool,
+}
+
+/// The [`SgdState`] stores the velocity (momentum buffer) of the [`Sgd`] optimizer for a
+/// single set of parameters.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(
+        rename_all = "snake_case",
+        bound(
+            serialize = "A: serde::Serialize, D: serde::Serialize, D::Smaller: serde::Serialize",
+            deserialize = "A: serde::Deserialize<'de>, D: serde::Deserialize<'de>, D::Smaller: serde::Deserialize<'de>"
+        )
+    )
+)]
+pub struct SgdState<A = f64, D = Ix2>
+where
+    D: Dimension,
+{
+    pub velocity: Params<A, D>,
+}
+
+impl<A> Sgd<A> {
+    /// returns a new instance of the optimizer using the given learning rate and no momentum
+    pub fn new(learning_rate: A) -> Self
+    where
+        A: num_traits::Zero,
+    {
+        Self {
+            learning_rate,
+            momentum: A::zero(),
+            weight_decay: A::zero(),
+            nesterov: false,
+        }
+    }
+    /// create a new instance of the optimizer using the hyperparameters of the given config;
+    /// the `learning_rate`, `momentum` and `weight_decay` are used when present, falling back
+    /// onto a learning rate of `0.01` and no momentum or decay otherwise.
+    pub fn from_config<C>(config: &C) -> Self
+    where
+        A: Copy + FromPrimitive + num_traits::Zero,
+        C: ExtendedModelConfig<A>,
+    {
+        Self {
+            learning_rate: config
+                .learning_rate()
+                .copied()
+                .unwrap_or_else(|| A::from_f64(0.01).unwrap()),
+            momentum: config.momentum().copied().unwrap_or_else(A::zero),
+            weight_decay: config.weight_decay().copied().unwrap_or_else(A::zero),
+            nesterov: false,
+        }
+    }
+    /// returns a copy of the momentum factor
+    pub const fn momentum(&self) -> A
+    where
+        A: Copy,
+    {
+        self.momentum
+    }
+    /// returns a copy of the weight decay (L2 penalty)
+    pub const fn weight_decay(&self) -> A
+    where
+        A: Copy,
+    {
+        self.weight_decay
+    }
+    /// returns true if the optimizer uses Nesterov momentum
+    pub const fn is_nesterov(&self) -> bool {
+        self.nesterov
+    }
+    /// consumes the current instance to create another with the given momentum
+    pub fn with_momentum(self, momentum: A) -> Self {
+        Self { momentum, ..self }
+    }
+    /// consumes the current instance to create another with the given weight decay
+    pub fn with_weight_decay(self, weight_decay: A) -> Self {
+        Self {
+            weight_decay,
+            ..self
+        }
+    }
+    /// consumes the current instance to toggle the use of Nesterov momentum
+    pub fn with_nesterov(self, nesterov: bool) -> Self {
+        Self { nesterov, ..self }
+    }
+    /// update a single tensor of the parameters
+    fn update<S, T, D>(
+        &self,
+        param: &mut ArrayBase<S, D, A>,
+        grad: &ArrayBase<T, D, A>,
+        velocity: &mut Array<A, D>,
+    ) where
+        A: Float,
+        D: Dimension,
+        S: DataMut<Elem = A>,
+        T: Data<Elem = A>,
+    {
+        let Self {
+            learning_rate: lr,
+            momentum: mu,
+            weight_decay: wd,
+            nesterov,
+        } = *self;
+        Zip::from(param)
+            .and(grad)
+            .and(velocity)
+            .for_each(|p, &g, v| {
+                let mut g = g + wd * *p;
+                if !mu.is_zero() {
+                    *v = mu * *v + g;
+                    g = if nesterov { g + mu * *v } else { *v };
+                }
+                *p = *p - lr * g;
+            });
+    }
+}
+
+impl<A> Default for Sgd<A>
+where
+    A: FromPrimitive + num_traits::Zero,
+{
+    /// uses a learning rate of `0.01` without any momentum or weight decay
+    fn default() -> Self {
+        Self::new(A::from_f64(0.01).unwrap())
+    }
+}
+
+impl<A, D> Optimizer<A, D> for Sgd<A>
+where
+    A: Float,
+    D: Dimension,
+{
+    type State = SgdState<A, D>;
+
+    fn learning_rate(&self) -> A {
+        self.learning_rate
+    }
+
+    fn set_learning_rate(&mut self, learning_rate: A) {
+        self.learning_rate = learning_rate;
+    }
+
+    fn init_state<S>(&self, params: &ParamsBase<S, D, A>) -> Self::State
+    where
+        S: RawData<Elem = A>,
+    {
+        SgdState {
+            velocity: zeros_like(params),
+        }
+    }
+
+    fn step<S, T>(
+        &self,
+        params: &mut ParamsBase<S, D, A>,
+        grad: &ParamsBase<T, D, A>,
+        state: &mut Self::State,
+    ) -> crate::Result<()>
+    where
+        S: DataMut<Elem = A>,
+        T: Data<Elem = A>,
+    {
+        check_shapes(params, grad)?;
+        check_shapes(params, &state.velocity)?;
+        self.update(&mut params.bias, grad.bias(), &mut state.velocity.bias);
+        self.update(
+            &mut params.weights,
+            grad.weights(),
+            &mut state.velocity.weights,
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,45 @@
+/*
+    Appellation: optimizer <module>
+    Created At: 2026.10.18:09:13:02
+    Contrib: @FL03
+*/
+use concision_params::ParamsBase;
+use ndarray::{Data, DataMut, Dimension, Ix2, RawData};
+
+/// The [`Optimizer`] trait establishes a common interface for algorithms that update the
+/// parameters of a model using the gradient of the loss w.r.t. those parameters.
+///
+/// Implementors are expected to be lightweight, serving as the _configuration_ of the
+/// algorithm, while any information accumulated over the course of training is stored within
+/// the associated [`State`](Optimizer::State). A separate state is maintained for each set
+/// of parameters being optimized and is initialized using [`init_state`](Optimizer::init_state)
+/// so that its buffers are shaped like the parameters themselves.
+///
+/// **Note:** the gradient is expected to be that of the loss function w.r.t. the parameters,
+/// meaning that each step moves the parameters _against_ the gradient.
+pub trait Optimizer<A, D = Ix2>
+where
+    D: Dimension,
+{
+    /// the per-parameter state maintained by the optimizer
+    type State;
+
+    /// returns the current learning rate of the optimizer
+    fn learning_rate(&self) -> A;
+    /// update the learning rate of the optimizer
+    fn set_learning_rate(&mut self, learning_rate: A);
+    /// initialize a new state for the given parameters
+    fn init_state<S>(&self, params: &ParamsBase<S, D, A>) -> Self::State
+    where
+        S: RawData<Elem = A>;
+    /// perform a single optimization step, updating the parameters (and state) in-place
+    fn step<S, T>(
+        &self,
+        params: &mut ParamsBase<S, D, A>,
+        grad: &ParamsBase<T, D, A>,
+        state: &mut Self::State,
+    ) -> crate::Result<()>
+    where
+        S: DataMut<Elem = A>,
+        T: Data<Elem = A>;
+}
@@ -5,10 +5,15 @@
 */
 //! Additional utilities for creating, manipulating, and managing tensors and models.
 #[doc(inline)]
+#[cfg(feature = "std")]
+pub use self::fs::*;
+#[doc(inline)]
 pub use self::{arith::*, dropout::*, gradient::*, norm::*, pad::*, patterns::*, tensor::*};
 
 mod arith;
 mod dropout;
+#[cfg(feature = "std")]
+mod fs;
 mod gradient;
 mod norm;
 mod pad;
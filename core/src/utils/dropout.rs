@@ -4,7 +4,8 @@
     Contrib: @FL03
 */
 
-/// [Dropout] randomly zeroizes elements with a given probability (`p`).
+/// [Dropout] randomly zeroizes elements with a given probability (`p`), scaling the remaining
+/// elements by `1 / (1 - p)` so that their expected value is preserved.
 pub trait DropOut {
     type Output;
 
@@ -14,6 +15,12 @@ pub trait DropOut {
 /// The [Dropout] layer is randomly zeroizes inputs with a given probability (`p`).
 /// This regularization technique is often used to prevent overfitting.
 ///
+/// When executed within a [`RawContext`](crate::nn::RawContext), using the
+/// [`ForwardWith`](crate::nn::ForwardWith) trait, the layer only drops elements while
+/// training, scaling the remaining elements by `1 / (1 - p)` so that their expected value is
+/// preserved, and passes its input through unchanged when evaluating. Without a context, i.e.
+/// using the [`Forward`](concision_traits::Forward) trait, the layer runs in
+/// [`Mode::Train`](crate::nn::Mode::Train).
 ///
 /// ### Config
 ///
@@ -33,6 +40,7 @@ impl Dropout {
         (1f64 - self.p).recip()
     }
 
+    /// apply the dropout to the given input as if training; see [`DropOut`]
     pub fn forward<U>(&self, input: &U) -> Option<<U as DropOut>::Output>
     where
         U: DropOut,
@@ -50,27 +58,23 @@ impl Default for Dropout {
 #[cfg(feature = "rand")]
 mod impl_rand {
     use super::*;
+    use crate::nn::{ForwardWith, Mode, RawContext};
     use concision_init::NdRandom;
+    use concision_init::rand_distr::Bernoulli;
     use concision_traits::Forward;
-    use ndarray::{Array, ArrayBase, DataOwned, Dimension, ScalarOperand};
-    use num_traits::Num;
+    use ndarray::{Array, ArrayBase, Data, Dimension, ScalarOperand};
+    use num_traits::Float;
 
     impl<A, S, D> DropOut for ArrayBase<S, D, A>
     where
-        A: Num + ScalarOperand,
+        A: Float + ScalarOperand,
         D: Dimension,
-        S: DataOwned<Elem = A>,
+        S: Data<Elem = A>,
     {
         type Output = Array<A, D>;
 
         fn dropout(&self, p: f64) -> Self::Output {
-            let dim = self.dim();
-            // Create a mask of the same shape as the input array
-            let mask: Array<bool, D> = Array::bernoulli(dim, p).expect("Failed to create mask");
-            let mask = mask.mapv(|x| if x { A::zero() } else { A::one() });
-
-            // Element-wise multiplication to apply dropout
-            self.to_owned() * mask
+            Dropout::new(p).forward_with(self, &mut Mode::Train)
         }
     }
 
@@ -79,16 +83,47 @@ mod impl_rand {
         U: DropOut,
     {
         type Output = <U as DropOut>::Output;
-
+        /// the forward pass of the layer in [`Mode::Train`]
         fn forward(&self, input: &U) -> Self::Output {
             input.dropout(self.p)
         }
     }
+
+    impl<A, S, D, C> ForwardWith<ArrayBase<S, D, A>, C> for Dropout
+    where
+        A: Float + ScalarOperand,
+        C: RawContext,
+        D: Dimension,
+        S: Data<Elem = A>,
+    {
+        type Output = Array<A, D>;
+
+        fn forward_with(&self, input: &ArrayBase<S, D, A>, ctx: &mut C) -> Self::Output {
+            if ctx.is_eval() || self.p <= 0.0 {
+                return input.to_owned();
+            }
+            if self.p >= 1.0 {
+                return Array::zeros(input.raw_dim());
+            }
+            let distr = Bernoulli::new(self.p).expect("invalid dropout probability");
+            let mask: Array<bool, D> = match ctx.rng() {
+                Some(rng) => Array::rand_with(input.raw_dim(), distr, rng),
+                None => Array::rand(input.raw_dim(), distr),
+            };
+            let scale = A::from(self.scale()).unwrap();
+            let mut output = input.to_owned();
+            output.zip_mut_with(&mask, |x, &drop| {
+                *x = if drop { A::zero() } else { *x * scale }
+            });
+            output
+        }
+    }
 }
 
 #[cfg(all(test, feature = "rand"))]
 mod tests {
     use super::*;
+    use concision_traits::Forward;
     use ndarray::Array2;
 
     #[test]
@@ -100,5 +135,11 @@ mod tests {
 
         assert!(arr.iter().all(|&x| x == 1.0));
         assert!(out.iter().any(|x| x == &0f64));
+        // the surviving elements are rescaled to preserve the expected value
+        assert!(out.iter().all(|&x| x == 0.0 || x == 2.0));
+        // the trait-based forward pass also runs in training mode
+        let out = Forward::forward(&dropout, &arr);
+        assert!(out.iter().all(|&x| x == 0.0 || x == 2.0));
+        assert!(out.iter().any(|x| x == &0f64));
     }
 }
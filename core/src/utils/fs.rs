@@ -0,0 +1,60 @@
+/*
+    Appellation: fs <module>
+    Created At: 2026.10.18:23:52:14
+    Contrib: @FL03
+*/
+use core::sync::atomic::{AtomicUsize, Ordering};
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::path::Path;
+
+/// a process-wide counter used to give every temporary file a unique name
+static TMP_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+/// _atomically_ write the bytes to the given path; the bytes are first written (and synced)
+/// to a uniquely named temporary file alongside the destination which is then renamed over
+/// it, ensuring that the destination is either left untouched or entirely replaced. The
+/// parent directory is synced once the file has been renamed, making the write durable.
+pub fn write_atomic<P>(path: P, bytes: &[u8]) -> crate::Result<()>
+where
+    P: AsRef<Path>,
+{
+    let path = path.as_ref();
+    let name = path
+        .file_name()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "the path must name a file"))?;
+    // the process id and counter prevent concurrent writers from sharing a temporary file
+    let mut tmp_name = std::ffi::OsString::from(".");
+    tmp_name.push(name);
+    tmp_name.push(format!(
+        ".{}.{}.tmp",
+        std::process::id(),
+        TMP_COUNTER.fetch_add(1, Ordering::Relaxed)
+    ));
+    let tmp = path.with_file_name(tmp_name);
+    let res = File::create_new(&tmp).and_then(|mut file| {
+        file.write_all(bytes)?;
+        file.sync_all()?;
+        fs::rename(&tmp, path)
+    });
+    if res.is_err() {
+        let _ = fs::remove_file(&tmp);
+    }
+    res?;
+    sync_parent(path).map_err(Into::into)
+}
+
+/// sync the directory containing the given path, persisting any renames within it
+#[cfg(unix)]
+fn sync_parent(path: &Path) -> io::Result<()> {
+    match path.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => File::open(dir)?.sync_all(),
+        _ => File::open(".")?.sync_all(),
+    }
+}
+
+/// directories cannot be opened (and synced) on every platform; the rename is relied upon
+#[cfg(not(unix))]
+fn sync_parent(_path: &Path) -> io::Result<()> {
+    Ok(())
+}
@@ -27,9 +27,13 @@ where
     S: Data<Elem = A>,
 {
     let eps = A::from(eps).unwrap();
-    let mean = x.mean_axis(axis).unwrap();
-    let var = x.var_axis(axis, A::zero());
-    let inv_std = var.mapv(|v| (v + eps).recip().sqrt());
-
-    (x - &mean) * &inv_std
+    let mut out = x.to_owned();
+    // normalize each lane along the axis independently
+    for mut lane in out.lanes_mut(axis) {
+        let mean = lane.mean().unwrap_or_else(A::zero);
+        let var = lane.var(A::zero());
+        let inv_std = (var + eps).recip().sqrt();
+        lane.mapv_inplace(|xi| (xi - mean) * inv_std);
+    }
+    out
 }
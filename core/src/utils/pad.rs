@@ -6,6 +6,12 @@ mod impl_pad;
 mod impl_pad_mode;
 mod impl_padding;
 
+#[doc(inline)]
+pub use self::impl_pad::{pad, pad_gradient};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
 /// The [`Pad`] trait defines a padding operation for tensors.
 pub trait Pad<T> {
     type Output;
@@ -13,6 +19,15 @@ pub trait Pad<T> {
     fn pad(&self, mode: PadMode<T>, pad: &[[usize; 2]]) -> Self::Output;
 }
 
+/// The [`Padding`] configures how an operation (e.g. a convolution) pads its input; either
+/// `padding` elements are added to both sides of each axis or, when provided, the explicit
+/// per-axis `pad` widths are used.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
 pub struct Padding<T> {
     pub(crate) action: PadAction,
     pub(crate) mode: PadMode<T>,
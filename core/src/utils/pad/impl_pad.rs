@@ -3,11 +3,15 @@
     Created At: 2025.11.26:16:12:28
     Contrib: @FL03
 */
-use super::{Pad, PadAction, PadMode};
-use concision_traits::ArrayLike;
-use ndarray::{Array, ArrayBase, AxisDescription, DataOwned, Dimension, Slice};
+use super::{Pad, PadMode};
+use ndarray::{
+    Array, ArrayBase, ArrayViewMut1, Axis, AxisDescription, Data, Dimension, Slice, Zip,
+};
 use num_traits::{FromPrimitive, Num};
 
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
 fn reader(ndim: usize, pad: &[[usize; 2]]) -> Option<Vec<[usize; 2]>> {
     debug_assert!(pad.len() == ndim, "Inconsistent dimensions for padding");
     if pad.len() != ndim {
@@ -16,6 +20,96 @@ fn reader(ndim: usize, pad: &[[usize; 2]]) -> Option<Vec<[usize; 2]>> {
     Some(pad.to_vec())
 }
 
+/// returns the position, relative to the original data, of the element used to fill the
+/// padded position `index` of some lane whose original `len` elements begin at `offset`.
+/// Returns `None` for modes that do not copy an existing element (e.g. [`PadMode::Constant`])
+pub(crate) fn source_index<A>(
+    mode: &PadMode<A>,
+    index: usize,
+    offset: usize,
+    len: usize,
+) -> Option<usize> {
+    if len == 0 {
+        return None;
+    }
+    let k = index as isize - offset as isize;
+    let n = len as isize;
+    let pos = match mode {
+        PadMode::Edge => k.clamp(0, n - 1),
+        PadMode::Wrap => k.rem_euclid(n),
+        PadMode::Reflect if n == 1 => 0,
+        PadMode::Reflect => {
+            let k = k.rem_euclid(2 * n - 2);
+            if k < n { k } else { 2 * n - 2 - k }
+        }
+        PadMode::Symmetric => {
+            let k = k.rem_euclid(2 * n);
+            if k < n { k } else { 2 * n - 1 - k }
+        }
+        _ => return None,
+    };
+    Some(pos as usize)
+}
+
+/// compute the statistic used by the statistical padding modes over the given values
+fn statistic<A>(mode: &PadMode<A>, values: &[A]) -> Option<A>
+where
+    A: Copy + FromPrimitive + Num + PartialOrd,
+{
+    let (&first, rest) = values.split_first()?;
+    let value = match mode {
+        PadMode::Maximum => rest.iter().fold(first, |m, &x| if x > m { x } else { m }),
+        PadMode::Minimum => rest.iter().fold(first, |m, &x| if x < m { x } else { m }),
+        PadMode::Mean => {
+            let sum = rest.iter().fold(first, |acc, &x| acc + x);
+            sum / A::from_usize(values.len())?
+        }
+        PadMode::Median => {
+            let mut sorted = values.to_vec();
+            sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(core::cmp::Ordering::Equal));
+            let mid = sorted.len() / 2;
+            if sorted.len() % 2 == 0 {
+                (sorted[mid - 1] + sorted[mid]) / A::from_u8(2)?
+            } else {
+                sorted[mid]
+            }
+        }
+        // the most frequent value, preferring the smallest in the event of a tie
+        PadMode::Mode => {
+            let count = |v: A| values.iter().filter(|&&x| x == v).count();
+            values.iter().fold(first, |best, &x| {
+                let (cx, cb) = (count(x), count(best));
+                if cx > cb || (cx == cb && x < best) {
+                    x
+                } else {
+                    best
+                }
+            })
+        }
+        _ => return None,
+    };
+    Some(value)
+}
+
+/// fill the padded regions of a single lane whose original elements occupy `lo..lo + len`
+fn fill_lane<A>(mut lane: ArrayViewMut1<'_, A>, lo: usize, len: usize, mode: &PadMode<A>)
+where
+    A: Copy + FromPrimitive + Num + PartialOrd,
+{
+    let total = lane.len();
+    let padded = (0..lo).chain(lo + len..total);
+    let values = lane.slice(ndarray::s![lo..lo + len]).to_vec();
+    if let Some(value) = statistic(mode, &values) {
+        padded.for_each(|i| lane[i] = value);
+        return;
+    }
+    for i in padded {
+        if let Some(src) = source_index(mode, i, lo, len) {
+            lane[i] = lane[lo + src];
+        }
+    }
+}
+
 fn apply_padding<A, S, D>(
     data: &ArrayBase<S, D, A>,
     pad: &[[usize; 2]],
@@ -23,9 +117,9 @@ fn apply_padding<A, S, D>(
     output: &mut Array<A, D>,
 ) -> Option<bool>
 where
-    A: Copy + FromPrimitive + Num,
+    A: Copy + FromPrimitive + Num + PartialOrd,
     D: Dimension,
-    S: DataOwned<Elem = A>,
+    S: Data<Elem = A>,
 {
     let pad = reader(data.ndim(), pad)?;
 
@@ -38,13 +132,28 @@ where
         })
         .assign(data);
 
-    match mode.into_pad_action() {
-        PadAction::StopAfterCopy => {
-            // Do nothing
-            Some(true)
+    if mode.is_constant() {
+        return Some(true);
+    }
+    // pad each axis in turn; the lanes of each axis are restricted to the original data along
+    // the axes that have yet to be padded, so the corners are filled using the padded values
+    // of the preceding axes
+    for (ax, &[lo, _]) in pad.iter().enumerate() {
+        let len = data.len_of(Axis(ax));
+        let mut region = output.slice_each_axis_mut(|ad| {
+            let AxisDescription { axis, len, .. } = ad;
+            let [a, b] = pad[axis.index()];
+            if axis.index() > ax {
+                Slice::from(a..len - b)
+            } else {
+                Slice::from(..)
+            }
+        });
+        for lane in region.lanes_mut(Axis(ax)) {
+            fill_lane(lane, lo, len, &mode);
         }
-        _ => unimplemented!(),
     }
+    Some(true)
 }
 
 pub fn pad<A, S, D>(
@@ -53,9 +162,9 @@ pub fn pad<A, S, D>(
     mode: PadMode<A>,
 ) -> Array<A, D>
 where
-    A: Copy + FromPrimitive + Num,
+    A: Copy + FromPrimitive + Num + PartialOrd,
     D: Dimension,
-    S: DataOwned<Elem = A>,
+    S: Data<Elem = A>,
 {
     let pad = reader(data.ndim(), padding).expect("Inconsistent dimensions for padding");
     let mut dim = data.raw_dim();
@@ -63,16 +172,55 @@ where
         dim[ax] = ax_len + pad[0] + pad[1];
     }
 
-    let mut padded = data.array_like(dim, mode.init()).to_owned();
+    let mut padded = Array::from_elem(dim, mode.init());
     apply_padding(data, &pad, mode, &mut padded).expect("Failed to apply padding");
     padded
 }
 
+/// The adjoint of [`pad`]; given the gradient w.r.t. a padded array, compute the gradient
+/// w.r.t. the original array by accumulating the gradient of each padded element onto the
+/// element it was copied from.
+///
+/// The values produced by the statistical modes (e.g. [`PadMode::Mean`]) are treated as
+/// constants, hence their gradients are discarded along with those of [`PadMode::Constant`].
+pub fn pad_gradient<A, S, D>(
+    grad: &ArrayBase<S, D, A>,
+    padding: &[[usize; 2]],
+    mode: &PadMode<A>,
+) -> Array<A, D>
+where
+    A: Copy + Num,
+    D: Dimension,
+    S: Data<Elem = A>,
+{
+    let pad = reader(grad.ndim(), padding).expect("Inconsistent dimensions for padding");
+    let mut current = grad.to_owned();
+    // undo the padding of each axis in the reverse order in which it was applied
+    for (ax, &[lo, hi]) in pad.iter().enumerate().rev() {
+        let total = current.len_of(Axis(ax));
+        let len = total - lo - hi;
+        let mut next = current
+            .slice_axis(Axis(ax), Slice::from(lo..lo + len))
+            .to_owned();
+        Zip::from(next.lanes_mut(Axis(ax)))
+            .and(current.lanes(Axis(ax)))
+            .for_each(|mut dst, src| {
+                for i in (0..lo).chain(lo + len..total) {
+                    if let Some(j) = source_index(mode, i, lo, len) {
+                        dst[j] = dst[j] + src[i];
+                    }
+                }
+            });
+        current = next;
+    }
+    current
+}
+
 impl<A, S, D> Pad<A> for ArrayBase<S, D, A>
 where
-    A: Copy + FromPrimitive + Num,
+    A: Copy + FromPrimitive + Num + PartialOrd,
     D: Dimension,
-    S: DataOwned<Elem = A>,
+    S: Data<Elem = A>,
 {
     type Output = Array<A, D>;
 
@@ -4,6 +4,9 @@
 */
 use super::{PadAction, PadMode, Padding};
 
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+
 impl<T> Default for Padding<T> {
     fn default() -> Self {
         Self::new()
@@ -20,10 +23,49 @@ impl<T> Padding<T> {
         }
     }
 
+    /// returns a new instance padding both sides of each axis with `padding` elements using
+    /// the given mode
+    pub fn from_mode(mode: PadMode<T>, padding: usize) -> Self {
+        Self::new().with_mode(mode).with_padding(padding)
+    }
+    /// returns a new instance padding both sides of each axis with `padding` zeros
+    pub fn zeros(padding: usize) -> Self
+    where
+        T: num_traits::Zero,
+    {
+        Self::from_mode(PadMode::Constant(T::zero()), padding)
+    }
+
+    pub const fn action(&self) -> PadAction {
+        self.action
+    }
+
+    pub const fn mode(&self) -> &PadMode<T> {
+        &self.mode
+    }
+
     pub fn pad(&self) -> &[[usize; 2]] {
         &self.pad
     }
 
+    pub const fn padding(&self) -> usize {
+        self.padding
+    }
+    /// returns the widths used to pad each of the `ndim` axes; the explicit widths when
+    /// provided, otherwise `padding` on either side of every axis
+    pub fn pad_width(&self, ndim: usize) -> Vec<[usize; 2]> {
+        if self.pad.is_empty() {
+            vec![[self.padding; 2]; ndim]
+        } else {
+            assert_eq!(
+                self.pad.len(),
+                ndim,
+                "the explicit padding must provide the widths of each axis"
+            );
+            self.pad.clone()
+        }
+    }
+
     pub fn with_action(mut self, action: PadAction) -> Self {
         self.action = action;
         self
@@ -34,6 +76,11 @@ impl<T> Padding<T> {
         self
     }
 
+    pub fn with_pad(mut self, pad: Vec<[usize; 2]>) -> Self {
+        self.pad = pad;
+        self
+    }
+
     pub fn with_padding(mut self, padding: usize) -> Self {
         self.padding = padding;
         self
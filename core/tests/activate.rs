@@ -0,0 +1,237 @@
+/*
+    Appellation: activate <test>
+    Contrib: @FL03
+*/
+mod common;
+
+use concision_core::activate::{
+    ActivationKind, Activator, ActivatorGradient, ActivatorVjp, Elu, Gelu, GeluTanh, LeakyReLU,
+    Mish, Selu, Sigmoid, Silu, Softmax, SoftmaxActivation, SoftmaxGradient, SoftmaxLastAxis,
+    Softplus, softmax_axis, softmax_axis_vjp,
+};
+use concision_core::params::Params;
+use concision_core::{GeluLayer, LayerBase, LeakyReluLayer, StandardModelConfig};
+use ndarray::prelude::*;
+use strum::IntoEnumIterator;
+
+#[test]
+fn test_softmax_axis() {
+    let x: Array2<f64> = array![[1.0, 2.0, 3.0], [1000.0, 1000.0, 1000.0]];
+    let y = softmax_axis(&x, 1);
+    // each row should be a valid distribution, even for large inputs
+    for row in y.rows() {
+        assert!((row.sum() - 1.0).abs() < 1e-12);
+    }
+    assert!(y.iter().all(|v| v.is_finite()));
+    assert!((y[[1, 0]] - 1.0 / 3.0).abs() < 1e-12);
+    assert!(y[[0, 2]] > y[[0, 1]] && y[[0, 1]] > y[[0, 0]]);
+}
+
+/// compare the analytical gradient of an activation against a central difference
+fn check_gradient<F>(rho: F)
+where
+    F: Activator<Array1<f64>, Output = Array1<f64>>
+        + ActivatorGradient<Array1<f64>, Delta = Array1<f64>>,
+{
+    let x = Array1::linspace(-3.0, 3.0, 25) + 0.01;
+    let grad = rho.activate_gradient(x.clone());
+    // the activation is elementwise, hence its derivative is the gradient of the sum
+    common::check_gradient("x", &grad, &x, |x| rho.activate(x.clone()).sum());
+}
+
+#[test]
+fn test_activation_gradients() {
+    check_gradient(Sigmoid);
+    check_gradient(Gelu);
+    check_gradient(GeluTanh);
+    check_gradient(Silu);
+    check_gradient(LeakyReLU::new(0.2));
+    check_gradient(Elu::new(0.5));
+    check_gradient(Selu);
+    check_gradient(Softplus);
+    check_gradient(Mish);
+}
+
+#[test]
+fn test_activation_values() {
+    let x: Array1<f64> = array![-1.0, 0.0, 1.0];
+    assert_eq!(Sigmoid.activate(x.clone())[1], 0.5);
+    let gelu = Gelu.activate(x.clone());
+    assert!((gelu[2] - 0.841_344_7).abs() < 1e-6);
+    assert!(
+        (gelu - GeluTanh.activate(x.clone()))
+            .iter()
+            .all(|d| d.abs() < 1e-3)
+    );
+    assert_eq!(
+        LeakyReLU::default().activate(x.clone()),
+        array![-0.01, 0.0, 1.0]
+    );
+    assert!((Elu::default().activate(x.clone())[0] - (-1f64).exp_m1()).abs() < 1e-12);
+    assert!((Selu.activate(x.clone())[2] - 1.050_700_987_355_480_5).abs() < 1e-12);
+    // softplus remains finite for large inputs
+    assert_eq!(Softplus.activate(array![1000.0])[0], 1000.0);
+    assert!((Mish.activate(x)[2] - 0.865_098_4).abs() < 1e-6);
+}
+
+#[test]
+fn test_sigmoid_regression() {
+    let x: Array1<f64> = array![-2.0, 0.0, 0.5, 2.0];
+    let y = Sigmoid.activate(x.clone());
+    // the sigmoid of an array is the logistic function of each element...
+    let expected = x.mapv(|v| 1.0 / (1.0 + (-v).exp()));
+    assert!(y.iter().zip(&expected).all(|(a, b)| (a - b).abs() < 1e-12));
+    // ...rather than `1 / (1 + e^{signum(x)})`, which saturated to `1 / (1 + e)` for every
+    // positive input
+    let previous = x.mapv(|v| 1.0 / (1.0 + v.signum().exp()));
+    assert!((previous[2] - previous[3]).abs() < 1e-12);
+    assert!((y[3] - previous[3]).abs() > 0.5);
+    assert!((y[3] - 0.880_797_077_977_882_4).abs() < 1e-12);
+}
+
+#[test]
+fn test_activation_layers() {
+    let params = Params::<f64>::from_elem((3, 2), 0.5);
+    let layer = LeakyReluLayer::new(LeakyReLU::new(0.1), params.clone());
+    let y = layer.forward(&array![-1.0, -2.0, 0.0]);
+    assert_eq!(y, array![-0.1, -0.1]);
+    let layer = GeluLayer::from_params(params);
+    // only the bias contributes to the output for inputs of zero
+    let y = layer.forward(&array![0.0, 0.0, 0.0]);
+    assert_eq!(y, Gelu.activate(array![0.5, 0.5]));
+}
+
+#[test]
+fn test_softmax_vjp() {
+    let x: Array2<f64> = array![[0.5, -1.0, 2.0], [1.5, 0.0, -0.5]];
+    let grad: Array2<f64> = array![[1.0, 0.0, -2.0], [0.5, 3.0, 1.0]];
+    // the objective `<grad, softmax(x)>` has the vector-Jacobian product as its gradient
+    let objective = |x: &Array2<f64>, axis: Option<usize>| match axis {
+        Some(axis) => (softmax_axis(x, axis) * &grad).sum(),
+        None => (x.softmax() * &grad).sum(),
+    };
+    for axis in [None, Some(0), Some(1)] {
+        let vjp = match axis {
+            Some(axis) => x.softmax_axis_vjp(axis, &grad),
+            None => x.softmax_vjp(&grad),
+        };
+        common::check_gradient("vjp", &vjp, &x, |x| objective(x, axis));
+    }
+    // large inputs no longer overflow
+    assert!(array![1000.0, 1000.0].softmax().iter().all(|&p| p == 0.5));
+}
+
+#[test]
+fn test_softmax_activator() {
+    let x: Array2<f64> = array![[0.5, -1.0, 2.0], [1.5, 0.0, -0.5]];
+    let grad: Array2<f64> = array![[1.0, 0.0, -2.0], [0.5, 3.0, 1.0]];
+    // the softmax normalizes every element of the array
+    let y = Softmax.activate(x.view());
+    assert_eq!(y, x.softmax());
+    assert!((y.sum() - 1.0).abs() < 1e-12);
+    assert_eq!(
+        Softmax.activate_gradient(x.view()),
+        &y * &y.mapv(|s| 1.0 - s)
+    );
+    assert_eq!(Softmax.activate_vjp(x.view(), &grad), x.softmax_vjp(&grad));
+    // whereas the last-axis variant normalizes each row
+    let y = SoftmaxLastAxis.activate(x.view());
+    assert_eq!(y, softmax_axis(&x, 1));
+    let expected = softmax_axis_vjp(&x, 1, &grad);
+    assert_eq!(SoftmaxLastAxis.activate_vjp(x.view(), &grad), expected);
+    assert_eq!(ActivationKind::Softmax.activate(x.view()), y);
+    assert_eq!(
+        ActivationKind::Softmax.activate_vjp(x.view(), &grad),
+        expected
+    );
+}
+
+#[test]
+fn test_activation_kind_parse() {
+    use core::str::FromStr;
+    let tests = [
+        ("linear", ActivationKind::Linear),
+        ("identity", ActivationKind::Linear),
+        ("relu", ActivationKind::Relu),
+        ("gelu_tanh", ActivationKind::GeluTanh),
+        ("swish", ActivationKind::Silu),
+        ("leaky_relu", ActivationKind::leaky_relu(0.01)),
+        ("elu", ActivationKind::elu(1.0)),
+    ];
+    for (s, kind) in tests {
+        assert_eq!(ActivationKind::from_str(s).unwrap(), kind);
+    }
+    assert_eq!(ActivationKind::Silu.to_string(), "silu");
+    assert!(ActivationKind::from_str("unknown").is_err());
+}
+
+#[test]
+fn test_activation_kind_dispatch() {
+    let x: Array1<f64> = Array1::linspace(-2.0, 2.0, 9) + 0.05;
+    let cases: [(ActivationKind, Array1<f64>, Array1<f64>); 4] = [
+        (
+            ActivationKind::Gelu,
+            Gelu.activate(x.clone()),
+            Gelu.activate_gradient(x.clone()),
+        ),
+        (
+            ActivationKind::Mish,
+            Mish.activate(x.clone()),
+            Mish.activate_gradient(x.clone()),
+        ),
+        (
+            ActivationKind::leaky_relu(0.2),
+            LeakyReLU::new(0.2).activate(x.clone()),
+            LeakyReLU::new(0.2).activate_gradient(x.clone()),
+        ),
+        (ActivationKind::Linear, x.clone(), Array1::ones(x.len())),
+    ];
+    let grad = Array1::linspace(1.0, -1.0, x.len());
+    for (kind, y, dy) in cases {
+        assert_eq!(kind.activate(x.view()), y);
+        assert_eq!(kind.activate_vjp(x.view(), &grad), dy * &grad);
+    }
+    // every variant can be applied onto single-precision inputs
+    for kind in ActivationKind::iter() {
+        let y = kind.activate(Array1::<f32>::linspace(-1.0, 1.0, 5));
+        assert!(y.iter().all(|v| v.is_finite()), "{kind}");
+    }
+}
+
+#[test]
+fn test_activation_kind_layer() {
+    let config = StandardModelConfig::<f64>::new().with_activation("relu".parse().unwrap());
+    let layer = LayerBase::from_config(&config, Params::<f64>::from_elem((2, 2), -1.0));
+    assert_eq!(layer.rho(), &ActivationKind::Relu);
+    assert_eq!(layer.forward(&array![1.0, 1.0]), array![0.0, 0.0]);
+    // without an activation the layer falls back onto a linear activation
+    let layer = LayerBase::from_config(
+        &StandardModelConfig::<f64>::new(),
+        Params::<f64>::ones((2, 2)),
+    );
+    assert_eq!(layer.forward(&array![1.0, 1.0]), array![3.0, 3.0]);
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn test_activation_kind_serde() {
+    let layer = LayerBase::new(
+        ActivationKind::elu(0.5),
+        Params::<f64>::from_elem((3, 2), 0.25),
+    );
+    let json = serde_json::to_string(&layer).unwrap();
+    assert!(json.contains(r#""rho":{"elu":{"alpha":0.5}}"#));
+    let decoded: LayerBase<ActivationKind, Params<f64>> = serde_json::from_str(&json).unwrap();
+    assert_eq!(decoded, layer);
+    // the activation of the configuration is optional
+    let config: StandardModelConfig<f64> =
+        serde_json::from_str(r#"{"batch_size":1,"epochs":2,"hyperspace":{}}"#).unwrap();
+    assert_eq!(config.activation(), None);
+    let config: StandardModelConfig<f64> =
+        serde_json::from_str(r#"{"activation":"gelu","batch_size":1,"epochs":2,"hyperspace":{}}"#)
+            .unwrap();
+    assert_eq!(config.activation(), Some(ActivationKind::Gelu));
+    let mut config = config;
+    config.activation_mut().take();
+    assert_eq!(config.activation(), None);
+}
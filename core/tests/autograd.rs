@@ -0,0 +1,181 @@
+/*
+    Appellation: autograd <test>
+    Created At: 2026.10.18:12:31:02
+    Contrib: @FL03
+*/
+mod common;
+
+use common::{TOL, assert_close, check_gradient};
+use concision_core::activate::{
+    ActivationKind, HyperbolicTangent, ReLU, SoftmaxLastAxis, softmax_axis_vjp,
+};
+use concision_core::autograd::Tape;
+use concision_core::ex::testing::sample;
+use concision_core::loss::{CrossEntropyLoss, MseLoss};
+use concision_core::optim::{Optimizer, Sgd};
+use concision_core::params::Params;
+use concision_core::{Forward, Loss};
+use ndarray::prelude::*;
+
+fn sample_params(shape: (usize, usize), seed: f64) -> Params<f64> {
+    Params::new(
+        sample((1, shape.1), seed + 0.5).remove_axis(Axis(0)),
+        sample(shape, seed),
+    )
+}
+
+/// compute the loss of a two-layer network without recording it onto a tape
+fn network_loss(p1: &Params<f64>, p2: &Params<f64>, x: &Array2<f64>, y: &Array2<f64>) -> f64 {
+    let h = (x.dot(p1.weights()) + p1.bias()).tanh();
+    let z = h.dot(p2.weights()) + p2.bias();
+    let out = z.tanh();
+    MseLoss::new().loss(&out, y)
+}
+
+#[test]
+fn test_autograd_network_gradients() {
+    let (x, y) = (sample((4, 3), 0.1), sample((4, 2), 0.9).mapv(f64::abs));
+    let (p1, p2) = (sample_params((3, 5), 0.3), sample_params((5, 2), 1.7));
+    let tape = Tape::new();
+    let (l1, l2) = (tape.track(&p1), tape.track(&p2));
+    let input = tape.constant(x.view());
+    let h = l1.forward(&input).activate(HyperbolicTangent);
+    let out = l2.forward(&h).activate(HyperbolicTangent);
+    let loss = out.loss(&MseLoss::new(), &y);
+    assert!((loss.value().sum() - network_loss(&p1, &p2, &x, &y)).abs() < TOL);
+    let grads = loss.backward().unwrap();
+    let (g1, g2) = (grads.params(&l1).unwrap(), grads.params(&l2).unwrap());
+    // compare each gradient against a numerical approximation
+    check_gradient("weights", g1.weights(), p1.weights(), |w| {
+        network_loss(&Params::new(p1.bias().clone(), w.clone()), &p2, &x, &y)
+    });
+    check_gradient("bias", g2.bias(), p2.bias(), |b| {
+        network_loss(&p1, &Params::new(b.clone(), p2.weights().clone()), &x, &y)
+    });
+}
+
+#[test]
+fn test_autograd_elementwise_ops() {
+    let tape = Tape::new();
+    let a = tape.param(array![[1.0, 2.0], [3.0, 4.0]]);
+    let b = tape.param(array![0.5, -1.0]);
+    // the bias is broadcast across the rows, hence its gradient sums over them
+    let c = ((a + b) * a - a.scale(2.0)).mean();
+    let grads = (-c).backward().unwrap();
+    let av = array![[1.0, 2.0], [3.0, 4.0]];
+    let bv = array![0.5, -1.0];
+    // d/da mean((a + b) a - 2a) = (2a + b - 2) / 4
+    let ga = -(&av * 2.0 + &bv - 2.0) / 4.0;
+    assert_close(
+        "a",
+        &grads.get(a).unwrap().clone().into_dimensionality().unwrap(),
+        &ga,
+    );
+    let gb = -(av.sum_axis(Axis(0)) / 4.0);
+    assert_close(
+        "b",
+        &grads.get(b).unwrap().clone().into_dimensionality().unwrap(),
+        &gb,
+    );
+}
+
+#[test]
+fn test_autograd_vector_product() {
+    let tape = Tape::new();
+    let x = tape.param(array![1.0, -2.0]);
+    let w = tape.param(array![[0.5, 1.0, -1.0], [2.0, 0.0, 1.0]]);
+    let y = x.dot(w).unwrap().activate(ReLU).sum();
+    let grads = y.backward().unwrap();
+    // xW = [-3.5, 1, -3], hence only the second output survives the relu
+    assert_eq!(y.value().sum(), 1.0);
+    assert_eq!(grads.get(x).unwrap(), &array![1.0, 0.0].into_dyn());
+    assert_eq!(
+        grads.get(w).unwrap(),
+        &array![[0.0, 1.0, 0.0], [0.0, -2.0, 0.0]].into_dyn()
+    );
+}
+
+#[test]
+fn test_autograd_tracked_params() {
+    let params = sample_params((3, 2), 0.6);
+    let x = sample((4, 3), 0.2);
+    let tape = Tape::new();
+    let layer = tape.track(&params);
+    // the tracked parameters apply the forward pass of the parameters onto each sample
+    let out = layer.forward(&tape.constant(x.view())).value();
+    for (row, sample) in out.outer_iter().zip(x.rows()) {
+        assert_close("row", &row, &params.forward(&sample).into_dyn());
+    }
+    let vector = layer.forward(&tape.constant(x.row(0))).value();
+    assert_close("vector", &vector, &params.forward(&x.row(0)).into_dyn());
+    // the product of operands with more than two dimensions is an error
+    let cube = tape.constant(Array3::<f64>::zeros((2, 4, 3)));
+    assert!(layer.try_forward(&cube).is_err());
+    assert!(
+        layer
+            .try_forward(&tape.constant(Array2::<f64>::zeros((4, 2))))
+            .is_err()
+    );
+}
+
+#[test]
+fn test_autograd_softmax() {
+    let tape = Tape::new();
+    let x = tape.param(array![[0.5f64, -1.0, 2.0], [1.5, 0.0, -0.5]]);
+    // each row sums to one, so the gradient of the total must vanish
+    let grads = x.softmax(1).sum().backward().unwrap();
+    assert!(grads.get(x).unwrap().iter().all(|g| g.abs() < 1e-12));
+    // the gradient of a weighted sum matches the vector-Jacobian product
+    let w = tape.constant(array![[1.0, 0.0, -2.0], [0.5, 3.0, 1.0]]);
+    let grads = (x.softmax(1) * w).sum().backward().unwrap();
+    let expected = softmax_axis_vjp(&x.value(), 1, &w.value());
+    assert_close("x", grads.get(x).unwrap(), &expected);
+    // activating the variable with the softmax records the same operation
+    let grads = (x.activate(SoftmaxLastAxis) * w).sum().backward().unwrap();
+    assert_close("x", grads.get(x).unwrap(), &expected);
+    let grads = (x.activate(ActivationKind::Softmax) * w)
+        .sum()
+        .backward()
+        .unwrap();
+    assert_close("x", grads.get(x).unwrap(), &expected);
+}
+
+#[test]
+fn test_autograd_unused_params() {
+    let (p1, p2) = (Params::<f64>::ones((2, 2)), Params::<f64>::ones((2, 3)));
+    let tape = Tape::new();
+    let (l1, l2) = (tape.track(&p1), tape.track(&p2));
+    let loss = l1.forward(&tape.constant(array![[1.0, 1.0]])).sum();
+    let grads = loss.backward().unwrap();
+    assert_eq!(
+        grads.params(&l1).unwrap().weights(),
+        &array![[1.0, 1.0], [1.0, 1.0]]
+    );
+    // parameters that do not contribute to the output receive a gradient of zero
+    assert_eq!(grads.params(&l2).unwrap(), Params::zeros((2, 3)));
+}
+
+#[test]
+fn test_autograd_training() {
+    // learn to classify points by the sign of their first coordinate
+    let x = sample((16, 2), 0.4);
+    let labels = Array2::from_shape_fn((16, 2), |(i, j)| {
+        let positive = x[[i, 0]] > 0.0;
+        if positive == (j == 0) { 1.0 } else { 0.0 }
+    });
+    let mut params = sample_params((2, 2), 0.2);
+    let optimizer = Sgd::new(0.5);
+    let mut state = Optimizer::<f64>::init_state(&optimizer, &params);
+    let mut history = Vec::new();
+    for _ in 0..50 {
+        let tape = Tape::new();
+        let layer = tape.track(&params);
+        let loss = layer
+            .forward(&tape.constant(x.view()))
+            .loss(&CrossEntropyLoss::new(), &labels);
+        history.push(loss.value().sum());
+        let grad = loss.backward().unwrap().params(&layer).unwrap();
+        optimizer.step(&mut params, &grad, &mut state).unwrap();
+    }
+    assert!(history.last().unwrap() < &(history[0] * 0.5));
+}
@@ -0,0 +1,231 @@
+/*
+    Appellation: checkpoint <test>
+    Created At: 2026.10.18:23:19:26
+    Contrib: @FL03
+*/
+use concision_core::error::Error;
+use concision_core::models::{CHECKPOINT_FORMAT_VERSION, Checkpoint, CheckpointFormat, Dtype};
+use concision_core::{DeepModelParams, ModelFeatures, Params, StandardModelConfig};
+
+fn checkpoint() -> Checkpoint<f64> {
+    let features = ModelFeatures::custom(3, [5, 4], 2);
+    let mut params = DeepModelParams::<f64>::ones(features.clone());
+    params
+        .input_weights_mut()
+        .iter_mut()
+        .enumerate()
+        .for_each(|(i, w)| *w = (i as f64).sin());
+    params.output_bias_mut().fill(-0.25);
+    let mut config = StandardModelConfig::new();
+    config.add_parameter("learning_rate", 0.01);
+    *config.epochs_mut() = 10;
+    Checkpoint::new(features, params, config).with_step(42)
+}
+
+#[test]
+fn test_checkpoint_validate() {
+    let mut checkpoint = checkpoint();
+    assert!(checkpoint.validate().is_ok());
+    assert_eq!(checkpoint.header().dtype(), Dtype::F64);
+    assert_eq!(
+        checkpoint.header().format_version(),
+        CHECKPOINT_FORMAT_VERSION
+    );
+    assert_eq!(
+        checkpoint.header().crate_version(),
+        env!("CARGO_PKG_VERSION")
+    );
+    assert_eq!(checkpoint.step(), 42);
+    // a hidden layer whose shape disagrees with the layout is rejected
+    checkpoint.params_mut().hidden_mut()[0] = Params::zeros((5, 3));
+    match checkpoint.validate() {
+        Err(Error::InvalidCheckpointShape {
+            layer,
+            param,
+            found,
+            expected,
+        }) => {
+            assert_eq!(layer, "hidden.0");
+            assert_eq!(param, "weights");
+            assert_eq!(found, [5, 3]);
+            assert_eq!(expected, [5, 4]);
+        }
+        other => panic!("unexpected result: {other:?}"),
+    }
+    // as is a model with a different number of hidden layers
+    checkpoint.params_mut().hidden_mut().clear();
+    assert!(matches!(
+        checkpoint.validate(),
+        Err(Error::InvalidCheckpointLayers(0, 1))
+    ));
+}
+
+#[test]
+fn test_checkpoint_format_detect() {
+    assert_eq!(
+        CheckpointFormat::detect(b"CNCK\x01\x00\x00\x00"),
+        CheckpointFormat::Binary
+    );
+    assert_eq!(CheckpointFormat::detect(b"{}"), CheckpointFormat::Json);
+    assert_eq!(CheckpointFormat::Binary.feature(), "postcard");
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn test_checkpoint_json() {
+    use concision_core::optim::{Adam, AdamState, Optimizer};
+
+    let adam = Adam::new(0.01);
+    let base = checkpoint();
+    let state = core::iter::once(base.params().input())
+        .chain(base.params().hidden())
+        .chain(core::iter::once(base.params().output()))
+        .map(|layer| adam.init_state(layer))
+        .collect::<Vec<AdamState<f64>>>();
+    let checkpoint = base.with_optimizer(state);
+    let json = checkpoint
+        .to_json()
+        .expect("failed to encode the checkpoint");
+    // the element type is recorded using its stable tag rather than the name of the type
+    assert!(json.contains(r#""dtype": "F64""#));
+    let loaded = Checkpoint::<f64, Vec<AdamState<f64>>>::from_json(&json)
+        .expect("failed to decode the checkpoint");
+    assert_eq!(loaded.header(), checkpoint.header());
+    assert_eq!(loaded.step(), 42);
+    assert_eq!(loaded.config().epochs(), 10);
+    assert_eq!(loaded.config().get("learning_rate"), Some(&0.01));
+    assert_eq!(loaded.params().input(), checkpoint.params().input());
+    assert_eq!(loaded.params().output(), checkpoint.params().output());
+    assert_eq!(loaded.optimizer(), checkpoint.optimizer());
+    // the element type is verified before decoding the parameters
+    assert!(matches!(
+        Checkpoint::<f32, Vec<AdamState<f32>>>::from_json(&json),
+        Err(Error::InvalidCheckpointDtype(..))
+    ));
+    // as is the version of the format
+    let future = json.replacen(
+        &format!("\"format_version\": {CHECKPOINT_FORMAT_VERSION}"),
+        &format!("\"format_version\": {}", CHECKPOINT_FORMAT_VERSION + 1),
+        1,
+    );
+    assert!(matches!(
+        Checkpoint::<f64, Vec<AdamState<f64>>>::from_json(&future),
+        Err(Error::InvalidCheckpointVersion(v, CHECKPOINT_FORMAT_VERSION)) if v == CHECKPOINT_FORMAT_VERSION + 1
+    ));
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn test_model_params_json_owned_keys() {
+    let params = checkpoint().params().clone();
+    let same = |other: &DeepModelParams<f64>| {
+        other.input() == params.input()
+            && other.hidden() == params.hidden()
+            && other.output() == params.output()
+    };
+    // values are unable to lend their keys to the deserializer
+    let value = serde_json::to_value(&params).unwrap();
+    let decoded: DeepModelParams<f64> = serde_json::from_value(value).unwrap();
+    assert!(same(&decoded));
+    // escaped keys must be unescaped into an owned buffer
+    let json = serde_json::to_string(&params).unwrap();
+    let escaped = json.replacen("\"input\"", "\"\\u0069nput\"", 1);
+    assert_ne!(escaped, json);
+    let decoded: DeepModelParams<f64> = serde_json::from_str(&escaped).unwrap();
+    assert!(same(&decoded));
+    // unknown fields are still rejected
+    let unknown = json.replacen("\"output\"", "\"outputs\"", 1);
+    assert!(serde_json::from_str::<DeepModelParams<f64>>(&unknown).is_err());
+}
+
+#[cfg(feature = "postcard")]
+#[test]
+fn test_checkpoint_binary() {
+    let checkpoint = checkpoint();
+    let bytes = checkpoint
+        .to_bytes()
+        .expect("failed to encode the checkpoint");
+    assert!(bytes.starts_with(b"CNCK"));
+    let loaded = Checkpoint::<f64>::from_bytes(&bytes).expect("failed to decode the checkpoint");
+    assert_eq!(loaded.header(), checkpoint.header());
+    assert_eq!(loaded.step(), checkpoint.step());
+    assert_eq!(loaded.params().hidden(), checkpoint.params().hidden());
+    assert_eq!(loaded.optimizer(), None);
+    // data without the magic bytes is rejected
+    assert!(matches!(
+        Checkpoint::<f64>::from_bytes(&bytes[1..]),
+        Err(Error::InvalidCheckpoint)
+    ));
+    // as is data written using a newer version of the format
+    let mut future = bytes.clone();
+    future[4..8].copy_from_slice(&(CHECKPOINT_FORMAT_VERSION + 1).to_le_bytes());
+    assert!(matches!(
+        Checkpoint::<f64>::from_bytes(&future),
+        Err(Error::InvalidCheckpointVersion(..))
+    ));
+}
+
+/// returns the names of the temporary files left behind within the directory
+#[cfg(all(feature = "std", feature = "json"))]
+fn temp_files(dir: &std::path::Path) -> Vec<std::ffi::OsString> {
+    std::fs::read_dir(dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name())
+        .filter(|name| name.to_string_lossy().ends_with(".tmp"))
+        .collect()
+}
+
+#[cfg(all(feature = "std", feature = "json", feature = "postcard"))]
+#[test]
+fn test_checkpoint_save_load() {
+    let dir = std::env::temp_dir().join(format!("concision-checkpoint-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let checkpoint = checkpoint();
+    for (name, format) in [
+        ("model.json", CheckpointFormat::Json),
+        ("model.bin", CheckpointFormat::Binary),
+    ] {
+        let path = dir.join(name);
+        checkpoint
+            .save(&path, format)
+            .expect("failed to save the checkpoint");
+        // the temporary file is renamed over the destination
+        assert!(temp_files(&dir).is_empty());
+        let loaded = Checkpoint::<f64>::load(&path).expect("failed to load the checkpoint");
+        assert_eq!(loaded.header(), checkpoint.header());
+        assert_eq!(loaded.params().input(), checkpoint.params().input());
+    }
+    // an invalid checkpoint is never written
+    let mut invalid = checkpoint.clone();
+    invalid.params_mut().hidden_mut().clear();
+    let path = dir.join("model.json");
+    assert!(invalid.save(&path, CheckpointFormat::Json).is_err());
+    assert!(Checkpoint::<f64>::load(&path).is_ok());
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[cfg(all(feature = "std", feature = "json"))]
+#[test]
+fn test_checkpoint_save_concurrent() {
+    let dir = std::env::temp_dir().join(format!(
+        "concision-checkpoint-concurrent-{}",
+        std::process::id()
+    ));
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("model.json");
+    let checkpoint = checkpoint();
+    // concurrent saves to the same path must not clobber each other's temporary file
+    std::thread::scope(|scope| {
+        for _ in 0..8 {
+            scope.spawn(|| {
+                checkpoint
+                    .save(&path, CheckpointFormat::Json)
+                    .expect("failed to save the checkpoint")
+            });
+        }
+    });
+    let loaded = Checkpoint::<f64>::load(&path).expect("failed to load the checkpoint");
+    assert_eq!(loaded.header(), checkpoint.header());
+    assert!(temp_files(&dir).is_empty());
+    std::fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,71 @@
+/*
+    Appellation: common <module>
+    Created At: 2026.10.19:09:12:40
+    Contrib: @FL03
+*/
+//! finite-difference gradient checks shared by the integration tests
+// each test binary compiles the module, though not every binary uses each helper
+#![allow(dead_code)]
+
+use ndarray::{Array, ArrayBase, Data, Dimension};
+
+/// the step used when approximating a gradient with central differences
+pub const EPS: f64 = 1e-6;
+/// the tolerance allowed between an analytic gradient and its numerical approximation
+pub const TOL: f64 = 1e-6;
+
+/// approximate the derivative of `f` at zero using central differences, where `f` evaluates
+/// the objective after perturbing some value by the given amount
+pub fn finite_difference<F>(mut f: F) -> f64
+where
+    F: FnMut(f64) -> f64,
+{
+    (f(EPS) - f(-EPS)) / (2.0 * EPS)
+}
+
+/// approximate the gradient of the objective `f` w.r.t. each element of `x` using central
+/// differences
+pub fn numerical_gradient<D, F>(x: &Array<f64, D>, mut f: F) -> Array<f64, D>
+where
+    D: Dimension,
+    F: FnMut(&Array<f64, D>) -> f64,
+{
+    // a contiguous copy allows each element to be perturbed in logical order
+    let mut xp = x.as_standard_layout().into_owned();
+    let grad = (0..xp.len())
+        .map(|i| {
+            finite_difference(|eps| {
+                let v = xp.as_slice().unwrap()[i];
+                xp.as_slice_mut().unwrap()[i] = v + eps;
+                let y = f(&xp);
+                xp.as_slice_mut().unwrap()[i] = v;
+                y
+            })
+        })
+        .collect();
+    Array::from_shape_vec(x.raw_dim(), grad).unwrap()
+}
+
+/// assert that the analytic gradient matches the numerical approximation of the gradient of
+/// the objective `f` w.r.t. `x`, labelling any failure with the given name
+pub fn check_gradient<S, D, F>(name: &str, grad: &ArrayBase<S, D>, x: &Array<f64, D>, f: F)
+where
+    D: Dimension,
+    S: Data<Elem = f64>,
+    F: FnMut(&Array<f64, D>) -> f64,
+{
+    assert_close(name, grad, &numerical_gradient(x, f));
+}
+
+/// assert that the arrays share a shape and that each pair of elements is within [`TOL`]
+pub fn assert_close<S, T, D>(name: &str, found: &ArrayBase<S, D>, expected: &ArrayBase<T, D>)
+where
+    D: Dimension,
+    S: Data<Elem = f64>,
+    T: Data<Elem = f64>,
+{
+    assert_eq!(found.shape(), expected.shape(), "{name}: shape mismatch");
+    for ((idx, a), b) in found.indexed_iter().zip(expected.iter()) {
+        assert!((a - b).abs() < TOL, "{name}{idx:?}: {a} != {b}");
+    }
+}
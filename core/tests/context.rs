@@ -0,0 +1,141 @@
+/*
+    Appellation: context <test>
+    Created At: 2026.10.18:20:06:51
+    Contrib: @FL03
+*/
+use concision_core::nn::{BatchNorm1d, Context, ForwardWith, Mode, RawContext};
+use concision_core::{Forward, ForwardMut};
+use ndarray::prelude::*;
+
+#[test]
+fn test_context() {
+    let mut ctx = Context::default();
+    assert!(ctx.is_training());
+    assert_eq!(ctx.next_step(), 1);
+    assert_eq!(ctx.next_step(), 2);
+    assert_eq!(RawContext::step(&ctx), 2);
+    ctx.set_mode(Mode::Eval).reset_step();
+    assert!(ctx.is_eval());
+    assert_eq!(ctx.step(), 0);
+    // the unit type is a context that is always evaluating
+    assert!(().is_eval());
+    assert_eq!(Mode::from_training(true), Mode::Train);
+}
+
+#[test]
+fn test_batch_norm_with_context() {
+    let mut layer = BatchNorm1d::<f64>::new(2).with_momentum(1.0);
+    let x = array![[1.0, 10.0], [3.0, 30.0]];
+    layer.forward_mut(&x);
+    // the mode of the context takes precedence over that of the layer
+    let train = layer.forward_with(&x, &mut Context::train());
+    assert_eq!(train, layer.forward(&x));
+    let eval = layer.forward_with(&x, &mut Context::eval());
+    assert_eq!(eval, layer.clone().with_training(false).forward(&x));
+    assert_ne!(train, eval);
+}
+
+#[test]
+fn test_batch_norm_train_then_eval() {
+    let mut layer = BatchNorm1d::<f64>::new(2).with_momentum(0.5);
+    let x = array![[1.0, 10.0], [3.0, 30.0], [5.0, 50.0]];
+    let before = layer.forward_with(&x, &mut Context::eval());
+    // borrowing the layer within a training context leaves the running estimates unchanged
+    layer.forward_with(&x, &mut Context::train());
+    assert_eq!(layer.forward_with(&x, &mut Context::eval()), before);
+    // whereas the mutable pass folds the statistics of the batch into them
+    let train = layer.forward_mut_with(&x, &mut Context::train());
+    assert_eq!(train, layer.forward_with(&x, &mut Context::train()));
+    assert_eq!(layer.running_mean(), &array![1.5, 15.0]);
+    let after = layer.forward_with(&x, &mut Context::eval());
+    assert_ne!(after, before);
+    // evaluating does not update the running estimates
+    let stats = layer.clone();
+    assert_eq!(layer.forward_mut_with(&x, &mut Context::eval()), after);
+    assert_eq!(layer, stats);
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn test_dropout_with_context() {
+    use concision_core::Dropout;
+
+    let dropout = Dropout::new(0.5);
+    let x = Array2::<f64>::ones((64, 64));
+    // the input passes through unchanged when evaluating
+    assert_eq!(dropout.forward_with(&x, &mut Context::eval()), x);
+    assert_eq!(dropout.forward_with(&x, &mut ()), x);
+    // the surviving elements are scaled to preserve the expected value
+    let y = dropout.forward_with(&x, &mut Context::train().with_seed(42));
+    assert!(y.iter().all(|&v| v == 0.0 || v == 2.0));
+    assert!(y.iter().any(|&v| v == 0.0));
+    assert!((y.mean().unwrap() - 1.0).abs() < 0.1);
+    // seeding the context makes the layer reproducible
+    let z = dropout.forward_with(&x, &mut Context::train().with_seed(42));
+    assert_eq!(y, z);
+}
+
+/// a model applying dropout to the output of the sample model
+#[cfg(feature = "rand")]
+struct DropoutModel {
+    model: concision_core::ex::sample::TestModel<f64>,
+    dropout: concision_core::Dropout,
+}
+
+#[cfg(feature = "rand")]
+impl concision_core::Model<f64> for DropoutModel {
+    type Config = concision_core::StandardModelConfig<f64>;
+    type Layout = concision_core::ModelFeatures;
+
+    fn config(&self) -> &Self::Config {
+        self.model.config()
+    }
+
+    fn config_mut(&mut self) -> &mut Self::Config {
+        self.model.config_mut()
+    }
+
+    fn layout(&self) -> &Self::Layout {
+        self.model.layout()
+    }
+
+    fn params(&self) -> &concision_core::DeepModelParams<f64> {
+        self.model.params()
+    }
+
+    fn params_mut(&mut self) -> &mut concision_core::DeepModelParams<f64> {
+        self.model.params_mut()
+    }
+}
+
+#[cfg(feature = "rand")]
+impl<C: RawContext> ForwardWith<Array2<f64>, C> for DropoutModel {
+    type Output = Array2<f64>;
+
+    fn forward_with(&self, input: &Array2<f64>, ctx: &mut C) -> Self::Output {
+        let hidden = self.model.forward_with(input, ctx);
+        self.dropout.forward_with(&hidden, ctx)
+    }
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn test_model_predict_eval() {
+    use concision_core::ex::sample::TestModel;
+    use concision_core::{Dropout, Model, ModelFeatures, StandardModelConfig};
+
+    let features = ModelFeatures::deep(16, 16, 16, 2);
+    let model = DropoutModel {
+        model: TestModel::<f64>::new(StandardModelConfig::new(), features).init(),
+        dropout: Dropout::new(0.5),
+    };
+    let input = Array2::from_elem((16, 16), 0.5);
+    let expected = model.model.forward(&input);
+    // predictions are deterministic since the dropout is disabled
+    assert_eq!(model.predict_eval(&input), expected);
+    assert_eq!(model.predict_eval(&input), model.predict_eval(&input));
+    // whereas training drops (and rescales) elements of the output
+    let train = model.predict_with(&input, &mut Context::train().with_seed(7));
+    assert_ne!(train, expected);
+    assert!(train.iter().any(|&v| v == 0.0));
+}
@@ -0,0 +1,178 @@
+/*
+    Appellation: conv <test>
+    Created At: 2026.10.18:14:52:16
+    Contrib: @FL03
+*/
+mod common;
+
+use common::check_gradient;
+use concision_core::ex::testing::sample;
+use concision_core::nn::{Conv1d, Conv2d};
+use concision_core::params::Params;
+use concision_core::{Backward, Forward, PadMode, Padding};
+use ndarray::prelude::*;
+
+/// the objective used by the gradient checks; a weighted sum of the output
+fn objective<D: Dimension>(output: &Array<f64, D>, weights: &Array<f64, D>) -> f64 {
+    (output * weights).sum()
+}
+
+/// compare the analytic gradients of the layer against a numerical approximation
+macro_rules! check_gradients {
+    ($layer:expr, $x:expr) => {{
+        let (layer, x) = ($layer, $x);
+        let w = sample(layer.forward(&x).raw_dim(), 2.3);
+        let (grad, grad_x) = layer.gradients(&x, &w);
+        let mut lp = layer.clone();
+        check_gradient("kernel", grad.weights(), layer.params().weights(), |v| {
+            lp.params_mut().weights_mut().assign(v);
+            objective(&lp.forward(&x), &w)
+        });
+        let mut lp = layer.clone();
+        check_gradient("bias", grad.bias(), layer.params().bias(), |v| {
+            lp.params_mut().bias_mut().assign(v);
+            objective(&lp.forward(&x), &w)
+        });
+        check_gradient("input", &grad_x, &x, |x| objective(&layer.forward(x), &w));
+    }};
+}
+
+fn conv1d(in_channels: usize, out_channels: usize, kernel_size: usize) -> Conv1d<f64> {
+    let kernel = sample((out_channels, in_channels, kernel_size), 0.7);
+    let bias = sample((out_channels, 1), 1.1);
+    Conv1d::from_params(Params::new(bias, kernel)).unwrap()
+}
+
+fn conv2d(in_channels: usize, out_channels: usize, kernel: (usize, usize)) -> Conv2d<f64> {
+    let weights = sample((out_channels, in_channels, kernel.0, kernel.1), 0.7);
+    let bias = sample((out_channels, 1, 1), 1.1);
+    Conv2d::from_params(Params::new(bias, weights)).unwrap()
+}
+
+#[test]
+fn test_conv1d_forward() {
+    let kernel = array![[[1.0, 0.0, -1.0]]];
+    let layer = Conv1d::<f64>::from_kernel(kernel);
+    let x = array![[[1.0, 2.0, 4.0, 8.0, 16.0]]];
+    assert_eq!(layer.forward(&x), array![[[-3.0, -6.0, -12.0]]]);
+    // zero padding preserves the length of the input
+    let layer = layer.with_padding(Padding::zeros(1));
+    assert_eq!(layer.output_len(5), 5);
+    assert_eq!(layer.forward(&x), array![[[-2.0, -3.0, -6.0, -12.0, 8.0]]]);
+    // a stride of two skips every other position
+    let layer = layer.with_stride(2).unwrap();
+    assert_eq!(layer.forward(&x), array![[[-2.0, -6.0, 8.0]]]);
+}
+
+#[test]
+fn test_conv1d_dilation_and_groups() {
+    let kernel = array![[[1.0, 1.0]], [[1.0, -1.0]]];
+    let layer = Conv1d::<f64>::from_kernel(kernel)
+        .with_dilation(2)
+        .and_then(|layer| layer.with_groups(2))
+        .unwrap();
+    assert_eq!((layer.in_channels(), layer.out_channels()), (2, 2));
+    let x = array![[[1.0, 2.0, 3.0, 4.0], [0.0, 1.0, 4.0, 9.0]]];
+    // each group only sees its own channel; the dilated kernel spans three elements
+    assert_eq!(layer.forward(&x), array![[[4.0, 6.0], [-4.0, -8.0]]]);
+}
+
+#[test]
+fn test_conv_invalid_config() {
+    let mut layer = conv1d(2, 4, 3);
+    assert!(layer.set_stride(0).is_err());
+    assert!(layer.set_dilation(0).is_err());
+    assert!(layer.set_groups(0).is_err());
+    // the groups must divide the output channels
+    assert!(layer.set_groups(3).is_err());
+    assert_eq!(
+        (layer.stride(), layer.dilation(), layer.groups()),
+        (1, 1, 1)
+    );
+    let mut layer = conv2d(2, 4, (2, 2));
+    assert!(layer.set_stride((1, 0)).is_err());
+    assert!(layer.set_dilation((0, 1)).is_err());
+    assert!(conv2d(2, 4, (2, 2)).with_groups(0).is_err());
+    // the kernel may not have an axis of length zero
+    let kernel = Array3::<f64>::zeros((2, 1, 0));
+    assert!(Conv1d::from_params(Params::new(Array2::zeros((2, 1)), kernel)).is_err());
+}
+
+#[test]
+fn test_conv2d_forward() {
+    let layer = Conv2d::<f64>::from_kernel(Array4::ones((1, 1, 2, 2)));
+    let x = Array::from_shape_fn((1, 1, 3, 3), |(_, _, i, j)| (3 * i + j) as f64);
+    assert_eq!(layer.output_shape((3, 3)), (2, 2));
+    assert_eq!(layer.forward(&x), array![[[[8.0, 12.0], [20.0, 24.0]]]]);
+    // the bias of each output channel is broadcast over the spatial axes
+    let mut layer = layer;
+    layer.params_mut().bias_mut().fill(1.0);
+    assert_eq!(layer.forward(&x), array![[[[9.0, 13.0], [21.0, 25.0]]]]);
+}
+
+#[test]
+fn test_conv1d_gradients() {
+    let x = sample((2, 4, 7), 0.2);
+    check_gradients!(conv1d(4, 6, 3), x.clone());
+    check_gradients!(
+        conv1d(2, 4, 3)
+            .with_stride(2)
+            .and_then(|layer| layer.with_dilation(2))
+            .and_then(|layer| layer.with_groups(2))
+            .unwrap()
+            .with_padding(Padding::zeros(2)),
+        x.clone()
+    );
+    for mode in [
+        PadMode::Reflect,
+        PadMode::Edge,
+        PadMode::Symmetric,
+        PadMode::Wrap,
+    ] {
+        check_gradients!(
+            conv1d(4, 3, 3).with_padding(Padding::from_mode(mode, 2)),
+            x.clone()
+        );
+    }
+}
+
+#[test]
+fn test_conv2d_gradients() {
+    let x = sample((2, 4, 5, 6), 0.2);
+    check_gradients!(conv2d(4, 3, (3, 2)), x.clone());
+    check_gradients!(
+        conv2d(2, 4, (2, 3))
+            .with_stride((2, 1))
+            .and_then(|layer| layer.with_dilation((1, 2)))
+            .and_then(|layer| layer.with_groups(2))
+            .unwrap()
+            .with_padding(Padding::from_mode(PadMode::Reflect, 1)),
+        x.clone()
+    );
+    let explicit = Padding::zeros(0).with_pad(vec![[1, 0], [0, 2]]);
+    check_gradients!(conv2d(4, 2, (2, 2)).with_padding(explicit), x);
+}
+
+#[test]
+fn test_conv_backward() {
+    // following the `Backward` convention, the update adds `gamma` times the gradient
+    let mut layer = conv2d(2, 2, (2, 2));
+    let x = sample((3, 2, 4, 4), 0.5);
+    let delta = sample((3, 2, 3, 3), 0.9);
+    let (grad, _) = layer.gradients(&x, &delta);
+    let expected = layer.params().weights() - &(grad.weights() * 0.1);
+    layer.backward(&x, &delta, -0.1);
+    let diff = (layer.params().weights() - &expected).mapv(f64::abs);
+    assert!(diff.iter().all(|&d| d < 1e-12));
+    // training the layer to reproduce a fixed target reduces the error
+    let mut layer = conv1d(3, 2, 3).with_padding(Padding::zeros(1));
+    let x = sample((4, 3, 6), 0.1);
+    let target = conv1d(3, 2, 3).with_padding(Padding::zeros(1)).forward(&x) * 0.5;
+    let error = |layer: &Conv1d<f64>| (layer.forward(&x) - &target).mapv(|e| e * e).sum();
+    let initial = error(&layer);
+    for _ in 0..50 {
+        let delta = (layer.forward(&x) - &target) * 2.0;
+        layer.backward(&x, &delta, -0.01);
+    }
+    assert!(error(&layer) < initial * 0.5);
+}
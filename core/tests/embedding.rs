@@ -0,0 +1,118 @@
+/*
+    Appellation: embedding <test>
+    Created At: 2026.10.18:18:10:44
+    Contrib: @FL03
+*/
+mod common;
+
+use common::check_gradient;
+use concision_core::nn::Embedding;
+use concision_core::optim::{Adam, Sgd};
+use concision_core::{Backward, Forward};
+use ndarray::prelude::*;
+
+fn table() -> Array2<f64> {
+    array![[1.0, 2.0], [3.0, 4.0], [5.0, 6.0], [7.0, 8.0]]
+}
+
+#[test]
+fn test_embedding_forward() {
+    let layer = Embedding::new(table());
+    assert_eq!(layer.vocab_size(), 4);
+    assert_eq!(layer.embedding_dim(), 2);
+    let output = layer.forward(&array![2, 0, 2]);
+    assert_eq!(output, array![[5.0, 6.0], [1.0, 2.0], [5.0, 6.0]]);
+    // a batch of sequences produces a batch of embeddings
+    let output = layer.forward(&array![[3, 1], [0, 0]]);
+    assert_eq!(output.dim(), (2, 2, 2));
+    assert_eq!(output.slice(s![0, 0, ..]), array![7.0, 8.0]);
+    assert_eq!(layer.lookup(&[1]), array![[3.0, 4.0]]);
+    assert!(layer.check_indices(&[0, 4]).is_err());
+}
+
+#[test]
+fn test_embedding_padding_idx() {
+    let mut layer = Embedding::new(table()).with_padding_idx(1).unwrap();
+    assert_eq!(layer.table().row(1), array![0.0, 0.0]);
+    assert!(Embedding::new(table()).with_padding_idx(4).is_err());
+    // the padding index never receives a gradient
+    let input = array![1, 2, 1];
+    let delta = Array2::ones((3, 2));
+    let grad = layer.gradient(&input, &delta);
+    assert_eq!(grad.indices(), &[2]);
+    layer.backward(&input, &delta, -0.5);
+    assert_eq!(layer.table().row(1), array![0.0, 0.0]);
+    assert_eq!(layer.table().row(2), array![4.5, 5.5]);
+}
+
+#[test]
+fn test_embedding_sparse_gradient() {
+    let layer = Embedding::new(table());
+    let input = array![3, 0, 3];
+    let delta = array![[1.0, 0.0], [0.5, 0.5], [2.0, -1.0]];
+    let grad = layer.gradient(&input, &delta);
+    // repeated indices are accumulated while the rows are sorted
+    assert_eq!(grad.indices(), &[0, 3]);
+    assert_eq!(grad.values(), &array![[0.5, 0.5], [3.0, -1.0]]);
+    let dense = grad.to_dense(layer.vocab_size());
+    assert_eq!(dense.row(1), array![0.0, 0.0]);
+    assert_eq!(dense.row(3), array![3.0, -1.0]);
+}
+
+#[test]
+fn test_embedding_backward_touches_rows() {
+    let mut layer = Embedding::new(table());
+    let input = array![[0, 2]];
+    layer.backward(&input, &Array3::ones((1, 2, 2)), -1.0);
+    assert_eq!(
+        layer.table(),
+        &array![[0.0, 1.0], [3.0, 4.0], [4.0, 5.0], [7.0, 8.0]]
+    );
+}
+
+#[test]
+fn test_embedding_max_norm() {
+    let mut layer = Embedding::new(table()).with_max_norm(5.0);
+    // rows exceeding the norm are rescaled when looked up, leaving the others untouched
+    let output = layer.lookup(&[0, 1, 3]);
+    assert_eq!(output.row(0), array![1.0, 2.0]);
+    assert!((output.row(1).pow2().sum().sqrt() - 5.0).abs() < 1e-12);
+    assert!((output.row(2).pow2().sum().sqrt() - 5.0).abs() < 1e-12);
+    // the updated rows are renormalized, while the rest of the table is left as is
+    layer.backward(&array![3], &array![[0.0, 0.0]], -1.0);
+    let norm = layer.table().row(3).pow2().sum().sqrt();
+    assert!((norm - 5.0).abs() < 1e-12);
+    assert_eq!(layer.table().row(2), array![5.0, 6.0]);
+}
+
+#[test]
+fn test_embedding_max_norm_gradient() {
+    let layer = Embedding::new(table()).with_max_norm(5.0);
+    // the first row is within the norm while the others are rescaled
+    let input = array![0, 2, 3, 2];
+    let w = array![[0.3, -1.2], [0.7, 0.4], [-0.5, 1.1], [1.3, 0.2]];
+    let objective = |table: &Array2<f64>| {
+        let layer = Embedding::new(table.clone()).with_max_norm(5.0);
+        (layer.lookup(&input) * &w).sum()
+    };
+    let grad = layer.gradient(&input, &w).to_dense(layer.vocab_size());
+    check_gradient("table", &grad, &table(), objective);
+}
+
+#[test]
+fn test_embedding_optimizer_step() {
+    let mut layer = Embedding::new(table());
+    let adam = Adam::new(0.1);
+    let mut state = layer.init_state();
+    let grad = layer.gradient(&array![1, 1], &array![[1.0, -1.0], [1.0, -1.0]]);
+    layer.step(&adam, &grad, &mut state).unwrap();
+    // only the touched row allocates state and is updated
+    assert!(state[1].is_some());
+    assert!(state.iter().enumerate().all(|(i, s)| i == 1 || s.is_none()));
+    assert!((layer.table()[[1, 0]] - 2.9).abs() < 1e-6);
+    assert!((layer.table()[[1, 1]] - 4.1).abs() < 1e-6);
+    assert_eq!(layer.table().row(0), table().row(0));
+    // the state must cover every row of the table
+    let sgd = Sgd::new(0.1);
+    assert!(layer.step(&sgd, &grad, &mut Vec::new()).is_err());
+}
@@ -0,0 +1,131 @@
+/*
+    Appellation: learnable <test>
+    Created At: 2026.10.18:14:02:15
+    Contrib: @FL03
+*/
+mod common;
+
+use common::{TOL, check_gradient, finite_difference};
+use concision_core::activate::{
+    Activator, ActivatorGradient, LearnableActivator, LearnableParameters, PReLU, Silu, Swish,
+};
+use concision_core::optim::{Adam, Optimizer, Sgd};
+use concision_core::params::Params;
+use concision_core::{LayerBase, LearnableLayer};
+use ndarray::prelude::*;
+
+fn input() -> Array2<f64> {
+    array![[0.5, -1.5], [-0.25, 2.0], [-2.0, -0.75]]
+}
+
+fn upstream() -> Array2<f64> {
+    array![[1.0, -0.5], [0.25, 2.0], [-1.5, 0.75]]
+}
+
+/// the objective `<grad, f(x)>` whose gradients are the vector-Jacobian products
+fn objective<F>(rho: &F, x: &Array2<f64>) -> f64
+where
+    F: Activator<Array2<f64>, Output = Array2<f64>>,
+{
+    (rho.activate(x.clone()) * upstream()).sum()
+}
+
+fn check_input_gradient<F>(rho: &F, grad: &Array2<f64>)
+where
+    F: Activator<Array2<f64>, Output = Array2<f64>>,
+{
+    check_gradient("input", grad, &input(), |x| objective(rho, x));
+}
+
+#[test]
+fn test_prelu() {
+    let rho = PReLU::from_slopes(array![0.1, 0.5]);
+    let (x, g) = (input(), upstream());
+    // each channel is scaled by its own slope
+    let y = rho.activate(x.clone());
+    assert_eq!(y, array![[0.5, -0.75], [-0.025, 2.0], [-0.2, -0.375]]);
+    check_input_gradient(&rho, &rho.input_gradient(&x, &g));
+    assert_eq!(
+        rho.activate_gradient(x.clone()),
+        array![[1.0, 0.5], [0.1, 1.0], [0.1, 0.5]]
+    );
+    let grad = rho.slope_gradient(&x, &g);
+    check_gradient("slopes", &grad, rho.slopes(), |slopes| {
+        objective(&PReLU::from_slopes(slopes.clone()), &x)
+    });
+    // a single slope is shared by every channel
+    let shared = PReLU::<f64>::new(1);
+    assert_eq!(shared.slopes(), &array![0.25]);
+    let grad = shared.slope_gradient(&x, &g);
+    assert_eq!(grad.len(), 1);
+    assert!((grad[0] - rho.slope_gradient(&x, &g).sum()).abs() < TOL);
+}
+
+#[test]
+fn test_swish() {
+    let rho = Swish::new(1.5);
+    let (x, g) = (input(), upstream());
+    check_input_gradient(&rho, &rho.input_gradient(&x, &g));
+    let grad = rho.beta_gradient(&x, &g);
+    let expected = finite_difference(|eps| objective(&Swish::new(1.5 + eps), &x));
+    assert!((grad - expected).abs() < TOL, "{grad} != {expected}");
+    // with a beta of one, the activation is equivalent to the SiLU
+    let default = Swish::<f64>::default();
+    assert_eq!(default.beta(), 1.0);
+    let diff = default.activate(x.clone()) - Silu.activate(x.clone());
+    assert!(diff.iter().all(|d| d.abs() < 1e-12));
+}
+
+#[test]
+fn test_learnable_layer_backward() {
+    let mut layer = LayerBase::new(PReLU::from_elem(2, 0.5), Params::<f64>::zeros((2, 2)));
+    layer.params_mut().bias_mut().assign(&array![-1.0, 1.0]);
+    let x = array![1.0, 2.0];
+    let z = layer.params().forward(&x);
+    assert_eq!(layer.forward(&x), array![-0.5, 1.0]);
+    // the error of the output, i.e. the negative gradient of the loss
+    let error = array![1.0, 1.0];
+    layer.backward_learnable(&x, &z, &error, 0.1);
+    // only the slope of the first channel receives a gradient
+    assert_eq!(layer.rho().slopes(), &array![0.5 - 0.1, 0.5]);
+    assert_eq!(layer.params().bias(), &array![-1.0 + 0.05, 1.0 + 0.1]);
+}
+
+#[test]
+fn test_learnable_optimizer_step() {
+    let (x, g) = (input(), upstream());
+    // the optimizer maintains its own state (i.e. the velocity) for the slopes
+    let sgd = Sgd::new(0.1).with_momentum(0.5);
+    let mut rho = PReLU::from_slopes(array![0.1, 0.5]);
+    let mut state = rho.init_state(&sgd);
+    let grad = rho.slope_gradient(&x, &g);
+    rho.step(&sgd, &grad, &mut state).unwrap();
+    rho.step(&sgd, &grad, &mut state).unwrap();
+    let expected = array![0.1, 0.5] - &grad * (0.1 * (1.0 + 1.5));
+    for (a, b) in rho.slopes().iter().zip(expected.iter()) {
+        assert!((a - b).abs() < 1e-12, "{a} != {b}");
+    }
+    // a learnable beta is updated just like a single parameter of a layer
+    let adam = Adam::new(0.01);
+    let mut swish = Swish::new(1.5);
+    let mut state = swish.init_state(&adam);
+    let grad = swish.parameter_gradient(&x, &g);
+    assert_eq!(grad.len(), 1);
+    let mut params = Params::<f64, Ix1>::new(arr0(0.0), array![1.5]);
+    let mut expected = adam.init_state(&params);
+    for _ in 0..3 {
+        swish.step(&adam, &grad, &mut state).unwrap();
+        adam.step(
+            &mut params,
+            &Params::new(arr0(0.0), grad.clone()),
+            &mut expected,
+        )
+        .unwrap();
+    }
+    assert_eq!(swish.beta(), params.weights()[0]);
+    // the gradient must be shaped like the parameters
+    assert!(
+        rho.step(&sgd, &array![1.0], &mut rho.init_state(&sgd))
+            .is_err()
+    );
+}
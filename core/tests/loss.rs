@@ -0,0 +1,196 @@
+/*
+    Appellation: loss <test>
+    Created At: 2026.10.18:11:31:40
+    Contrib: @FL03
+*/
+mod common;
+
+use common::TOL;
+use concision_core::loss::reduction::{NoReduction, Sum};
+use concision_core::loss::{
+    BinaryCrossEntropy, CrossEntropyLoss, HingeLoss, HuberLoss, KlDivergence, MaeLoss, MseLoss,
+    SoftmaxCrossEntropy,
+};
+use concision_core::{Activator, ActivatorGradient, Loss, LossGradient};
+use ndarray::prelude::*;
+
+/// verify the analytical gradient of the loss using central differences
+fn check_gradient<L>(loss: &L, pred: &Array2<f64>, target: &Array2<f64>)
+where
+    L: Loss<Array2<f64>, Array2<f64>, Output = f64>
+        + LossGradient<Array2<f64>, Array2<f64>, Grad = Array2<f64>>,
+{
+    let grad = loss.gradient(pred, target);
+    common::check_gradient("pred", &grad, pred, |x| loss.loss(x, target));
+}
+
+fn pred() -> Array2<f64> {
+    array![[0.2, 0.7, 0.4], [0.9, 0.15, 0.55]]
+}
+
+fn target() -> Array2<f64> {
+    array![[0.0, 1.0, 0.0], [1.0, 0.0, 0.0]]
+}
+
+#[test]
+fn test_regression_losses() {
+    let (p, y) = (
+        array![[1.0, 2.0], [3.0, 5.0]],
+        array![[1.5, 2.0], [1.0, 4.0]],
+    );
+    assert_eq!(MseLoss::new().loss(&p, &y), (0.25 + 0.0 + 4.0 + 1.0) / 4.0);
+    assert_eq!(MaeLoss::new().loss(&p, &y), (0.5 + 0.0 + 2.0 + 1.0) / 4.0);
+    // the huber loss is quadratic within the threshold and linear beyond it
+    assert_eq!(
+        HuberLoss::new(1.0).loss(&p, &y),
+        (0.125 + 0.0 + 1.5 + 0.5) / 4.0
+    );
+
+    check_gradient(&MseLoss::new(), &pred(), &target());
+    check_gradient(&MaeLoss::new(), &pred(), &target());
+    check_gradient(&HuberLoss::new(0.5), &(pred() * 3.0), &target());
+}
+
+#[test]
+fn test_reductions() {
+    let (p, y) = (
+        array![[1.0, 2.0], [3.0, 5.0]],
+        array![[1.5, 2.0], [1.0, 4.0]],
+    );
+    let loss = MseLoss::new().with_reduction(Sum);
+    assert_eq!(loss.loss(&p, &y), 5.25);
+    check_gradient(&loss, &pred(), &target());
+    let unreduced = MseLoss::new().with_reduction(NoReduction).loss(&p, &y);
+    assert_eq!(unreduced, array![[0.25, 0.0], [4.0, 1.0]]);
+}
+
+#[test]
+fn test_binary_cross_entropy() {
+    let loss = BinaryCrossEntropy::new();
+    let expected = -(0.7f64.ln() + 0.9f64.ln()) / 2.0;
+    assert!((loss.loss(&array![[0.7, 0.9]], &array![[1.0, 1.0]]) - expected).abs() < TOL);
+    check_gradient(&loss, &pred(), &target());
+    check_gradient(
+        &BinaryCrossEntropy::new()
+            .with_weights(array![1.0, 2.0, 0.5])
+            .unwrap(),
+        &pred(),
+        &target(),
+    );
+}
+
+#[test]
+fn test_cross_entropy_from_logits() {
+    let logits = array![[2.0, 1.0, 0.1], [0.5, 2.5, -1.0]];
+    let labels = array![[1.0, 0.0, 0.0], [0.0, 1.0, 0.0]];
+    let loss = CrossEntropyLoss::new();
+    // the gradient of the unweighted loss is `(softmax(z) - y) / n`
+    let exp = logits.mapv(f64::exp);
+    let probs = &exp / &exp.sum_axis(Axis(1)).insert_axis(Axis(1));
+    let expected = (&probs - &labels) / 2.0;
+    let grad = loss.gradient(&logits, &labels);
+    assert!(
+        grad.iter()
+            .zip(expected.iter())
+            .all(|(a, b)| (a - b).abs() < TOL)
+    );
+    let value = -(probs[[0, 0]].ln() + probs[[1, 1]].ln()) / 2.0;
+    assert!((loss.loss(&logits, &labels) - value).abs() < TOL);
+    check_gradient(&loss, &logits, &labels);
+    // the loss remains finite for very large logits
+    let large = array![[1000.0, 0.0, -1000.0]];
+    assert!(loss.loss(&large, &array![[0.0, 0.0, 1.0]]).is_finite());
+    // the class weights scale the contribution of each sample
+    let weighted = CrossEntropyLoss::new()
+        .with_weights(array![2.0, 0.5, 1.0])
+        .unwrap();
+    check_gradient(&weighted, &logits, &labels);
+    let unreduced = weighted
+        .clone()
+        .with_reduction(NoReduction)
+        .loss(&logits, &labels);
+    assert!((unreduced[0] + 2.0 * probs[[0, 0]].ln()).abs() < TOL);
+    assert!((unreduced[1] + 0.5 * probs[[1, 1]].ln()).abs() < TOL);
+    // the mean of the weighted loss is normalized by the total weight of the samples
+    let expected = unreduced.sum() / 2.5;
+    assert!((weighted.loss(&logits, &labels) - expected).abs() < TOL);
+}
+
+#[test]
+fn test_invalid_loss_weights() {
+    assert!(CrossEntropyLoss::<f64>::new().with_weights(array![]).is_err());
+    assert!(
+        CrossEntropyLoss::new()
+            .with_weights(array![0.0, 0.0])
+            .is_err()
+    );
+    assert!(
+        BinaryCrossEntropy::new()
+            .with_weights(array![1.0, -0.5])
+            .is_err()
+    );
+    assert!(
+        SoftmaxCrossEntropy::new()
+            .with_weights(array![1.0, f64::NAN])
+            .is_err()
+    );
+    // a weight of zero is permitted so long as the total weight is positive
+    assert!(
+        BinaryCrossEntropy::new()
+            .with_weights(array![0.0, 1.0])
+            .is_ok()
+    );
+}
+
+#[test]
+fn test_softmax_cross_entropy() {
+    let logits: Array2<f64> = array![[2.0, 1.0, 0.1], [0.5, 2.5, -1.0]];
+    let labels = array![[1.0, 0.0, 0.0], [0.0, 1.0, 0.0]];
+    let fused = SoftmaxCrossEntropy::new().with_reduction(Sum);
+    // the activation produces a distribution over the last axis
+    let probs = fused.activate(logits.clone());
+    for row in probs.rows() {
+        assert!((row.sum() - 1.0).abs() < TOL);
+    }
+    // without any averaging, the gradient w.r.t. the logits is exactly `p - y`
+    let grad = fused.gradient(&logits, &labels);
+    assert!(
+        grad.iter()
+            .zip((&probs - &labels).iter())
+            .all(|(a, b)| (a - b).abs() < TOL)
+    );
+    check_gradient(&fused, &logits, &labels);
+    check_gradient(&SoftmaxCrossEntropy::new(), &logits, &labels);
+    // the error of the output layer is passed through untouched
+    let error = &labels - &probs;
+    assert_eq!(fused.activate_gradient(error.clone()), error);
+}
+
+#[test]
+fn test_kl_divergence() {
+    let p: Array2<f64> = array![[0.25, 0.25, 0.5]];
+    let loss = KlDivergence::new().with_reduction(Sum);
+    assert!(loss.loss(&p, &p).abs() < TOL);
+    let q = array![[0.5, 0.25, 0.25]];
+    let expected = 0.5 * (0.5f64 / 0.25).ln() + 0.25 * (0.25f64 / 0.5).ln();
+    assert!((loss.loss(&p, &q) - expected).abs() < TOL);
+    check_gradient(
+        &KlDivergence::new(),
+        &pred(),
+        &array![[0.2, 0.3, 0.5], [0.1, 0.0, 0.9]],
+    );
+}
+
+#[test]
+fn test_hinge_loss() {
+    let loss = HingeLoss::new().with_reduction(Sum);
+    let p = array![[2.0, 0.5, -0.5]];
+    let y = array![[1.0, 1.0, 1.0]];
+    assert_eq!(loss.loss(&p, &y), 0.0 + 0.5 + 1.5);
+    assert_eq!(loss.gradient(&p, &y), array![[0.0, -1.0, -1.0]]);
+    check_gradient(
+        &HingeLoss::new(),
+        &array![[0.3, -0.2], [1.7, 0.1]],
+        &array![[1.0, -1.0], [1.0, 1.0]],
+    );
+}
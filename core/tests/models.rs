@@ -4,7 +4,10 @@
     Contrib: @FL03
 */
 use concision_core::ex::sample::TestModel;
-use concision_core::{Model, ModelFeatures, StandardModelConfig};
+use concision_core::{
+    DeepModelParams, IntoModelFeatures, Model, ModelFeatures, ModelFormat, RawModelLayout,
+    StandardModelConfig,
+};
 use ndarray::prelude::*;
 
 #[test]
@@ -21,9 +24,71 @@ fn test_simple_model() {
     config.set_momentum(0.9);
     config.set_decay(0.0001);
     // define and initialize a new model
-    let model = TestModel::<f64>::new(config, features).init();
+    let model = TestModel::<f64>::new(config, features.clone()).init();
     // forward the input through the model
     let output = model.predict(&input);
     // verify the shape of the output
     assert_eq! { output.dim(), (features.output()) }
 }
+
+#[test]
+fn test_custom_features() {
+    let features = vec![784, 512, 256, 64, 10].into_model_features();
+    assert!(features.inner().is_custom());
+    assert_eq!(features.layers(), 2);
+    assert_eq!(features.widths(), [512, 256, 64]);
+    assert_eq!(features.dim_input(), (784, 512));
+    assert_eq!(features.dim_hidden_at(0), (512, 256));
+    assert_eq!(features.dim_hidden_at(1), (256, 64));
+    assert_eq!(features.dim_output(), (64, 10));
+    assert_eq!(features.size(), 784 * 512 + 512 * 256 + 256 * 64 + 64 * 10);
+    // the layout may be recovered from the shape of the model
+    assert_eq!(
+        ModelFeatures::from_shape_and_size(&[784, 512, 256, 64, 10], 0),
+        features
+    );
+    assert_eq!(ModelFeatures::from_layout(&features), features);
+    // uniform formats report the same width for every hidden layer
+    let deep = ModelFeatures::deep(3, 9, 1, 2);
+    assert_eq!(deep.widths(), [9, 9, 9]);
+    assert_eq!(RawModelLayout::size(&deep), deep.size());
+}
+
+#[test]
+fn test_custom_format() {
+    let mut format = ModelFormat::custom([8, 4, 2]);
+    assert_eq!(format.layers(), 2);
+    assert!(!format.is_uniform());
+    format.set_layers(3);
+    assert_eq!(format.widths(), [8, 4, 2, 2]);
+    format.set_layers(1);
+    assert_eq!(format.widths(), [8, 4]);
+    assert_eq!(format.clone().with_hidden(5).widths(), [5, 5]);
+    assert_eq!(format.to_string(), "{ widths: [8, 4] }");
+    assert!(ModelFormat::custom([]).is_layer());
+}
+
+#[test]
+fn test_custom_params() {
+    let features = ModelFeatures::custom(6, [5, 4, 3], 2);
+    let params = DeepModelParams::<f64>::zeros(features.clone());
+    assert_eq!(params.dim_input(), (6, 5));
+    assert_eq!(params.dim_hidden_at(0), (5, 4));
+    assert_eq!(params.dim_hidden_at(1), (4, 3));
+    assert_eq!(params.dim_output(), (3, 2));
+    assert_eq!(params.size(), features.size());
+    let ones = DeepModelParams::<f64>::ones(features.clone());
+    let output = ones.forward::<_, Array1<f64>>(&Array1::ones(6));
+    assert_eq!(
+        output,
+        Array1::from_elem(2, (6 * 5 * 4 * 3 + 5 * 4 * 3 + 4 * 3 + 3 + 1) as f64)
+    );
+    #[cfg(feature = "rand")]
+    {
+        let params = DeepModelParams::<f64>::glorot_uniform(features.clone());
+        assert_eq!(params.dim_hidden_at(1), (4, 3));
+        let model = TestModel::<f64>::new(StandardModelConfig::new(), features).init();
+        let output = model.predict(&Array1::linspace(0.0, 1.0, 6));
+        assert_eq!(output.dim(), 2);
+    }
+}
@@ -0,0 +1,163 @@
+/*
+    Appellation: norm <test>
+    Created At: 2026.10.18:19:24:03
+    Contrib: @FL03
+*/
+mod common;
+
+use common::check_gradient;
+use concision_core::ex::testing::sample;
+use concision_core::nn::{BatchNorm1d, BatchNorm2d, LayerNorm, RmsNorm};
+use concision_core::{Backward, Forward, ForwardMut};
+use ndarray::prelude::*;
+
+/// the objective used by the gradient checks; a weighted sum of the output
+fn objective<L, D>(layer: &L, x: &Array<f64, D>, w: &Array<f64, D>) -> f64
+where
+    D: Dimension,
+    L: Forward<Array<f64, D>, Output = Array<f64, D>>,
+{
+    (layer.forward(x) * w).sum()
+}
+
+/// compare the analytic gradients of the layer against a numerical approximation of the
+/// gradient of the objective `sum(w * layer(x))`
+macro_rules! check_gradients {
+    ($layer:expr, $x:expr) => {{
+        let (layer, x) = ($layer, $x);
+        let w = sample(layer.forward(&x).raw_dim(), 2.3);
+        let objective = |l: &_, x: &_| objective(l, x, &w);
+        let (grad, grad_x) = layer.gradients(&x, &w);
+        let mut lp = layer.clone();
+        check_gradient("gamma", &grad.gamma, layer.gamma(), |v| {
+            lp.gamma_mut().assign(v);
+            objective(&lp, &x)
+        });
+        let mut lp = layer.clone();
+        check_gradient("beta", &grad.beta, layer.beta(), |v| {
+            lp.beta_mut().assign(v);
+            objective(&lp, &x)
+        });
+        check_gradient("input", &grad_x, &x, |x| objective(&layer, x));
+    }};
+}
+
+fn affine(n: usize) -> (Array1<f64>, Array1<f64>) {
+    (sample(n, 0.5) + 1.0, sample(n, 1.3))
+}
+
+#[test]
+fn test_batch_norm_forward() {
+    let layer = BatchNorm1d::<f64>::new(2).with_eps(0.0);
+    let x = array![[1.0, 10.0], [3.0, 30.0]];
+    let y = layer.forward(&x);
+    assert_eq!(y, array![[-1.0, -1.0], [1.0, 1.0]]);
+    // each channel of the output has zero mean and unit variance
+    let x = sample((4, 3, 5), 0.1);
+    let y = BatchNorm1d::<f64>::new(3).forward(&x);
+    for c in 0..3 {
+        let channel = y.index_axis(Axis(1), c);
+        assert!(channel.mean().unwrap().abs() < 1e-12);
+        assert!((channel.var(0.0) - 1.0).abs() < 1e-3);
+    }
+}
+
+#[test]
+fn test_batch_norm_running_stats() {
+    let mut layer = BatchNorm1d::<f64>::new(2).with_momentum(0.5);
+    let x = array![[1.0, 10.0], [3.0, 30.0]];
+    let y = layer.forward_mut(&x);
+    assert_eq!(y, layer.forward(&x));
+    // the running variance uses the unbiased estimate of the variance
+    assert_eq!(layer.running_mean(), &array![1.0, 10.0]);
+    assert_eq!(layer.running_var(), &array![1.5, 100.5]);
+    // the running estimates are neither updated nor used while evaluating
+    layer.eval();
+    let y = layer.forward_mut(&x);
+    assert_eq!(layer.running_mean(), &array![1.0, 10.0]);
+    let expected = (1.0 - 1.0) / (1.5f64 + 1e-5).sqrt();
+    assert!((y[[0, 0]] - expected).abs() < 1e-12);
+    let expected = (30.0 - 10.0) / (100.5f64 + 1e-5).sqrt();
+    assert!((y[[1, 1]] - expected).abs() < 1e-12);
+    layer.reset_running_stats();
+    assert_eq!(layer.running_var(), &array![1.0, 1.0]);
+}
+
+#[test]
+fn test_batch_norm_gradients() {
+    let (gamma, beta) = affine(3);
+    let layer = BatchNorm1d::from_affine(gamma.clone(), beta.clone()).unwrap();
+    check_gradients!(layer.clone(), sample((4, 3), 0.1));
+    check_gradients!(layer.clone(), sample((2, 3, 4), 0.1));
+    check_gradients!(layer.with_training(false), sample((2, 3, 4), 0.1));
+    let layer = BatchNorm2d::from_affine(gamma, beta).unwrap();
+    check_gradients!(layer, sample((2, 3, 2, 3), 0.1));
+    assert!(BatchNorm2d::from_affine(array![1.0], array![0.0, 1.0]).is_err());
+}
+
+#[test]
+fn test_layer_norm_forward() {
+    let layer = LayerNorm::<f64>::new(3).with_eps(0.0);
+    let x = array![[1.0, 2.0, 3.0], [2.0, 2.0, 5.0]];
+    let y = layer.forward(&x);
+    for row in y.rows() {
+        assert!(row.mean().unwrap().abs() < 1e-12);
+        assert!((row.var(0.0) - 1.0).abs() < 1e-12);
+    }
+    let (gamma, beta) = (array![2.0, 2.0, 2.0], array![1.0, 1.0, 1.0]);
+    let layer = LayerNorm::from_affine(gamma, beta).unwrap().with_eps(0.0);
+    assert_eq!(layer.forward(&x), &y * 2.0 + 1.0);
+}
+
+#[test]
+fn test_rms_norm_forward() {
+    let layer = RmsNorm::<f64>::new(2).with_eps(0.0);
+    let y = layer.forward(&array![[3.0, 4.0]]);
+    let rms = (12.5f64).sqrt();
+    assert_eq!(y, array![[3.0 / rms, 4.0 / rms]]);
+}
+
+#[test]
+fn test_layer_norm_gradients() {
+    let (gamma, beta) = affine(4);
+    let layer = LayerNorm::from_affine(gamma.clone(), beta.clone()).unwrap();
+    check_gradients!(layer.clone(), sample((3, 4), 0.1));
+    check_gradients!(layer, sample((2, 3, 4), 0.1));
+    let layer = RmsNorm::from_affine(gamma, beta).unwrap();
+    check_gradients!(layer.clone(), sample((3, 4), 0.1));
+    check_gradients!(layer, sample((2, 3, 4), 0.1));
+}
+
+#[test]
+fn test_norm_backward() {
+    let x = sample((8, 4), 0.1);
+    let target = sample((8, 4), 0.9) * 2.0 + 0.5;
+    let mut layer = LayerNorm::<f64>::new(4);
+    let loss = |l: &LayerNorm<f64>| (l.forward(&x) - &target).pow2().sum();
+    let initial = loss(&layer);
+    for _ in 0..100 {
+        let delta = (layer.forward(&x) - &target) * 2.0;
+        layer.backward(&x, &delta, -0.01);
+    }
+    assert!(loss(&layer) < initial);
+    // the backward pass of the batch norm leaves the running estimates untouched
+    let mut layer = BatchNorm1d::<f64>::new(4);
+    layer.backward(&x, &Array2::ones((8, 4)), -0.1);
+    assert_eq!(layer.running_mean(), &Array1::<f64>::zeros(4));
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn test_norm_serde() {
+    let mut layer = BatchNorm2d::<f64>::new(2);
+    layer.forward_mut(&sample((2, 2, 3, 3), 0.1));
+    layer.eval();
+    let json = serde_json::to_string(&layer).unwrap();
+    let decoded: BatchNorm2d<f64> = serde_json::from_str(&json).unwrap();
+    assert_eq!(decoded, layer);
+    assert!(!decoded.is_training());
+    let layer = RmsNorm::<f64>::new(3);
+    let decoded: RmsNorm<f64> =
+        serde_json::from_str(&serde_json::to_string(&layer).unwrap()).unwrap();
+    assert_eq!(decoded, layer);
+}
@@ -0,0 +1,234 @@
+/*
+    Appellation: optim <test>
+    Created At: 2026.10.18:09:31:05
+    Contrib: @FL03
+*/
+use concision_core::StandardModelConfig;
+use concision_core::optim::{
+    Adam, AdamW, CosineAnnealing, ExponentialDecay, LinearWarmup, LrScheduler, Optimizer,
+    ReduceOnPlateau, RmsProp, Sgd, StepDecay,
+};
+use concision_core::params::Params;
+use ndarray::prelude::*;
+
+const TOL: f64 = 1e-10;
+
+/// the gradient of the loss $\frac{1}{2}\lVert\theta - c\rVert^2$ w.r.t. the parameters
+fn quadratic_grad(params: &Params<f64>, target: f64) -> Params<f64> {
+    params.mapv(|x| x - target)
+}
+
+/// run the optimizer on a simple quadratic, returning the final parameters
+fn minimize<O>(optimizer: &O, steps: usize) -> Params<f64>
+where
+    O: Optimizer<f64>,
+{
+    let mut params = Params::<f64>::ones((3, 2));
+    let mut state = optimizer.init_state(&params);
+    for _ in 0..steps {
+        let grad = quadratic_grad(&params, -2.0);
+        optimizer.step(&mut params, &grad, &mut state).unwrap();
+    }
+    params
+}
+
+fn assert_converged(params: &Params<f64>, target: f64, tol: f64) {
+    for x in params.weights().iter().chain(params.bias().iter()) {
+        assert!((x - target).abs() < tol, "{x} != {target}");
+    }
+}
+
+#[test]
+fn test_optimizers_converge() {
+    assert_converged(&minimize(&Sgd::new(0.1), 500), -2.0, 1e-6);
+    assert_converged(
+        &minimize(&Sgd::new(0.05).with_momentum(0.9), 500),
+        -2.0,
+        1e-6,
+    );
+    assert_converged(
+        &minimize(&Sgd::new(0.05).with_momentum(0.9).with_nesterov(true), 500),
+        -2.0,
+        1e-6,
+    );
+    assert_converged(&minimize(&RmsProp::new(0.01), 2000), -2.0, 1e-2);
+    assert_converged(&minimize(&Adam::new(0.1), 1000), -2.0, 1e-3);
+}
+
+#[test]
+fn test_sgd_momentum_step() {
+    let sgd = Sgd::new(0.1).with_momentum(0.5);
+    let mut params = Params::<f64>::from_weights(array![[1.0, 2.0]]);
+    let mut state = Optimizer::<f64>::init_state(&sgd, &params);
+    let grad = Params::from_weights(array![[1.0, -1.0]]);
+    sgd.step(&mut params, &grad, &mut state).unwrap();
+    // v = g; p = p - lr * v
+    assert_eq!(state.velocity.weights(), &array![[1.0, -1.0]]);
+    sgd.step(&mut params, &grad, &mut state).unwrap();
+    // v = 0.5 * g + g; p = p - lr * (g + 1.5 * g)
+    assert_eq!(state.velocity.weights(), &array![[1.5, -1.5]]);
+    let exp = array![[1.0 - 0.25, 2.0 + 0.25]];
+    for (a, b) in params.weights().iter().zip(exp.iter()) {
+        assert!((a - b).abs() < TOL);
+    }
+}
+
+#[test]
+fn test_adam_first_step() {
+    // the bias correction makes the first step roughly `lr * sign(g)`
+    let adam = Adam::new(0.01);
+    let mut params = Params::<f64>::zeros((2, 2));
+    let mut state = Optimizer::<f64>::init_state(&adam, &params);
+    let grad = Params::from_weights(array![[0.5, -3.0], [1e-3, -10.0]]);
+    adam.step(&mut params, &grad, &mut state).unwrap();
+    assert_eq!(state.step, 1);
+    let exp = array![[-0.01, 0.01], [-0.01, 0.01]];
+    for (a, b) in params.weights().iter().zip(exp.iter()) {
+        assert!((a - b).abs() < 1e-6, "{a} != {b}");
+    }
+}
+
+#[test]
+fn test_adamw_decoupled_decay() {
+    // with a zero gradient, adam leaves the parameters untouched while adamw shrinks them
+    let mut params = Params::<f64>::ones((2, 3));
+    let grad = Params::<f64>::zeros((2, 3));
+    let adamw = AdamW::new(0.1).with_weight_decay(0.5);
+    let mut state = Optimizer::<f64>::init_state(&adamw, &params);
+    adamw.step(&mut params, &grad, &mut state).unwrap();
+    assert_converged(&params, 0.95, TOL);
+
+    let adam = Adam::new(0.1);
+    let mut params = Params::<f64>::ones((2, 3));
+    let mut state = Optimizer::<f64>::init_state(&adam, &params);
+    adam.step(&mut params, &grad, &mut state).unwrap();
+    assert_converged(&params, 1.0, TOL);
+}
+
+#[test]
+fn test_optimizer_defaults() {
+    assert_eq!(Sgd::<f64>::default(), Sgd::new(0.01));
+    assert_eq!(RmsProp::<f64>::default(), RmsProp::new(0.01));
+    assert_eq!(Adam::<f64>::default(), Adam::new(0.001));
+    assert_eq!(AdamW::<f64>::default(), AdamW::new(0.001));
+    // a zero gradient must leave the parameters untouched rather than producing NaNs
+    let adam = Adam::<f64>::default();
+    let mut params = Params::<f64>::ones((2, 3));
+    let mut state = Optimizer::<f64>::init_state(&adam, &params);
+    adam.step(&mut params, &Params::zeros((2, 3)), &mut state)
+        .unwrap();
+    assert_converged(&params, 1.0, TOL);
+}
+
+#[test]
+fn test_optimizer_shape_mismatch() {
+    let sgd = Sgd::new(0.1);
+    let mut params = Params::<f64>::ones((2, 3));
+    let mut state = Optimizer::<f64>::init_state(&sgd, &params);
+    let grad = Params::<f64>::ones((3, 2));
+    assert!(sgd.step(&mut params, &grad, &mut state).is_err());
+    assert_eq!(params, Params::<f64>::ones((2, 3)));
+}
+
+#[test]
+fn test_optimizer_from_config() {
+    let mut config = StandardModelConfig::<f64>::new();
+    config.set_learning_rate(0.05);
+    config.set_momentum(0.9);
+    config.set_weight_decay(1e-4);
+    config.set_beta1(0.8);
+    config.set_epsilon(1e-6);
+
+    let sgd = Sgd::from_config(&config);
+    assert_eq!(Optimizer::<f64>::learning_rate(&sgd), 0.05);
+    assert_eq!(sgd.momentum(), 0.9);
+    assert_eq!(sgd.weight_decay(), 1e-4);
+
+    let adam = Adam::from_config(&config);
+    assert_eq!(adam.beta1(), 0.8);
+    assert_eq!(adam.beta2(), 0.999);
+    assert_eq!(adam.epsilon(), 1e-6);
+
+    let rmsprop = RmsProp::from_config(&config);
+    assert_eq!(rmsprop.momentum(), 0.9);
+    assert_eq!(rmsprop.decay(), 0.99);
+    // the weight decay of adamw falls back onto its own default when missing
+    config.remove_hyperparameter("weight_decay");
+    assert_eq!(AdamW::from_config(&config).weight_decay(), 0.01);
+}
+
+/// collect the learning rates produced by the scheduler over the given losses
+fn schedule<S: LrScheduler<f64>>(scheduler: &mut S, losses: &[f64]) -> Vec<f64> {
+    losses
+        .iter()
+        .map(|&loss| {
+            let lr = scheduler.learning_rate();
+            scheduler.step(loss);
+            lr
+        })
+        .collect()
+}
+
+fn assert_rates(found: &[f64], expected: &[f64]) {
+    assert_eq!(found.len(), expected.len());
+    for (a, b) in found.iter().zip(expected) {
+        assert!((a - b).abs() < TOL, "{found:?} != {expected:?}");
+    }
+}
+
+#[test]
+fn test_decay_schedulers() {
+    let losses = [1.0; 5];
+    let rates = schedule(&mut StepDecay::new(1.0, 0.5, 2), &losses);
+    assert_rates(&rates, &[1.0, 1.0, 0.5, 0.5, 0.25]);
+    let rates = schedule(&mut ExponentialDecay::new(1.0, 0.5), &losses);
+    assert_rates(&rates, &[1.0, 0.5, 0.25, 0.125, 0.0625]);
+}
+
+#[test]
+fn test_cosine_annealing_restarts() {
+    let mut scheduler = CosineAnnealing::new(1.0, 0.0, 2).with_mult(2);
+    let rates = schedule(&mut scheduler, &[1.0; 8]);
+    // the first cycle lasts two steps while the second lasts four
+    assert_rates(
+        &rates,
+        &[
+            1.0,
+            0.5,
+            1.0,
+            0.853_553_390_593_273_8,
+            0.5,
+            0.146_446_609_406_726_24,
+            1.0,
+            0.961_939_766_255_643_4,
+        ],
+    );
+    assert_eq!(scheduler.current_step(), 8);
+}
+
+#[test]
+fn test_linear_warmup() {
+    let mut scheduler = LinearWarmup::new(StepDecay::new(1.0, 0.5, 1), 0.25, 3);
+    let rates = schedule(&mut scheduler, &[1.0; 6]);
+    // the wrapped scheduler only advances once the warmup completes
+    assert_rates(&rates, &[0.25, 0.5, 0.75, 1.0, 0.5, 0.25]);
+}
+
+#[test]
+fn test_reduce_on_plateau() {
+    let mut scheduler = ReduceOnPlateau::new(1.0, 0.1, 1).with_min_lr(0.005);
+    let losses = [1.0, 0.5, 0.6, 0.5, 0.4, 0.4, 0.4, 0.4, 0.4];
+    let rates = schedule(&mut scheduler, &losses);
+    assert_rates(&rates, &[1.0, 1.0, 1.0, 1.0, 0.1, 0.1, 0.1, 0.01, 0.01]);
+    assert_eq!(scheduler.learning_rate(), 0.005);
+    assert_eq!(scheduler.best(), Some(&0.4));
+}
+
+#[test]
+fn test_reduce_on_plateau_negative_loss() {
+    let mut scheduler = ReduceOnPlateau::new(1.0, 0.5, 0).with_threshold(0.1);
+    // a loss above a negative best is never an improvement
+    schedule(&mut scheduler, &[-1.0, -0.95]);
+    assert_eq!(scheduler.best(), Some(&-1.0));
+    assert_eq!(scheduler.learning_rate(), 0.5);
+}
@@ -0,0 +1,110 @@
+/*
+    Appellation: pool <test>
+    Created At: 2026.10.18:15:41:33
+    Contrib: @FL03
+*/
+mod common;
+
+use common::check_gradient;
+use concision_core::ex::testing::sample;
+use concision_core::nn::{AvgPool1d, AvgPool2d, GlobalAvgPool, MaxPool1d, MaxPool2d};
+use concision_core::{Forward, PadMode, Padding};
+use ndarray::prelude::*;
+
+/// compare the gradient w.r.t. the input against a numerical approximation of the gradient
+/// of a weighted sum of the output
+macro_rules! check_gradient {
+    ($layer:expr, $x:expr) => {{
+        let (layer, x) = ($layer, $x);
+        let w = sample(layer.forward(&x).raw_dim(), 2.3);
+        let grad = layer.gradient(&x, &w);
+        check_gradient("input", &grad, &x, |x| (layer.forward(x) * &w).sum());
+    }};
+}
+
+#[test]
+fn test_max_pool1d() {
+    let layer = MaxPool1d::<f64>::new(2);
+    let x = array![[[1.0, 3.0, 2.0, 5.0, 4.0]]];
+    let (y, indices) = layer.forward_with_indices(&x);
+    // the trailing element does not fill a window and is dropped
+    assert_eq!(y, array![[[3.0, 5.0]]]);
+    assert_eq!(indices, array![[[1, 3]]]);
+    let grad = layer.gradient_with_indices(&indices, &array![[[1.0, 2.0]]], x.raw_dim());
+    assert_eq!(grad, array![[[0.0, 1.0, 0.0, 2.0, 0.0]]]);
+    // padding with negative infinity never selects a padded element
+    let layer = layer.with_padding(Padding::from_mode(PadMode::Constant(f64::NEG_INFINITY), 1));
+    assert_eq!(layer.forward(&(-x.clone())), array![[[-1.0, -2.0, -4.0]]]);
+    check_gradient!(layer, x);
+}
+
+#[test]
+fn test_max_pool2d() {
+    let layer = MaxPool2d::<f64>::new((2, 2));
+    let x = Array::from_shape_fn((1, 1, 4, 4), |(_, _, i, j)| ((4 * i + j) * 7 % 16) as f64);
+    let (y, indices) = layer.forward_with_indices(&x);
+    assert_eq!(y, array![[[[12.0, 14.0], [15.0, 13.0]]]]);
+    assert_eq!(indices, array![[[[4, 2], [9, 11]]]]);
+    check_gradient!(layer.clone(), sample((2, 3, 5, 6), 0.4));
+    check_gradient!(
+        layer.with_stride((1, 2)).unwrap(),
+        sample((2, 3, 5, 6), 0.4)
+    );
+}
+
+#[test]
+fn test_avg_pool() {
+    let layer = AvgPool1d::<f64>::new(2).with_stride(1).unwrap();
+    let x = array![[[1.0, 3.0, 2.0, 6.0]]];
+    assert_eq!(layer.forward(&x), array![[[2.0, 2.5, 4.0]]]);
+    // padded elements are included in the average
+    let layer = AvgPool1d::<f64>::new(2).with_padding(Padding::zeros(1));
+    assert_eq!(layer.forward(&x), array![[[0.5, 2.5, 3.0]]]);
+    check_gradient!(layer, sample((2, 3, 7), 0.1));
+    let layer = AvgPool2d::<f64>::new((2, 3))
+        .with_stride((1, 2))
+        .unwrap()
+        .with_padding(Padding::from_mode(PadMode::Reflect, 1));
+    check_gradient!(layer, sample((2, 2, 4, 5), 0.6));
+}
+
+#[test]
+fn test_global_avg_pool() {
+    let x = Array::from_shape_fn((2, 3, 2, 2), |(b, c, i, j)| (b * 10 + c + i * 2 + j) as f64);
+    let y = GlobalAvgPool.forward(&x);
+    assert_eq!(y, array![[1.5, 2.5, 3.5], [11.5, 12.5, 13.5]]);
+    let grad = GlobalAvgPool.gradient(&x, &Array2::ones((2, 3)));
+    assert!(grad.iter().all(|&g| g == 0.25));
+    check_gradient!(GlobalAvgPool, sample((2, 3, 5), 0.8));
+}
+
+#[test]
+fn test_pool_invalid_stride() {
+    let mut layer = MaxPool1d::<f64>::new(2);
+    assert!(layer.set_stride(0).is_err());
+    assert_eq!(layer.stride(), 2);
+    assert!(AvgPool2d::<f64>::new((2, 2)).with_stride((0, 1)).is_err());
+}
+
+#[test]
+fn test_pool_backward() {
+    use concision_core::Backward;
+    /// the pooling layers may be driven through the [`Backward`] trait like any other layer
+    fn update<L, X, Y>(mut layer: L, x: &X, delta: &Y) -> L
+    where
+        L: Backward<X, Y, Elem = f64>,
+    {
+        layer.backward(x, delta, 0.1);
+        layer
+    }
+    let x = sample((2, 3, 6), 0.2);
+    let layer = MaxPool1d::<f64>::new(2);
+    let delta = layer.forward(&x);
+    // the layers have no parameters, hence the update leaves them unchanged
+    assert_eq!(update(layer.clone(), &x, &delta), layer);
+    let layer = AvgPool1d::<f64>::new(3);
+    let delta = layer.forward(&x);
+    assert_eq!(update(layer.clone(), &x, &delta), layer);
+    let delta = GlobalAvgPool.forward(&x);
+    assert_eq!(update(GlobalAvgPool, &x, &delta), GlobalAvgPool);
+}
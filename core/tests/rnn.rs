@@ -0,0 +1,172 @@
+/*
+    Appellation: rnn <test>
+    Created At: 2026.10.18:17:12:40
+    Contrib: @FL03
+*/
+mod common;
+
+use common::check_gradient;
+use concision_core::ex::testing::sample;
+use concision_core::nn::{GruCell, LstmCell, Recurrent, RecurrentCell, RnnCell};
+use concision_core::params::Params;
+use concision_core::{Backward, Forward};
+use ndarray::prelude::*;
+
+/// returns non-trivial input and recurrent parameters for a cell with the given gates
+fn params(input_size: usize, hidden_size: usize, gates: usize) -> (Params<f64>, Params<f64>) {
+    let cols = gates * hidden_size;
+    (
+        Params::new(sample(cols, 0.3), sample((input_size, cols), 0.7)),
+        Params::new(sample(cols, 1.9), sample((hidden_size, cols), 1.1)),
+    )
+}
+
+/// a function returning a mutable reference to some parameters of a cell
+type Accessor<C> = fn(&mut C) -> &mut Params<f64>;
+
+/// the objective used by the gradient checks; a weighted sum of the output
+fn objective<C>(layer: &Recurrent<C, f64>, x: &Array3<f64>, w: &Array3<f64>) -> f64
+where
+    C: RecurrentCell<f64>,
+{
+    (layer.forward(x) * w).sum()
+}
+
+/// compare the analytic gradients of the layer against a numerical approximation
+fn check_gradients<C>(layer: &Recurrent<C, f64>, x: &Array3<f64>)
+where
+    C: RecurrentCell<f64> + Clone,
+{
+    let w = sample(layer.forward(x).raw_dim(), 2.3);
+    let (grad, grad_x) = layer.gradients(x, &w);
+    let accessors: [(Accessor<C>, &Params<f64>); 2] = [
+        (C::input_mut, grad.input()),
+        (C::recurrent_mut, grad.recurrent()),
+    ];
+    for (access, g) in accessors {
+        let mut layer = layer.clone();
+        let params = access(layer.cell_mut()).clone();
+        check_gradient("weights", g.weights(), params.weights(), |v| {
+            access(layer.cell_mut()).weights_mut().assign(v);
+            objective(&layer, x, &w)
+        });
+        access(layer.cell_mut())
+            .weights_mut()
+            .assign(params.weights());
+        check_gradient("bias", g.bias(), params.bias(), |v| {
+            access(layer.cell_mut()).bias_mut().assign(v);
+            objective(&layer, x, &w)
+        });
+    }
+    check_gradient("input", &grad_x, x, |x| objective(layer, x, &w));
+}
+
+#[test]
+fn test_rnn_cell_from_params() {
+    let (input, recurrent) = params(3, 4, 1);
+    let cell = RnnCell::from_params(input, recurrent).unwrap();
+    assert_eq!(cell.input_size(), 3);
+    assert_eq!(cell.hidden_size(), 4);
+    // the parameters of a GRU require three gates
+    let (input, recurrent) = params(3, 4, 1);
+    assert!(GruCell::from_params(input, recurrent).is_err());
+}
+
+#[test]
+fn test_recurrent_forward() {
+    let (input, recurrent) = params(3, 4, 4);
+    let layer = Recurrent::new(LstmCell::from_params(input, recurrent).unwrap());
+    let x = sample((2, 5, 3), 0.1);
+    let output = layer.forward(&x);
+    assert_eq!(output.dim(), (2, 5, 4));
+    // unrolling a single sequence is equivalent to a batch of one
+    let single = layer.forward(&x.index_axis(Axis(0), 1).to_owned());
+    assert_eq!(single, output.index_axis(Axis(0), 1));
+    // the final state matches the last hidden state
+    let (_, state) = layer.forward_with_state(&x, layer.cell().init_state(2));
+    assert_eq!(state.hidden(), output.index_axis(Axis(1), 4));
+}
+
+#[test]
+fn test_rnn_gradients() {
+    let (input, recurrent) = params(3, 4, 1);
+    let layer = Recurrent::new(RnnCell::from_params(input, recurrent).unwrap());
+    check_gradients(&layer, &sample((2, 5, 3), 0.1));
+}
+
+#[test]
+fn test_gru_gradients() {
+    let (input, recurrent) = params(3, 4, 3);
+    let layer = Recurrent::new(GruCell::from_params(input, recurrent).unwrap());
+    check_gradients(&layer, &sample((2, 5, 3), 0.1));
+}
+
+#[test]
+fn test_lstm_gradients() {
+    let (input, recurrent) = params(3, 4, 4);
+    let layer = Recurrent::new(LstmCell::from_params(input, recurrent).unwrap());
+    check_gradients(&layer, &sample((2, 5, 3), 0.1));
+}
+
+#[test]
+fn test_truncated_bptt() {
+    let (input, recurrent) = params(2, 3, 3);
+    let layer = Recurrent::new(GruCell::from_params(input, recurrent).unwrap()).with_truncation(2);
+    let x = sample((1, 4, 2), 0.1);
+    // only the final step receives a gradient, hence it cannot reach the first window
+    let mut delta = Array3::zeros((1, 4, 3));
+    delta.slice_mut(s![.., 3, ..]).fill(1.0);
+    let (_, grad_x) = layer.gradients(&x, &delta);
+    assert!(grad_x.slice(s![.., ..2, ..]).iter().all(|&g| g == 0.0));
+    assert!(grad_x.slice(s![.., 2.., ..]).iter().any(|&g| g != 0.0));
+    // without truncation the gradient propagates through every step
+    let (_, grad_x) = layer.clone().with_truncation(0).gradients(&x, &delta);
+    assert!(grad_x.slice(s![.., 0, ..]).iter().any(|&g| g != 0.0));
+}
+
+#[test]
+fn test_gradient_clipping() {
+    let (input, recurrent) = params(3, 4, 1);
+    let layer = Recurrent::new(RnnCell::from_params(input, recurrent).unwrap()).with_clip(0.1);
+    let x = sample((2, 5, 3), 0.1);
+    let (grad, _) = layer.gradients(&x, &Array3::ones((2, 5, 4)));
+    for params in [grad.input(), grad.recurrent()] {
+        let norm = params.weights().mapv(|w| w * w).sum().sqrt();
+        assert!(norm <= 0.1 + 1e-12, "the norm {norm} exceeds the threshold");
+    }
+}
+
+#[test]
+fn test_recurrent_train() {
+    let (input, recurrent) = params(1, 4, 1);
+    let mut layer = Recurrent::new(RnnCell::from_params(input, recurrent).unwrap()).with_clip(1.0);
+    // learn to drive every hidden state towards a constant target
+    let x = sample((3, 6, 1), 0.1);
+    let target = Array3::from_elem((3, 6, 4), 0.25);
+    let loss = |layer: &Recurrent<RnnCell<f64>, f64>| (layer.forward(&x) - &target).pow2().sum();
+    let initial = loss(&layer);
+    for _ in 0..50 {
+        let delta = (layer.forward(&x) - &target) * 2.0;
+        layer.backward(&x, &delta, -0.05);
+    }
+    assert!(loss(&layer) < initial / 4.0);
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn test_orthogonal_cell() {
+    let cell = LstmCell::<f64>::orthogonal(3, 4);
+    let recurrent = cell.recurrent().weights();
+    assert_eq!(recurrent.dim(), (4, 16));
+    // each gate block of the recurrent weights is orthogonal
+    for k in 0..4 {
+        let block = recurrent.slice(s![.., k * 4..(k + 1) * 4]);
+        let gram = block.t().dot(&block);
+        assert!(
+            (gram - Array2::<f64>::eye(4))
+                .iter()
+                .all(|v| v.abs() < 1e-9)
+        );
+    }
+    assert!(cell.recurrent().bias().iter().all(|&b| b == 0.0));
+}
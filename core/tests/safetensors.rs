@@ -0,0 +1,164 @@
+/*
+    Appellation: safetensors <test>
+    Created At: 2026.10.19:01:12:44
+    Contrib: @FL03
+*/
+#![cfg(feature = "safetensors")]
+use concision_core::error::Error;
+use concision_core::models::{Dtype, SafeTensors, SafeTensorsWriter};
+use concision_core::{DeepModelParams, ModelFeatures, Params};
+use ndarray::{Array1, Ix1, Ix2, Ix3};
+
+fn params(shape: (usize, usize)) -> Params<f32> {
+    Params::from_shape_fn(
+        shape,
+        |(i, j)| (i * shape.1 + j) as f32 * 0.5,
+        |j| -(j as f32),
+    )
+}
+
+fn deep() -> DeepModelParams<f64> {
+    let features = ModelFeatures::custom(4, [6, 5, 3], 2);
+    let layer = |(n, m): (usize, usize)| {
+        Params::from_shape_fn((n, m), |(i, j)| ((i * m + j) as f64).sin(), |j| j as f64)
+    };
+    let hidden = (0..features.layers())
+        .map(|i| layer(features.dim_hidden_at(i)))
+        .collect();
+    DeepModelParams::new(
+        layer(features.dim_input()),
+        hidden,
+        layer(features.dim_output()),
+    )
+}
+
+#[test]
+fn test_safetensors_params() {
+    let params = params((3, 4));
+    let bytes = SafeTensorsWriter::new()
+        .with_params("dense", &params)
+        .with_metadata("format", "pt")
+        .to_bytes()
+        .expect("failed to encode the tensors");
+    // the data begins on an 8-byte boundary
+    let len = u64::from_le_bytes(bytes[..8].try_into().unwrap()) as usize;
+    assert_eq!((8 + len) % 8, 0);
+    let tensors = SafeTensors::parse(&bytes).expect("failed to parse the tensors");
+    assert_eq!(tensors.len(), 2);
+    assert_eq!(
+        tensors.metadata().get("format").map(String::as_str),
+        Some("pt")
+    );
+    let info = tensors.tensor("dense.weights").unwrap();
+    assert_eq!(info.dtype(), Dtype::F32);
+    assert_eq!(info.shape(), [3, 4]);
+    let loaded = tensors.params::<f32, Ix2>("dense").unwrap();
+    assert_eq!(loaded, params);
+    assert_eq!(
+        tensors.array::<f32, Ix1>("dense.bias").unwrap(),
+        params.bias()
+    );
+}
+
+#[test]
+fn test_safetensors_deep_params() {
+    let model = deep();
+    let writer = model.to_safetensors();
+    let names = writer.names().collect::<Vec<_>>();
+    assert_eq!(names.len(), 2 * (model.hidden().len() + 2));
+    assert!(names.contains(&"hidden.1.weights"));
+    let bytes = writer.to_bytes().unwrap();
+    let tensors = SafeTensors::parse(&bytes).unwrap();
+    let loaded = DeepModelParams::<f64>::from_safetensors(&tensors).unwrap();
+    assert_eq!(loaded.hidden().len(), 2);
+    assert_eq!(loaded.input(), model.input());
+    assert_eq!(loaded.hidden(), model.hidden());
+    assert_eq!(loaded.output(), model.output());
+}
+
+#[test]
+fn test_safetensors_errors() {
+    let bytes = SafeTensorsWriter::new()
+        .with_params("dense", &params((2, 3)))
+        .to_bytes()
+        .unwrap();
+    let tensors = SafeTensors::parse(&bytes).unwrap();
+    assert!(matches!(
+        tensors.params::<f64, Ix2>("dense"),
+        Err(Error::InvalidTensorDtype { .. })
+    ));
+    assert!(matches!(
+        tensors.params::<f32, Ix2>("conv"),
+        Err(Error::TensorNotFound(name)) if name == "conv.weights"
+    ));
+    assert!(matches!(
+        tensors.array::<f32, Ix3>("dense.weights"),
+        Err(Error::InvalidSafeTensors(_))
+    ));
+    // truncated data is rejected when parsing
+    assert!(matches!(
+        SafeTensors::parse(&bytes[..bytes.len() - 1]),
+        Err(Error::InvalidSafeTensors(_))
+    ));
+    assert!(matches!(
+        SafeTensors::parse(&bytes[..4]),
+        Err(Error::InvalidSafeTensors(_))
+    ));
+}
+
+#[test]
+fn test_safetensors_foreign() {
+    // a file written by other tooling; neither padded nor ordered
+    let header = r#"{"b":{"dtype":"F32","shape":[2],"data_offsets":[8,16]},"w":{"dtype":"F32","shape":[1,2],"data_offsets":[0,8]},"x":{"dtype":"I64","shape":[],"data_offsets":[16,24]}}"#;
+    let mut bytes = (header.len() as u64).to_le_bytes().to_vec();
+    bytes.extend_from_slice(header.as_bytes());
+    for x in [1f32, 2.0, 3.0, 4.0] {
+        bytes.extend_from_slice(&x.to_le_bytes());
+    }
+    bytes.extend_from_slice(&7i64.to_le_bytes());
+    let tensors = SafeTensors::parse(&bytes).unwrap();
+    assert_eq!(tensors.tensor("x").unwrap().dtype(), Dtype::I64);
+    assert_eq!(
+        tensors.array::<f32, Ix1>("b").unwrap(),
+        Array1::from_vec(vec![3.0, 4.0])
+    );
+    assert_eq!(
+        tensors
+            .array::<f32, Ix2>("w")
+            .unwrap()
+            .into_raw_vec_and_offset()
+            .0,
+        [1.0, 2.0]
+    );
+}
+
+#[cfg(feature = "memmap2")]
+#[test]
+fn test_safetensors_mmap() {
+    use concision_core::models::{DeepParamsBase, mmap};
+    use ndarray::ViewRepr;
+
+    let path = std::env::temp_dir().join(format!(
+        "concision-safetensors-{}.safetensors",
+        std::process::id()
+    ));
+    let model = deep();
+    model.to_safetensors().save(&path).unwrap();
+    // SAFETY: the file is private to this test and is not modified while it is mapped
+    let buf = unsafe { mmap(&path) }.unwrap();
+    let tensors = SafeTensors::parse(&buf).unwrap();
+    let view = DeepParamsBase::<ViewRepr<&f64>, Ix2, f64>::from_safetensors_view(&tensors)
+        .expect("failed to view the model");
+    // the view borrows the mapped data rather than copying it
+    let range = buf.as_ptr_range();
+    let ptr = view.input().weights().as_ptr() as *const u8;
+    assert!(range.contains(&ptr));
+    assert_eq!(view.input(), &model.input().view());
+    assert_eq!(view.hidden()[1], model.hidden()[1].view());
+    assert_eq!(view.output().bias(), model.output().bias());
+    let params = tensors.params_view::<f64, Ix2>("hidden.0").unwrap();
+    assert_eq!(params.weights(), model.hidden()[0].weights());
+    drop(tensors);
+    drop(buf);
+    std::fs::remove_file(&path).unwrap();
+}
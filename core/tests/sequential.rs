@@ -0,0 +1,318 @@
+/*
+    Appellation: sequential <test>
+    Created At: 2026.10.18:21:18:40
+    Contrib: @FL03
+*/
+mod common;
+
+use common::check_gradient;
+use concision_core::activate::ActivationKind;
+use concision_core::ex::testing::sample;
+use concision_core::nn::{AvgPool1d, BatchNorm1d, Context, Conv1d, Flatten, LayerNorm, Sequential};
+use concision_core::params::Params;
+use concision_core::utils::Dropout;
+use concision_core::{Backward, Forward, ForwardMut};
+use ndarray::prelude::*;
+
+/// create a dense layer with the given number of input and output features
+fn dense(inputs: usize, outputs: usize, seed: f64) -> Params<f64> {
+    Params::new(sample(outputs, seed), sample((inputs, outputs), seed + 0.5))
+}
+
+/// a small convolutional network mixing each family of layers
+fn network() -> Sequential<f64> {
+    Sequential::new()
+        .with_layer(Conv1d::from_kernel(sample((3, 2, 2), 0.1)))
+        .with_layer(ActivationKind::Relu)
+        .with_layer(AvgPool1d::<f64>::new(2))
+        .with_layer(Flatten)
+        .with_layer(dense(6, 3, 0.4))
+        .with_layer(ActivationKind::Tanh)
+        .with_layer(BatchNorm1d::<f64>::new(3))
+        .with_layer(dense(3, 2, 0.8))
+        .with_layer(ActivationKind::Softmax)
+}
+
+/// returns true if the arrays are equal up to rounding
+fn close<D: Dimension>(a: &Array<f64, D>, b: &Array<f64, D>) -> bool {
+    a.shape() == b.shape() && a.iter().zip(b).all(|(x, y)| (x - y).abs() < 1e-12)
+}
+
+/// the objective used by the gradient checks; a weighted sum of the output
+fn objective(model: &Sequential<f64>, x: &Array3<f64>, w: &ArrayD<f64>) -> f64 {
+    (model.forward(x) * w).sum()
+}
+
+#[test]
+fn test_sequential_forward() {
+    let (l1, l2) = (dense(3, 4, 0.1), dense(4, 2, 0.2));
+    let model = Sequential::new()
+        .with_layer(l1.clone())
+        .with_layer(ActivationKind::Relu)
+        .with_layer(l2.clone())
+        .with_layer(ActivationKind::Sigmoid);
+    let x = sample((5, 3), 0.3);
+    let hidden = (x.dot(l1.weights()) + l1.bias()).mapv(|v| v.max(0.0));
+    let expected = (hidden.dot(l2.weights()) + l2.bias()).mapv(|v| 1.0 / (1.0 + (-v).exp()));
+    let y = model.forward(&x).into_dimensionality::<Ix2>().unwrap();
+    assert!(close(&y, &expected));
+}
+
+#[test]
+fn test_sequential_softmax() {
+    let layer = dense(3, 4, 0.6);
+    let mut model = Sequential::new()
+        .with_layer(layer.clone())
+        .with_layer(ActivationKind::Softmax);
+    let x = sample((5, 3), 0.9);
+    // the softmax normalizes each sample, rather than the entire batch
+    let z = x.dot(layer.weights()) + layer.bias();
+    let y = model.forward(&x).into_dimensionality::<Ix2>().unwrap();
+    for (row, z) in y.rows().into_iter().zip(z.rows()) {
+        let exp = z.mapv(f64::exp);
+        assert!(close(&row.to_owned(), &(&exp / exp.sum())));
+    }
+    // the gradient w.r.t. the input agrees with a numerical approximation
+    let w = sample((5, 4), 1.7).into_dyn();
+    let objective = |model: &Sequential<f64>, x: &Array2<f64>| (model.forward(x) * &w).sum();
+    let tape = model
+        .forward_tape(&x.clone().into_dyn(), &mut Context::eval())
+        .unwrap();
+    let grad = model
+        .backward_with(tape, &w, 0.0, &mut Context::eval())
+        .unwrap();
+    let grad = grad.into_dimensionality::<Ix2>().unwrap();
+    check_gradient("input", &grad, &x, |x| objective(&model, x));
+}
+
+#[test]
+fn test_sequential_introspection() {
+    let mut model = network();
+    assert_eq!(model.len(), 9);
+    assert_eq!(
+        model.names(),
+        [
+            "conv1d",
+            "activation",
+            "avg_pool1d",
+            "flatten",
+            "dense",
+            "activation",
+            "batch_norm1d",
+            "dense",
+            "activation",
+        ]
+    );
+    // conv: 3 * 2 * 2 + 3; dense: 6 * 3 + 3; norm: 3 + 3; dense: 3 * 2 + 2
+    assert_eq!(model.num_params(), 15 + 21 + 6 + 8);
+    assert!(model.downcast_ref::<Conv1d<f64>>(0).is_some());
+    assert!(model.downcast_ref::<Params<f64>>(0).is_none());
+    model
+        .downcast_mut::<Params<f64>>(4)
+        .unwrap()
+        .weights_mut()
+        .fill(0.0);
+    assert_eq!(
+        model
+            .downcast_ref::<Params<f64>>(4)
+            .unwrap()
+            .weights()
+            .sum(),
+        0.0
+    );
+    assert!(model.get(9).is_none());
+    // the layers of the container must agree on the dimensionality of their inputs
+    assert!(
+        model
+            .try_forward(&sample((4, 10), 0.0).into_dyn(), &mut Context::eval())
+            .is_err()
+    );
+}
+
+#[test]
+fn test_sequential_gradients() {
+    let mut model = network();
+    let x = sample((4, 2, 5), 0.5);
+    let w = sample(model.forward(&x).raw_dim(), 2.3);
+    // a learning rate of zero leaves the layers unchanged
+    let tape = model
+        .forward_tape(&x.clone().into_dyn(), &mut Context::eval())
+        .unwrap();
+    let grad_x = model
+        .backward_with(tape, &w, 0.0, &mut Context::eval())
+        .and_then(|g| Ok(g.into_dimensionality::<Ix3>()?))
+        .unwrap();
+    check_gradient("input", &grad_x, &x, |x| objective(&model, x, &w));
+    // a learning rate of one adds the gradient of each parameter onto it
+    let before = model.downcast_ref::<Params<f64>>(4).unwrap().clone();
+    let tape = model
+        .forward_tape(&x.clone().into_dyn(), &mut Context::eval())
+        .unwrap();
+    model
+        .backward_with(tape, &w, 1.0, &mut Context::eval())
+        .unwrap();
+    let after = model.downcast_ref::<Params<f64>>(4).unwrap().clone();
+    let grad = after.weights() - before.weights();
+    // rebuild the network, since every layer was updated by the backward pass
+    let mut model = network();
+    check_gradient("weights", &grad, before.weights(), |v| {
+        let layer = model.downcast_mut::<Params<f64>>(4).unwrap();
+        layer.weights_mut().assign(v);
+        objective(&model, &x, &w)
+    });
+}
+
+#[test]
+fn test_sequential_training() {
+    let mut model = Sequential::new()
+        .with_layer(dense(3, 8, 0.1))
+        .with_layer(ActivationKind::Tanh)
+        .with_layer(LayerNorm::<f64>::new(8))
+        .with_layer(dense(8, 1, 0.2));
+    let x = sample((16, 3), 0.7);
+    let y = x.sum_axis(Axis(1)).insert_axis(Axis(1)).mapv(f64::sin);
+    let loss = |model: &Sequential<f64>| (model.forward(&x) - &y).pow2().mean().unwrap();
+    let initial = loss(&model);
+    for _ in 0..200 {
+        let delta = (model.forward_mut(&x) - &y) * (2.0 / x.nrows() as f64);
+        model.backward(&x, &delta, -0.05);
+    }
+    assert!(loss(&model) < initial / 10.0);
+}
+
+#[test]
+fn test_sequential_dropout() {
+    let model = Sequential::new().with_layer(Dropout::new(0.5));
+    let x = sample((8, 8), 0.1);
+    // the dropout layer is the identity when evaluating
+    assert_eq!(model.forward(&x), x.clone().into_dyn());
+    let model = model.with_layer(dense(8, 2, 0.3));
+    let result = model.forward_tape(&x.into_dyn(), &mut Context::train());
+    #[cfg(feature = "rand")]
+    {
+        // the backward pass reuses the mask drawn by the recorded forward pass
+        let tape = result.unwrap();
+        let delta = ArrayD::ones(IxDyn(&[8, 2]));
+        let kept = tape.inputs()[1].mapv(|v| v != 0.0);
+        let mut model = model;
+        let grad = model
+            .backward_with(tape, &delta, 0.0, &mut Context::train())
+            .unwrap();
+        assert_eq!(grad.mapv(|g| g != 0.0), kept);
+        // the gradient is routed through the elements that were kept, scaled accordingly
+        let weights = model.downcast_ref::<Params<f64>>(1).unwrap().weights();
+        let full = weights.sum_axis(Axis(1));
+        for row in grad.rows() {
+            for (&g, &f) in row.iter().zip(full.iter()) {
+                assert!(g == 0.0 || (g - 2.0 * f).abs() < 1e-12);
+            }
+        }
+    }
+    #[cfg(not(feature = "rand"))]
+    assert!(result.is_err());
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn test_sequential_backward_tape() {
+    let network = || {
+        Sequential::new()
+            .with_layer(dense(6, 6, 0.4))
+            .with_layer(Dropout::new(0.5))
+            .with_layer(dense(6, 2, 0.9))
+    };
+    let (mut model, mut other) = (network(), network());
+    let x = sample((5, 6), 0.2);
+    let delta = Array2::<f64>::ones((5, 2));
+    // the training forward pass retains its tape for the backward pass
+    let output = model.forward_mut(&x);
+    let tape = model.tape().cloned().unwrap();
+    assert_eq!(tape.output(), &output);
+    model.backward(&x, &delta, 1.0);
+    assert!(model.tape().is_none());
+    // consuming the same tape explicitly yields the same update, i.e. the backward pass
+    // reuses the dropout mask of the forward pass rather than drawing another
+    other
+        .backward_with(tape, &delta.into_dyn(), 1.0, &mut Context::train())
+        .unwrap();
+    let weights = |model: &Sequential<f64>| model.downcast_ref::<Params<f64>>(0).unwrap().clone();
+    assert_eq!(weights(&model), weights(&other));
+    assert_ne!(weights(&model), weights(&network()));
+}
+
+#[test]
+fn test_sequential_backward_without_tape() {
+    let network = || {
+        Sequential::new()
+            .with_layer(dense(3, 4, 0.1))
+            .with_layer(ActivationKind::Tanh)
+            .with_layer(dense(4, 2, 0.6))
+    };
+    let (mut model, mut other) = (network(), network());
+    let x = sample((4, 3), 0.5);
+    let delta = Array2::<f64>::ones((4, 2));
+    // without a retained tape, the backward pass records one afresh
+    model.backward(&x, &delta, 0.1);
+    other.forward_mut(&x);
+    other.backward(&x, &delta, 0.1);
+    let weights = |model: &Sequential<f64>| model.downcast_ref::<Params<f64>>(2).unwrap().clone();
+    assert_eq!(weights(&model), weights(&other));
+    assert_ne!(weights(&model), weights(&network()));
+    // as does a tape recorded for another input
+    other.forward_mut(&x.mapv(|v| v + 1.0));
+    model.backward(&x, &delta, 0.1);
+    other.backward(&x, &delta, 0.1);
+    assert_eq!(weights(&model), weights(&other));
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn test_sequential_serde() {
+    use concision_core::nn::{Module, RawContext};
+    let model = network();
+    let x = sample((4, 2, 5), 0.5);
+    let json = serde_json::to_string(&model).unwrap();
+    let restored: Sequential<f64> = serde_json::from_str(&json).unwrap();
+    assert_eq!(restored.names(), model.names());
+    assert_eq!(restored.num_params(), model.num_params());
+    assert!(close(&restored.forward(&x), &model.forward(&x)));
+
+    /// a layer lacking a serializable representation
+    #[derive(Debug)]
+    struct Identity;
+
+    impl Module<f64> for Identity {
+        fn name(&self) -> &'static str {
+            "identity"
+        }
+
+        fn forward_dyn(
+            &self,
+            input: &ArrayD<f64>,
+            _: &mut dyn RawContext,
+        ) -> concision_core::Result<(ArrayD<f64>, Option<ArrayD<f64>>)> {
+            Ok((input.clone(), None))
+        }
+
+        fn backward_dyn(
+            &mut self,
+            _: &ArrayD<f64>,
+            _: Option<&ArrayD<f64>>,
+            delta: &ArrayD<f64>,
+            _: f64,
+            _: &mut dyn RawContext,
+        ) -> concision_core::Result<ArrayD<f64>> {
+            Ok(delta.clone())
+        }
+
+        fn as_any(&self) -> &dyn core::any::Any {
+            self
+        }
+
+        fn as_any_mut(&mut self) -> &mut dyn core::any::Any {
+            self
+        }
+    }
+
+    assert!(serde_json::to_string(&model.with_layer(Identity)).is_err());
+}
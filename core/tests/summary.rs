@@ -0,0 +1,123 @@
+/*
+    Appellation: summary <test>
+    Created At: 2026.10.18:22:21:09
+    Contrib: @FL03
+*/
+use concision_core::activate::ActivationKind;
+use concision_core::ex::sample::TestModel;
+use concision_core::models::ShallowModelParams;
+use concision_core::{DeepModelParams, Model, ModelFeatures, StandardModelConfig, Train};
+use ndarray::{Array1, Ix2};
+
+#[test]
+fn test_params_summary() {
+    let features = ModelFeatures::custom(6, [5, 4], 2);
+    let summary = DeepModelParams::<f64>::zeros(features.clone()).summary();
+    assert_eq!(summary.len(), 3);
+    assert_eq!(summary.dtype(), "f64");
+    let names = summary
+        .layers()
+        .iter()
+        .map(|l| l.name())
+        .collect::<Vec<_>>();
+    assert_eq!(names, ["input", "hidden.0", "output"]);
+    let hidden = &summary.layers()[1];
+    assert_eq!(hidden.weight_shape(), [5, 4]);
+    assert_eq!(hidden.bias_shape(), [4]);
+    assert_eq!(hidden.count_params(), 24);
+    assert_eq!(hidden.bytes(), 24 * 8);
+    assert_eq!(hidden.flops(), 40);
+    assert!(hidden.is_trainable());
+    assert_eq!(hidden.activation(), None);
+    // the totals account for the weights and biases of every layer
+    assert_eq!(summary.count_params(), features.size() + 5 + 4 + 2);
+    assert_eq!(summary.bytes(), summary.count_params() * 8);
+    assert_eq!(summary.flops(), 2 * features.size());
+    // shallow models are supported as well
+    let shallow = ShallowModelParams::<f32>::default(Ix2(3, 4), Ix2(4, 4), Ix2(4, 1));
+    let summary = shallow.summary();
+    assert_eq!(summary.layers()[1].name(), "hidden");
+    assert_eq!(summary.bytes(), (12 + 4 + 16 + 4 + 4 + 1) * 4);
+}
+
+#[test]
+fn test_model_summary() {
+    let features = ModelFeatures::deep(3, 4, 1, 2);
+    let mut model = TestModel::<f64>::new(StandardModelConfig::new(), features);
+    assert_eq!(model.summary().count_frozen(), 0);
+    // freezing a layer of the parameters is reflected by the summary
+    model.params_mut().freeze(0);
+    let summary = model.summary();
+    let activations = summary
+        .layers()
+        .iter()
+        .map(|l| l.activation())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        activations,
+        [
+            Some(ActivationKind::Relu),
+            Some(ActivationKind::Relu),
+            Some(ActivationKind::Relu),
+            Some(ActivationKind::Sigmoid),
+        ]
+    );
+    assert!(summary.layers()[0].is_frozen());
+    assert_eq!(summary.count_frozen(), 16);
+    assert_eq!(summary.count_trainable(), summary.count_params() - 16);
+
+    let table = summary.to_string();
+    let mut lines = table.lines();
+    let header = lines.next().unwrap();
+    for column in [
+        "Layer",
+        "Weights",
+        "Params",
+        "Trainable",
+        "Activation",
+        "Memory",
+    ] {
+        assert!(header.contains(column));
+    }
+    let input = lines.nth(1).unwrap();
+    assert!(input.starts_with("input"));
+    assert!(input.contains("(3, 4)") && input.contains("no") && input.contains("relu"));
+    assert!(table.contains("Total params: 61 (trainable: 45, frozen: 16)"));
+    assert!(table.contains("488 B (f64)"));
+}
+
+#[test]
+fn test_frozen_layers() {
+    let features = ModelFeatures::deep(3, 4, 1, 2);
+    let mut config = StandardModelConfig::new();
+    config.set_learning_rate(0.1);
+    let mut model = TestModel::<f64>::new(config, features).init();
+    model.params_mut().freeze(0).freeze(2);
+    assert_eq!(model.params().frozen().collect::<Vec<_>>(), [0, 2]);
+    assert!(model.is_frozen(2) && !model.is_frozen(1));
+    // training leaves the frozen layers unchanged while updating the others
+    let before = model.params().clone();
+    let (x, y) = (Array1::linspace(0.1, 0.9, 3), Array1::from_elem(1, 0.5));
+    model.train(&x, &y).unwrap();
+    assert_eq!(model.params().input(), before.input());
+    assert_eq!(model.params().hidden()[1], before.hidden()[1]);
+    assert_ne!(model.params().hidden()[0], before.hidden()[0]);
+    assert_ne!(model.params().output(), before.output());
+    // the layers may be thawed again
+    model.params_mut().unfreeze(0);
+    assert_eq!(model.summary().count_frozen(), 20);
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn test_summary_serde() {
+    let features = ModelFeatures::deep(3, 4, 1, 2);
+    let model = TestModel::<f64>::new(StandardModelConfig::new(), features);
+    let summary = model.summary();
+    let json = serde_json::to_value(&summary).unwrap();
+    assert_eq!(json["dtype"], "f64");
+    assert_eq!(json["layers"][3]["activation"], "sigmoid");
+    assert_eq!(json["layers"][1]["weight_shape"], serde_json::json!([4, 4]));
+    let restored: concision_core::models::ModelSummary = serde_json::from_value(json).unwrap();
+    assert_eq!(restored, summary);
+}
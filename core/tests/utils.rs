@@ -3,7 +3,7 @@
     Created At: 2025.11.26:12:41:08
     Contrib: @FL03
 */
-use concision_core::{linarr, tril};
+use concision_core::{Pad, PadMode, layer_norm_axis, linarr, pad_gradient, tril};
 use ndarray::prelude::*;
 
 #[test]
@@ -23,3 +23,55 @@ fn test_tril() {
     let exp = array![[0.0, 0.0, 0.0], [3.0, 4.0, 0.0], [6.0, 7.0, 8.0,]];
     assert_eq!(exp, tril(&a));
 }
+
+#[test]
+fn test_layer_norm_axis() {
+    let x = array![[1.0, 2.0, 3.0], [10.0, 20.0, 30.0]];
+    let y = layer_norm_axis(&x, Axis(1), 0.0);
+    // each row is normalized independently
+    let exp = 1.5f64.sqrt();
+    for row in y.rows() {
+        assert!((row[0] + exp).abs() < 1e-12);
+        assert!(row[1].abs() < 1e-12);
+        assert!((row[2] - exp).abs() < 1e-12);
+    }
+}
+
+#[test]
+fn test_pad_modes() {
+    let x = array![1.0, 2.0, 3.0];
+    let cases = [
+        (PadMode::Constant(0.0), array![0.0, 0.0, 1.0, 2.0, 3.0, 0.0]),
+        (PadMode::Edge, array![1.0, 1.0, 1.0, 2.0, 3.0, 3.0]),
+        (PadMode::Reflect, array![3.0, 2.0, 1.0, 2.0, 3.0, 2.0]),
+        (PadMode::Symmetric, array![2.0, 1.0, 1.0, 2.0, 3.0, 3.0]),
+        (PadMode::Wrap, array![2.0, 3.0, 1.0, 2.0, 3.0, 1.0]),
+        (PadMode::Maximum, array![3.0, 3.0, 1.0, 2.0, 3.0, 3.0]),
+        (PadMode::Mean, array![2.0, 2.0, 1.0, 2.0, 3.0, 2.0]),
+    ];
+    for (mode, exp) in cases {
+        assert_eq!(x.pad(mode, &[[2, 1]]), exp, "{mode}");
+    }
+}
+
+#[test]
+fn test_pad_2d_corners() {
+    let x = array![[1.0, 2.0], [3.0, 4.0]];
+    let y = x.pad(PadMode::Edge, &[[1, 0], [0, 1]]);
+    assert_eq!(y, array![[1.0, 2.0, 2.0], [1.0, 2.0, 2.0], [3.0, 4.0, 4.0]]);
+}
+
+#[test]
+fn test_pad_gradient() {
+    // the gradient of each padded element is accumulated onto its source
+    let grad = Array1::<f64>::ones(6);
+    let padding = [[2, 1]];
+    assert_eq!(
+        pad_gradient(&grad, &padding, &PadMode::Reflect),
+        array![1.0, 3.0, 2.0]
+    );
+    assert_eq!(
+        pad_gradient(&grad, &padding, &PadMode::Constant(0.0)),
+        array![1.0, 1.0, 1.0]
+    );
+}
@@ -5,17 +5,42 @@
 */
 //! this module provides loading mechanisms for datasets and models.
 #[doc(inline)]
-pub use self::dataloader::Dataloader;
+pub use self::dataloader::{Batch, Batches, Dataloader, Epochs};
 
 pub mod dataloader;
 
 pub(crate) mod prelude {
     #[doc(inline)]
     pub use super::dataloader::*;
+    #[doc(inline)]
+    pub use super::{Compile, Loader};
 }
 
-pub trait Compile {}
+/// The [`Compile`] trait establishes a means of converting some dataset into a [`Loader`]
+/// capable of batching its contents.
+pub trait Compile {
+    type Loader: Loader;
+
+    fn compile(self, batch_size: usize) -> concision_core::Result<Self::Loader>;
+}
 
 /// The [`Loader`] trait establishes a common interface for all implemented loading mechanisms
 /// within the framework.
-pub trait Loader {}
+pub trait Loader {
+    type Batch<'a>
+    where
+        Self: 'a;
+    type Iter<'a>: Iterator<Item = Self::Batch<'a>>
+    where
+        Self: 'a;
+    /// returns the (maximum) number of samples within each batch
+    fn batch_size(&self) -> usize;
+    /// returns the number of batches yielded by the loader
+    fn len(&self) -> usize;
+    /// returns an iterator over the batches of the loader
+    fn batches(&self) -> Self::Iter<'_>;
+    /// returns true if the loader yields no batches
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
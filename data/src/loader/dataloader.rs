@@ -1,4 +1,314 @@
-#[doc(hidden)]
-/// The [Dataloader] struct enables developers to quickly spin up models using public or
-/// otherwise hosted datasets.
-pub struct Dataloader;
+/*
+    Appellation: dataloader <module>
+    Created At: 2026.01.13:14:05:18
+    Contrib: @FL03
+*/
+use super::{Compile, Loader};
+use crate::dataset::DatasetBase;
+use concision_core::{Error, Result, Train};
+use ndarray::{Array2, ArrayView2, Axis, CowArray, ErrorKind, Ix2, ShapeError, s};
+use num_traits::{Float, FromPrimitive};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "rand")]
+use concision_core::init::{permutation_with, rand::rngs::SmallRng, rng_from_seed};
+
+/// a type alias for the batches yielded by the [`Dataloader`]; each batch is a pair of
+/// aligned `(records, targets)` arrays that borrow from the underlying dataset whenever the
+/// rows are contiguous.
+pub type Batch<'a, A> = (CowArray<'a, A, Ix2>, CowArray<'a, A, Ix2>);
+
+/// The [`Dataloader`] is an in-memory loader that partitions a dataset into batches of rows,
+/// optionally shuffling them at the start of every epoch and dropping the final, incomplete
+/// batch.
+#[derive(Clone, Debug)]
+pub struct Dataloader<A> {
+    pub(crate) dataset: DatasetBase<Array2<A>, Array2<A>>,
+    pub(crate) batch_size: usize,
+    pub(crate) drop_last: bool,
+    #[cfg(feature = "rand")]
+    pub(crate) shuffle: bool,
+    #[cfg(feature = "rand")]
+    pub(crate) seed: Option<u64>,
+}
+
+/// An iterator over the batches of a single epoch produced by the [`Dataloader`].
+pub struct Batches<'a, A> {
+    pub(crate) dataset: &'a DatasetBase<Array2<A>, Array2<A>>,
+    pub(crate) batch_size: usize,
+    /// an optional permutation of the row indices
+    pub(crate) indices: Option<Vec<usize>>,
+    pub(crate) index: usize,
+    pub(crate) stop: usize,
+}
+
+/// An unbounded iterator over the epochs of a [`Dataloader`], yielding a fresh [`Batches`]
+/// iterator for each epoch; shuffling (when enabled) is performed per-epoch.
+pub struct Epochs<'a, A> {
+    pub(crate) loader: &'a Dataloader<A>,
+    pub(crate) epoch: usize,
+    #[cfg(feature = "rand")]
+    pub(crate) rng: SmallRng,
+}
+
+/* ************* Implementations ************* */
+
+impl<A> Dataloader<A> {
+    /// create a new loader over the given dataset using batches of (at most) `batch_size` rows
+    ///
+    /// ## Errors
+    ///
+    /// - [`InvalidBatchSize`](Error::InvalidBatchSize) if the batch size is zero
+    /// - [`ShapeError`](Error::ShapeError) if the number of records and targets differ
+    pub fn new(dataset: DatasetBase<Array2<A>, Array2<A>>, batch_size: usize) -> Result<Self> {
+        if batch_size == 0 {
+            return Err(Error::InvalidBatchSize(batch_size));
+        }
+        if dataset.records().nrows() != dataset.targets().nrows() {
+            return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+        }
+        Ok(Self {
+            dataset,
+            batch_size,
+            drop_last: false,
+            #[cfg(feature = "rand")]
+            shuffle: false,
+            #[cfg(feature = "rand")]
+            seed: None,
+        })
+    }
+    /// returns an immutable reference to the underlying dataset
+    pub const fn dataset(&self) -> &DatasetBase<Array2<A>, Array2<A>> {
+        &self.dataset
+    }
+    /// returns the number of rows within each batch
+    pub const fn batch_size(&self) -> usize {
+        self.batch_size
+    }
+    /// returns true if the final, incomplete batch is dropped
+    pub const fn drop_last(&self) -> bool {
+        self.drop_last
+    }
+    #[cfg(feature = "rand")]
+    /// returns true if the rows are shuffled at the start of every epoch
+    pub const fn shuffle(&self) -> bool {
+        self.shuffle
+    }
+    #[cfg(feature = "rand")]
+    /// returns the seed used to shuffle the rows, if any
+    pub const fn seed(&self) -> Option<u64> {
+        self.seed
+    }
+    /// returns the number of samples (rows) within the dataset
+    pub fn samples(&self) -> usize {
+        self.dataset().records().nrows()
+    }
+    /// returns the number of batches yielded per epoch
+    pub fn len(&self) -> usize {
+        if self.drop_last {
+            self.samples() / self.batch_size
+        } else {
+            self.samples().div_ceil(self.batch_size)
+        }
+    }
+    /// returns true if no batches are yielded
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    /// consumes the current instance to create another that drops the last batch if it is
+    /// incomplete
+    pub fn with_drop_last(self, drop_last: bool) -> Self {
+        Self { drop_last, ..self }
+    }
+    #[cfg(feature = "rand")]
+    /// consumes the current instance to create another that shuffles the rows at the start of
+    /// every epoch
+    pub fn with_shuffle(self, shuffle: bool) -> Self {
+        Self { shuffle, ..self }
+    }
+    #[cfg(feature = "rand")]
+    /// consumes the current instance to create another whose shuffling is seeded, making the
+    /// order of each epoch reproducible
+    pub fn with_seed(self, seed: u64) -> Self {
+        Self {
+            seed: Some(seed),
+            ..self
+        }
+    }
+    /// returns an iterator over the batches of the dataset in their original order
+    pub fn batches(&self) -> Batches<'_, A> {
+        Batches::new(self, None)
+    }
+    /// returns an unbounded iterator over the epochs of the loader
+    pub fn epochs(&self) -> Epochs<'_, A> {
+        Epochs {
+            loader: self,
+            epoch: 0,
+            #[cfg(feature = "rand")]
+            rng: rng_from_seed(self.seed),
+        }
+    }
+}
+
+impl<A> Dataloader<A>
+where
+    A: Clone,
+{
+    /// train the model for the given number of epochs, passing every batch of each epoch onto
+    /// the model's [`Train`] implementation; this is the batched counterpart of
+    /// [`Train::train_for`], returning the average loss of each epoch.
+    ///
+    /// ## Errors
+    ///
+    /// - [`EmptyBatch`](Error::EmptyBatch) if the loader does not yield any batches
+    /// - any error raised by the model while training
+    pub fn train_for<M, T, E>(&self, model: &mut M, epochs: usize) -> Result<Vec<T>>
+    where
+        M: for<'b> Train<ArrayView2<'b, A>, ArrayView2<'b, A>, Error = E, Output = T>,
+        T: Float + FromPrimitive,
+        Error: From<E>,
+    {
+        if self.is_empty() {
+            return Err(Error::EmptyBatch);
+        }
+        let mut history = Vec::with_capacity(epochs);
+        for batches in self.epochs().take(epochs) {
+            let n = T::from_usize(batches.len()).unwrap();
+            let mut loss = T::zero();
+            for (x, y) in batches {
+                loss = loss + model.train(&x.view(), &y.view())?;
+            }
+            history.push(loss / n);
+        }
+        Ok(history)
+    }
+}
+
+impl<'a, A> Batches<'a, A> {
+    pub(crate) fn new(loader: &'a Dataloader<A>, indices: Option<Vec<usize>>) -> Self {
+        Self {
+            dataset: loader.dataset(),
+            batch_size: loader.batch_size(),
+            indices,
+            index: 0,
+            stop: usize::min(loader.len() * loader.batch_size(), loader.samples()),
+        }
+    }
+    /// returns true if the batches are drawn from a permutation of the rows
+    pub const fn is_shuffled(&self) -> bool {
+        self.indices.is_some()
+    }
+}
+
+impl<'a, A> Epochs<'a, A> {
+    /// returns the number of epochs yielded thus far
+    pub const fn epoch(&self) -> usize {
+        self.epoch
+    }
+}
+
+impl<'a, A> Iterator for Batches<'a, A>
+where
+    A: Clone,
+{
+    type Item = Batch<'a, A>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.stop {
+            return None;
+        }
+        let end = usize::min(self.index + self.batch_size, self.stop);
+        let DatasetBase { records, targets } = self.dataset;
+        let batch = match &self.indices {
+            Some(indices) => {
+                let rows = &indices[self.index..end];
+                (
+                    CowArray::from(records.select(Axis(0), rows)),
+                    CowArray::from(targets.select(Axis(0), rows)),
+                )
+            }
+            None => (
+                CowArray::from(records.slice(s![self.index..end, ..])),
+                CowArray::from(targets.slice(s![self.index..end, ..])),
+            ),
+        };
+        self.index = end;
+        Some(batch)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = (self.stop - self.index).div_ceil(self.batch_size);
+        (n, Some(n))
+    }
+}
+
+impl<'a, A> ExactSizeIterator for Batches<'a, A> where A: Clone {}
+
+impl<'a, A> Iterator for Epochs<'a, A> {
+    type Item = Batches<'a, A>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        #[cfg(feature = "rand")]
+        let indices = self
+            .loader
+            .shuffle()
+            .then(|| permutation_with(self.loader.samples(), &mut self.rng));
+        #[cfg(not(feature = "rand"))]
+        let indices = None;
+        self.epoch += 1;
+        Some(Batches::new(self.loader, indices))
+    }
+}
+
+impl<A> Loader for Dataloader<A>
+where
+    A: Clone,
+{
+    type Batch<'a>
+        = Batch<'a, A>
+    where
+        Self: 'a;
+    type Iter<'a>
+        = Batches<'a, A>
+    where
+        Self: 'a;
+
+    fn batch_size(&self) -> usize {
+        self.batch_size
+    }
+
+    fn len(&self) -> usize {
+        Dataloader::len(self)
+    }
+
+    fn batches(&self) -> Self::Iter<'_> {
+        Dataloader::batches(self)
+    }
+}
+
+impl<A> Compile for DatasetBase<Array2<A>, Array2<A>>
+where
+    A: Clone,
+{
+    type Loader = Dataloader<A>;
+
+    fn compile(self, batch_size: usize) -> Result<Self::Loader> {
+        Dataloader::new(self, batch_size)
+    }
+}
+
+impl<'a, A> Compile for DatasetBase<ArrayView2<'a, A>, ArrayView2<'a, A>>
+where
+    A: Clone,
+{
+    type Loader = Dataloader<A>;
+
+    fn compile(self, batch_size: usize) -> Result<Self::Loader> {
+        let DatasetBase { records, targets } = self;
+        Dataloader::new(
+            DatasetBase::new(records.to_owned(), targets.to_owned()),
+            batch_size,
+        )
+    }
+}
@@ -8,6 +8,10 @@ mod impl_trainer;
 use crate::Records;
 use crate::dataset::DatasetBase;
 use concision_core::Model;
+use concision_core::optim::LrScheduler;
+
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, vec::Vec};
 
 pub trait ModelTrainer<T> {
     type Model: Model<T>;
@@ -40,6 +44,12 @@ where
     pub(crate) model: &'a mut M,
     /// the accumulated loss
     pub(crate) loss: T,
+    /// the effective learning rate of each epoch
+    pub(crate) learning_rates: Vec<T>,
+    /// the effective learning rate of each step (batch)
+    pub(crate) step_learning_rates: Vec<T>,
+    /// an optional scheduler used to update the learning rate after each epoch
+    pub(crate) scheduler: Option<Box<dyn LrScheduler<T> + 'a>>,
 }
 
 impl<'a, M, T, R> core::ops::Deref for Trainer<'a, M, T, R>
@@ -6,7 +6,14 @@
 use super::Trainer;
 use crate::dataset::DatasetBase;
 use crate::{IntoDataset, Records};
-use concision_core::Model;
+use concision_core::config::{ExtendedModelConfig, HyperParam, ModelConfiguration};
+use concision_core::optim::LrScheduler;
+use concision_core::{Error, Model, Train};
+use ndarray::{ArrayBase, ArrayView2, Axis, Data, ErrorKind, Ix2, ShapeError};
+use num_traits::{Float, FromPrimitive};
+
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, vec::Vec};
 
 impl<'a, M, T, R> Trainer<'a, M, T, R>
 where
@@ -22,6 +29,20 @@ where
             dataset: dataset.into_dataset(),
             model,
             loss: T::default(),
+            learning_rates: Vec::new(),
+            step_learning_rates: Vec::new(),
+            scheduler: None,
+        }
+    }
+    /// consumes the current instance to create another using the given learning-rate
+    /// scheduler
+    pub fn with_scheduler<L>(self, scheduler: L) -> Self
+    where
+        L: LrScheduler<T> + 'a,
+    {
+        Self {
+            scheduler: Some(Box::new(scheduler)),
+            ..self
         }
     }
     /// returns an immutable reference to the total loss
@@ -32,6 +53,30 @@ where
     pub fn loss_mut(&mut self) -> &mut T {
         &mut self.loss
     }
+    /// returns the effective learning rate of each epoch completed by the trainer
+    pub fn learning_rates(&self) -> &[T] {
+        &self.learning_rates
+    }
+    /// returns the effective learning rate of each step (batch) completed by the trainer
+    pub fn step_learning_rates(&self) -> &[T] {
+        &self.step_learning_rates
+    }
+    /// returns an immutable reference to the learning-rate scheduler, if any
+    pub fn scheduler(&self) -> Option<&(dyn LrScheduler<T> + 'a)> {
+        self.scheduler.as_deref()
+    }
+    /// returns a mutable reference to the learning-rate scheduler, if any
+    pub fn scheduler_mut(&mut self) -> Option<&mut (dyn LrScheduler<T> + 'a)> {
+        self.scheduler.as_deref_mut()
+    }
+    /// sets the learning-rate scheduler, returning a mutable reference to the trainer
+    pub fn set_scheduler<L>(&mut self, scheduler: L) -> &mut Self
+    where
+        L: LrScheduler<T> + 'a,
+    {
+        self.scheduler = Some(Box::new(scheduler));
+        self
+    }
     /// returns an immutable reference to the training session's dataset
     pub const fn dataset(&self) -> &DatasetBase<R::Inputs, R::Targets> {
         &self.dataset
@@ -40,8 +85,91 @@ where
     pub fn dataset_mut(&mut self) -> &mut DatasetBase<R::Inputs, R::Targets> {
         &mut self.dataset
     }
+}
 
-    pub fn begin(&self) -> &Self {
-        todo!("Define a generic training loop...")
+impl<'a, M, T, R, A, S, U, E> Trainer<'a, M, T, R>
+where
+    A: 'a,
+    M: Model<T> + for<'b> Train<ArrayView2<'b, A>, ArrayView2<'b, A>, Error = E, Output = T>,
+    M::Config: ExtendedModelConfig<T>,
+    R: Records<Inputs = ArrayBase<S, Ix2, A>, Targets = ArrayBase<U, Ix2, A>>,
+    S: Data<Elem = A>,
+    U: Data<Elem = A>,
+    Error: From<E>,
+{
+    /// begin the training session; the model is trained for the configured number of epochs,
+    /// each of which partitions the dataset into chunks of (at most) `batch_size` rows before
+    /// passing them onto the model's [`Train`] implementation.
+    ///
+    /// The method returns the history of the session, recording the average loss of each
+    /// epoch, while the total loss is accumulated into the trainer.
+    ///
+    /// When a [`LrScheduler`] is attached, its learning rate is written onto the model's
+    /// configuration before each epoch and the scheduler is advanced using the average loss
+    /// of the epoch once it completes. The effective learning rate of every epoch and every
+    /// step is recorded and made available through [`learning_rates`](Trainer::learning_rates)
+    /// and [`step_learning_rates`](Trainer::step_learning_rates) respectively; both logs are
+    /// reset whenever a new session begins.
+    ///
+    /// ## Errors
+    ///
+    /// - [`InvalidBatchSize`](Error::InvalidBatchSize) if the configured batch size is zero
+    /// - [`EmptyBatch`](Error::EmptyBatch) if the dataset does not contain any records
+    /// - [`ShapeError`](Error::ShapeError) if the number of records and targets differ
+    pub fn begin(&mut self) -> Result<Vec<T>, Error>
+    where
+        T: Float + FromPrimitive,
+    {
+        let epochs = self.model.config().epochs();
+        let batch_size = self.model.config().batch_size();
+        if batch_size == 0 {
+            return Err(Error::InvalidBatchSize(batch_size));
+        }
+        let DatasetBase { records, targets } = &self.dataset;
+        if records.nrows() == 0 || targets.nrows() == 0 {
+            return Err(Error::EmptyBatch);
+        }
+        if records.nrows() != targets.nrows() {
+            return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+        }
+        self.learning_rates.clear();
+        self.step_learning_rates.clear();
+        let mut history = Vec::with_capacity(epochs);
+        for _epoch in 0..epochs {
+            if let Some(scheduler) = self.scheduler.as_ref() {
+                let lr = scheduler.learning_rate();
+                self.model.config_mut().set(HyperParam::LearningRate, lr);
+            }
+            let lr = self.model.config().learning_rate().copied();
+            if let Some(lr) = lr {
+                self.learning_rates.push(lr);
+            }
+            let mut loss = T::zero();
+            let mut batches = 0_usize;
+            for (x, y) in records
+                .axis_chunks_iter(Axis(0), batch_size)
+                .zip(targets.axis_chunks_iter(Axis(0), batch_size))
+            {
+                if let Some(&lr) = self.model.config().learning_rate() {
+                    self.step_learning_rates.push(lr);
+                }
+                loss = loss + self.model.train(&x, &y)?;
+                batches += 1;
+            }
+            self.loss = self.loss + loss;
+            let avg = loss / T::from_usize(batches).unwrap();
+            #[cfg(feature = "tracing")]
+            tracing::debug!(
+                "epoch {}/{epochs}: loss = {:?}, learning rate = {:?}",
+                _epoch + 1,
+                avg.to_f64(),
+                lr.and_then(|lr| lr.to_f64())
+            );
+            if let Some(scheduler) = self.scheduler.as_mut() {
+                scheduler.step(avg);
+            }
+            history.push(avg);
+        }
+        Ok(history)
     }
 }
@@ -0,0 +1,101 @@
+extern crate concision_data as data;
+
+use concision_core::ex::sample::TestModel;
+use concision_core::{Error, ModelFeatures, StandardModelConfig};
+use data::{Compile, Dataloader, DatasetBase, Loader};
+use ndarray::prelude::*;
+
+fn sample_dataset(n: usize) -> DatasetBase<Array2<f64>, Array2<f64>> {
+    let records = Array1::linspace(0.0, (2 * n - 1) as f64, 2 * n)
+        .into_shape_with_order((n, 2))
+        .unwrap();
+    let targets = Array1::linspace(0.0, (n - 1) as f64, n)
+        .into_shape_with_order((n, 1))
+        .unwrap();
+    DatasetBase::new(records, targets)
+}
+
+#[test]
+fn test_dataloader() -> anyhow::Result<()> {
+    let loader = sample_dataset(10).compile(4)?;
+    assert_eq!(loader.len(), 3);
+    assert_eq!(Loader::batches(&loader).len(), Loader::len(&loader));
+    let batches = loader.batches().collect::<Vec<_>>();
+    assert_eq!(batches.len(), 3);
+    assert_eq!(batches[0].0.dim(), (4, 2));
+    assert_eq!(batches[2].0.dim(), (2, 2));
+    // the records and targets should remain aligned
+    for (x, y) in loader.batches() {
+        assert_eq!(x.column(0).mapv(|v| v / 2.0), y.column(0));
+    }
+    Ok(())
+}
+
+#[test]
+fn test_dataloader_drop_last() -> anyhow::Result<()> {
+    let loader = Dataloader::new(sample_dataset(10), 4)?.with_drop_last(true);
+    assert_eq!(loader.len(), 2);
+    assert!(
+        loader
+            .batches()
+            .all(|(x, y)| x.nrows() == 4 && y.nrows() == 4)
+    );
+    Ok(())
+}
+
+#[test]
+fn test_dataloader_errors() {
+    assert!(matches!(
+        Dataloader::new(sample_dataset(4), 0),
+        Err(Error::InvalidBatchSize(0))
+    ));
+    let dataset = DatasetBase::new(Array2::<f64>::zeros((4, 2)), Array2::<f64>::zeros((3, 1)));
+    assert!(Dataloader::new(dataset, 2).is_err());
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn test_dataloader_shuffle() -> anyhow::Result<()> {
+    let loader = Dataloader::new(sample_dataset(16), 4)?
+        .with_shuffle(true)
+        .with_seed(42);
+    let epochs = loader.epochs().take(2).collect::<Vec<_>>();
+    assert_eq!(epochs.len(), 2);
+    let collect = |loader: &Dataloader<f64>| {
+        loader
+            .epochs()
+            .next()
+            .unwrap()
+            .flat_map(|(_, y)| y.iter().copied().collect::<Vec<_>>())
+            .collect::<Vec<_>>()
+    };
+    // seeded shuffling should be reproducible
+    let order = collect(&loader);
+    assert_eq!(order, collect(&loader));
+    // every row must be visited exactly once
+    let mut sorted = order.clone();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    assert_eq!(sorted, Array1::linspace(0.0, 15.0, 16).to_vec());
+    // the records and targets should remain aligned
+    for (x, y) in loader.epochs().next().unwrap() {
+        assert_eq!(x.column(0).mapv(|v| v / 2.0), y.column(0));
+    }
+    Ok(())
+}
+
+#[test]
+fn test_dataloader_train_for() -> anyhow::Result<()> {
+    let mut config = StandardModelConfig::new();
+    config.set_learning_rate(0.01);
+    let mut model = TestModel::<f64>::new(config, ModelFeatures::deep(2, 4, 1, 2)).init();
+    let records = Array1::<f64>::linspace(0.0, 1.0, 20)
+        .into_shape_with_order((10, 2))
+        .unwrap();
+    let targets = Array2::<f64>::from_elem((10, 1), 0.5);
+    let loader = Dataloader::new(DatasetBase::new(records, targets), 4)?;
+    let history = loader.train_for(&mut model, 3)?;
+    // a single (average) loss should be recorded for each epoch
+    assert_eq!(history.len(), 3);
+    assert!(history.iter().all(|loss| loss.is_finite()));
+    Ok(())
+}
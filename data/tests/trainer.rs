@@ -0,0 +1,73 @@
+/*
+    Appellation: trainer <test>
+    Contrib: @FL03
+*/
+use concision_core::ex::sample::TestModel;
+use concision_core::optim::StepDecay;
+use concision_core::{Error, ModelFeatures, StandardModelConfig};
+use concision_data::{DatasetBase, Trainer};
+use ndarray::prelude::*;
+
+fn sample_model(epochs: usize, batch_size: usize) -> TestModel<f64> {
+    let features = ModelFeatures::deep(3, 9, 1, 2);
+    let mut config = StandardModelConfig::new()
+        .with_epochs(epochs)
+        .with_batch_size(batch_size);
+    config.set_learning_rate(0.01);
+    TestModel::<f64>::new(config, features).init()
+}
+
+#[test]
+fn test_trainer_begin() {
+    let mut model = sample_model(3, 4);
+    let records = Array1::<f64>::linspace(0.0, 1.0, 30)
+        .into_shape_with_order((10, 3))
+        .unwrap();
+    let targets = Array2::<f64>::from_elem((10, 1), 0.5);
+    let mut trainer = Trainer::new(&mut model, DatasetBase::new(records, targets));
+    let history = trainer.begin().expect("training failed");
+    // a single entry should be recorded for each epoch
+    assert_eq!(history.len(), 3);
+    assert!(history[0].is_finite());
+}
+
+#[test]
+fn test_trainer_scheduler() {
+    let mut model = sample_model(4, 5);
+    let records = Array2::<f64>::from_elem((10, 3), 0.25);
+    let targets = Array2::<f64>::from_elem((10, 1), 0.5);
+    let mut trainer = Trainer::new(&mut model, DatasetBase::new(records, targets))
+        .with_scheduler(StepDecay::new(0.1, 0.5, 2));
+    trainer.begin().expect("training failed");
+    // the scheduler halves the learning rate every other epoch
+    assert_eq!(trainer.learning_rates(), &[0.1, 0.1, 0.05, 0.05]);
+    // two batches are drawn per epoch, each of which records the effective rate
+    assert_eq!(
+        trainer.step_learning_rates(),
+        &[0.1, 0.1, 0.1, 0.1, 0.05, 0.05, 0.05, 0.05]
+    );
+    assert_eq!(trainer.scheduler().map(|s| s.current_step()), Some(4));
+    // beginning another session resets the logs while the scheduler carries on decaying
+    trainer.begin().expect("training failed");
+    assert_eq!(trainer.learning_rates(), &[0.025, 0.025, 0.0125, 0.0125]);
+    assert_eq!(trainer.step_learning_rates().len(), 8);
+    drop(trainer);
+    // the model's configuration reflects the rate used during the final epoch
+    assert_eq!(model.config().learning_rate(), Some(&0.0125));
+}
+
+#[test]
+fn test_trainer_invalid_batch_size() {
+    let mut model = sample_model(1, 0);
+    let dataset = DatasetBase::new(Array2::<f64>::zeros((4, 3)), Array2::<f64>::zeros((4, 1)));
+    let mut trainer = Trainer::new(&mut model, dataset);
+    assert!(matches!(trainer.begin(), Err(Error::InvalidBatchSize(0))));
+}
+
+#[test]
+fn test_trainer_empty_batch() {
+    let mut model = sample_model(1, 2);
+    let dataset = DatasetBase::new(Array2::<f64>::zeros((0, 3)), Array2::<f64>::zeros((0, 1)));
+    let mut trainer = Trainer::new(&mut model, dataset);
+    assert!(matches!(trainer.begin(), Err(Error::EmptyBatch)));
+}
@@ -16,7 +16,7 @@ fn main() -> anyhow::Result<()> {
     // initialize the scaled dot-product attention layer
     let layer = SDPA::<f64>::new(0.1, 1.0);
     // compute the attention scores
-    let z_score = layer.attention(&qkv);
+    let z_score = layer.attention(&qkv)?;
     println!("z_score: {:?}", z_score);
 
     Ok(())
@@ -24,7 +24,11 @@
 //!   expensive.
 //!
 #[doc(inline)]
-pub use self::{multi_head::MultiHeadAttention, qkv::*, scaled::SDPA};
+pub use self::{
+    multi_head::{MultiHeadAttention, MultiHeadGradients},
+    qkv::*,
+    scaled::{SDPA, causal_mask},
+};
 
 #[cfg(feature = "signal")]
 pub mod fft;
@@ -47,7 +51,7 @@ pub(crate) mod prelude {
     #[doc(inline)]
     pub use super::qkv::QkvParamsBase;
     #[doc(inline)]
-    pub use super::scaled::SDPA;
+    pub use super::scaled::{SDPA, causal_mask};
 }
 
 #[cfg(test)]
@@ -63,8 +67,175 @@ mod tests {
         // initialize the scaled dot-product attention layer
         let layer = SDPA::<f64>::new(0.1, 1.0);
         // compute the attention scores
-        let z_score = layer.attention(&qkv);
+        let z_score = layer.attention(&qkv).unwrap();
         // verify the output dimensions
         assert_eq!(z_score.shape(), &[m, n]);
     }
+
+    #[test]
+    fn test_attention_weights_are_row_stochastic() {
+        let query = ndarray::Array2::<f64>::from_shape_fn((4, 3), |(i, j)| (i * 3 + j) as f64);
+        let layer = SDPA::<f64>::new(0.0, 3f64.sqrt());
+        let weights = layer.weights(&query, &query).unwrap();
+        for row in weights.rows() {
+            assert!((row.sum() - 1.0).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_causal_attention() {
+        let n = 5;
+        let query = ndarray::Array2::<f64>::from_shape_fn((n, 3), |(i, j)| (i + j) as f64 / 10.0);
+        let layer = SDPA::<f64>::new(0.0, 1.0).with_causal(true);
+        let weights = layer.weights(&query, &query).unwrap();
+        for ((i, j), &w) in weights.indexed_iter() {
+            if j > i {
+                assert_eq!(w, 0.0);
+            }
+        }
+        // the first position may only attend to itself
+        assert!((weights[[0, 0]] - 1.0).abs() < 1e-12);
+        for row in weights.rows() {
+            assert!((row.sum() - 1.0).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_causal_attention_lengths() {
+        // the causal mask is built from the lengths of the queries and keys of each call
+        let layer = SDPA::<f64>::new(0.0, 1.0).with_causal(true);
+        let key = ndarray::Array2::<f64>::from_shape_fn((6, 3), |(i, j)| (i * j) as f64 / 10.0);
+        let weights = layer
+            .weights(&key.slice(ndarray::s![..4, ..]), &key.view())
+            .unwrap();
+        assert_eq!(weights.dim(), (4, 6));
+        for ((i, j), &w) in weights.indexed_iter() {
+            if j > i {
+                assert_eq!(w, 0.0);
+            }
+        }
+        assert_eq!(layer.weights(&key, &key).unwrap().dim(), (6, 6));
+    }
+
+    #[test]
+    fn test_batched_attention() {
+        let qkv = Qkv::<f64, ndarray::Ix3>::new(
+            ndarray::Array3::from_shape_fn((2, 4, 3), |(b, i, j)| (b + i * j) as f64 / 7.0),
+            ndarray::Array3::from_shape_fn((2, 5, 3), |(b, i, j)| (b * i + j) as f64 / 5.0),
+            ndarray::Array3::from_shape_fn((2, 5, 2), |(b, i, j)| (b + i + j) as f64),
+        );
+        let layer = SDPA::<f64>::new(0.0, 3f64.sqrt()).with_causal(true);
+        let output = layer.attention(&qkv).unwrap();
+        assert_eq!(output.dim(), (2, 4, 2));
+        for b in 0..2 {
+            let seq = Qkv::new(
+                qkv.query().index_axis(ndarray::Axis(0), b).to_owned(),
+                qkv.key().index_axis(ndarray::Axis(0), b).to_owned(),
+                qkv.value().index_axis(ndarray::Axis(0), b).to_owned(),
+            );
+            assert_eq!(
+                output.index_axis(ndarray::Axis(0), b),
+                layer.attention(&seq).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_attention_dropout_validation() {
+        let mut layer = SDPA::<f64>::new(0.0, 1.0);
+        assert!(layer.set_dropout(0.25).is_ok());
+        assert_eq!(*layer.dropout(), 0.25);
+        assert!(layer.set_dropout(1.0).is_err());
+        assert!(layer.set_dropout(-0.1).is_err());
+        assert!(layer.set_dropout(f64::NAN).is_err());
+        assert_eq!(*layer.dropout(), 0.25);
+    }
+
+    #[test]
+    fn test_multi_head_attention() {
+        use super::MultiHeadAttention;
+        use cnc::Forward;
+
+        let (seq_len, d_model) = (5, 8);
+        let input = ndarray::Array2::<f64>::ones((seq_len, d_model));
+        let layer = MultiHeadAttention::<f64>::zeros(2, d_model).expect("invalid layer");
+        assert_eq!(layer.num_heads(), 2);
+        assert_eq!(layer.d_head(), 4);
+        let output = layer.forward(&input).unwrap();
+        assert_eq!(output.dim(), (seq_len, d_model));
+        // the number of heads must evenly divide the model dimension
+        assert!(MultiHeadAttention::<f64>::zeros(3, d_model).is_err());
+        assert!(MultiHeadAttention::<f64>::zeros(0, d_model).is_err());
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_multi_head_attention_init() {
+        use super::MultiHeadAttention;
+        use cnc::Forward;
+
+        let input = ndarray::Array2::<f64>::ones((3, 12));
+        let layer = MultiHeadAttention::<f64>::glorot_normal(4, 12)
+            .expect("invalid layer")
+            .with_causal(true);
+        assert_eq!(layer.heads()[0].query().dim(), (12, 3));
+        let output = layer.forward(&input).unwrap();
+        assert_eq!(output.dim(), (3, 12));
+        assert!(output.iter().all(|x| x.is_finite()));
+        assert!(MultiHeadAttention::<f64>::lecun_normal(4, 12).is_ok());
+        assert!(MultiHeadAttention::<f64>::glorot_uniform(4, 12).is_ok());
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_attention_dropout() {
+        let qkv = Qkv::<f64>::ones((16, 8));
+        let mut layer = SDPA::<f64>::new(0.5, 1.0);
+        // dropout is disabled outside of training
+        let expected = layer.attention(&qkv).unwrap();
+        assert!(expected.iter().all(|&x| (x - 1.0).abs() < 1e-12));
+        layer.set_training(true);
+        let output = layer.attention(&qkv).unwrap();
+        assert_eq!(output.dim(), expected.dim());
+        assert_ne!(output, expected);
+    }
+
+    #[test]
+    fn test_attention_mask_shape() {
+        let qkv = Qkv::<f64>::ones((4, 3));
+        // a mask that cannot be broadcast onto the scores is reported rather than panicking
+        let layer = SDPA::<f64>::new(0.0, 1.0).with_mask(ndarray::Array2::zeros((3, 3)));
+        assert!(layer.check_mask(4, 4).is_err());
+        assert!(layer.attention(&qkv).is_err());
+        assert!(
+            layer
+                .gradient(&qkv, &ndarray::Array2::ones((4, 3)))
+                .is_err()
+        );
+        // while a mask shared by each query is broadcast onto every row
+        let mask = ndarray::array![[0.0, 0.0, f64::NEG_INFINITY, 0.0]];
+        let layer = SDPA::<f64>::new(0.0, 1.0).with_mask(mask);
+        let weights = layer.weights(qkv.query(), qkv.key()).unwrap();
+        assert!(weights.column(2).iter().all(|&w| w == 0.0));
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_attention_dropout_context() {
+        use cnc::nn::Context;
+
+        let qkv = Qkv::<f64>::ones((16, 8));
+        let layer = SDPA::<f64>::new(0.5, 1.0);
+        // the dropout draws from the generator of the context, making it reproducible
+        let a = layer.attention_with(&qkv, &mut Context::train().with_seed(7));
+        let b = layer.attention_with(&qkv, &mut Context::train().with_seed(7));
+        assert_eq!(a.unwrap(), b.unwrap());
+        let output = layer
+            .attention_with(&qkv, &mut Context::train().with_seed(8))
+            .unwrap();
+        assert_ne!(output, layer.attention(&qkv).unwrap());
+        // while an evaluation context disables the dropout
+        let output = layer.attention_with(&qkv, &mut Context::eval()).unwrap();
+        assert_eq!(output, layer.attention(&qkv).unwrap());
+    }
 }
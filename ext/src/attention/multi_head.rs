@@ -2,8 +2,336 @@
     Appellation: multi-head <module>
     Contrib: @FL03
 */
+use super::{Qkv, SDPA};
+use cnc::nn::{ForwardWith, Mode, RawContext};
+use cnc::params::Params;
+use cnc::{Backward, Error, Forward};
+use ndarray::{Array2, ArrayBase, ArrayView2, Axis, Data, Ix2, ScalarOperand, concatenate, s};
+use num_traits::{Float, FromPrimitive};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "rand")]
+use cnc::init::{
+    NdRandom,
+    rand_distr::{Distribution, StandardNormal, uniform::SampleUniform},
+};
+
+/// The gradients computed by [`MultiHeadAttention::gradients`]; those w.r.t. the projections
+/// of each head, the output projection and the input, respectively.
+pub type MultiHeadGradients<A = f64> = (Vec<Qkv<A>>, Params<A>, Array2<A>);
 
 /// Multi-Headed attention is the first evolution of the Scaled Dot-Product Attention
 /// mechanism. They allow the model to jointly attend to information from different
 /// representation subspaces at different positions.
-pub struct MultiHeadAttention;
+///
+/// Each head projects the `(seq_len, d_model)` input onto its own query, key and value
+/// subspaces of width `d_model / num_heads` before applying the [`SDPA`] mechanism; the
+/// outputs of each head are then concatenated and passed through the output projection.
+pub struct MultiHeadAttention<A = f64> {
+    /// the dimension of the model, i.e. the number of input (and output) features
+    pub(crate) d_model: usize,
+    /// the query, key and value projections of each head; each of shape `(d_model, d_head)`
+    pub(crate) heads: Vec<Qkv<A>>,
+    /// the output projection with weights of shape `(d_model, d_model)`
+    pub(crate) output: Params<A>,
+    /// the scaled dot-product attention mechanism shared by each head
+    pub(crate) attention: SDPA<A>,
+}
+
+impl<A> MultiHeadAttention<A> {
+    /// create a new instance of the [`MultiHeadAttention`] layer from the given parameters;
+    /// the temperature of the attention mechanism is set to `sqrt(d_head)`.
+    pub fn from_params(d_model: usize, heads: Vec<Qkv<A>>, output: Params<A>) -> cnc::Result<Self>
+    where
+        A: Float + FromPrimitive,
+    {
+        let num_heads = heads.len();
+        if num_heads == 0 || d_model % num_heads != 0 {
+            return Err(Error::InvalidModelConfig);
+        }
+        let d_head = d_model / num_heads;
+        let valid = heads.iter().all(|h| {
+            h.query().dim() == (d_model, d_head)
+                && h.key().dim() == (d_model, d_head)
+                && h.value().dim() == (d_model, d_head)
+        });
+        if !valid || output.weights().dim() != (d_model, d_model) {
+            return Err(Error::InvalidModelConfig);
+        }
+        let temperature = A::from_usize(d_head).unwrap().sqrt();
+        Ok(Self {
+            d_model,
+            heads,
+            output,
+            attention: SDPA::new(A::zero(), temperature),
+        })
+    }
+    /// initialize the projections of each head, along with the output projection, using the
+    /// given functions; each function is provided the shape of the parameters
+    pub fn init_with<F, G>(num_heads: usize, d_model: usize, qkv: F, output: G) -> cnc::Result<Self>
+    where
+        A: Float + FromPrimitive,
+        F: Fn((usize, usize)) -> cnc::Result<Qkv<A>>,
+        G: FnOnce((usize, usize)) -> cnc::Result<Params<A>>,
+    {
+        if num_heads == 0 || d_model % num_heads != 0 {
+            return Err(Error::InvalidModelConfig);
+        }
+        let d_head = d_model / num_heads;
+        let heads = (0..num_heads)
+            .map(|_| qkv((d_model, d_head)))
+            .collect::<cnc::Result<Vec<_>>>()?;
+        Self::from_params(d_model, heads, output((d_model, d_model))?)
+    }
+    /// create a new instance of the layer with each of its parameters set to zero
+    pub fn zeros(num_heads: usize, d_model: usize) -> cnc::Result<Self>
+    where
+        A: Float + FromPrimitive,
+    {
+        Self::init_with(
+            num_heads,
+            d_model,
+            |shape| Ok(Qkv::zeros(shape)),
+            |shape| Ok(Params::zeros(shape)),
+        )
+    }
+    #[cfg(feature = "rand")]
+    /// create a new instance of the layer whose parameters are initialized using the Glorot
+    /// (Xavier) normal distribution
+    pub fn glorot_normal(num_heads: usize, d_model: usize) -> cnc::Result<Self>
+    where
+        A: Float + FromPrimitive + ScalarOperand,
+        StandardNormal: Distribution<A>,
+    {
+        Self::init_with(
+            num_heads,
+            d_model,
+            |shape| Ok(Qkv::glorot_normal(shape)),
+            |shape| Ok(Params::glorot_normal(shape)),
+        )
+    }
+    #[cfg(feature = "rand")]
+    /// create a new instance of the layer whose parameters are initialized using the Glorot
+    /// (Xavier) uniform distribution
+    pub fn glorot_uniform(num_heads: usize, d_model: usize) -> cnc::Result<Self>
+    where
+        A: Float + FromPrimitive + ScalarOperand + SampleUniform,
+        <A as SampleUniform>::Sampler: Clone,
+    {
+        Self::init_with(num_heads, d_model, Qkv::glorot_uniform, |shape| {
+            Params::glorot_uniform(shape).map_err(Into::into)
+        })
+    }
+    #[cfg(feature = "rand")]
+    /// create a new instance of the layer whose parameters are initialized using the LeCun
+    /// normal distribution
+    pub fn lecun_normal(num_heads: usize, d_model: usize) -> cnc::Result<Self>
+    where
+        A: Float + FromPrimitive + ScalarOperand,
+        StandardNormal: Distribution<A>,
+    {
+        Self::init_with(
+            num_heads,
+            d_model,
+            |shape| Ok(Qkv::lecun_normal(shape)),
+            |shape| Ok(Params::lecun_normal(shape)),
+        )
+    }
+    /// returns the dimension of the model
+    pub const fn d_model(&self) -> usize {
+        self.d_model
+    }
+    /// returns the dimension of each head
+    pub fn d_head(&self) -> usize {
+        self.d_model / self.num_heads()
+    }
+    /// returns the number of heads
+    pub fn num_heads(&self) -> usize {
+        self.heads.len()
+    }
+    /// returns an immutable reference to the attention mechanism
+    pub const fn attention(&self) -> &SDPA<A> {
+        &self.attention
+    }
+    /// returns a mutable reference to the attention mechanism
+    pub fn attention_mut(&mut self) -> &mut SDPA<A> {
+        &mut self.attention
+    }
+    /// returns an immutable reference to the projections of each head
+    pub fn heads(&self) -> &[Qkv<A>] {
+        &self.heads
+    }
+    /// returns a mutable reference to the projections of each head
+    pub fn heads_mut(&mut self) -> &mut [Qkv<A>] {
+        &mut self.heads
+    }
+    /// returns an immutable reference to the output projection
+    pub const fn output(&self) -> &Params<A> {
+        &self.output
+    }
+    /// returns a mutable reference to the output projection
+    pub fn output_mut(&mut self) -> &mut Params<A> {
+        &mut self.output
+    }
+    /// consumes the current instance to create another with the given dropout probability,
+    /// returning an error unless `0 <= dropout < 1`
+    pub fn with_dropout(mut self, dropout: A) -> cnc::Result<Self>
+    where
+        A: Float,
+    {
+        self.attention.set_dropout(dropout)?;
+        Ok(self)
+    }
+    /// consumes the current instance to create another whose heads apply a causal mask,
+    /// preventing each position from attending to any subsequent position
+    pub fn with_causal(self, causal: bool) -> Self {
+        Self {
+            attention: self.attention.with_causal(causal),
+            ..self
+        }
+    }
+    /// computes the attention output of each head, returning the concatenated result of
+    /// shape `(seq_len, d_model)` _before_ the output projection
+    pub fn concat_heads<S>(&self, input: &ArrayBase<S, Ix2>) -> cnc::Result<Array2<A>>
+    where
+        A: Float + ScalarOperand,
+        S: Data<Elem = A>,
+    {
+        let mut mode = Mode::from_training(self.attention().is_training());
+        self.concat_heads_with(input, &mut mode)
+    }
+    /// computes the [`concat_heads`](MultiHeadAttention::concat_heads) within the given
+    /// context, which is shared by the attention mechanism of each head
+    pub fn concat_heads_with<S, C>(
+        &self,
+        input: &ArrayBase<S, Ix2>,
+        ctx: &mut C,
+    ) -> cnc::Result<Array2<A>>
+    where
+        A: Float + ScalarOperand,
+        C: RawContext,
+        S: Data<Elem = A>,
+    {
+        let outputs = self
+            .heads()
+            .iter()
+            .map(|head| self.attention().attention_with(&head.project(input), ctx))
+            .collect::<cnc::Result<Vec<Array2<A>>>>()?;
+        let views = outputs
+            .iter()
+            .map(|x| x.view())
+            .collect::<Vec<ArrayView2<A>>>();
+        Ok(concatenate(Axis(1), &views)?)
+    }
+}
+
+impl<A> MultiHeadAttention<A>
+where
+    A: Float + ScalarOperand,
+{
+    /// compute the gradients of the layer w.r.t. the projections of each head, the output
+    /// projection and the input given the gradient `delta` w.r.t. its output; returns an
+    /// error if the mask of the attention mechanism cannot be broadcast onto the scores of
+    /// the input (see [`SDPA::check_mask`])
+    pub fn gradients<S, T>(
+        &self,
+        input: &ArrayBase<S, Ix2>,
+        delta: &ArrayBase<T, Ix2>,
+    ) -> cnc::Result<MultiHeadGradients<A>>
+    where
+        S: Data<Elem = A>,
+        T: Data<Elem = A>,
+    {
+        let concat = self.concat_heads(input)?;
+        let dconcat = delta.dot(&self.output().weights().t());
+        let d_head = self.d_head();
+        // backpropagate through each of the heads
+        let mut heads = Vec::with_capacity(self.num_heads());
+        let mut grad = Array2::<A>::zeros(input.raw_dim());
+        for (h, head) in self.heads().iter().enumerate() {
+            let dout = dconcat.slice(s![.., h * d_head..(h + 1) * d_head]);
+            let dqkv = self.attention().gradient(&head.project(input), &dout)?;
+            heads.push(head.gradient(input, &dqkv));
+            grad = grad + head.input_gradient(&dqkv);
+        }
+        let output = Params::new(delta.sum_axis(Axis(0)), concat.t().dot(delta));
+        Ok((heads, output, grad))
+    }
+    /// computes the gradient w.r.t. the input of the layer given the `delta` w.r.t. its
+    /// output
+    pub fn input_gradient<S, T>(
+        &self,
+        input: &ArrayBase<S, Ix2>,
+        delta: &ArrayBase<T, Ix2>,
+    ) -> cnc::Result<Array2<A>>
+    where
+        S: Data<Elem = A>,
+        T: Data<Elem = A>,
+    {
+        let dconcat = delta.dot(&self.output().weights().t());
+        let d_head = self.d_head();
+        let mut grad = Array2::<A>::zeros(input.raw_dim());
+        for (h, head) in self.heads().iter().enumerate() {
+            let dout = dconcat.slice(s![.., h * d_head..(h + 1) * d_head]);
+            let dqkv = self.attention().gradient(&head.project(input), &dout)?;
+            grad = grad + head.input_gradient(&dqkv);
+        }
+        Ok(grad)
+    }
+}
+
+impl<A, S> Forward<ArrayBase<S, Ix2>> for MultiHeadAttention<A>
+where
+    A: Float + ScalarOperand,
+    S: Data<Elem = A>,
+{
+    type Output = cnc::Result<Array2<A>>;
+
+    fn forward(&self, input: &ArrayBase<S, Ix2>) -> Self::Output {
+        let concat = self.concat_heads(input)?;
+        Ok(concat.dot(self.output().weights()) + self.output().bias())
+    }
+}
+
+impl<A, S, C> ForwardWith<ArrayBase<S, Ix2>, C> for MultiHeadAttention<A>
+where
+    A: Float + ScalarOperand,
+    C: RawContext,
+    S: Data<Elem = A>,
+{
+    type Output = cnc::Result<Array2<A>>;
+
+    fn forward_with(&self, input: &ArrayBase<S, Ix2>, ctx: &mut C) -> Self::Output {
+        let concat = self.concat_heads_with(input, ctx)?;
+        Ok(concat.dot(self.output().weights()) + self.output().bias())
+    }
+}
+
+impl<A, S, T> Backward<ArrayBase<S, Ix2>, ArrayBase<T, Ix2>> for MultiHeadAttention<A>
+where
+    A: Float + ScalarOperand,
+    S: Data<Elem = A>,
+    T: Data<Elem = A>,
+{
+    type Elem = A;
+
+    /// ## Panics
+    ///
+    /// Panics if the mask of the attention mechanism cannot be broadcast onto the scores of
+    /// the input; use [`gradients`](MultiHeadAttention::gradients) to handle such errors.
+    fn backward(&mut self, input: &ArrayBase<S, Ix2>, delta: &ArrayBase<T, Ix2>, gamma: A) {
+        let (heads, output, _) = self
+            .gradients(input, delta)
+            .expect("the attention mask is incompatible with the input");
+        for (head, grad) in self.heads.iter_mut().zip(heads) {
+            head.query.scaled_add(gamma, &grad.query);
+            head.key.scaled_add(gamma, &grad.key);
+            head.value.scaled_add(gamma, &grad.value);
+        }
+        self.output
+            .weights_mut()
+            .scaled_add(gamma, output.weights());
+        self.output.bias_mut().scaled_add(gamma, output.bias());
+    }
+}
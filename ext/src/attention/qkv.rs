@@ -2,11 +2,22 @@
     Appellation: qkv <module>
     Contrib: @FL03
 */
-use cnc::Forward;
+use cnc::{Backward, Forward};
 use ndarray::linalg::Dot;
-use ndarray::{ArrayBase, Data, DataOwned, Dimension, Ix2, RawData, ShapeBuilder};
+use ndarray::{
+    Array2, ArrayBase, Data, DataMut, DataOwned, Dimension, Ix2, LinalgScalar, RawData,
+    ShapeBuilder,
+};
 use num_traits::{One, Zero};
 
+#[cfg(feature = "rand")]
+use cnc::init::{
+    NdRandom,
+    rand_distr::{Distribution, StandardNormal, uniform::SampleUniform},
+};
+#[cfg(feature = "rand")]
+use num_traits::{Float, FromPrimitive};
+
 pub type Qkv<A = f64, D = Ix2> = QkvParamsBase<ndarray::OwnedRepr<A>, D>;
 
 /// This object is designed to store the parameters of the QKV (Query, Key, Value)
@@ -121,6 +132,105 @@ where
     }
 }
 
+impl<A, S> QkvParamsBase<S, Ix2>
+where
+    S: Data<Elem = A>,
+{
+    /// project the given input onto the query, key and value subspaces, returning the results
+    /// as a new set of owned parameters
+    pub fn project<T>(&self, input: &ArrayBase<T, Ix2>) -> Qkv<A>
+    where
+        A: LinalgScalar,
+        T: Data<Elem = A>,
+    {
+        QkvParamsBase::new(
+            input.dot(&self.query),
+            input.dot(&self.key),
+            input.dot(&self.value),
+        )
+    }
+}
+
+impl<A, S> QkvParamsBase<S, Ix2>
+where
+    S: Data<Elem = A>,
+{
+    /// given the `input` and the gradients w.r.t. the projected query, key and value vectors,
+    /// compute the gradients of the projection weights, i.e. `X^T dQ`, `X^T dK` and `X^T dV`
+    pub fn gradient<T, U>(&self, input: &ArrayBase<T, Ix2>, delta: &QkvParamsBase<U, Ix2>) -> Qkv<A>
+    where
+        A: LinalgScalar,
+        T: Data<Elem = A>,
+        U: Data<Elem = A>,
+    {
+        QkvParamsBase::new(
+            input.t().dot(&delta.query),
+            input.t().dot(&delta.key),
+            input.t().dot(&delta.value),
+        )
+    }
+    /// given the gradients w.r.t. the projected query, key and value vectors, compute the
+    /// gradient w.r.t. the input of the projections
+    pub fn input_gradient<U>(&self, delta: &QkvParamsBase<U, Ix2>) -> Array2<A>
+    where
+        A: LinalgScalar,
+        U: Data<Elem = A>,
+    {
+        delta.query.dot(&self.query.t())
+            + delta.key.dot(&self.key.t())
+            + delta.value.dot(&self.value.t())
+    }
+}
+
+#[cfg(feature = "rand")]
+impl<A, S, D> QkvParamsBase<S, D>
+where
+    D: Dimension,
+    S: DataOwned<Elem = A>,
+{
+    /// initialize the query, key and value parameters using the Glorot (Xavier) normal
+    /// distribution
+    pub fn glorot_normal<Sh: ShapeBuilder<Dim = D>>(shape: Sh) -> Self
+    where
+        A: Float + FromPrimitive,
+        StandardNormal: Distribution<A>,
+    {
+        let dim = shape.into_shape_with_order().raw_dim().clone();
+        Self::new(
+            ArrayBase::glorot_normal(dim.clone()),
+            ArrayBase::glorot_normal(dim.clone()),
+            ArrayBase::glorot_normal(dim),
+        )
+    }
+    /// initialize the query, key and value parameters using the Glorot (Xavier) uniform
+    /// distribution
+    pub fn glorot_uniform<Sh: ShapeBuilder<Dim = D>>(shape: Sh) -> cnc::Result<Self>
+    where
+        A: Float + FromPrimitive + SampleUniform,
+        <A as SampleUniform>::Sampler: Clone,
+    {
+        let dim = shape.into_shape_with_order().raw_dim().clone();
+        Ok(Self::new(
+            ArrayBase::glorot_uniform(dim.clone())?,
+            ArrayBase::glorot_uniform(dim.clone())?,
+            ArrayBase::glorot_uniform(dim)?,
+        ))
+    }
+    /// initialize the query, key and value parameters using the LeCun normal distribution
+    pub fn lecun_normal<Sh: ShapeBuilder<Dim = D>>(shape: Sh) -> Self
+    where
+        A: Float,
+        StandardNormal: Distribution<A>,
+    {
+        let dim = shape.into_shape_with_order().raw_dim().clone();
+        Self::new(
+            ArrayBase::lecun_normal(dim.clone()),
+            ArrayBase::lecun_normal(dim.clone()),
+            ArrayBase::lecun_normal(dim),
+        )
+    }
+}
+
 impl<X, Y, A, S, D> Forward<X> for QkvParamsBase<S, D>
 where
     A: Clone,
@@ -139,3 +249,22 @@ where
         query + key + value
     }
 }
+
+impl<A, S, T, U> Backward<ArrayBase<T, Ix2>, QkvParamsBase<U, Ix2>> for QkvParamsBase<S, Ix2>
+where
+    A: LinalgScalar,
+    S: DataMut<Elem = A>,
+    T: Data<Elem = A>,
+    U: Data<Elem = A>,
+{
+    type Elem = A;
+    /// update the projection weights given the `input` and the `delta` w.r.t. the projected
+    /// query, key and value vectors; following the conventions of the framework, the scaled
+    /// gradient is _added_ to the weights.
+    fn backward(&mut self, input: &ArrayBase<T, Ix2>, delta: &QkvParamsBase<U, Ix2>, gamma: A) {
+        let grad = self.gradient(input, delta);
+        self.query.scaled_add(gamma, &grad.query);
+        self.key.scaled_add(gamma, &grad.key);
+        self.value.scaled_add(gamma, &grad.value);
+    }
+}
@@ -2,10 +2,16 @@
     Appellation: scaled <module>
     Contrib: @FL03
 */
-use super::QkvParamsBase;
-use ndarray::linalg::Dot;
+use super::{Qkv, QkvParamsBase};
+use cnc::activate::softmax_axis;
+use cnc::nn::{ForwardWith, Mode, RawContext};
+#[cfg(feature = "rand")]
+use cnc::utils::Dropout;
+use cnc::utils::tril;
+use cnc::{Error, Forward, Result};
 use ndarray::{
-    Array, ArrayBase, ArrayView, Data, DataOwned, Dimension, Ix2, OwnedRepr, RawData, ScalarOperand,
+    Array2, Array3, ArrayBase, Axis, Data, DataOwned, Dimension, ErrorKind, Ix2, Ix3, OwnedRepr,
+    RawData, ScalarOperand, ShapeError,
 };
 use num_traits::Float;
 
@@ -26,6 +32,28 @@ where
     pub(crate) temperature: A,
     /// The attention mask used to prevent attending to certain positions in the input sequence.
     pub(crate) mask: Option<ArrayBase<S, D>>,
+    /// whether or not each position is prevented from attending to subsequent positions
+    pub(crate) causal: bool,
+    /// whether or not the mechanism is in training mode; dropout is only applied when training
+    pub(crate) training: bool,
+}
+
+/// returns an additive, causal mask of shape `(n, m)` whose entries are zero on and below
+/// the diagonal and negative infinity above it, preventing each of the `n` queries from
+/// attending to any of the `m` keys that follow it.
+pub fn causal_mask<A, S>(n: usize, m: usize) -> ArrayBase<S, Ix2>
+where
+    A: Float,
+    S: DataOwned<Elem = A>,
+{
+    let lower = tril(&Array2::<A>::ones((n, m)));
+    ArrayBase::from_shape_fn((n, m), |ij| {
+        if lower[ij].is_zero() {
+            A::neg_infinity()
+        } else {
+            A::zero()
+        }
+    })
 }
 
 impl<A, S, D> SDPA<A, D, S>
@@ -40,15 +68,19 @@ where
     /// * `shape` - The shape of the mask and attention scores array.
     /// * `dropout` - The dropout rate to be applied to the attention scores.
     /// * `temperature` - The temperature parameter used to scale the attention scores.
+    ///
+    /// **Note:** the dropout rate is not validated; use [`with_dropout`](SDPA::with_dropout)
+    /// to ensure that it lies within `[0, 1)`.
     pub fn new(dropout: A, temperature: A) -> Self
     where
-        A: Clone + Default,
         S: DataOwned,
     {
         Self {
             dropout,
             temperature,
             mask: None,
+            causal: false,
+            training: false,
         }
     }
     /// returns an immutable reference to the dropout probability
@@ -59,6 +91,14 @@ where
     pub fn dropout_mut(&mut self) -> &mut A {
         &mut self.dropout
     }
+    /// returns true if a [`causal_mask`] is applied to the attention scores
+    pub const fn is_causal(&self) -> bool {
+        self.causal
+    }
+    /// returns true if the mechanism is in training mode
+    pub const fn is_training(&self) -> bool {
+        self.training
+    }
     /// returns an immutable reference to the attention mask
     pub const fn mask(&self) -> Option<&ArrayBase<S, D>> {
         self.mask.as_ref()
@@ -76,20 +116,41 @@ where
         &mut self.temperature
     }
 
-    pub fn set_dropout(&mut self, dropout: A) -> &mut Self {
+    /// set the dropout probability, returning an error unless `0 <= dropout < 1`
+    pub fn set_dropout(&mut self, dropout: A) -> Result<&mut Self>
+    where
+        A: Float,
+    {
+        if !(dropout >= A::zero() && dropout < A::one()) {
+            return Err(Error::InvalidModelConfig);
+        }
         *self.dropout_mut() = dropout;
-        self
+        Ok(self)
     }
 
     pub fn set_temperature(&mut self, temperature: A) -> &mut Self {
         *self.temperature_mut() = temperature;
         self
     }
+    /// toggle the causal masking of the attention scores
+    pub fn set_causal(&mut self, causal: bool) -> &mut Self {
+        self.causal = causal;
+        self
+    }
+    /// toggle the training mode of the mechanism
+    pub fn set_training(&mut self, training: bool) -> &mut Self {
+        self.training = training;
+        self
+    }
     /// set the attention mask to the given value
     pub fn set_mask(&mut self, mask: ArrayBase<S, D>) -> &mut Self {
         self.mask = Some(mask);
         self
     }
+    /// remove the attention mask, returning it if it was set
+    pub fn take_mask(&mut self) -> Option<ArrayBase<S, D>> {
+        self.mask.take()
+    }
     /// consumes the current instance to create another with the given mask
     pub fn with_mask(self, mask: ArrayBase<S, D>) -> Self {
         Self {
@@ -97,28 +158,262 @@ where
             ..self
         }
     }
+    /// consumes the current instance to create another with the given dropout probability,
+    /// returning an error unless `0 <= dropout < 1`
+    pub fn with_dropout(mut self, dropout: A) -> Result<Self>
+    where
+        A: Float,
+    {
+        self.set_dropout(dropout)?;
+        Ok(self)
+    }
+    /// consumes the current instance to create another which applies a [`causal_mask`] to
+    /// the attention scores when `causal` is true
+    pub fn with_causal(self, causal: bool) -> Self {
+        Self { causal, ..self }
+    }
+    /// consumes the current instance to create another with the given training mode
+    pub fn with_training(self, training: bool) -> Self {
+        Self { training, ..self }
+    }
     /// Computes the attention scores using the dot product of the query and key vectors,
-    pub fn attention(
+    /// normalizing each row with the softmax function before weighting the values.
+    ///
+    /// The mechanism is implemented for a single sequence, i.e. queries, keys and values of
+    /// shape `(seq_len, d)`, as well as for a batch of sequences of shape
+    /// `(batch, seq_len, d)`. When the mechanism is in training mode (and the `rand` feature
+    /// is enabled), inverted dropout is applied to the attention weights; use
+    /// [`attention_with`](SDPA::attention_with) to draw from a seedable [`Context`].
+    ///
+    /// [`Context`]: cnc::nn::Context
+    pub fn attention<X, Y>(&self, qkv: &X) -> Y
+    where
+        Self: Forward<X, Output = Y>,
+    {
+        <Self as Forward<X>>::forward(self, qkv)
+    }
+    /// computes the [`attention`](SDPA::attention) within the given context, which decides
+    /// whether dropout is applied and provides the random number generator it draws from
+    pub fn attention_with<X, Y, C>(&self, qkv: &X, ctx: &mut C) -> Y
+    where
+        C: RawContext,
+        Self: ForwardWith<X, C, Output = Y>,
+    {
+        <Self as ForwardWith<X, C>>::forward_with(self, qkv, ctx)
+    }
+}
+
+impl<A, S> SDPA<A, Ix2, S>
+where
+    S: RawData<Elem = A>,
+{
+    /// verify that the stored mask, if any, can be broadcast onto the attention scores of
+    /// `n` queries and `m` keys, i.e. an array of shape `(n, m)`
+    pub fn check_mask(&self, n: usize, m: usize) -> Result<()>
+    where
+        S: Data,
+    {
+        match self.mask() {
+            Some(mask) if mask.broadcast((n, m)).is_none() => {
+                Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into())
+            }
+            _ => Ok(()),
+        }
+    }
+    /// computes the scaled (and masked) attention scores, i.e.
+    ///
+    /// ```math
+    /// S = \frac{QK^T}{\tau} + M
+    /// ```
+    ///
+    /// When the mechanism is causal, the [`causal_mask`] is built from the number of queries
+    /// and keys given; an error is returned unless any stored mask can be broadcast to the
+    /// shape `(n_q, n_k)` (see [`check_mask`](SDPA::check_mask)).
+    pub fn scores<T>(&self, query: &ArrayBase<T, Ix2>, key: &ArrayBase<T, Ix2>) -> Result<Array2<A>>
+    where
+        A: Float + ScalarOperand,
+        S: Data,
+        T: Data<Elem = A>,
+    {
+        // compute the dot product of the query and key vectors, scaling by the temperature
+        let mut scores = query.dot(&key.t()) / self.temperature;
+        let (n, m) = scores.dim();
+        self.check_mask(n, m)?;
+        if self.is_causal() {
+            scores = scores + causal_mask::<A, OwnedRepr<A>>(n, m);
+        }
+        // apply the additive mask, if any
+        match self.mask() {
+            Some(mask) => Ok(scores + mask),
+            None => Ok(scores),
+        }
+    }
+    /// computes the attention weights by applying a numerically stable softmax to each row of
+    /// the [`scores`](SDPA::scores)
+    pub fn weights<T>(
+        &self,
+        query: &ArrayBase<T, Ix2>,
+        key: &ArrayBase<T, Ix2>,
+    ) -> Result<Array2<A>>
+    where
+        A: Float + ScalarOperand,
+        S: Data,
+        T: Data<Elem = A>,
+    {
+        self.scores(query, key)
+            .map(|scores| softmax_axis(&scores, 1))
+    }
+
+    /// computes the gradients of the attention output w.r.t. the query, key and value vectors
+    /// given the `delta` (the gradient of the loss w.r.t. the output). With
+    /// `P = softmax(S)` and `S = QK^T / τ + M`, the gradients are
+    ///
+    /// ```math
+    /// \begin{aligned}
+    /// dV &= P^T \delta \\
+    /// dS &= P \odot (\delta V^T - \mathrm{rowsum}(P \odot \delta V^T)) \\
+    /// dQ &= dS K / \tau \\
+    /// dK &= dS^T Q / \tau
+    /// \end{aligned}
+    /// ```
+    ///
+    /// **Note:** the gradients are computed without dropout, i.e. w.r.t. the deterministic
+    /// (evaluation) mode of the mechanism.
+    pub fn gradient<T, U>(
         &self,
-        QkvParamsBase { query, key, value }: &QkvParamsBase<S, D>,
-    ) -> Array<A, D>
+        qkv: &QkvParamsBase<T, Ix2>,
+        delta: &ArrayBase<U, Ix2>,
+    ) -> Result<Qkv<A>>
     where
         A: Float + ScalarOperand,
         S: Data,
-        Array<A, D>: Dot<ArrayBase<S, D>, Output = Array<A, D>>,
-        for<'a> ArrayBase<S, D>: Dot<ArrayView<'a, A, D>, Output = Array<A, D>>,
+        T: Data<Elem = A>,
+        U: Data<Elem = A>,
+    {
+        let QkvParamsBase { query, key, value } = qkv;
+        let weights = self.weights(query, key)?;
+        let dscores = self.scores_gradient(&weights, value, delta) / self.temperature;
+        Ok(QkvParamsBase::new(
+            dscores.dot(key),
+            dscores.t().dot(query),
+            weights.t().dot(delta),
+        ))
+    }
+    /// computes the gradient of the attention output w.r.t. the temperature parameter given
+    /// the `delta`, i.e. `-sum(dS ⊙ QK^T) / τ^2`
+    pub fn temperature_gradient<T, U>(
+        &self,
+        QkvParamsBase { query, key, value }: &QkvParamsBase<T, Ix2>,
+        delta: &ArrayBase<U, Ix2>,
+    ) -> Result<A>
+    where
+        A: Float + ScalarOperand,
+        S: Data,
+        T: Data<Elem = A>,
+        U: Data<Elem = A>,
+    {
+        let weights = self.weights(query, key)?;
+        let dscores = self.scores_gradient(&weights, value, delta);
+        let raw = query.dot(&key.t());
+        Ok(-(dscores * raw).sum() / self.temperature.powi(2))
+    }
+    /// backpropagate the `delta` through the weighted values and the row-wise softmax,
+    /// returning the gradient w.r.t. the (scaled) scores
+    fn scores_gradient<T, U>(
+        &self,
+        weights: &Array2<A>,
+        value: &ArrayBase<T, Ix2>,
+        delta: &ArrayBase<U, Ix2>,
+    ) -> Array2<A>
+    where
+        A: Float + ScalarOperand,
+        T: Data<Elem = A>,
+        U: Data<Elem = A>,
     {
-        // Compute the dot product of the query and key vectors
-        let scores = query.dot(&key.t());
+        let dweights = delta.dot(&value.t());
+        let dot = (&dweights * weights).sum_axis(Axis(1)).insert_axis(Axis(1));
+        // masked entries carry a weight of zero, so they receive no gradient
+        weights * &(dweights - dot)
+    }
+}
 
-        // Scale the scores by the square root of the dimension of the key vectors
-        let scaled_scores = scores / self.temperature;
+/*
+ ************* Implementations *************
+*/
 
-        // Apply the softmax function to obtain the attention weights
-        let attention_weights = scaled_scores.mapv(|x| x.exp()) / scaled_scores.sum();
+impl<A, S, T, C> ForwardWith<QkvParamsBase<T, Ix2>, C> for SDPA<A, Ix2, S>
+where
+    A: Float + ScalarOperand,
+    C: RawContext,
+    S: Data<Elem = A>,
+    T: Data<Elem = A>,
+{
+    type Output = Result<Array2<A>>;
+    /// computes the attention of a single sequence, i.e. queries, keys and values of shape
+    /// `(seq_len, d)`, normalizing each row of the scores with the softmax function before
+    /// weighting the values. While the context is training (and the `rand` feature is
+    /// enabled), inverted dropout is applied to the attention weights using the random number
+    /// generator of the context.
+    fn forward_with(
+        &self,
+        QkvParamsBase { query, key, value }: &QkvParamsBase<T, Ix2>,
+        ctx: &mut C,
+    ) -> Self::Output {
+        #[allow(unused_mut)]
+        let mut weights = self.weights(query, key)?;
+        #[cfg(feature = "rand")]
+        if self.dropout > A::zero() {
+            // the probability is validated by `set_dropout`
+            let dropout = Dropout::new(self.dropout.to_f64().unwrap());
+            weights = dropout.forward_with(&weights, ctx);
+        }
+        #[cfg(not(feature = "rand"))]
+        let _ = ctx;
+        // compute the final attention output by weighting the value vectors
+        Ok(weights.dot(value))
+    }
+}
 
-        // Compute the final attention output by multiplying the attention weights with the value vectors
+impl<A, S, T, C> ForwardWith<QkvParamsBase<T, Ix3>, C> for SDPA<A, Ix2, S>
+where
+    A: Float + ScalarOperand,
+    C: RawContext,
+    S: Data<Elem = A>,
+    T: Data<Elem = A>,
+{
+    type Output = Result<Array3<A>>;
+    /// computes the attention of a batch of sequences, i.e. queries, keys and values of shape
+    /// `(batch, seq_len, d)`, with each sequence attending independently
+    fn forward_with(
+        &self,
+        QkvParamsBase { query, key, value }: &QkvParamsBase<T, Ix3>,
+        ctx: &mut C,
+    ) -> Self::Output {
+        let (batch, n, _) = query.dim();
+        let mut output = Array3::zeros((batch, n, value.len_of(Axis(2))));
+        let seqs = query
+            .outer_iter()
+            .zip(key.outer_iter())
+            .zip(value.outer_iter());
+        for (((q, k), v), mut y) in seqs.zip(output.outer_iter_mut()) {
+            y.assign(&self.forward_with(&QkvParamsBase::new(q, k, v), ctx)?);
+        }
+        Ok(output)
+    }
+}
 
-        attention_weights.dot(value)
+impl<A, S, T, D> Forward<QkvParamsBase<T, D>> for SDPA<A, Ix2, S>
+where
+    D: Dimension,
+    S: RawData<Elem = A>,
+    T: RawData<Elem = A>,
+    Self: ForwardWith<QkvParamsBase<T, D>, Mode>,
+{
+    type Output = <Self as ForwardWith<QkvParamsBase<T, D>, Mode>>::Output;
+    /// computes the attention in the mode of the mechanism (see
+    /// [`is_training`](SDPA::is_training)); since the mode has no random number generator of
+    /// its own, any dropout draws from the thread-local generator
+    fn forward(&self, input: &QkvParamsBase<T, D>) -> Self::Output {
+        self.forward_with(input, &mut Mode::from_training(self.is_training()))
     }
 }
@@ -9,6 +9,7 @@
 //!
 //! - `attention`: Enables attention mechanisms commonly used in transformer architectures.
 //! - `snn`: Introduces spiking neural network components for neuromorphic computing.
+//! - `transformer`: Enables the transformer encoder along with its positional encodings.
 //!
 #![allow(
     clippy::missing_errors_doc,
@@ -40,6 +41,8 @@ mod macros {
 pub mod attention;
 #[cfg(feature = "snn")]
 pub mod snn;
+#[cfg(feature = "transformer")]
+pub mod transformer;
 
 /// re-exports
 #[cfg(feature = "attention")]
@@ -51,4 +54,6 @@ pub mod prelude {
     pub use crate::attention::prelude::*;
     #[cfg(feature = "snn")]
     pub use crate::snn::prelude::*;
+    #[cfg(feature = "transformer")]
+    pub use crate::transformer::prelude::*;
 }
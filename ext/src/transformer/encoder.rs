@@ -0,0 +1,176 @@
+/*
+    Appellation: encoder <module>
+    Contrib: @FL03
+*/
+use super::{FeedForward, PositionalEncoding};
+use crate::attention::MultiHeadAttention;
+use cnc::utils::layer_norm_axis;
+use cnc::{Error, Forward};
+use ndarray::{Array2, Array3, ArrayBase, Axis, Data, Ix2, Ix3, ScalarOperand};
+use num_traits::{Float, FromPrimitive};
+
+#[cfg(feature = "rand")]
+use cnc::init::rand_distr::{Distribution, StandardNormal};
+
+/// The [`EncoderBlock`] is a single layer of the transformer's encoder stack. The input is
+/// passed through a [`MultiHeadAttention`] sub-layer followed by a position-wise
+/// [`FeedForward`] network, where each sub-layer is wrapped by a residual connection and
+/// followed by layer normalization (i.e. the _post-norm_ formulation):
+///
+/// ```math
+/// \begin{aligned}
+/// h &= \mathrm{LayerNorm}(x + \mathrm{MultiHead}(x)) \\
+/// y &= \mathrm{LayerNorm}(h + \mathrm{FFN}(h))
+/// \end{aligned}
+/// ```
+///
+/// The first block of a stack may optionally add a [`PositionalEncoding`] onto its input
+/// (see [`with_encoding`](EncoderBlock::with_encoding)) before the attention sub-layer.
+pub struct EncoderBlock<A = f64> {
+    /// the self-attention sub-layer
+    pub(crate) attention: MultiHeadAttention<A>,
+    /// the position-wise feed-forward sub-layer
+    pub(crate) ffn: FeedForward<A>,
+    /// the positional encoding added onto the input, if any
+    pub(crate) encoding: Option<PositionalEncoding<A>>,
+    /// the epsilon used by the layer normalization to avoid division by zero
+    pub(crate) eps: f64,
+}
+
+impl<A> EncoderBlock<A> {
+    /// create a new encoder block from the given sub-layers
+    pub fn new(attention: MultiHeadAttention<A>, ffn: FeedForward<A>) -> cnc::Result<Self> {
+        if attention.d_model() != ffn.d_model() {
+            return Err(Error::InvalidModelConfig);
+        }
+        Ok(Self {
+            attention,
+            ffn,
+            encoding: None,
+            eps: 1e-5,
+        })
+    }
+    /// create a new encoder block with each of its parameters set to zero
+    pub fn zeros(num_heads: usize, d_model: usize, d_ff: usize) -> cnc::Result<Self>
+    where
+        A: Float + FromPrimitive,
+    {
+        Self::new(
+            MultiHeadAttention::zeros(num_heads, d_model)?,
+            FeedForward::zeros(d_model, d_ff),
+        )
+    }
+    #[cfg(feature = "rand")]
+    /// create a new encoder block whose parameters are initialized using the Glorot (Xavier)
+    /// normal distribution
+    pub fn glorot_normal(num_heads: usize, d_model: usize, d_ff: usize) -> cnc::Result<Self>
+    where
+        A: Float + FromPrimitive + ScalarOperand,
+        StandardNormal: Distribution<A>,
+    {
+        Self::new(
+            MultiHeadAttention::glorot_normal(num_heads, d_model)?,
+            FeedForward::glorot_normal(d_model, d_ff),
+        )
+    }
+    #[cfg(feature = "rand")]
+    /// create a new encoder block whose parameters are initialized using the LeCun normal
+    /// distribution
+    pub fn lecun_normal(num_heads: usize, d_model: usize, d_ff: usize) -> cnc::Result<Self>
+    where
+        A: Float + FromPrimitive + ScalarOperand,
+        StandardNormal: Distribution<A>,
+    {
+        Self::new(
+            MultiHeadAttention::lecun_normal(num_heads, d_model)?,
+            FeedForward::lecun_normal(d_model, d_ff),
+        )
+    }
+    /// returns an immutable reference to the attention sub-layer
+    pub const fn attention(&self) -> &MultiHeadAttention<A> {
+        &self.attention
+    }
+    /// returns a mutable reference to the attention sub-layer
+    pub fn attention_mut(&mut self) -> &mut MultiHeadAttention<A> {
+        &mut self.attention
+    }
+    /// returns an immutable reference to the feed-forward sub-layer
+    pub const fn ffn(&self) -> &FeedForward<A> {
+        &self.ffn
+    }
+    /// returns a mutable reference to the feed-forward sub-layer
+    pub fn ffn_mut(&mut self) -> &mut FeedForward<A> {
+        &mut self.ffn
+    }
+    /// returns an immutable reference to the positional encoding of the block, if any
+    pub const fn encoding(&self) -> Option<&PositionalEncoding<A>> {
+        self.encoding.as_ref()
+    }
+    /// returns the epsilon used by the layer normalization
+    pub const fn eps(&self) -> f64 {
+        self.eps
+    }
+    /// returns the dimension of the model
+    pub fn d_model(&self) -> usize {
+        self.attention.d_model()
+    }
+    /// set the epsilon used by the layer normalization
+    pub fn set_eps(&mut self, eps: f64) -> &mut Self {
+        self.eps = eps;
+        self
+    }
+    /// consumes the current instance to create another with the given epsilon
+    pub fn with_eps(self, eps: f64) -> Self {
+        Self { eps, ..self }
+    }
+    /// consumes the current instance to create another that adds the given positional
+    /// encoding onto its input; returns an error if the dimension of the encoding differs
+    /// from that of the model
+    pub fn with_encoding(self, encoding: PositionalEncoding<A>) -> cnc::Result<Self> {
+        if encoding.d_model() != self.d_model() {
+            return Err(Error::InvalidModelConfig);
+        }
+        Ok(Self {
+            encoding: Some(encoding),
+            ..self
+        })
+    }
+}
+
+impl<A, S> Forward<ArrayBase<S, Ix2>> for EncoderBlock<A>
+where
+    A: Float + FromPrimitive + ScalarOperand,
+    S: Data<Elem = A>,
+{
+    type Output = cnc::Result<Array2<A>>;
+
+    fn forward(&self, input: &ArrayBase<S, Ix2>) -> Self::Output {
+        let input = match &self.encoding {
+            Some(encoding) => encoding.try_forward(input)?,
+            None => input.to_owned(),
+        };
+        // self-attention sub-layer with a residual connection
+        let attn = self.attention.forward(&input)?;
+        let hidden = layer_norm_axis(&(input + &attn), Axis(1), self.eps);
+        // position-wise feed-forward sub-layer with a residual connection
+        let ffn = self.ffn.forward(&hidden);
+        Ok(layer_norm_axis(&(hidden + &ffn), Axis(1), self.eps))
+    }
+}
+
+impl<A, S> Forward<ArrayBase<S, Ix3>> for EncoderBlock<A>
+where
+    A: Float + FromPrimitive + ScalarOperand,
+    S: Data<Elem = A>,
+{
+    type Output = cnc::Result<Array3<A>>;
+
+    fn forward(&self, input: &ArrayBase<S, Ix3>) -> Self::Output {
+        let mut output = Array3::zeros(input.raw_dim());
+        // each sequence of the batch is processed independently
+        for (x, mut y) in input.outer_iter().zip(output.outer_iter_mut()) {
+            y.assign(&self.forward(&x)?);
+        }
+        Ok(output)
+    }
+}
@@ -0,0 +1,123 @@
+/*
+    Appellation: ffn <module>
+    Contrib: @FL03
+*/
+use cnc::params::Params;
+use cnc::{Error, Forward, LinearLayer, ReluLayer};
+use ndarray::{Array2, ArrayBase, Data, Ix2, ScalarOperand};
+use num_traits::Float;
+
+#[cfg(feature = "rand")]
+use cnc::init::{
+    NdRandom,
+    rand_distr::{Distribution, StandardNormal},
+};
+#[cfg(feature = "rand")]
+use num_traits::FromPrimitive;
+
+/// The position-wise [`FeedForward`] network of the transformer; two linear transformations
+/// with a ReLU activation in between, applied to each position independently:
+///
+/// ```math
+/// FFN(x) = \max(0, xW_1 + b_1)W_2 + b_2
+/// ```
+#[derive(Clone, Debug)]
+pub struct FeedForward<A = f64> {
+    /// the hidden layer with weights of shape `(d_model, d_ff)`
+    pub(crate) hidden: ReluLayer<Params<A>>,
+    /// the output layer with weights of shape `(d_ff, d_model)`
+    pub(crate) output: LinearLayer<Params<A>>,
+}
+
+impl<A> FeedForward<A> {
+    /// create a new instance of the network from the given hidden and output parameters
+    pub fn from_params(hidden: Params<A>, output: Params<A>) -> cnc::Result<Self> {
+        let (d_model, d_ff) = hidden.weights().dim();
+        if output.weights().dim() != (d_ff, d_model) {
+            return Err(Error::InvalidModelConfig);
+        }
+        Ok(Self {
+            hidden: ReluLayer::from_params(hidden),
+            output: LinearLayer::from_params(output),
+        })
+    }
+    /// create a new instance of the network with each of its parameters set to zero
+    pub fn zeros(d_model: usize, d_ff: usize) -> Self
+    where
+        A: Clone + num_traits::Zero,
+    {
+        Self {
+            hidden: ReluLayer::from_params(Params::zeros((d_model, d_ff))),
+            output: LinearLayer::from_params(Params::zeros((d_ff, d_model))),
+        }
+    }
+    #[cfg(feature = "rand")]
+    /// create a new instance of the network whose parameters are initialized using the
+    /// Glorot (Xavier) normal distribution
+    pub fn glorot_normal(d_model: usize, d_ff: usize) -> Self
+    where
+        A: Float + FromPrimitive + ScalarOperand,
+        StandardNormal: Distribution<A>,
+    {
+        Self {
+            hidden: ReluLayer::from_params(Params::glorot_normal((d_model, d_ff))),
+            output: LinearLayer::from_params(Params::glorot_normal((d_ff, d_model))),
+        }
+    }
+    #[cfg(feature = "rand")]
+    /// create a new instance of the network whose parameters are initialized using the LeCun
+    /// normal distribution
+    pub fn lecun_normal(d_model: usize, d_ff: usize) -> Self
+    where
+        A: Float + FromPrimitive + ScalarOperand,
+        StandardNormal: Distribution<A>,
+    {
+        Self {
+            hidden: ReluLayer::from_params(Params::lecun_normal((d_model, d_ff))),
+            output: LinearLayer::from_params(Params::lecun_normal((d_ff, d_model))),
+        }
+    }
+    /// returns the dimension of the model
+    pub fn d_model(&self) -> usize {
+        self.hidden.params().weights().nrows()
+    }
+    /// returns the dimension of the hidden layer
+    pub fn d_ff(&self) -> usize {
+        self.hidden.params().weights().ncols()
+    }
+    /// returns an immutable reference to the hidden layer
+    pub const fn hidden(&self) -> &ReluLayer<Params<A>> {
+        &self.hidden
+    }
+    /// returns a mutable reference to the hidden layer
+    pub fn hidden_mut(&mut self) -> &mut ReluLayer<Params<A>> {
+        &mut self.hidden
+    }
+    /// returns an immutable reference to the output layer
+    pub const fn output(&self) -> &LinearLayer<Params<A>> {
+        &self.output
+    }
+    /// returns a mutable reference to the output layer
+    pub fn output_mut(&mut self) -> &mut LinearLayer<Params<A>> {
+        &mut self.output
+    }
+}
+
+impl<A, S> Forward<ArrayBase<S, Ix2>> for FeedForward<A>
+where
+    A: Float + ScalarOperand,
+    S: Data<Elem = A>,
+{
+    type Output = Array2<A>;
+
+    fn forward(&self, input: &ArrayBase<S, Ix2>) -> Self::Output {
+        // each row of the input is a position, hence the weights are applied on the right
+        let hidden = self.hidden.params();
+        let z = self
+            .hidden
+            .activate(input.dot(hidden.weights()) + hidden.bias());
+        let output = self.output.params();
+        self.output
+            .activate(z.dot(output.weights()) + output.bias())
+    }
+}
@@ -0,0 +1,96 @@
+/*
+    Appellation: transformer <module>
+    Contrib: @FL03
+*/
+//! ## Transformer
+//!
+//! This module provides the building blocks of the transformer architecture introduced in
+//! ["Attention is All You Need"](https://arxiv.org/pdf/1706.03762) by Vaswani et al.
+//!
+//! ### _Components_
+//!
+//! - [`EncoderBlock`]: a single encoder layer composed of a [`MultiHeadAttention`] sub-layer
+//!   and a position-wise [`FeedForward`] network, each wrapped by a residual connection and
+//!   followed by layer normalization.
+//! - [`FeedForward`]: the position-wise feed-forward network, i.e. two linear
+//!   transformations separated by a ReLU activation.
+//! - [`PositionalEncoding`]: the sinusoidal positional encodings used to inject information
+//!   about the position of each token within the sequence.
+//!
+//! [`MultiHeadAttention`]: crate::attention::MultiHeadAttention
+#[doc(inline)]
+pub use self::{encoder::EncoderBlock, ffn::FeedForward, position::*};
+
+pub mod encoder;
+pub mod ffn;
+pub mod position;
+
+pub(crate) mod prelude {
+    #[doc(inline)]
+    pub use super::encoder::EncoderBlock;
+    #[doc(inline)]
+    pub use super::ffn::FeedForward;
+    #[doc(inline)]
+    pub use super::position::PositionalEncoding;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cnc::Forward;
+    use ndarray::{Array2, Array3, Axis};
+
+    #[test]
+    fn test_positional_encoding() {
+        let pe = PositionalEncoding::<f64>::new(16, 8);
+        let encoding = pe.encoding();
+        assert_eq!(encoding.dim(), (16, 8));
+        // the first position encodes to alternating zeros and ones
+        for (i, &v) in encoding.row(0).iter().enumerate() {
+            assert_eq!(v, if i % 2 == 0 { 0.0 } else { 1.0 });
+        }
+        let input = Array2::<f64>::zeros((4, 8));
+        assert_eq!(pe.forward(&input), encoding.slice(ndarray::s![..4, ..]));
+        // sequences exceeding the maximum length, or of another dimension, are rejected
+        assert!(pe.try_forward(&Array2::<f64>::zeros((17, 8))).is_err());
+        assert!(pe.try_forward(&Array3::<f64>::zeros((2, 4, 6))).is_err());
+    }
+
+    #[test]
+    fn test_encoder_block() {
+        let (batch, seq_len, d_model) = (2, 5, 8);
+        let block = EncoderBlock::<f64>::zeros(2, d_model, 16).expect("invalid block");
+        let input = Array2::from_shape_fn((seq_len, d_model), |(i, j)| (i + j) as f64);
+        let output = block.forward(&input).expect("forward pass failed");
+        assert_eq!(output.dim(), (seq_len, d_model));
+        // each position should be normalized across the model dimension
+        for row in output.rows() {
+            assert!(row.mean().unwrap().abs() < 1e-9);
+        }
+        let batched = Array3::from_shape_fn((batch, seq_len, d_model), |(_, i, j)| (i + j) as f64);
+        let outputs = block.forward(&batched).expect("forward pass failed");
+        assert_eq!(outputs.dim(), (batch, seq_len, d_model));
+        for y in outputs.axis_iter(Axis(0)) {
+            assert_eq!(y, output);
+        }
+        // the errors of the positional encoding are propagated by the block
+        let encoded = EncoderBlock::<f64>::zeros(2, d_model, 16)
+            .and_then(|block| block.with_encoding(PositionalEncoding::new(4, d_model)))
+            .expect("invalid block");
+        assert!(encoded.forward(&input).is_err());
+        assert!(encoded.forward(&input.slice(ndarray::s![..4, ..])).is_ok());
+        assert!(block.with_encoding(PositionalEncoding::new(4, 6)).is_err());
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_encoder_block_init() {
+        let block = EncoderBlock::<f64>::glorot_normal(4, 16, 32).expect("invalid block");
+        let pe = PositionalEncoding::<f64>::new(32, 16);
+        let input = pe.forward(&Array2::<f64>::ones((10, 16)));
+        let output = block.forward(&input).expect("forward pass failed");
+        assert_eq!(output.dim(), (10, 16));
+        assert!(output.iter().all(|x| x.is_finite()));
+        assert!(EncoderBlock::<f64>::glorot_normal(3, 16, 32).is_err());
+    }
+}
@@ -0,0 +1,125 @@
+/*
+    Appellation: position <module>
+    Contrib: @FL03
+*/
+use cnc::{Error, Forward};
+use ndarray::{Array, Array2, Array3, ArrayBase, Data, Dimension, Ix2, Ix3, ScalarOperand, s};
+use num_traits::{Float, FromPrimitive};
+
+/// returns the sinusoidal positional encodings of shape `(seq_len, d_model)`, where
+///
+/// ```math
+/// \begin{aligned}
+/// PE_{(pos, 2i)} &= \sin(pos / 10000^{2i / d_{model}}) \\
+/// PE_{(pos, 2i + 1)} &= \cos(pos / 10000^{2i / d_{model}})
+/// \end{aligned}
+/// ```
+pub fn sinusoidal_encoding<A>(seq_len: usize, d_model: usize) -> Array2<A>
+where
+    A: Float + FromPrimitive,
+{
+    let base = A::from_f64(10000.0).unwrap();
+    let d = A::from_usize(d_model).unwrap();
+    Array2::from_shape_fn((seq_len, d_model), |(pos, i)| {
+        let pos = A::from_usize(pos).unwrap();
+        let exponent = A::from_usize(i - i % 2).unwrap() / d;
+        let angle = pos / base.powf(exponent);
+        if i % 2 == 0 { angle.sin() } else { angle.cos() }
+    })
+}
+
+/// The [`PositionalEncoding`] injects information about the relative (or absolute) position
+/// of each token by adding a precomputed, sinusoidal encoding onto the input embeddings.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct PositionalEncoding<A = f64> {
+    /// the precomputed encodings of shape `(max_len, d_model)`
+    pub(crate) encoding: Array2<A>,
+}
+
+impl<A> PositionalEncoding<A> {
+    /// create a new instance capable of encoding sequences of up to `max_len` positions
+    pub fn new(max_len: usize, d_model: usize) -> Self
+    where
+        A: Float + FromPrimitive,
+    {
+        Self {
+            encoding: sinusoidal_encoding(max_len, d_model),
+        }
+    }
+    /// returns an immutable reference to the precomputed encodings
+    pub const fn encoding(&self) -> &Array2<A> {
+        &self.encoding
+    }
+    /// returns the dimension of the model
+    pub fn d_model(&self) -> usize {
+        self.encoding.ncols()
+    }
+    /// returns the maximum length of the sequences supported by the encoding
+    pub fn max_len(&self) -> usize {
+        self.encoding.nrows()
+    }
+    /// add the encodings onto the input, whose last two axes are the positions and features
+    /// of the sequence (the encodings are broadcast across any leading, batch axes); returns
+    /// an error if the sequence exceeds the maximum length of the encoding or the number of
+    /// features differs from the dimension of the model
+    pub fn try_forward<S, D>(&self, input: &ArrayBase<S, D>) -> cnc::Result<Array<A, D>>
+    where
+        A: Float,
+        D: Dimension,
+        S: Data<Elem = A>,
+    {
+        let ndim = input.ndim();
+        if ndim < 2 {
+            return Err(Error::IncompatibleInput);
+        }
+        let (seq_len, features) = (input.shape()[ndim - 2], input.shape()[ndim - 1]);
+        if features != self.d_model() {
+            return Err(Error::InvalidInputFeatures(features, self.d_model()));
+        }
+        if seq_len > self.max_len() {
+            return Err(Error::IncompatibleInput);
+        }
+        let mut output = input.to_owned();
+        output.zip_mut_with(&self.encoding.slice(s![..seq_len, ..]), |x, &pe| {
+            *x = *x + pe
+        });
+        Ok(output)
+    }
+}
+
+impl<A, S> Forward<ArrayBase<S, Ix2>> for PositionalEncoding<A>
+where
+    A: Float + ScalarOperand,
+    S: Data<Elem = A>,
+{
+    type Output = Array2<A>;
+    /// add the encodings onto the given sequence
+    ///
+    /// ## Panics
+    ///
+    /// panics if the input is incompatible with the encoding; use
+    /// [`try_forward`](PositionalEncoding::try_forward) to handle such errors
+    fn forward(&self, input: &ArrayBase<S, Ix2>) -> Self::Output {
+        self.try_forward(input)
+            .expect("the input is incompatible with the positional encoding")
+    }
+}
+
+impl<A, S> Forward<ArrayBase<S, Ix3>> for PositionalEncoding<A>
+where
+    A: Float + ScalarOperand,
+    S: Data<Elem = A>,
+{
+    type Output = Array3<A>;
+    /// add the encodings onto each sequence of the batch
+    ///
+    /// ## Panics
+    ///
+    /// panics if the input is incompatible with the encoding; use
+    /// [`try_forward`](PositionalEncoding::try_forward) to handle such errors
+    fn forward(&self, input: &ArrayBase<S, Ix3>) -> Self::Output {
+        self.try_forward(input)
+            .expect("the input is incompatible with the positional encoding")
+    }
+}
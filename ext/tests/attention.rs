@@ -0,0 +1,176 @@
+/*
+    Appellation: attention <test>
+    Contrib: @FL03
+*/
+extern crate concision as cnc;
+
+mod common;
+
+use cnc::ex::testing::sample;
+use cnc::{Backward, Forward};
+use common::{TOL, assert_close, finite_difference, numerical_gradient};
+use concision_ext::attention::{MultiHeadAttention, Qkv, SDPA};
+use ndarray::prelude::*;
+
+fn check_sdpa_gradient(layer: &SDPA<f64>) {
+    let (n, d) = (4, 3);
+    let qkv = Qkv::new(
+        sample((n, d), 0.1),
+        sample((n, d), 0.7),
+        sample((n, d), 1.3),
+    );
+    let r = sample((n, d), 2.1);
+    // the loss is the weighted sum of the attention outputs
+    let loss = |qkv: &Qkv<f64>| (layer.attention(qkv).unwrap() * &r).sum();
+    let grad = layer.gradient(&qkv, &r).unwrap();
+
+    let dq = numerical_gradient(qkv.query(), |q| {
+        loss(&Qkv::new(q.clone(), qkv.key().clone(), qkv.value().clone()))
+    });
+    let dk = numerical_gradient(qkv.key(), |k| {
+        loss(&Qkv::new(
+            qkv.query().clone(),
+            k.clone(),
+            qkv.value().clone(),
+        ))
+    });
+    let dv = numerical_gradient(qkv.value(), |v| {
+        loss(&Qkv::new(qkv.query().clone(), qkv.key().clone(), v.clone()))
+    });
+    assert_close("query", grad.query(), &dq);
+    assert_close("key", grad.key(), &dk);
+    assert_close("value", grad.value(), &dv);
+}
+
+#[test]
+fn test_sdpa_gradient() {
+    check_sdpa_gradient(&SDPA::new(0.0, 1.7));
+}
+
+#[test]
+fn test_sdpa_gradient_causal() {
+    check_sdpa_gradient(&SDPA::new(0.0, 3f64.sqrt()).with_causal(true));
+}
+
+#[test]
+fn test_sdpa_temperature_gradient() {
+    let (n, d) = (4, 3);
+    let qkv = Qkv::new(
+        sample((n, d), 0.2),
+        sample((n, d), 0.5),
+        sample((n, d), 0.9),
+    );
+    let r = sample((n, d), 1.1);
+    let tau = 1.3;
+    let loss = |t: f64| (SDPA::<f64>::new(0.0, t).attention(&qkv).unwrap() * &r).sum();
+    let expected = finite_difference(|eps| loss(tau + eps));
+    let grad = SDPA::<f64>::new(0.0, tau)
+        .temperature_gradient(&qkv, &r)
+        .unwrap();
+    assert!((grad - expected).abs() < TOL, "{grad} != {expected}");
+}
+
+#[test]
+fn test_qkv_projection_gradient() {
+    let (n, d_model, d_head) = (4, 5, 3);
+    let x = sample((n, d_model), 0.3);
+    let r = sample((n, d_head), 1.9);
+    let params = Qkv::new(
+        sample((d_model, d_head), 0.4),
+        sample((d_model, d_head), 0.8),
+        sample((d_model, d_head), 1.2),
+    );
+    let layer = SDPA::<f64>::new(0.0, 3f64.sqrt());
+    let loss = |p: &Qkv<f64>| (layer.attention(&p.project(&x)).unwrap() * &r).sum();
+    let grad = params.gradient(&x, &layer.gradient(&params.project(&x), &r).unwrap());
+
+    let dwq = numerical_gradient(params.query(), |w| {
+        loss(&Qkv::new(
+            w.clone(),
+            params.key().clone(),
+            params.value().clone(),
+        ))
+    });
+    let dwk = numerical_gradient(params.key(), |w| {
+        loss(&Qkv::new(
+            params.query().clone(),
+            w.clone(),
+            params.value().clone(),
+        ))
+    });
+    let dwv = numerical_gradient(params.value(), |w| {
+        loss(&Qkv::new(
+            params.query().clone(),
+            params.key().clone(),
+            w.clone(),
+        ))
+    });
+    assert_close("query", grad.query(), &dwq);
+    assert_close("key", grad.key(), &dwk);
+    assert_close("value", grad.value(), &dwv);
+    // the input gradient should agree with the numerical approximation as well
+    let dx = numerical_gradient(&x, |x| {
+        (layer.attention(&params.project(x)).unwrap() * &r).sum()
+    });
+    let dqkv = layer.gradient(&params.project(&x), &r).unwrap();
+    assert_close("input", &params.input_gradient(&dqkv), &dx);
+}
+
+#[test]
+fn test_qkv_backward() {
+    let (n, d_model, d_head) = (4, 5, 3);
+    let x = sample((n, d_model), 0.3);
+    let delta = Qkv::new(
+        sample((n, d_head), 0.1),
+        sample((n, d_head), 0.2),
+        sample((n, d_head), 0.3),
+    );
+    let mut params = Qkv::<f64>::zeros((d_model, d_head));
+    let grad = params.gradient(&x, &delta);
+    params.backward(&x, &delta, 0.5);
+    assert_close("query", params.query(), &(grad.query() * 0.5));
+    assert_close("key", params.key(), &(grad.key() * 0.5));
+    assert_close("value", params.value(), &(grad.value() * 0.5));
+}
+
+#[test]
+fn test_multi_head_attention_gradient() {
+    let (n, d_model, heads) = (3, 4, 2);
+    let d_head = d_model / heads;
+    let x = sample((n, d_model), 0.6);
+    let r = sample((n, d_model), 1.4);
+    let build = |wo: &Array2<f64>| {
+        let heads = (0..heads)
+            .map(|h| {
+                let h = h as f64;
+                Qkv::new(
+                    sample((d_model, d_head), h + 0.1),
+                    sample((d_model, d_head), h + 0.5),
+                    sample((d_model, d_head), h + 0.9),
+                )
+            })
+            .collect();
+        let output = cnc::params::Params::from_weights(wo.clone());
+        MultiHeadAttention::from_params(d_model, heads, output).unwrap()
+    };
+    let wo = sample((d_model, d_model), 2.2);
+    let layer = build(&wo);
+    // verify the gradient w.r.t. the input
+    let dx = numerical_gradient(&x, |x| (layer.forward(x).unwrap() * &r).sum());
+    assert_close("input", &layer.input_gradient(&x, &r).unwrap(), &dx);
+    // verify the update applied to the output projection
+    let dwo = numerical_gradient(&wo, |w| (build(w).forward(&x).unwrap() * &r).sum());
+    let mut trained = build(&wo);
+    trained.backward(&x, &r, 1.0);
+    assert_close("output", &(trained.output().weights() - &wo), &dwo);
+    // the fallible gradients agree with the updates applied by the backward pass
+    let (_, output, grad) = layer.gradients(&x, &r).unwrap();
+    assert_close("output", output.weights(), &dwo);
+    assert_close("input", &grad, &dx);
+    // a mask that cannot be broadcast onto the scores is an error rather than a panic
+    let mut masked = build(&wo);
+    masked
+        .attention_mut()
+        .set_mask(Array2::zeros((n + 1, n + 1)));
+    assert!(masked.gradients(&x, &r).is_err());
+}
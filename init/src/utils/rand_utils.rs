@@ -3,7 +3,8 @@
    Contrib: FL03 <jo3mccain@icloud.com>
 */
 use crate::NdRandom;
-use ndarray::{Array, ArrayBase, DataOwned, Dimension, IntoDimension, ShapeBuilder};
+use ndarray::{Array, Array2, ArrayBase, DataOwned, Dimension, IntoDimension, ShapeBuilder};
+use num_traits::Float;
 use rand::{SeedableRng, rngs};
 use rand_distr::{
     Distribution, StandardNormal,
@@ -62,3 +63,71 @@ where
         .map(|distr| ArrayBase::rand_with(shape, &distr, &mut rngs::StdRng::seed_from_u64(key)))
         .map_err(Into::into)
 }
+/// Generate a random matrix whose rows (or columns, whichever are fewer) are orthonormal,
+/// scaled by the given `gain`. The matrix is computed by orthonormalizing a sample of the
+/// [`StandardNormal`] distribution using the (modified) Gram-Schmidt process.
+///
+/// Orthogonal matrices preserve the norm of the vectors they multiply, making them a popular
+/// choice for initializing the recurrent weights of a network.
+pub fn orthogonal<A>(shape: (usize, usize), gain: A) -> Array2<A>
+where
+    A: 'static + Float,
+    StandardNormal: Distribution<A>,
+{
+    orthogonal_with(shape, gain, &mut rngs::SmallRng::from_rng(&mut rand::rng()))
+}
+/// Generate a random orthogonal matrix, as in [`orthogonal`], using the given random number
+/// generator
+pub fn orthogonal_with<A, R>(shape: (usize, usize), gain: A, rng: &mut R) -> Array2<A>
+where
+    A: 'static + Float,
+    R: rand::Rng + ?Sized,
+    StandardNormal: Distribution<A>,
+{
+    let (rows, cols) = shape;
+    // orthonormalize the vectors along the longer axis of the matrix
+    let transpose = rows > cols;
+    let mut q: Array2<A> = ArrayBase::rand_with(
+        if transpose {
+            (cols, rows)
+        } else {
+            (rows, cols)
+        },
+        StandardNormal,
+        rng,
+    );
+    for i in 0..q.nrows() {
+        for j in 0..i {
+            let proj = q.row(i).dot(&q.row(j));
+            let prev = q.row(j).to_owned();
+            q.row_mut(i).zip_mut_with(&prev, |x, &p| *x = *x - proj * p);
+        }
+        let norm = q.row(i).dot(&q.row(i)).sqrt();
+        if norm > A::epsilon() {
+            q.row_mut(i).mapv_inplace(|x| x / norm);
+        }
+    }
+    q.mapv_inplace(|x| gain * x);
+    if transpose { q.reversed_axes() } else { q }
+}
+/// Create a new (small) random number generator seeded with the given key or, when no key is
+/// provided, from the entropy source of the operating system
+pub fn rng_from_seed(seed: Option<u64>) -> rngs::SmallRng {
+    match seed {
+        Some(seed) => rngs::SmallRng::seed_from_u64(seed),
+        None => rngs::SmallRng::from_os_rng(),
+    }
+}
+#[cfg(feature = "alloc")]
+/// Generate a random permutation of the indices `0..n` using the given random number
+/// generator
+pub fn permutation_with<R>(n: usize, rng: &mut R) -> alloc::vec::Vec<usize>
+where
+    R: rand::Rng + ?Sized,
+{
+    use rand::seq::SliceRandom;
+
+    let mut indices = (0..n).collect::<alloc::vec::Vec<_>>();
+    indices.shuffle(rng);
+    indices
+}
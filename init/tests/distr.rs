@@ -40,3 +40,19 @@ fn test_truncnorm() {
     let arr = Array::truncnorm(shape, mean, std).unwrap();
     assert!(arr.iter().all(|&x| x >= -bnd && x <= bnd));
 }
+
+#[test]
+fn test_orthogonal() {
+    for shape in [(4, 4), (3, 5), (6, 2)] {
+        let q = concision_init::orthogonal::<f64>(shape, 2.0);
+        assert_eq!(q.dim(), shape);
+        // the shorter axis of the matrix is orthogonal with a norm equal to the gain
+        let gram = if shape.0 <= shape.1 {
+            q.dot(&q.t())
+        } else {
+            q.t().dot(&q)
+        };
+        let eye = Array2::<f64>::eye(gram.nrows()) * 4.0;
+        assert!((gram - eye).iter().all(|x| x.abs() < 1e-9));
+    }
+}
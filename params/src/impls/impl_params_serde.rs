@@ -13,6 +13,16 @@ use core::marker::PhantomData;
 
 const FIELDS: [&str; 2] = ["bias", "weights"];
 
+/// the fields of the [`ParamsBase`]; deserializing the keys into an identifier (rather than a
+/// borrowed string) supports any deserializer, including those that can only provide owned
+/// or escaped keys
+#[derive(serde::Deserialize)]
+#[serde(field_identifier, rename_all = "lowercase")]
+enum Field {
+    Bias,
+    Weights,
+}
+
 struct ParamsBaseVisitor<S, D>
 where
     D: Dimension,
@@ -47,6 +57,27 @@ where
 
         Ok(ParamsBase { bias, weights })
     }
+
+    fn visit_map<M>(self, mut map: M) -> Result<Self::Value, M::Error>
+    where
+        M: serde::de::MapAccess<'a>,
+    {
+        let (mut bias, mut weights) = (None, None);
+        while let Some(key) = map.next_key::<Field>()? {
+            match key {
+                Field::Bias if bias.is_some() => return Err(Error::duplicate_field("bias")),
+                Field::Bias => bias = Some(map.next_value()?),
+                Field::Weights if weights.is_some() => {
+                    return Err(Error::duplicate_field("weights"));
+                }
+                Field::Weights => weights = Some(map.next_value()?),
+            }
+        }
+        Ok(ParamsBase {
+            bias: bias.ok_or_else(|| Error::missing_field("bias"))?,
+            weights: weights.ok_or_else(|| Error::missing_field("weights"))?,
+        })
+    }
 }
 
 impl<'a, A, S, D> Deserialize<'a> for ParamsBase<S, D, A>
@@ -0,0 +1,39 @@
+/*
+    Appellation: serde <test>
+    Created At: 2026.10.18:13:31:08
+    Contrib: @FL03
+*/
+#![cfg(feature = "json")]
+use concision_params::Params;
+
+#[test]
+fn test_params_json() {
+    let params = Params::<f64>::from_elem((3, 2), 0.5);
+    let json = serde_json::to_string(&params).unwrap();
+    let decoded: Params<f64> = serde_json::from_str(&json).unwrap();
+    assert_eq!(decoded, params);
+    // the fields of the object may appear in any order
+    let reordered =
+        r#"{"weights":{"v":1,"dim":[1,1],"data":[2.0]},"bias":{"v":1,"dim":[1],"data":[1.0]}}"#;
+    let decoded: Params<f64> = serde_json::from_str(reordered).unwrap();
+    assert_eq!(decoded.weights()[[0, 0]], 2.0);
+    assert_eq!(decoded.bias()[0], 1.0);
+    assert!(
+        serde_json::from_str::<Params<f64>>(r#"{"bias":{"v":1,"dim":[1],"data":[1.0]}}"#).is_err()
+    );
+}
+
+#[test]
+fn test_params_json_owned_keys() {
+    let params = Params::<f64>::from_elem((2, 3), 1.5);
+    // values are unable to lend their keys to the deserializer
+    let value = serde_json::to_value(&params).unwrap();
+    let decoded: Params<f64> = serde_json::from_value(value).unwrap();
+    assert_eq!(decoded, params);
+    // escaped keys must be unescaped into an owned buffer
+    let escaped = r#"{"bi\u0061s":{"v":1,"dim":[1],"data":[1.0]},"weights":{"v":1,"dim":[1,1],"data":[2.0]}}"#;
+    let decoded: Params<f64> = serde_json::from_str(escaped).unwrap();
+    assert_eq!(decoded.bias()[0], 1.0);
+    let unknown = r#"{"bias":{"v":1,"dim":[1],"data":[1.0]},"gamma":1.0}"#;
+    assert!(serde_json::from_str::<Params<f64>>(unknown).is_err());
+}
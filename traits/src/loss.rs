@@ -20,6 +20,18 @@ pub trait Loss<X, Y> {
     fn loss(&self, lhs: &X, rhs: &Y) -> Self::Output;
 }
 
+/// The [`LossGradient`] trait extends the [`Loss`] trait to compute the gradient of the loss
+/// w.r.t. the `lhs`, i.e. the predictions.
+pub trait LossGradient<X, Y>: Loss<X, Y> {
+    type Grad;
+    /// compute the gradient of the loss w.r.t. the `lhs`
+    fn gradient(&self, lhs: &X, rhs: &Y) -> Self::Grad;
+    /// compute both the loss and its gradient w.r.t. the `lhs`
+    fn loss_with_gradient(&self, lhs: &X, rhs: &Y) -> (Self::Output, Self::Grad) {
+        (self.loss(lhs, rhs), self.gradient(lhs, rhs))
+    }
+}
+
 /// A trait for computing the mean absolute error of a tensor or array
 pub trait MeanAbsoluteError {
     type Output;